@@ -1,5 +1,8 @@
-pub const ANCHOR_DISCRIMINATOR: usize = 8;
+// The constants shared with the other implementations come from `fundraiser-interface`
+pub use fundraiser_interface::state::{
+    ANCHOR_DISCRIMINATOR_LEN as ANCHOR_DISCRIMINATOR, AMENDMENT_GRACE_PERIOD, BPS_SCALER, MAX_ACCEPTED_MINTS, MAX_FEE_BPS, MAX_MILESTONES,
+    MAX_REWARD_TIERS, PERCENTAGE_SCALER,
+};
+
 pub const MIN_AMOUNT_TO_RAISE: u64 = 3;
-pub const SECONDS_TO_DAYS: i64 = 86400;
-pub const MAX_CONTRIBUTION_PERCENTAGE: u64 = 10;
-pub const PERCENTAGE_SCALER: u64 = 100;
\ No newline at end of file
+pub const SOL_DECIMALS: u32 = 9;
\ No newline at end of file
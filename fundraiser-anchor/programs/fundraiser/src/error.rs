@@ -16,6 +16,109 @@ pub enum FundraiserError {
     FundraiserNotEnded,
     #[msg("The fundraiser has ended")]
     FundraiserEnded,
-    #[msg("Invalid total amount. i should be bigger than 3")]
-    InvalidAmount
-}
\ No newline at end of file
+    #[msg("Invalid total amount. It should be bigger than 3")]
+    InvalidAmount,
+    #[msg("The fundraiser has been cancelled")]
+    FundraiserCancelled,
+    #[msg("Invalid milestone schedule")]
+    InvalidMilestones,
+    #[msg("The fundraiser has no milestones")]
+    NoMilestones,
+    #[msg("The milestone is still locked")]
+    MilestoneLocked,
+    #[msg("All milestones have been released")]
+    MilestonesCompleted,
+    #[msg("Funds are released through milestones")]
+    MilestonesConfigured,
+    #[msg("Invalid voting configuration")]
+    InvalidVotingConfig,
+    #[msg("Voting is disabled for this fundraiser")]
+    VotingDisabled,
+    #[msg("The voting window is closed")]
+    VotingWindowClosed,
+    #[msg("The voting window is still open")]
+    VotingWindowOpen,
+    #[msg("The contributor has already voted on this milestone")]
+    AlreadyVoted,
+    #[msg("The contributor has no voting weight")]
+    NoVotingWeight,
+    #[msg("The milestone has not been approved")]
+    MilestoneNotApproved,
+    #[msg("The milestone has been approved")]
+    MilestoneApproved,
+    #[msg("Refunds are disabled for flexible fundraisers")]
+    RefundsDisabled,
+    #[msg("The fundraiser does not raise SOL")]
+    NotSolFundraiser,
+    #[msg("Mints with a transfer hook are not supported")]
+    TransferHookNotSupported,
+    #[msg("Invalid price feed")]
+    InvalidPriceFeed,
+    #[msg("The mint is not accepted by this fundraiser")]
+    MintNotAccepted,
+    #[msg("The mint cannot be added to this fundraiser")]
+    CannotAddMint,
+    #[msg("The fundraiser accepts multiple mints")]
+    MultiMintFundraiser,
+    #[msg("Invalid contribution limits")]
+    InvalidContributionLimits,
+    #[msg("The contribution would exceed the hard cap")]
+    HardCapReached,
+    #[msg("Invalid fundraiser account")]
+    InvalidFundraiserAccount,
+    #[msg("The fundraiser issues receipt tokens")]
+    ReceiptsEnabled,
+    #[msg("The fundraiser does not issue receipt tokens")]
+    ReceiptsDisabled,
+    #[msg("Receipt tokens cannot be enabled for this fundraiser")]
+    CannotEnableReceipts,
+    #[msg("Invalid receipt mint")]
+    InvalidReceiptMint,
+    #[msg("Invalid reward tiers")]
+    InvalidRewardTiers,
+    #[msg("Reward tiers cannot change once contributions came in")]
+    RewardTiersLocked,
+    #[msg("Invalid reward tier")]
+    InvalidRewardTier,
+    #[msg("The reward tier is sold out")]
+    RewardTierSoldOut,
+    #[msg("The pledge is below the minimum of the reward tier")]
+    PledgeBelowTierMinimum,
+    #[msg("The platform fee is above the maximum")]
+    InvalidFee,
+    #[msg("Invalid fee recipient")]
+    InvalidFeeRecipient,
+    #[msg("Only the upgrade authority can configure the platform")]
+    NotUpgradeAuthority,
+    #[msg("The fundraiser has no amendments left")]
+    AmendmentsExhausted,
+    #[msg("An amendment can only extend the deadline or lower the goal")]
+    InvalidAmendment,
+    #[msg("Contributors can still withdraw after the last amendment")]
+    GracePeriodActive
+}
+
+// Every error has to keep the code the other implementations use for it
+macro_rules! assert_shared_codes {
+    ($($variant:ident),* $(,)?) => {
+        $(const _: () = assert!(
+            FundraiserError::$variant as u32 + anchor_lang::error::ERROR_CODE_OFFSET
+                == fundraiser_interface::error::FundraiserError::$variant as u32
+        );)*
+    };
+}
+
+assert_shared_codes!(
+    TargetNotMet, TargetMet, ContributionTooBig, ContributionTooSmall,
+    MaximumContributionsReached, FundraiserNotEnded, FundraiserEnded, InvalidAmount,
+    FundraiserCancelled, InvalidMilestones, NoMilestones, MilestoneLocked,
+    MilestonesCompleted, MilestonesConfigured, InvalidVotingConfig, VotingDisabled,
+    VotingWindowClosed, VotingWindowOpen, AlreadyVoted, NoVotingWeight,
+    MilestoneNotApproved, MilestoneApproved, RefundsDisabled, NotSolFundraiser,
+    TransferHookNotSupported, InvalidPriceFeed, MintNotAccepted, CannotAddMint,
+    MultiMintFundraiser, InvalidContributionLimits, HardCapReached, InvalidFundraiserAccount,
+    ReceiptsEnabled, ReceiptsDisabled, CannotEnableReceipts, InvalidReceiptMint,
+    InvalidRewardTiers, RewardTiersLocked, InvalidRewardTier, RewardTierSoldOut,
+    PledgeBelowTierMinimum, InvalidFee, InvalidFeeRecipient, NotUpgradeAuthority,
+    AmendmentsExhausted, InvalidAmendment, GracePeriodActive,
+);
\ No newline at end of file
@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+use fundraiser_interface::event;
+
+// Field order and types follow `fundraiser_interface::event`, the native and optimised programs log the same bytes.
+// SOL fundraisers log the default pubkey as their mint.
+
+#[event]
+pub struct FundraiserCreated {
+    pub fundraiser: Pubkey,
+    pub maker: Pubkey,
+    pub mint_to_raise: Pubkey,
+    pub amount_to_raise: u64,
+    pub time_ending: i64,
+}
+
+// What reached the vault, after any transfer fee
+#[event]
+pub struct Contributed {
+    pub fundraiser: Pubkey,
+    pub contributor: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct Refunded {
+    pub fundraiser: Pubkey,
+    pub contributor: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+}
+
+// Paid out to the maker, by the checkers or a milestone release
+#[event]
+pub struct Withdrawn {
+    pub fundraiser: Pubkey,
+    pub maker: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct Cancelled {
+    pub fundraiser: Pubkey,
+    pub maker: Pubkey,
+}
+
+// The goal and deadline after the maker extended the deadline or lowered the goal
+#[event]
+pub struct Amended {
+    pub fundraiser: Pubkey,
+    pub maker: Pubkey,
+    pub amount_to_raise: u64,
+    pub time_ending: i64,
+}
+
+const fn same_discriminator(a: [u8; 8], b: [u8; event::DISCRIMINATOR_LEN]) -> bool {
+    let mut index = 0;
+    while index < a.len() {
+        if a[index] != b[index] {
+            return false;
+        }
+        index += 1;
+    }
+    true
+}
+
+// The interface hardcodes Anchor's discriminators, fail the build if they ever drift apart
+const _: () = {
+    assert!(same_discriminator(FundraiserCreated::DISCRIMINATOR, event::FUNDRAISER_CREATED));
+    assert!(same_discriminator(Contributed::DISCRIMINATOR, event::CONTRIBUTED));
+    assert!(same_discriminator(Refunded::DISCRIMINATOR, event::REFUNDED));
+    assert!(same_discriminator(Withdrawn::DISCRIMINATOR, event::WITHDRAWN));
+    assert!(same_discriminator(Cancelled::DISCRIMINATOR, event::CANCELLED));
+    assert!(same_discriminator(Amended::DISCRIMINATOR, event::AMENDED));
+};
\ No newline at end of file
@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken, 
+    token_interface::{
+        Mint, 
+        TokenInterface, 
+        TokenAccount
+    }
+};
+
+use crate::{
+    instructions::check_transfer_hook, 
+    state::{
+        Fundraiser, 
+        FundraiserStatus, 
+        PriceFeed
+    }, 
+    FundraiserError, 
+    MAX_ACCEPTED_MINTS
+};
+
+#[derive(Accounts)]
+pub struct AddAcceptedMint<'info> {
+    #[account(mut)]
+    pub maker: Signer<'info>,
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        seeds = [b"price_feed", price_feed.authority.as_ref(), mint.key().as_ref()],
+        bump = price_feed.bump,
+    )]
+    pub price_feed: Account<'info, PriceFeed>,
+    #[account(
+        mut,
+        has_one = maker,
+        seeds = [b"fundraiser".as_ref(), maker.key().as_ref(), fundraiser.campaign_id.to_le_bytes().as_ref()],
+        bump = fundraiser.bump,
+    )]
+    pub fundraiser: Account<'info, Fundraiser>,
+    #[account(
+        init,
+        payer = maker,
+        associated_token::mint = mint,
+        associated_token::authority = fundraiser,
+        associated_token::token_program = token_program,
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl<'info> AddAcceptedMint<'info> {
+    pub fn add_accepted_mint(&mut self) -> Result<()> {
+
+        // Mints can only be added to a token fundraiser paying out in one go, before anyone has contributed
+        require!(
+            self.fundraiser.status == FundraiserStatus::Active
+                && !self.fundraiser.is_sol()
+                && !self.fundraiser.issues_receipts()
+                && self.fundraiser.current_amount == 0
+                && self.fundraiser.milestone_count == 0
+                && self.fundraiser.voting_period == 0,
+            FundraiserError::CannotAddMint
+        );
+
+        check_transfer_hook(&self.mint.to_account_info())?;
+
+        // The first extra mint turns the fundraiser into a multi-mint one, the original mint stays accepted
+        if !self.fundraiser.is_multi_mint() {
+            self.fundraiser.accepted_mints[0] = self.fundraiser.mint_to_raise;
+            self.fundraiser.accepted_mint_count = 1;
+            self.fundraiser.price_authority = self.price_feed.authority;
+        }
+
+        // Every accepted mint is valued by the same price authority
+        require_keys_eq!(
+            self.price_feed.authority,
+            self.fundraiser.price_authority,
+            FundraiserError::InvalidPriceFeed
+        );
+
+        require!(
+            (self.fundraiser.accepted_mint_count as usize) < MAX_ACCEPTED_MINTS
+                && self.fundraiser.accepted_mint_index(&self.mint.key()).is_none(),
+            FundraiserError::CannotAddMint
+        );
+
+        let index = self.fundraiser.accepted_mint_count as usize;
+        self.fundraiser.accepted_mints[index] = self.mint.key();
+        self.fundraiser.accepted_mint_count += 1;
+
+        Ok(())
+    }
+}
\ No newline at end of file
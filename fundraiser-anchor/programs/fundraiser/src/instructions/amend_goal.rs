@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::{state::Fundraiser, FundraiserError};
+use crate::events::Amended;
+
+#[derive(Accounts)]
+pub struct AmendGoal<'info> {
+    pub maker: Signer<'info>,
+    #[account(
+        mut,
+        has_one = maker,
+        seeds = [b"fundraiser".as_ref(), maker.key().as_ref(), fundraiser.campaign_id.to_le_bytes().as_ref()],
+        bump = fundraiser.bump,
+    )]
+    pub fundraiser: Account<'info, Fundraiser>,
+}
+
+impl<'info> AmendGoal<'info> {
+    pub fn amend_goal(&mut self, amount: u64) -> Result<()> {
+
+        // The goal can only come down, and never to nothing
+        require!(
+            amount > 0,
+            FundraiserError::InvalidAmount
+        );
+        require!(
+            amount < self.fundraiser.amount_to_raise,
+            FundraiserError::InvalidAmendment
+        );
+
+        // The contribution limits have to hold for the new goal too
+        self.fundraiser.contribution_limits.validate(amount)?;
+
+        // Count the amendment, contributors can withdraw until the grace period is over
+        self.fundraiser.amend(Clock::get()?.unix_timestamp)?;
+        self.fundraiser.amount_to_raise = amount;
+
+        emit!(Amended {
+            fundraiser: self.fundraiser.key(),
+            maker: self.maker.key(),
+            amount_to_raise: amount,
+            time_ending: self.fundraiser.time_ending,
+        });
+
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{
+        Fundraiser, 
+        FundraiserStatus
+    }, 
+    FundraiserError
+};
+use crate::events::Cancelled;
+
+#[derive(Accounts)]
+pub struct Cancel<'info> {
+    pub maker: Signer<'info>,
+    #[account(
+        mut,
+        has_one = maker,
+        seeds = [b"fundraiser".as_ref(), maker.key().as_ref(), fundraiser.campaign_id.to_le_bytes().as_ref()],
+        bump = fundraiser.bump,
+    )]
+    pub fundraiser: Account<'info, Fundraiser>,
+}
+
+impl<'info> Cancel<'info> {
+    pub fn cancel(&mut self) -> Result<()> {
+
+        // Check that the fundraiser has not been cancelled already
+        require!(
+            self.fundraiser.status == FundraiserStatus::Active,
+            FundraiserError::FundraiserCancelled
+        );
+
+        // Once a tranche has left the vault, refunds could no longer be honoured in full
+        require!(
+            self.fundraiser.milestones_released == 0,
+            FundraiserError::MilestonesConfigured
+        );
+
+        // Flip the fundraiser into the cancelled state, contributors can now refund right away
+        self.fundraiser.status = FundraiserStatus::Cancelled;
+
+        emit!(Cancelled {
+            fundraiser: self.fundraiser.key(),
+            maker: self.maker.key(),
+        });
+
+        Ok(())
+    }
+}
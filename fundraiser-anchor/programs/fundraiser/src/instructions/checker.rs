@@ -1,28 +1,36 @@
 use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken, 
-    token::{
-        transfer, 
+    token_interface::{
+        transfer_checked, 
         Mint, 
-        Token, 
+        TokenInterface, 
         TokenAccount, 
-        Transfer
+        TransferChecked
     }
 };
 
 use crate::{
-    state::Fundraiser, 
+    state::{
+        Config,
+        Fundraiser, 
+        FundraiserStatus, 
+        FundingMode
+    }, 
     FundraiserError
 };
+use fundraiser_interface::state::fee_amount;
+use crate::events::Withdrawn;
 
 #[derive(Accounts)]
 pub struct CheckContributions<'info> {
     #[account(mut)]
     pub maker: Signer<'info>,
-    pub mint_to_raise: Account<'info, Mint>,
+    pub mint_to_raise: InterfaceAccount<'info, Mint>,
     #[account(
         mut,
-        seeds = [b"fundraiser".as_ref(), maker.key().as_ref()],
+        constraint = !fundraiser.is_multi_mint() @ FundraiserError::MultiMintFundraiser,
+        seeds = [b"fundraiser".as_ref(), maker.key().as_ref(), fundraiser.campaign_id.to_le_bytes().as_ref()],
         bump = fundraiser.bump,
         close = maker,
     )]
@@ -31,36 +39,102 @@ pub struct CheckContributions<'info> {
         mut,
         associated_token::mint = mint_to_raise,
         associated_token::authority = fundraiser,
+        associated_token::token_program = token_program,
     )]
-    pub vault: Account<'info, TokenAccount>,
+    pub vault: InterfaceAccount<'info, TokenAccount>,
     #[account(
         init_if_needed,
         payer = maker,
         associated_token::mint = mint_to_raise,
         associated_token::authority = maker,
+        associated_token::token_program = token_program,
     )]
-    pub maker_ata: Account<'info, TokenAccount>,
-    pub token_program: Program<'info, Token>,
+    pub maker_ata: InterfaceAccount<'info, TokenAccount>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
     pub associated_token_program: Program<'info, AssociatedToken>,
+    // Only needed when the fundraiser pays a platform fee
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Option<Account<'info, Config>>,
+    #[account(mut)]
+    pub fee_recipient_ata: Option<InterfaceAccount<'info, TokenAccount>>,
 }
 
 impl<'info> CheckContributions<'info> {
     pub fn check_contributions(&self) -> Result<()> {
+
+        // A cancelled fundraiser can only be refunded
+        require!(
+            self.fundraiser.status == FundraiserStatus::Active,
+            FundraiserError::FundraiserCancelled
+        );
+
+        // A fundraiser with a milestone schedule pays out through release_milestone
+        require!(
+            self.fundraiser.milestone_count == 0,
+            FundraiserError::MilestonesConfigured
+        );
         
-        // Check if the target amount has been met
+        // Contributors get to withdraw after an amendment before the maker is paid
+        let current_time = Clock::get()?.unix_timestamp;
         require!(
-            self.vault.amount >= self.fundraiser.amount_to_raise,
-            FundraiserError::TargetNotMet
+            !self.fundraiser.in_grace_period(current_time),
+            FundraiserError::GracePeriodActive
         );
-        // Transfer the funds to the maker
+
+        match self.fundraiser.funding_mode {
+            // Check if the target amount has been met
+            FundingMode::Fixed => require!(
+                self.fundraiser.current_amount >= self.fundraiser.amount_to_raise,
+                FundraiserError::TargetNotMet
+            ),
+            // A flexible fundraiser pays out whatever was raised once the deadline passes
+            FundingMode::Flexible => {
+                require!(
+                    current_time >= self.fundraiser.time_ending,
+                    FundraiserError::FundraiserNotEnded
+                );
+            }
+        }
+        // Pay out what the contributions were credited with, tokens sent straight to the vault don't count
+        let amount = self.fundraiser.current_amount;
+
+        // The platform takes its cut at the rate snapshotted when the fundraiser was created
+        let fee = fee_amount(amount, self.fundraiser.fee_bps);
+        if fee > 0 {
+            let (Some(config), Some(fee_recipient_ata)) = (&self.config, &self.fee_recipient_ata) else {
+                return err!(FundraiserError::InvalidFeeRecipient);
+            };
+            require!(
+                fee_recipient_ata.owner == config.fee_recipient && fee_recipient_ata.mint == self.mint_to_raise.key(),
+                FundraiserError::InvalidFeeRecipient
+            );
+            self.transfer(fee_recipient_ata.to_account_info(), fee)?;
+        }
+
+        // Transfer the rest of the funds from the vault to the maker
+        self.transfer(self.maker_ata.to_account_info(), amount - fee)?;
+
+        emit!(Withdrawn {
+            fundraiser: self.fundraiser.key(),
+            maker: self.maker.key(),
+            mint: self.mint_to_raise.key(),
+            amount: amount - fee,
+        });
+
+        Ok(())
+    }
+    fn transfer(&self, to: AccountInfo<'info>, amount: u64) -> Result<()> {
         // CPI to the token program to transfer the funds
         let cpi_program = self.token_program.to_account_info();
 
-        // Transfer the funds from the vault to the maker
-        let cpi_accounts = Transfer {
+        let cpi_accounts = TransferChecked {
             from: self.vault.to_account_info(),
-            to: self.maker_ata.to_account_info(),
+            mint: self.mint_to_raise.to_account_info(),
+            to,
             authority: self.fundraiser.to_account_info(),
         };
 
@@ -68,15 +142,13 @@ impl<'info> CheckContributions<'info> {
         let signer_seeds: [&[&[u8]]; 1] = [&[
             b"fundraiser".as_ref(),
             self.maker.to_account_info().key.as_ref(),
+            &self.fundraiser.campaign_id.to_le_bytes(),
             &[self.fundraiser.bump],
         ]];
 
         // CPI context with signer since the fundraiser account is a PDA
         let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, &signer_seeds);
 
-        // Transfer the funds from the vault to the maker
-        transfer(cpi_ctx, self.vault.amount)?;
-
-        Ok(())
+        transfer_checked(cpi_ctx, amount, self.mint_to_raise.decimals)
     }
 }
\ No newline at end of file
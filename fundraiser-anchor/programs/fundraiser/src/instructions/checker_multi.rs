@@ -0,0 +1,159 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken, 
+    token_interface::{
+        transfer_checked, 
+        Mint, 
+        TokenInterface, 
+        TokenAccount, 
+        TransferChecked
+    }
+};
+
+use crate::{
+    state::{
+        Config,
+        Fundraiser, 
+        FundraiserStatus, 
+        FundingMode,
+        PriceFeed
+    }, 
+    FundraiserError
+};
+use fundraiser_interface::state::fee_amount;
+use crate::events::Withdrawn;
+
+#[derive(Accounts)]
+pub struct CheckContributionsMulti<'info> {
+    #[account(mut)]
+    pub maker: Signer<'info>,
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        seeds = [b"price_feed", fundraiser.price_authority.as_ref(), mint.key().as_ref()],
+        bump = price_feed.bump,
+    )]
+    pub price_feed: Account<'info, PriceFeed>,
+    #[account(
+        mut,
+        has_one = maker,
+        seeds = [b"fundraiser".as_ref(), maker.key().as_ref(), fundraiser.campaign_id.to_le_bytes().as_ref()],
+        bump = fundraiser.bump,
+    )]
+    pub fundraiser: Account<'info, Fundraiser>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = fundraiser,
+        associated_token::token_program = token_program,
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        init_if_needed,
+        payer = maker,
+        associated_token::mint = mint,
+        associated_token::authority = maker,
+        associated_token::token_program = token_program,
+    )]
+    pub maker_ata: InterfaceAccount<'info, TokenAccount>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    // Only needed when the fundraiser pays a platform fee
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Option<Account<'info, Config>>,
+    #[account(mut)]
+    pub fee_recipient_ata: Option<InterfaceAccount<'info, TokenAccount>>,
+}
+
+impl<'info> CheckContributionsMulti<'info> {
+    // Pays out the vault of one accepted mint, the maker calls this once per mint
+    pub fn check_contributions_multi(&mut self, price_feeds: &[AccountInfo<'info>]) -> Result<()> {
+
+        // A cancelled fundraiser can only be refunded
+        require!(
+            self.fundraiser.status == FundraiserStatus::Active,
+            FundraiserError::FundraiserCancelled
+        );
+
+        require!(
+            self.fundraiser.accepted_mint_index(&self.mint.key()).is_some(),
+            FundraiserError::MintNotAccepted
+        );
+
+        // Contributors get to withdraw after an amendment before the maker is paid
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(
+            !self.fundraiser.in_grace_period(current_time),
+            FundraiserError::GracePeriodActive
+        );
+
+        match self.fundraiser.funding_mode {
+            // Value every vault at the current prices, once the first payout went through the target counts as met
+            FundingMode::Fixed => require!(
+                self.fundraiser.amount_released > 0
+                    || self.fundraiser.value_raised(price_feeds)? >= self.fundraiser.amount_to_raise,
+                FundraiserError::TargetNotMet
+            ),
+            // A flexible fundraiser pays out whatever was raised once the deadline passes
+            FundingMode::Flexible => {
+                require!(
+                    current_time >= self.fundraiser.time_ending,
+                    FundraiserError::FundraiserNotEnded
+                );
+            }
+        }
+
+        let amount = self.vault.amount;
+
+        // The platform takes its cut at the rate snapshotted when the fundraiser was created
+        let fee = fee_amount(amount, self.fundraiser.fee_bps);
+        if fee > 0 {
+            let (Some(config), Some(fee_recipient_ata)) = (&self.config, &self.fee_recipient_ata) else {
+                return err!(FundraiserError::InvalidFeeRecipient);
+            };
+            require!(
+                fee_recipient_ata.owner == config.fee_recipient && fee_recipient_ata.mint == self.mint.key(),
+                FundraiserError::InvalidFeeRecipient
+            );
+            self.transfer(fee_recipient_ata.to_account_info(), fee)?;
+        }
+
+        // Transfer the rest of the funds from the vault to the maker
+        self.transfer(self.maker_ata.to_account_info(), amount - fee)?;
+
+        // Track the value paid out so far in the common unit, the fee included
+        self.fundraiser.amount_released += self.price_feed.value(amount);
+
+        emit!(Withdrawn {
+            fundraiser: self.fundraiser.key(),
+            maker: self.maker.key(),
+            mint: self.mint.key(),
+            amount: amount - fee,
+        });
+
+        Ok(())
+    }
+    fn transfer(&self, to: AccountInfo<'info>, amount: u64) -> Result<()> {
+        let cpi_accounts = TransferChecked {
+            from: self.vault.to_account_info(),
+            mint: self.mint.to_account_info(),
+            to,
+            authority: self.fundraiser.to_account_info(),
+        };
+
+        // Signer seeds to sign the CPI on behalf of the fundraiser account
+        let signer_seeds: [&[&[u8]]; 1] = [&[
+            b"fundraiser".as_ref(),
+            self.maker.to_account_info().key.as_ref(),
+            &self.fundraiser.campaign_id.to_le_bytes(),
+            &[self.fundraiser.bump],
+        ]];
+
+        let cpi_ctx = CpiContext::new_with_signer(self.token_program.to_account_info(), cpi_accounts, &signer_seeds);
+
+        transfer_checked(cpi_ctx, amount, self.mint.decimals)
+    }
+}
\ No newline at end of file
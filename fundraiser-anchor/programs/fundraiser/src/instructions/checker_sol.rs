@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{
+        Config,
+        Fundraiser, 
+        FundraiserStatus, 
+        FundingMode
+    }, 
+    FundraiserError
+};
+use fundraiser_interface::state::fee_amount;
+use crate::events::Withdrawn;
+
+#[derive(Accounts)]
+pub struct CheckContributionsSol<'info> {
+    #[account(mut)]
+    pub maker: Signer<'info>,
+    #[account(
+        mut,
+        constraint = fundraiser.is_sol() @ FundraiserError::NotSolFundraiser,
+        seeds = [b"fundraiser".as_ref(), maker.key().as_ref(), fundraiser.campaign_id.to_le_bytes().as_ref()],
+        bump = fundraiser.bump,
+        close = maker,
+    )]
+    pub fundraiser: Account<'info, Fundraiser>,
+    pub system_program: Program<'info, System>,
+    // Only needed when the fundraiser pays a platform fee
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Option<Account<'info, Config>>,
+    #[account(mut)]
+    pub fee_recipient: Option<SystemAccount<'info>>,
+}
+
+impl<'info> CheckContributionsSol<'info> {
+    pub fn check_contributions_sol(&self) -> Result<()> {
+
+        // A cancelled fundraiser can only be refunded
+        require!(
+            self.fundraiser.status == FundraiserStatus::Active,
+            FundraiserError::FundraiserCancelled
+        );
+
+        // Contributors get to withdraw after an amendment before the maker is paid
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(
+            !self.fundraiser.in_grace_period(current_time),
+            FundraiserError::GracePeriodActive
+        );
+
+        match self.fundraiser.funding_mode {
+            // Check if the target amount has been met
+            FundingMode::Fixed => require!(
+                self.fundraiser.current_amount >= self.fundraiser.amount_to_raise,
+                FundraiserError::TargetNotMet
+            ),
+            // A flexible fundraiser pays out whatever was raised once the deadline passes
+            FundingMode::Flexible => {
+                require!(
+                    current_time >= self.fundraiser.time_ending,
+                    FundraiserError::FundraiserNotEnded
+                );
+            }
+        }
+
+        // The platform takes its cut at the rate snapshotted when the fundraiser was created
+        let fee = fee_amount(self.fundraiser.current_amount, self.fundraiser.fee_bps);
+        if fee > 0 {
+            let (Some(config), Some(fee_recipient)) = (&self.config, &self.fee_recipient) else {
+                return err!(FundraiserError::InvalidFeeRecipient);
+            };
+            require_keys_eq!(fee_recipient.key(), config.fee_recipient, FundraiserError::InvalidFeeRecipient);
+
+            self.fundraiser.sub_lamports(fee)?;
+            fee_recipient.add_lamports(fee)?;
+        }
+
+        emit!(Withdrawn {
+            fundraiser: self.fundraiser.key(),
+            maker: self.maker.key(),
+            mint: Pubkey::default(),
+            amount: self.fundraiser.current_amount - fee,
+        });
+
+        // Closing the fundraiser account hands the rest of the raised lamports and the rent back to the maker
+
+        Ok(())
+    }
+}
\ No newline at end of file
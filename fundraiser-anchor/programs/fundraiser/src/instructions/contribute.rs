@@ -1,31 +1,34 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{
+use anchor_spl::token_interface::{
     Mint, 
-    transfer, 
-    Token, 
+    transfer_checked, 
+    TokenInterface, 
     TokenAccount, 
-    Transfer
+    TransferChecked
 };
 
 use crate::{
     state::{
         Contributor, 
-        Fundraiser
+        Fundraiser,
+        FundraiserStatus,
+        RewardTiers
     }, FundraiserError, 
-    ANCHOR_DISCRIMINATOR, 
-    MAX_CONTRIBUTION_PERCENTAGE, 
-    PERCENTAGE_SCALER, SECONDS_TO_DAYS
+    ANCHOR_DISCRIMINATOR
 };
+use crate::events::Contributed;
 
 #[derive(Accounts)]
 pub struct Contribute<'info> {
     #[account(mut)]
     pub contributor: Signer<'info>,
-    pub mint_to_raise: Account<'info, Mint>,
+    pub mint_to_raise: InterfaceAccount<'info, Mint>,
     #[account(
         mut,
         has_one = mint_to_raise,
-        seeds = [b"fundraiser".as_ref(), fundraiser.maker.as_ref()],
+        constraint = !fundraiser.is_multi_mint() @ FundraiserError::MultiMintFundraiser,
+        constraint = !fundraiser.issues_receipts() @ FundraiserError::ReceiptsEnabled,
+        seeds = [b"fundraiser".as_ref(), fundraiser.maker.as_ref(), fundraiser.campaign_id.to_le_bytes().as_ref()],
         bump = fundraiser.bump,
     )]
     pub fundraiser: Account<'info, Fundraiser>,
@@ -40,55 +43,58 @@ pub struct Contribute<'info> {
     #[account(
         mut,
         associated_token::mint = mint_to_raise,
-        associated_token::authority = contributor
+        associated_token::authority = contributor,
+        associated_token::token_program = token_program,
     )]
-    pub contributor_ata: Account<'info, TokenAccount>,
+    pub contributor_ata: InterfaceAccount<'info, TokenAccount>,
     #[account(
         mut,
         associated_token::mint = fundraiser.mint_to_raise,
-        associated_token::authority = fundraiser
+        associated_token::authority = fundraiser,
+        associated_token::token_program = token_program,
     )]
-    pub vault: Account<'info, TokenAccount>,
-    pub token_program: Program<'info, Token>,
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
+    // Only needed to pick a reward tier
+    #[account(
+        mut,
+        seeds = [b"reward_tiers", fundraiser.key().as_ref()],
+        bump = reward_tiers.bump,
+    )]
+    pub reward_tiers: Option<Account<'info, RewardTiers>>,
 }
 
 impl<'info> Contribute<'info> {
-    pub fn contribute(&mut self, amount: u64) -> Result<()> {
+    pub fn contribute(&mut self, amount: u64, reward_tier: Option<u8>, bumps: &ContributeBumps) -> Result<()> {
 
-        // Check if the amount to contribute meets the minimum amount required
+        // Check that the maker has not cancelled the fundraiser
         require!(
-            amount > 1_u8.pow(self.mint_to_raise.decimals as u32) as u64, 
-            FundraiserError::ContributionTooSmall
+            self.fundraiser.status == FundraiserStatus::Active,
+            FundraiserError::FundraiserCancelled
         );
 
-        // Check if the amount to contribute is less than the maximum allowed contribution
-        require!(
-            amount <= (self.fundraiser.amount_to_raise * MAX_CONTRIBUTION_PERCENTAGE) / PERCENTAGE_SCALER, 
-            FundraiserError::ContributionTooBig
-        );
+        // Check the contribution against the limits the maker set
+        self.fundraiser.check_contribution(self.contributor_account.amount, amount)?;
 
-        // Check if the fundraising duration has been reached
+        // Check if the fundraiser deadline has been reached
         let current_time = Clock::get()?.unix_timestamp;
         require!(
-            self.fundraiser.duration <= ((current_time - self.fundraiser.time_started) / SECONDS_TO_DAYS) as u8,
+            current_time <= self.fundraiser.time_ending,
             crate::FundraiserError::FundraiserEnded
         );
 
-        // Check if the maximum contributions per contributor have been reached
-        require!(
-            (self.contributor_account.amount <= (self.fundraiser.amount_to_raise * MAX_CONTRIBUTION_PERCENTAGE) / PERCENTAGE_SCALER)
-                && (self.contributor_account.amount + amount <= (self.fundraiser.amount_to_raise * MAX_CONTRIBUTION_PERCENTAGE) / PERCENTAGE_SCALER),
-            FundraiserError::MaximumContributionsReached
-        );
+        // Remember the vault balance, a transfer fee can make the vault receive less than the amount sent
+        let vault_amount_before = self.vault.amount;
 
         // Transfer the funds to the vault
         // CPI to the token program to transfer the funds
         let cpi_program = self.token_program.to_account_info();
 
         // Transfer the funds from the contributor to the vault
-        let cpi_accounts = Transfer {
+        let cpi_accounts = TransferChecked {
             from: self.contributor_ata.to_account_info(),
+            mint: self.mint_to_raise.to_account_info(),
             to: self.vault.to_account_info(),
             authority: self.contributor.to_account_info(),
         };
@@ -97,12 +103,31 @@ impl<'info> Contribute<'info> {
         let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
 
         // Transfer the funds from the contributor to the vault
-        transfer(cpi_ctx, amount)?;
+        transfer_checked(cpi_ctx, amount, self.mint_to_raise.decimals)?;
+
+        // Only credit what actually landed in the vault
+        self.vault.reload()?;
+        let amount_received = self.vault.amount - vault_amount_before;
 
         // Update the fundraiser and contributor accounts with the new amounts
-        self.fundraiser.current_amount += amount;
+        self.fundraiser.current_amount += amount_received;
+
+        self.contributor_account.amount += amount_received;
+        self.contributor_account.bump = bumps.contributor_account;
+
+        // The pledge has to reach the minimum of the tier the contributor picked, with a slot left
+        if let Some(index) = reward_tier {
+            let reward_tiers = self.reward_tiers.as_mut().ok_or(FundraiserError::InvalidRewardTier)?;
+            reward_tiers.claim(self.contributor_account.reward_tier, index, self.contributor_account.amount)?;
+            self.contributor_account.reward_tier = index + 1;
+        }
 
-        self.contributor_account.amount += amount;
+        emit!(Contributed {
+            fundraiser: self.fundraiser.key(),
+            contributor: self.contributor.key(),
+            mint: self.mint_to_raise.key(),
+            amount: amount_received,
+        });
 
         Ok(())
     }
@@ -0,0 +1,125 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    Mint, 
+    transfer_checked, 
+    TokenInterface, 
+    TokenAccount, 
+    TransferChecked
+};
+
+use crate::{
+    state::{
+        Contributor, 
+        Fundraiser,
+        FundraiserStatus,
+        PriceFeed
+    }, FundraiserError, 
+    ANCHOR_DISCRIMINATOR
+};
+use crate::events::Contributed;
+
+#[derive(Accounts)]
+pub struct ContributeMulti<'info> {
+    #[account(mut)]
+    pub contributor: Signer<'info>,
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        seeds = [b"price_feed", fundraiser.price_authority.as_ref(), mint.key().as_ref()],
+        bump = price_feed.bump,
+    )]
+    pub price_feed: Account<'info, PriceFeed>,
+    #[account(
+        mut,
+        seeds = [b"fundraiser".as_ref(), fundraiser.maker.as_ref(), fundraiser.campaign_id.to_le_bytes().as_ref()],
+        bump = fundraiser.bump,
+    )]
+    pub fundraiser: Account<'info, Fundraiser>,
+    #[account(
+        init_if_needed,
+        payer = contributor,
+        seeds = [b"contributor", fundraiser.key().as_ref(), contributor.key().as_ref()],
+        bump,
+        space = ANCHOR_DISCRIMINATOR + Contributor::INIT_SPACE,
+    )]
+    pub contributor_account: Account<'info, Contributor>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = contributor,
+        associated_token::token_program = token_program,
+    )]
+    pub contributor_ata: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = fundraiser,
+        associated_token::token_program = token_program,
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ContributeMulti<'info> {
+    pub fn contribute_multi(&mut self, amount: u64, bumps: &ContributeMultiBumps) -> Result<()> {
+
+        // Check that the maker has not cancelled the fundraiser
+        require!(
+            self.fundraiser.status == FundraiserStatus::Active,
+            FundraiserError::FundraiserCancelled
+        );
+
+        // Check that the fundraiser accepts this mint
+        let index = self.fundraiser
+            .accepted_mint_index(&self.mint.key())
+            .ok_or(FundraiserError::MintNotAccepted)?;
+
+        // Limits apply to the contribution's value in the common unit, not to the token amount
+        let value = self.price_feed.value(amount);
+        self.fundraiser.check_contribution(self.contributor_account.amount, value)?;
+
+        // Check if the fundraiser deadline has been reached
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(
+            current_time <= self.fundraiser.time_ending,
+            crate::FundraiserError::FundraiserEnded
+        );
+
+        // Remember the vault balance, a transfer fee can make the vault receive less than the amount sent
+        let vault_amount_before = self.vault.amount;
+
+        // Transfer the funds from the contributor to the vault of this mint
+        let cpi_accounts = TransferChecked {
+            from: self.contributor_ata.to_account_info(),
+            mint: self.mint.to_account_info(),
+            to: self.vault.to_account_info(),
+            authority: self.contributor.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new(self.token_program.to_account_info(), cpi_accounts);
+
+        transfer_checked(cpi_ctx, amount, self.mint.decimals)?;
+
+        // Only credit what actually landed in the vault
+        self.vault.reload()?;
+        let amount_received = self.vault.amount - vault_amount_before;
+        let value_received = self.price_feed.value(amount_received);
+
+        // Track the tokens per mint so refunds return them as they came in, and their value towards the goal
+        self.fundraiser.amounts_raised[index] += amount_received;
+        self.fundraiser.current_amount += value_received;
+
+        self.contributor_account.amounts[index] += amount_received;
+        self.contributor_account.amount += value_received;
+        self.contributor_account.bump = bumps.contributor_account;
+
+        emit!(Contributed {
+            fundraiser: self.fundraiser.key(),
+            contributor: self.contributor.key(),
+            mint: self.mint.key(),
+            amount: amount_received,
+        });
+
+        Ok(())
+    }
+}
\ No newline at end of file
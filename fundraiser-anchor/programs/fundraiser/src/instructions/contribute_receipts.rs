@@ -0,0 +1,135 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken, 
+    token_interface::{
+        Mint, 
+        mint_to, 
+        MintTo, 
+        transfer_checked, 
+        TokenInterface, 
+        TokenAccount, 
+        TransferChecked
+    }
+};
+
+use crate::{
+    state::{
+        Fundraiser,
+        FundraiserStatus
+    }, FundraiserError
+};
+use crate::events::Contributed;
+
+#[derive(Accounts)]
+pub struct ContributeReceipts<'info> {
+    #[account(mut)]
+    pub contributor: Signer<'info>,
+    pub mint_to_raise: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        address = fundraiser.receipt_mint @ FundraiserError::InvalidReceiptMint,
+    )]
+    pub receipt_mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        has_one = mint_to_raise,
+        constraint = fundraiser.issues_receipts() @ FundraiserError::ReceiptsDisabled,
+        seeds = [b"fundraiser".as_ref(), fundraiser.maker.as_ref(), fundraiser.campaign_id.to_le_bytes().as_ref()],
+        bump = fundraiser.bump,
+    )]
+    pub fundraiser: Account<'info, Fundraiser>,
+    #[account(
+        mut,
+        associated_token::mint = mint_to_raise,
+        associated_token::authority = contributor,
+        associated_token::token_program = token_program,
+    )]
+    pub contributor_ata: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        init_if_needed,
+        payer = contributor,
+        associated_token::mint = receipt_mint,
+        associated_token::authority = contributor,
+        associated_token::token_program = token_program,
+    )]
+    pub contributor_receipt_ata: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        associated_token::mint = fundraiser.mint_to_raise,
+        associated_token::authority = fundraiser,
+        associated_token::token_program = token_program,
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl<'info> ContributeReceipts<'info> {
+    pub fn contribute_receipts(&mut self, amount: u64) -> Result<()> {
+
+        // Check that the maker has not cancelled the fundraiser
+        require!(
+            self.fundraiser.status == FundraiserStatus::Active,
+            FundraiserError::FundraiserCancelled
+        );
+
+        // The receipts held by the contributor take the place of the contributor account
+        self.fundraiser.check_contribution(self.contributor_receipt_ata.amount, amount)?;
+
+        // Check if the fundraiser deadline has been reached
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(
+            current_time <= self.fundraiser.time_ending,
+            FundraiserError::FundraiserEnded
+        );
+
+        // Remember the vault balance, a transfer fee can make the vault receive less than the amount sent
+        let vault_amount_before = self.vault.amount;
+
+        // Transfer the funds from the contributor to the vault
+        let cpi_accounts = TransferChecked {
+            from: self.contributor_ata.to_account_info(),
+            mint: self.mint_to_raise.to_account_info(),
+            to: self.vault.to_account_info(),
+            authority: self.contributor.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new(self.token_program.to_account_info(), cpi_accounts);
+
+        transfer_checked(cpi_ctx, amount, self.mint_to_raise.decimals)?;
+
+        // Only what actually landed in the vault is backed by receipts
+        self.vault.reload()?;
+        let amount_received = self.vault.amount - vault_amount_before;
+
+        // Signer seeds to mint on behalf of the fundraiser account, it is the receipt mint authority
+        let signer_seeds: [&[&[u8]]; 1] = [&[
+            b"fundraiser".as_ref(),
+            self.fundraiser.maker.as_ref(),
+            &self.fundraiser.campaign_id.to_le_bytes(),
+            &[self.fundraiser.bump],
+        ]];
+
+        let cpi_accounts = MintTo {
+            mint: self.receipt_mint.to_account_info(),
+            to: self.contributor_receipt_ata.to_account_info(),
+            authority: self.fundraiser.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new_with_signer(self.token_program.to_account_info(), cpi_accounts, &signer_seeds);
+
+        mint_to(cpi_ctx, amount_received)?;
+
+        self.fundraiser.current_amount += amount_received;
+
+        emit!(Contributed {
+            fundraiser: self.fundraiser.key(),
+            contributor: self.contributor.key(),
+            mint: self.mint_to_raise.key(),
+            amount: amount_received,
+        });
+
+        Ok(())
+    }
+}
\ No newline at end of file
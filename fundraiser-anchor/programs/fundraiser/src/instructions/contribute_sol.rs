@@ -0,0 +1,86 @@
+use anchor_lang::{
+    prelude::*, 
+    system_program::{
+        transfer, 
+        Transfer
+    }
+};
+
+use crate::{
+    state::{
+        Contributor, 
+        Fundraiser,
+        FundraiserStatus
+    }, FundraiserError, 
+    ANCHOR_DISCRIMINATOR
+};
+use crate::events::Contributed;
+
+#[derive(Accounts)]
+pub struct ContributeSol<'info> {
+    #[account(mut)]
+    pub contributor: Signer<'info>,
+    #[account(
+        mut,
+        constraint = fundraiser.is_sol() @ FundraiserError::NotSolFundraiser,
+        seeds = [b"fundraiser".as_ref(), fundraiser.maker.as_ref(), fundraiser.campaign_id.to_le_bytes().as_ref()],
+        bump = fundraiser.bump,
+    )]
+    pub fundraiser: Account<'info, Fundraiser>,
+    #[account(
+        init_if_needed,
+        payer = contributor,
+        seeds = [b"contributor", fundraiser.key().as_ref(), contributor.key().as_ref()],
+        bump,
+        space = ANCHOR_DISCRIMINATOR + Contributor::INIT_SPACE,
+    )]
+    pub contributor_account: Account<'info, Contributor>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ContributeSol<'info> {
+    pub fn contribute_sol(&mut self, amount: u64, bumps: &ContributeSolBumps) -> Result<()> {
+
+        // Check that the maker has not cancelled the fundraiser
+        require!(
+            self.fundraiser.status == FundraiserStatus::Active,
+            FundraiserError::FundraiserCancelled
+        );
+
+        // Check the contribution against the limits the maker set
+        self.fundraiser.check_contribution(self.contributor_account.amount, amount)?;
+
+        // Check if the fundraiser deadline has been reached
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(
+            current_time <= self.fundraiser.time_ending,
+            crate::FundraiserError::FundraiserEnded
+        );
+
+        // Transfer the lamports from the contributor to the fundraiser account
+        let cpi_ctx = CpiContext::new(
+            self.system_program.to_account_info(),
+            Transfer {
+                from: self.contributor.to_account_info(),
+                to: self.fundraiser.to_account_info(),
+            },
+        );
+
+        transfer(cpi_ctx, amount)?;
+
+        // Update the fundraiser and contributor accounts with the new amounts
+        self.fundraiser.current_amount += amount;
+
+        self.contributor_account.amount += amount;
+        self.contributor_account.bump = bumps.contributor_account;
+
+        emit!(Contributed {
+            fundraiser: self.fundraiser.key(),
+            contributor: self.contributor.key(),
+            mint: Pubkey::default(),
+            amount,
+        });
+
+        Ok(())
+    }
+}
\ No newline at end of file
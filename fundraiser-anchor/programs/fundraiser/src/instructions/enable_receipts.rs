@@ -0,0 +1,74 @@
+use anchor_lang::{prelude::*, solana_program::program_pack::Pack};
+use anchor_spl::{
+    token_2022::spl_token_2022,
+    token_interface::{
+        Mint, 
+        TokenInterface
+    }
+};
+
+use crate::{
+    state::{
+        Fundraiser, 
+        FundraiserStatus
+    }, 
+    FundraiserError
+};
+
+#[derive(Accounts)]
+pub struct EnableReceipts<'info> {
+    pub maker: Signer<'info>,
+    #[account(
+        mut,
+        has_one = maker,
+        has_one = mint_to_raise,
+        seeds = [b"fundraiser".as_ref(), maker.key().as_ref(), fundraiser.campaign_id.to_le_bytes().as_ref()],
+        bump = fundraiser.bump,
+    )]
+    pub fundraiser: Account<'info, Fundraiser>,
+    #[account(
+        mint::token_program = token_program,
+    )]
+    pub mint_to_raise: InterfaceAccount<'info, Mint>,
+    #[account(
+        mint::authority = fundraiser,
+        mint::decimals = mint_to_raise.decimals,
+        mint::token_program = token_program,
+    )]
+    pub receipt_mint: InterfaceAccount<'info, Mint>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> EnableReceipts<'info> {
+    pub fn enable_receipts(&mut self) -> Result<()> {
+
+        require!(
+            !self.fundraiser.issues_receipts(),
+            FundraiserError::ReceiptsEnabled
+        );
+
+        // Receipts stand for a share of a single mint paid out in one go, and have to be enabled before anyone has contributed
+        require!(
+            self.fundraiser.status == FundraiserStatus::Active
+                && !self.fundraiser.is_sol()
+                && !self.fundraiser.is_multi_mint()
+                && self.fundraiser.current_amount == 0
+                && self.fundraiser.milestone_count == 0
+                && self.fundraiser.voting_period == 0,
+            FundraiserError::CannotEnableReceipts
+        );
+
+        // Only the fundraiser can mint receipts, a mint without extensions also rules out
+        // a permanent delegate moving or burning receipts on a holder's behalf
+        require!(
+            self.receipt_mint.to_account_info().data_len() == spl_token_2022::state::Mint::LEN
+                && self.receipt_mint.freeze_authority.is_none()
+                && self.receipt_mint.supply == 0,
+            FundraiserError::InvalidReceiptMint
+        );
+
+        self.fundraiser.receipt_mint = self.receipt_mint.key();
+
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::{state::Fundraiser, FundraiserError};
+use crate::events::Amended;
+
+#[derive(Accounts)]
+pub struct ExtendDeadline<'info> {
+    pub maker: Signer<'info>,
+    #[account(
+        mut,
+        has_one = maker,
+        seeds = [b"fundraiser".as_ref(), maker.key().as_ref(), fundraiser.campaign_id.to_le_bytes().as_ref()],
+        bump = fundraiser.bump,
+    )]
+    pub fundraiser: Account<'info, Fundraiser>,
+}
+
+impl<'info> ExtendDeadline<'info> {
+    pub fn extend_deadline(&mut self, time_ending: i64) -> Result<()> {
+
+        // The deadline can only move out
+        require!(
+            time_ending > self.fundraiser.time_ending,
+            FundraiserError::InvalidAmendment
+        );
+
+        // The first milestone, and the vote on it, still have to come after the fundraiser ends
+        require!(
+            self.fundraiser.milestone_count == 0
+                || time_ending <= self.fundraiser.milestones[0].unlock_time - self.fundraiser.voting_period,
+            FundraiserError::InvalidMilestones
+        );
+
+        // Count the amendment, contributors can withdraw until the grace period is over
+        self.fundraiser.amend(Clock::get()?.unix_timestamp)?;
+        self.fundraiser.time_ending = time_ending;
+
+        emit!(Amended {
+            fundraiser: self.fundraiser.key(),
+            maker: self.maker.key(),
+            amount_to_raise: self.fundraiser.amount_to_raise,
+            time_ending,
+        });
+
+        Ok(())
+    }
+}
\ No newline at end of file
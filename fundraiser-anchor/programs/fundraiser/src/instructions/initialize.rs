@@ -1,26 +1,36 @@
 use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken, 
-    token::{
+    token_2022::spl_token_2022::{
+        self, 
+        extension::{
+            transfer_hook::TransferHook, 
+            BaseStateWithExtensions, 
+            StateWithExtensions
+        }
+    }, 
+    token_interface::{
         Mint, 
-        Token, 
+        TokenInterface, 
         TokenAccount
     }
 };
 
 use crate::{
-    state::Fundraiser, FundraiserError, ANCHOR_DISCRIMINATOR, MIN_AMOUNT_TO_RAISE
+    state::{Config, ContributionLimits, Fundraiser, FundraiserStatus, FundingMode, Milestone}, FundraiserError, ANCHOR_DISCRIMINATOR, BPS_SCALER, MAX_ACCEPTED_MINTS, MAX_MILESTONES, MIN_AMOUNT_TO_RAISE, PERCENTAGE_SCALER
 };
+use crate::events::FundraiserCreated;
 
 #[derive(Accounts)]
+#[instruction(campaign_id: u64)]
 pub struct Initialize<'info> {
     #[account(mut)]
     pub maker: Signer<'info>,
-    pub mint_to_raise: Account<'info, Mint>,
+    pub mint_to_raise: InterfaceAccount<'info, Mint>,
     #[account(
         init,
         payer = maker,
-        seeds = [b"fundraiser", maker.key().as_ref()],
+        seeds = [b"fundraiser", maker.key().as_ref(), campaign_id.to_le_bytes().as_ref()],
         bump,
         space = ANCHOR_DISCRIMINATOR + Fundraiser::INIT_SPACE,
     )]
@@ -30,15 +40,35 @@ pub struct Initialize<'info> {
         payer = maker,
         associated_token::mint = mint_to_raise,
         associated_token::authority = fundraiser,
+        associated_token::token_program = token_program,
     )]
-    pub vault: Account<'info, TokenAccount>,
+    pub vault: InterfaceAccount<'info, TokenAccount>,
     pub system_program: Program<'info, System>,
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
+    /// CHECK: the platform config, which does not exist until the upgrade authority creates it
+    #[account(
+        seeds = [b"config"],
+        bump,
+    )]
+    pub config: UncheckedAccount<'info>,
 }
 
 impl<'info> Initialize<'info> {
-    pub fn initialize(&mut self, amount: u64, duration: u8, bumps: &InitializeBumps) -> Result<()> {
+    pub fn initialize(
+        &mut self,
+        campaign_id: u64,
+        amount: u64,
+        time_ending: i64,
+        milestones: Vec<Milestone>,
+        voting_period: i64,
+        quorum_bps: u16,
+        approval_bps: u16,
+        funding_mode: FundingMode,
+        contribution_limits: ContributionLimits,
+        max_amendments: u8,
+        bumps: &InitializeBumps,
+    ) -> Result<()> {
 
         // Check if the amount to raise meets the minimum amount required
         require!(
@@ -46,17 +76,114 @@ impl<'info> Initialize<'info> {
             FundraiserError::InvalidAmount
         );
 
+        check_transfer_hook(&self.mint_to_raise.to_account_info())?;
+
+        contribution_limits.validate(amount)?;
+
+        // Check the milestone schedule, an empty schedule releases everything at once
+        require!(milestones.len() <= MAX_MILESTONES, FundraiserError::InvalidMilestones);
+
+        if !milestones.is_empty() {
+            let mut total_percentage: u64 = 0;
+            let mut previous_unlock_time = time_ending;
+
+            for milestone in milestones.iter() {
+                // Every tranche must release something and unlock in order, after the fundraiser ends
+                require!(
+                    milestone.percentage > 0 && milestone.unlock_time >= previous_unlock_time,
+                    FundraiserError::InvalidMilestones
+                );
+
+                total_percentage += milestone.percentage as u64;
+                previous_unlock_time = milestone.unlock_time;
+            }
+
+            // The tranches must add up to the whole amount raised
+            require!(total_percentage == PERCENTAGE_SCALER, FundraiserError::InvalidMilestones);
+        }
+
+        // Check the voting configuration, a voting period of 0 disables voting
+        require!(
+            voting_period >= 0
+                && quorum_bps as u64 <= BPS_SCALER
+                && approval_bps as u64 <= BPS_SCALER,
+            FundraiserError::InvalidVotingConfig
+        );
+
+        if voting_period > 0 {
+            // Backers vote on milestones, and only once the contributions are settled
+            require!(
+                !milestones.is_empty() && milestones[0].unlock_time - voting_period >= time_ending,
+                FundraiserError::InvalidVotingConfig
+            );
+        }
+
+        let mut schedule = [Milestone::default(); MAX_MILESTONES];
+        schedule[..milestones.len()].copy_from_slice(&milestones);
+
+        // The fee in force now applies for the whole campaign, there is none until the platform is configured
+        let fee_bps = if *self.config.owner == crate::ID {
+            Config::try_deserialize(&mut &self.config.try_borrow_data()?[..])?.fee_bps
+        } else {
+            0
+        };
+
         // Initialize the fundraiser account
         self.fundraiser.set_inner(Fundraiser {
             maker: self.maker.key(),
             mint_to_raise: self.mint_to_raise.key(),
             amount_to_raise: amount,
             current_amount: 0,
-            time_started: Clock::get()?.unix_timestamp,
-            duration,
-            bump: bumps.fundraiser
+            time_ending,
+            bump: bumps.fundraiser,
+            campaign_id,
+            status: FundraiserStatus::Active,
+            milestone_count: milestones.len() as u8,
+            milestones: schedule,
+            milestones_released: 0,
+            amount_released: 0,
+            voting_period,
+            quorum_bps,
+            approval_bps,
+            votes_for: 0,
+            votes_against: 0,
+            funding_mode,
+            price_authority: Pubkey::default(),
+            accepted_mint_count: 0,
+            accepted_mints: [Pubkey::default(); MAX_ACCEPTED_MINTS],
+            amounts_raised: [0; MAX_ACCEPTED_MINTS],
+            contribution_limits,
+            receipt_mint: Pubkey::default(),
+            fee_bps,
+            max_amendments,
+            amendment_count: 0,
+            grace_ending: 0,
+        });
+
+        emit!(FundraiserCreated {
+            fundraiser: self.fundraiser.key(),
+            maker: self.maker.key(),
+            mint_to_raise: self.mint_to_raise.key(),
+            amount_to_raise: amount,
+            time_ending,
         });
         
         Ok(())
     }
+}
+
+// Transfer hooks run another program on every transfer, mints that use them are not supported
+pub fn check_transfer_hook(mint_info: &AccountInfo) -> Result<()> {
+    if *mint_info.owner == spl_token_2022::ID {
+        let mint_data = mint_info.try_borrow_data()?;
+        let mint = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&mint_data)?;
+        if let Ok(transfer_hook) = mint.get_extension::<TransferHook>() {
+            require!(
+                Option::<Pubkey>::from(transfer_hook.program_id).is_none(),
+                FundraiserError::TransferHookNotSupported
+            );
+        }
+    }
+
+    Ok(())
 }
\ No newline at end of file
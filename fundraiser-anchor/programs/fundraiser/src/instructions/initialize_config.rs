@@ -0,0 +1,44 @@
+use anchor_lang::{prelude::*, solana_program::bpf_loader_upgradeable};
+
+use crate::{state::Config, FundraiserError, ANCHOR_DISCRIMINATOR, MAX_FEE_BPS};
+
+#[derive(Accounts)]
+pub struct InitializeConfig<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        init,
+        payer = admin,
+        seeds = [b"config"],
+        bump,
+        space = ANCHOR_DISCRIMINATOR + Config::INIT_SPACE,
+    )]
+    pub config: Account<'info, Config>,
+    // Only the upgrade authority of this program can configure the platform
+    #[account(
+        seeds = [crate::ID.as_ref()],
+        bump,
+        seeds::program = bpf_loader_upgradeable::ID,
+        constraint = program_data.upgrade_authority_address == Some(admin.key()) @ FundraiserError::NotUpgradeAuthority,
+    )]
+    pub program_data: Account<'info, ProgramData>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitializeConfig<'info> {
+    pub fn initialize_config(&mut self, fee_bps: u16, fee_recipient: Pubkey, bumps: &InitializeConfigBumps) -> Result<()> {
+
+        // The fee is capped, and it has to go somewhere
+        require!(fee_bps <= MAX_FEE_BPS, FundraiserError::InvalidFee);
+        require!(fee_recipient != Pubkey::default(), FundraiserError::InvalidFeeRecipient);
+
+        self.config.set_inner(Config {
+            admin: self.admin.key(),
+            fee_bps,
+            fee_recipient,
+            bump: bumps.config,
+        });
+
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{Config, ContributionLimits, Fundraiser, FundraiserStatus, FundingMode, Milestone}, FundraiserError, ANCHOR_DISCRIMINATOR, MAX_ACCEPTED_MINTS, MAX_MILESTONES, MIN_AMOUNT_TO_RAISE, SOL_DECIMALS
+};
+use crate::events::FundraiserCreated;
+
+#[derive(Accounts)]
+#[instruction(campaign_id: u64)]
+pub struct InitializeSol<'info> {
+    #[account(mut)]
+    pub maker: Signer<'info>,
+    #[account(
+        init,
+        payer = maker,
+        seeds = [b"fundraiser", maker.key().as_ref(), campaign_id.to_le_bytes().as_ref()],
+        bump,
+        space = ANCHOR_DISCRIMINATOR + Fundraiser::INIT_SPACE,
+    )]
+    pub fundraiser: Account<'info, Fundraiser>,
+    pub system_program: Program<'info, System>,
+    /// CHECK: the platform config, which does not exist until the upgrade authority creates it
+    #[account(
+        seeds = [b"config"],
+        bump,
+    )]
+    pub config: UncheckedAccount<'info>,
+}
+
+impl<'info> InitializeSol<'info> {
+    pub fn initialize_sol(&mut self, campaign_id: u64, amount: u64, time_ending: i64, funding_mode: FundingMode, contribution_limits: ContributionLimits, max_amendments: u8, bumps: &InitializeSolBumps) -> Result<()> {
+
+        // Check if the amount to raise meets the minimum amount required
+        require!(
+            amount > MIN_AMOUNT_TO_RAISE.pow(SOL_DECIMALS),
+            FundraiserError::InvalidAmount
+        );
+
+        contribution_limits.validate(amount)?;
+
+        // The fee in force now applies for the whole campaign, there is none until the platform is configured
+        let fee_bps = if *self.config.owner == crate::ID {
+            Config::try_deserialize(&mut &self.config.try_borrow_data()?[..])?.fee_bps
+        } else {
+            0
+        };
+
+        // Initialize the fundraiser account, the raised lamports are held on top of its rent-exempt balance
+        self.fundraiser.set_inner(Fundraiser {
+            maker: self.maker.key(),
+            mint_to_raise: Pubkey::default(),
+            amount_to_raise: amount,
+            current_amount: 0,
+            time_ending,
+            bump: bumps.fundraiser,
+            campaign_id,
+            status: FundraiserStatus::Active,
+            milestone_count: 0,
+            milestones: [Milestone::default(); MAX_MILESTONES],
+            milestones_released: 0,
+            amount_released: 0,
+            voting_period: 0,
+            quorum_bps: 0,
+            approval_bps: 0,
+            votes_for: 0,
+            votes_against: 0,
+            funding_mode,
+            price_authority: Pubkey::default(),
+            accepted_mint_count: 0,
+            accepted_mints: [Pubkey::default(); MAX_ACCEPTED_MINTS],
+            amounts_raised: [0; MAX_ACCEPTED_MINTS],
+            contribution_limits,
+            receipt_mint: Pubkey::default(),
+            fee_bps,
+            max_amendments,
+            amendment_count: 0,
+            grace_ending: 0,
+        });
+
+        emit!(FundraiserCreated {
+            fundraiser: self.fundraiser.key(),
+            maker: self.maker.key(),
+            mint_to_raise: Pubkey::default(),
+            amount_to_raise: amount,
+            time_ending,
+        });
+
+        Ok(())
+    }
+}
\ No newline at end of file
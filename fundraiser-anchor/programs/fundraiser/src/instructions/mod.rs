@@ -2,8 +2,48 @@ pub mod initialize;
 pub mod contribute;
 pub mod checker;
 pub mod refund;
+pub mod cancel;
+pub mod release_milestone;
+pub mod vote;
+pub mod reject_milestone;
+pub mod initialize_sol;
+pub mod contribute_sol;
+pub mod checker_sol;
+pub mod refund_sol;
+pub mod set_price;
+pub mod add_accepted_mint;
+pub mod contribute_multi;
+pub mod checker_multi;
+pub mod refund_multi;
+pub mod enable_receipts;
+pub mod contribute_receipts;
+pub mod refund_receipts;
+pub mod set_reward_tiers;
+pub mod initialize_config;
+pub mod extend_deadline;
+pub mod amend_goal;
 
 pub use initialize::*;
 pub use contribute::*;
 pub use checker::*;
-pub use refund::*;
\ No newline at end of file
+pub use refund::*;
+pub use cancel::*;
+pub use release_milestone::*;
+pub use vote::*;
+pub use reject_milestone::*;
+pub use initialize_sol::*;
+pub use contribute_sol::*;
+pub use checker_sol::*;
+pub use refund_sol::*;
+pub use set_price::*;
+pub use add_accepted_mint::*;
+pub use contribute_multi::*;
+pub use checker_multi::*;
+pub use refund_multi::*;
+pub use enable_receipts::*;
+pub use contribute_receipts::*;
+pub use refund_receipts::*;
+pub use set_reward_tiers::*;
+pub use initialize_config::*;
+pub use extend_deadline::*;
+pub use amend_goal::*;
\ No newline at end of file
@@ -1,30 +1,35 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{
-    transfer, 
+use anchor_spl::token_interface::{
+    transfer_checked, 
     Mint, 
-    Token, 
+    TokenInterface, 
     TokenAccount, 
-    Transfer
+    TransferChecked
 };
 
 use crate::{
     state::{
         Contributor, 
-        Fundraiser
-    }, 
-    SECONDS_TO_DAYS
+        Fundraiser,
+        FundraiserStatus,
+        FundingMode,
+        RewardTiers
+    }
 };
+use crate::events::Refunded;
 
 #[derive(Accounts)]
 pub struct Refund<'info> {
     #[account(mut)]
     pub contributor: Signer<'info>,
     pub maker: SystemAccount<'info>,
-    pub mint_to_raise: Account<'info, Mint>,
+    pub mint_to_raise: InterfaceAccount<'info, Mint>,
     #[account(
         mut,
         has_one = mint_to_raise,
-        seeds = [b"fundraiser", maker.key().as_ref()],
+        constraint = !fundraiser.is_multi_mint() @ crate::FundraiserError::MultiMintFundraiser,
+        constraint = !fundraiser.issues_receipts() @ crate::FundraiserError::ReceiptsEnabled,
+        seeds = [b"fundraiser", maker.key().as_ref(), fundraiser.campaign_id.to_le_bytes().as_ref()],
         bump = fundraiser.bump,
     )]
     pub fundraiser: Account<'info, Fundraiser>,
@@ -38,42 +43,63 @@ pub struct Refund<'info> {
     #[account(
         mut,
         associated_token::mint = mint_to_raise,
-        associated_token::authority = contributor
+        associated_token::authority = contributor,
+        associated_token::token_program = token_program,
     )]
-    pub contributor_ata: Account<'info, TokenAccount>,
+    pub contributor_ata: InterfaceAccount<'info, TokenAccount>,
     #[account(
         mut,
         associated_token::mint = mint_to_raise,
-        associated_token::authority = fundraiser
+        associated_token::authority = fundraiser,
+        associated_token::token_program = token_program,
     )]
-    pub vault: Account<'info, TokenAccount>,
-    pub token_program: Program<'info, Token>,
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
+    // Only needed if the contributor holds a reward tier
+    #[account(
+        mut,
+        seeds = [b"reward_tiers", fundraiser.key().as_ref()],
+        bump = reward_tiers.bump,
+    )]
+    pub reward_tiers: Option<Account<'info, RewardTiers>>,
 }
 
 impl<'info> Refund<'info> {
     pub fn refund(&mut self) -> Result<()> {
 
-        // Check if the fundraising duration has been reached
+        // A cancelled or rejected fundraiser can be refunded right away, regardless of time or vault balance,
+        // and so can an active one for a while after the maker amended it
         let current_time = Clock::get()?.unix_timestamp;
- 
-        require!(
-            self.fundraiser.duration >= ((current_time - self.fundraiser.time_started) / SECONDS_TO_DAYS) as u8,
-            crate::FundraiserError::FundraiserNotEnded
-        );
+        if self.fundraiser.status == FundraiserStatus::Active && !self.fundraiser.in_grace_period(current_time) {
+            // A flexible fundraiser keeps what it raises, contributors can only get out if it is cancelled or rejected
+            require!(
+                self.fundraiser.funding_mode == FundingMode::Fixed,
+                crate::FundraiserError::RefundsDisabled
+            );
+
+            // Check if the fundraiser deadline has been reached
+            require!(
+                current_time >= self.fundraiser.time_ending,
+                crate::FundraiserError::FundraiserNotEnded
+            );
 
-        require!(
-            self.vault.amount < self.fundraiser.amount_to_raise,
-            crate::FundraiserError::TargetMet
-        );
+            // Nothing can be refunded once the maker has been paid
+            require!(
+                self.fundraiser.amount_released == 0
+                    && self.fundraiser.current_amount < self.fundraiser.amount_to_raise,
+                crate::FundraiserError::TargetMet
+            );
+        }
 
         // Transfer the funds back to the contributor
         // CPI to the token program to transfer the funds
         let cpi_program = self.token_program.to_account_info();
 
         // Transfer the funds from the vault to the contributor
-        let cpi_accounts = Transfer {
+        let cpi_accounts = TransferChecked {
             from: self.vault.to_account_info(),
+            mint: self.mint_to_raise.to_account_info(),
             to: self.contributor_ata.to_account_info(),
             authority: self.fundraiser.to_account_info(),
         };
@@ -82,18 +108,41 @@ impl<'info> Refund<'info> {
         let signer_seeds: [&[&[u8]]; 1] = [&[
             b"fundraiser".as_ref(),
             self.maker.to_account_info().key.as_ref(),
+            &self.fundraiser.campaign_id.to_le_bytes(),
             &[self.fundraiser.bump],
         ]];
 
         // CPI context with signer since the fundraiser account is a PDA
         let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, &signer_seeds);
 
+        // Once backers reject a milestone, each of them gets their pro-rata share of what is still in the vault
+        let amount = if self.fundraiser.status == FundraiserStatus::Rejected {
+            ((self.contributor_account.amount as u128 * self.vault.amount as u128)
+                .checked_div(self.fundraiser.current_amount as u128)
+                .ok_or(ProgramError::ArithmeticOverflow)?) as u64
+        } else {
+            self.contributor_account.amount
+        };
+
         // Transfer the funds from the vault to the contributor
-        transfer(cpi_ctx, self.contributor_account.amount)?;
+        transfer_checked(cpi_ctx, amount, self.mint_to_raise.decimals)?;
 
         // Update the fundraiser state by reducing the amount contributed
         self.fundraiser.current_amount -= self.contributor_account.amount;
 
+        // Give the reward tier slot back
+        if self.contributor_account.reward_tier != 0 {
+            let reward_tiers = self.reward_tiers.as_mut().ok_or(crate::FundraiserError::InvalidRewardTier)?;
+            reward_tiers.release(self.contributor_account.reward_tier);
+        }
+
+        emit!(Refunded {
+            fundraiser: self.fundraiser.key(),
+            contributor: self.contributor.key(),
+            mint: self.mint_to_raise.key(),
+            amount,
+        });
+
         Ok(())
     }
 }
\ No newline at end of file
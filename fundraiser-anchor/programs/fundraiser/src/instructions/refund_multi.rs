@@ -0,0 +1,136 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, 
+    Mint, 
+    TokenInterface, 
+    TokenAccount, 
+    TransferChecked
+};
+
+use crate::{
+    state::{
+        Contributor, 
+        Fundraiser,
+        FundraiserStatus,
+        FundingMode,
+        PriceFeed
+    }, 
+    FundraiserError
+};
+use crate::events::Refunded;
+
+#[derive(Accounts)]
+pub struct RefundMulti<'info> {
+    #[account(mut)]
+    pub contributor: Signer<'info>,
+    pub maker: SystemAccount<'info>,
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        seeds = [b"price_feed", fundraiser.price_authority.as_ref(), mint.key().as_ref()],
+        bump = price_feed.bump,
+    )]
+    pub price_feed: Account<'info, PriceFeed>,
+    #[account(
+        mut,
+        seeds = [b"fundraiser", maker.key().as_ref(), fundraiser.campaign_id.to_le_bytes().as_ref()],
+        bump = fundraiser.bump,
+    )]
+    pub fundraiser: Account<'info, Fundraiser>,
+    #[account(
+        mut,
+        seeds = [b"contributor", fundraiser.key().as_ref(), contributor.key().as_ref()],
+        bump,
+    )]
+    pub contributor_account: Account<'info, Contributor>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = contributor,
+        associated_token::token_program = token_program,
+    )]
+    pub contributor_ata: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = fundraiser,
+        associated_token::token_program = token_program,
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RefundMulti<'info> {
+    // Returns what the contributor sent in one accepted mint, the contributor calls this once per mint
+    pub fn refund_multi(&mut self, price_feeds: &[AccountInfo<'info>]) -> Result<()> {
+
+        let index = self.fundraiser
+            .accepted_mint_index(&self.mint.key())
+            .ok_or(FundraiserError::MintNotAccepted)?;
+
+        // A cancelled fundraiser can be refunded right away, regardless of time or prices,
+        // and so can an active one for a while after the maker amended it
+        let current_time = Clock::get()?.unix_timestamp;
+        if self.fundraiser.status == FundraiserStatus::Active && !self.fundraiser.in_grace_period(current_time) {
+            // A flexible fundraiser keeps what it raises, contributors can only get out if it is cancelled
+            require!(
+                self.fundraiser.funding_mode == FundingMode::Fixed,
+                FundraiserError::RefundsDisabled
+            );
+
+            // Check if the fundraiser deadline has been reached
+            require!(
+                current_time >= self.fundraiser.time_ending,
+                FundraiserError::FundraiserNotEnded
+            );
+
+            // Value every vault at the current prices, nothing can be refunded once the maker has been paid
+            require!(
+                self.fundraiser.amount_released == 0
+                    && self.fundraiser.value_raised(price_feeds)? < self.fundraiser.amount_to_raise,
+                FundraiserError::TargetMet
+            );
+        }
+
+        // Refunds return the original tokens, not their value
+        let amount = self.contributor_account.amounts[index];
+
+        // Transfer the funds from the vault to the contributor
+        let cpi_accounts = TransferChecked {
+            from: self.vault.to_account_info(),
+            mint: self.mint.to_account_info(),
+            to: self.contributor_ata.to_account_info(),
+            authority: self.fundraiser.to_account_info(),
+        };
+
+        // Signer seeds to sign the CPI on behalf of the fundraiser account
+        let signer_seeds: [&[&[u8]]; 1] = [&[
+            b"fundraiser".as_ref(),
+            self.maker.to_account_info().key.as_ref(),
+            &self.fundraiser.campaign_id.to_le_bytes(),
+            &[self.fundraiser.bump],
+        ]];
+
+        let cpi_ctx = CpiContext::new_with_signer(self.token_program.to_account_info(), cpi_accounts, &signer_seeds);
+
+        transfer_checked(cpi_ctx, amount, self.mint.decimals)?;
+
+        // The value is taken off at the current price, the tokens themselves are tracked exactly
+        let value = self.price_feed.value(amount);
+
+        self.fundraiser.amounts_raised[index] -= amount;
+        self.fundraiser.current_amount = self.fundraiser.current_amount.saturating_sub(value);
+
+        self.contributor_account.amounts[index] = 0;
+        self.contributor_account.amount = self.contributor_account.amount.saturating_sub(value);
+
+        emit!(Refunded {
+            fundraiser: self.fundraiser.key(),
+            contributor: self.contributor.key(),
+            mint: self.mint.key(),
+            amount,
+        });
+
+        Ok(())
+    }
+}
\ No newline at end of file
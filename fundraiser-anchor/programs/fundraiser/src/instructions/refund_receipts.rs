@@ -0,0 +1,144 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken, 
+    token_interface::{
+        burn, 
+        Burn, 
+        transfer_checked, 
+        Mint, 
+        TokenInterface, 
+        TokenAccount, 
+        TransferChecked
+    }
+};
+
+use crate::{
+    state::{
+        Fundraiser,
+        FundraiserStatus,
+        FundingMode
+    }, FundraiserError
+};
+use crate::events::Refunded;
+
+#[derive(Accounts)]
+pub struct RefundReceipts<'info> {
+    #[account(mut)]
+    pub holder: Signer<'info>,
+    pub mint_to_raise: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        address = fundraiser.receipt_mint @ FundraiserError::InvalidReceiptMint,
+    )]
+    pub receipt_mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        has_one = mint_to_raise,
+        constraint = fundraiser.issues_receipts() @ FundraiserError::ReceiptsDisabled,
+        seeds = [b"fundraiser", fundraiser.maker.as_ref(), fundraiser.campaign_id.to_le_bytes().as_ref()],
+        bump = fundraiser.bump,
+    )]
+    pub fundraiser: Account<'info, Fundraiser>,
+    #[account(
+        mut,
+        token::mint = receipt_mint,
+        token::authority = holder,
+        token::token_program = token_program,
+    )]
+    pub holder_receipt_account: InterfaceAccount<'info, TokenAccount>,
+    // Receipts can change hands, so the holder may not have an account for the raised mint yet
+    #[account(
+        init_if_needed,
+        payer = holder,
+        associated_token::mint = mint_to_raise,
+        associated_token::authority = holder,
+        associated_token::token_program = token_program,
+    )]
+    pub holder_ata: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        associated_token::mint = mint_to_raise,
+        associated_token::authority = fundraiser,
+        associated_token::token_program = token_program,
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl<'info> RefundReceipts<'info> {
+    pub fn refund_receipts(&mut self) -> Result<()> {
+
+        // A cancelled fundraiser can be refunded right away, regardless of time or vault balance,
+        // and so can an active one for a while after the maker amended it
+        let current_time = Clock::get()?.unix_timestamp;
+        if self.fundraiser.status == FundraiserStatus::Active && !self.fundraiser.in_grace_period(current_time) {
+            // A flexible fundraiser keeps what it raises, holders can only get out if it is cancelled
+            require!(
+                self.fundraiser.funding_mode == FundingMode::Fixed,
+                FundraiserError::RefundsDisabled
+            );
+
+            // Check if the fundraiser deadline has been reached
+            require!(
+                current_time >= self.fundraiser.time_ending,
+                FundraiserError::FundraiserNotEnded
+            );
+
+            // Nothing can be refunded once the maker has been paid
+            require!(
+                self.fundraiser.amount_released == 0
+                    && self.fundraiser.current_amount < self.fundraiser.amount_to_raise,
+                FundraiserError::TargetMet
+            );
+        }
+
+        // Refunds follow the receipts, whoever holds them gets the tokens they stand for
+        let amount = self.holder_receipt_account.amount;
+        require!(amount > 0, FundraiserError::InvalidAmount);
+
+        // Burn the receipts, the holder signs for their own account
+        let cpi_accounts = Burn {
+            mint: self.receipt_mint.to_account_info(),
+            from: self.holder_receipt_account.to_account_info(),
+            authority: self.holder.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new(self.token_program.to_account_info(), cpi_accounts);
+
+        burn(cpi_ctx, amount)?;
+
+        // Signer seeds to sign the CPI on behalf of the fundraiser account
+        let signer_seeds: [&[&[u8]]; 1] = [&[
+            b"fundraiser".as_ref(),
+            self.fundraiser.maker.as_ref(),
+            &self.fundraiser.campaign_id.to_le_bytes(),
+            &[self.fundraiser.bump],
+        ]];
+
+        // Transfer the funds from the vault to the holder
+        let cpi_accounts = TransferChecked {
+            from: self.vault.to_account_info(),
+            mint: self.mint_to_raise.to_account_info(),
+            to: self.holder_ata.to_account_info(),
+            authority: self.fundraiser.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new_with_signer(self.token_program.to_account_info(), cpi_accounts, &signer_seeds);
+
+        transfer_checked(cpi_ctx, amount, self.mint_to_raise.decimals)?;
+
+        // Update the fundraiser state by reducing the amount contributed
+        self.fundraiser.current_amount -= amount;
+
+        emit!(Refunded {
+            fundraiser: self.fundraiser.key(),
+            contributor: self.holder.key(),
+            mint: self.mint_to_raise.key(),
+            amount,
+        });
+
+        Ok(())
+    }
+}
\ No newline at end of file
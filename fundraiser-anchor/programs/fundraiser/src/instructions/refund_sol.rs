@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{
+        Contributor, 
+        Fundraiser,
+        FundraiserStatus,
+        FundingMode
+    }, 
+    FundraiserError
+};
+use crate::events::Refunded;
+
+#[derive(Accounts)]
+pub struct RefundSol<'info> {
+    #[account(mut)]
+    pub contributor: Signer<'info>,
+    pub maker: SystemAccount<'info>,
+    #[account(
+        mut,
+        constraint = fundraiser.is_sol() @ FundraiserError::NotSolFundraiser,
+        seeds = [b"fundraiser", maker.key().as_ref(), fundraiser.campaign_id.to_le_bytes().as_ref()],
+        bump = fundraiser.bump,
+    )]
+    pub fundraiser: Account<'info, Fundraiser>,
+    #[account(
+        mut,
+        seeds = [b"contributor", fundraiser.key().as_ref(), contributor.key().as_ref()],
+        bump,
+        close = contributor,
+    )]
+    pub contributor_account: Account<'info, Contributor>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RefundSol<'info> {
+    pub fn refund_sol(&mut self) -> Result<()> {
+
+        // A cancelled fundraiser can be refunded right away, regardless of time or amount raised,
+        // and so can an active one for a while after the maker amended it
+        let current_time = Clock::get()?.unix_timestamp;
+        if self.fundraiser.status == FundraiserStatus::Active && !self.fundraiser.in_grace_period(current_time) {
+            // A flexible fundraiser keeps what it raises, contributors can only get out if it is cancelled
+            require!(
+                self.fundraiser.funding_mode == FundingMode::Fixed,
+                FundraiserError::RefundsDisabled
+            );
+
+            // Check if the fundraiser deadline has been reached
+            require!(
+                current_time >= self.fundraiser.time_ending,
+                FundraiserError::FundraiserNotEnded
+            );
+
+            require!(
+                self.fundraiser.current_amount < self.fundraiser.amount_to_raise,
+                FundraiserError::TargetMet
+            );
+        }
+
+        // The fundraiser account is owned by the program, so the lamports can be moved directly.
+        // Only the contributed lamports leave, the account stays rent-exempt
+        let amount = self.contributor_account.amount;
+        self.fundraiser.sub_lamports(amount)?;
+        self.contributor.add_lamports(amount)?;
+
+        // Update the fundraiser state by reducing the amount contributed
+        self.fundraiser.current_amount -= amount;
+
+        emit!(Refunded {
+            fundraiser: self.fundraiser.key(),
+            contributor: self.contributor.key(),
+            mint: Pubkey::default(),
+            amount,
+        });
+
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{
+        Fundraiser, 
+        FundraiserStatus
+    }, 
+    FundraiserError
+};
+
+#[derive(Accounts)]
+pub struct RejectMilestone<'info> {
+    pub signer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"fundraiser".as_ref(), fundraiser.maker.as_ref(), fundraiser.campaign_id.to_le_bytes().as_ref()],
+        bump = fundraiser.bump,
+    )]
+    pub fundraiser: Account<'info, Fundraiser>,
+}
+
+impl<'info> RejectMilestone<'info> {
+    pub fn reject_milestone(&mut self) -> Result<()> {
+
+        // A cancelled or rejected fundraiser can only be refunded
+        require!(
+            self.fundraiser.status == FundraiserStatus::Active,
+            FundraiserError::FundraiserCancelled
+        );
+
+        require!(
+            self.fundraiser.voting_period > 0,
+            FundraiserError::VotingDisabled
+        );
+
+        let index = self.fundraiser.milestones_released as usize;
+        require!(
+            index < self.fundraiser.milestone_count as usize,
+            FundraiserError::MilestonesCompleted
+        );
+
+        // The outcome is only final once the voting window has closed
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(
+            current_time >= self.fundraiser.milestones[index].unlock_time,
+            FundraiserError::VotingWindowOpen
+        );
+
+        // The quorum is measured against what is raised, which can still shrink during the grace period
+        require!(
+            !self.fundraiser.in_grace_period(current_time),
+            FundraiserError::GracePeriodActive
+        );
+
+        require!(
+            !self.fundraiser.milestone_approved(),
+            FundraiserError::MilestoneApproved
+        );
+
+        // Anyone can settle a failed vote, contributors can then refund what is left in the vault
+        self.fundraiser.status = FundraiserStatus::Rejected;
+
+        Ok(())
+    }
+}
\ No newline at end of file
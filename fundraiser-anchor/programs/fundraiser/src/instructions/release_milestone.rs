@@ -0,0 +1,176 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken, 
+    token_interface::{
+        transfer_checked, 
+        Mint, 
+        TokenInterface, 
+        TokenAccount, 
+        TransferChecked
+    }
+};
+
+use crate::{
+    state::{
+        Config,
+        Fundraiser, 
+        FundraiserStatus
+    }, 
+    FundraiserError, 
+    PERCENTAGE_SCALER
+};
+use fundraiser_interface::state::fee_amount;
+use crate::events::Withdrawn;
+
+#[derive(Accounts)]
+pub struct ReleaseMilestone<'info> {
+    #[account(mut)]
+    pub maker: Signer<'info>,
+    pub mint_to_raise: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        has_one = maker,
+        has_one = mint_to_raise,
+        seeds = [b"fundraiser".as_ref(), maker.key().as_ref(), fundraiser.campaign_id.to_le_bytes().as_ref()],
+        bump = fundraiser.bump,
+    )]
+    pub fundraiser: Account<'info, Fundraiser>,
+    #[account(
+        mut,
+        associated_token::mint = mint_to_raise,
+        associated_token::authority = fundraiser,
+        associated_token::token_program = token_program,
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        init_if_needed,
+        payer = maker,
+        associated_token::mint = mint_to_raise,
+        associated_token::authority = maker,
+        associated_token::token_program = token_program,
+    )]
+    pub maker_ata: InterfaceAccount<'info, TokenAccount>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    // Only needed when the fundraiser pays a platform fee
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Option<Account<'info, Config>>,
+    #[account(mut)]
+    pub fee_recipient_ata: Option<InterfaceAccount<'info, TokenAccount>>,
+}
+
+impl<'info> ReleaseMilestone<'info> {
+    pub fn release_milestone(&mut self) -> Result<()> {
+
+        // A cancelled fundraiser can only be refunded
+        require!(
+            self.fundraiser.status == FundraiserStatus::Active,
+            FundraiserError::FundraiserCancelled
+        );
+
+        require!(
+            self.fundraiser.milestone_count > 0,
+            FundraiserError::NoMilestones
+        );
+
+        // Milestones are released in order, one at a time
+        let index = self.fundraiser.milestones_released as usize;
+        require!(
+            index < self.fundraiser.milestone_count as usize,
+            FundraiserError::MilestonesCompleted
+        );
+
+        // Check if the raised funds are secured, either by meeting the target or by a flexible fundraiser ending
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(
+            self.fundraiser.funds_secured(current_time),
+            FundraiserError::TargetNotMet
+        );
+        require!(
+            !self.fundraiser.in_grace_period(current_time),
+            FundraiserError::GracePeriodActive
+        );
+
+        // Check if the milestone has unlocked
+        let milestone = self.fundraiser.milestones[index];
+        require!(
+            current_time >= milestone.unlock_time,
+            FundraiserError::MilestoneLocked
+        );
+
+        // When backers vote, the milestone needs their approval before it leaves the vault
+        if self.fundraiser.voting_period > 0 {
+            require!(
+                self.fundraiser.milestone_approved(),
+                FundraiserError::MilestoneNotApproved
+            );
+        }
+
+        // The last milestone sweeps the vault so no rounding dust is left behind
+        let amount = if index + 1 == self.fundraiser.milestone_count as usize {
+            self.vault.amount
+        } else {
+            (self.fundraiser.current_amount * milestone.percentage as u64) / PERCENTAGE_SCALER
+        };
+
+        // The platform takes its cut of every release at the rate snapshotted when the fundraiser was created
+        let fee = fee_amount(amount, self.fundraiser.fee_bps);
+        if fee > 0 {
+            let (Some(config), Some(fee_recipient_ata)) = (&self.config, &self.fee_recipient_ata) else {
+                return err!(FundraiserError::InvalidFeeRecipient);
+            };
+            require!(
+                fee_recipient_ata.owner == config.fee_recipient && fee_recipient_ata.mint == self.mint_to_raise.key(),
+                FundraiserError::InvalidFeeRecipient
+            );
+            self.transfer(fee_recipient_ata.to_account_info(), fee)?;
+        }
+
+        // Transfer the rest of the unlocked share from the vault to the maker
+        self.transfer(self.maker_ata.to_account_info(), amount - fee)?;
+
+        // Track what has already been released, the fee included
+        self.fundraiser.milestones_released += 1;
+        self.fundraiser.amount_released += amount;
+
+        // Reset the tally for the next milestone
+        self.fundraiser.votes_for = 0;
+        self.fundraiser.votes_against = 0;
+
+        emit!(Withdrawn {
+            fundraiser: self.fundraiser.key(),
+            maker: self.maker.key(),
+            mint: self.mint_to_raise.key(),
+            amount: amount - fee,
+        });
+
+        Ok(())
+    }
+    fn transfer(&self, to: AccountInfo<'info>, amount: u64) -> Result<()> {
+        // CPI to the token program to transfer the funds
+        let cpi_program = self.token_program.to_account_info();
+
+        let cpi_accounts = TransferChecked {
+            from: self.vault.to_account_info(),
+            mint: self.mint_to_raise.to_account_info(),
+            to,
+            authority: self.fundraiser.to_account_info(),
+        };
+
+        // Signer seeds to sign the CPI on behalf of the fundraiser account
+        let signer_seeds: [&[&[u8]]; 1] = [&[
+            b"fundraiser".as_ref(),
+            self.maker.to_account_info().key.as_ref(),
+            &self.fundraiser.campaign_id.to_le_bytes(),
+            &[self.fundraiser.bump],
+        ]];
+
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, &signer_seeds);
+
+        transfer_checked(cpi_ctx, amount, self.mint_to_raise.decimals)
+    }
+}
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::{
+    state::PriceFeed, 
+    FundraiserError, 
+    ANCHOR_DISCRIMINATOR
+};
+
+#[derive(Accounts)]
+pub struct SetPrice<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        seeds = [b"price_feed", authority.key().as_ref(), mint.key().as_ref()],
+        bump,
+        space = ANCHOR_DISCRIMINATOR + PriceFeed::INIT_SPACE,
+    )]
+    pub price_feed: Account<'info, PriceFeed>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetPrice<'info> {
+    pub fn set_price(&mut self, price: u64, bumps: &SetPriceBumps) -> Result<()> {
+
+        // A mint without a price could never count towards a goal
+        require!(price > 0, FundraiserError::InvalidPriceFeed);
+
+        // Create the price feed on first use, later calls only move the price
+        self.price_feed.set_inner(PriceFeed {
+            authority: self.authority.key(),
+            mint: self.mint.key(),
+            decimals: self.mint.decimals,
+            price,
+            bump: bumps.price_feed,
+        });
+
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{
+        Fundraiser, 
+        FundraiserStatus, 
+        RewardTier, 
+        RewardTiers
+    }, 
+    FundraiserError, 
+    ANCHOR_DISCRIMINATOR, 
+    MAX_REWARD_TIERS
+};
+
+#[derive(Accounts)]
+pub struct SetRewardTiers<'info> {
+    #[account(mut)]
+    pub maker: Signer<'info>,
+    #[account(
+        has_one = maker,
+        seeds = [b"fundraiser".as_ref(), maker.key().as_ref(), fundraiser.campaign_id.to_le_bytes().as_ref()],
+        bump = fundraiser.bump,
+    )]
+    pub fundraiser: Account<'info, Fundraiser>,
+    #[account(
+        init_if_needed,
+        payer = maker,
+        seeds = [b"reward_tiers", fundraiser.key().as_ref()],
+        bump,
+        space = ANCHOR_DISCRIMINATOR + RewardTiers::INIT_SPACE,
+    )]
+    pub reward_tiers: Account<'info, RewardTiers>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetRewardTiers<'info> {
+    pub fn set_reward_tiers(&mut self, tiers: Vec<RewardTier>, bumps: &SetRewardTiersBumps) -> Result<()> {
+
+        // Contributors pick a tier as they pledge, so the tiers are fixed once the first contribution came in
+        require!(
+            self.fundraiser.status == FundraiserStatus::Active && self.fundraiser.current_amount == 0,
+            FundraiserError::RewardTiersLocked
+        );
+
+        // Every tier in use has to offer at least one reward
+        require!(
+            tiers.len() <= MAX_REWARD_TIERS && tiers.iter().all(|tier| tier.max_supply > 0),
+            FundraiserError::InvalidRewardTiers
+        );
+
+        let mut schedule = [RewardTier::default(); MAX_REWARD_TIERS];
+        schedule[..tiers.len()].copy_from_slice(&tiers);
+
+        // Nothing has been claimed yet
+        self.reward_tiers.set_inner(RewardTiers {
+            fundraiser: self.fundraiser.key(),
+            tier_count: tiers.len() as u8,
+            tiers: schedule,
+            claimed: [0; MAX_REWARD_TIERS],
+            bump: bumps.reward_tiers,
+        });
+
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{
+        Contributor, 
+        Fundraiser, 
+        FundraiserStatus
+    }, 
+    FundraiserError
+};
+
+#[derive(Accounts)]
+pub struct Vote<'info> {
+    pub contributor: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"fundraiser".as_ref(), fundraiser.maker.as_ref(), fundraiser.campaign_id.to_le_bytes().as_ref()],
+        bump = fundraiser.bump,
+    )]
+    pub fundraiser: Account<'info, Fundraiser>,
+    #[account(
+        mut,
+        seeds = [b"contributor", fundraiser.key().as_ref(), contributor.key().as_ref()],
+        bump,
+    )]
+    pub contributor_account: Account<'info, Contributor>,
+}
+
+impl<'info> Vote<'info> {
+    pub fn vote(&mut self, approve: bool) -> Result<()> {
+
+        // A cancelled or rejected fundraiser can only be refunded
+        require!(
+            self.fundraiser.status == FundraiserStatus::Active,
+            FundraiserError::FundraiserCancelled
+        );
+
+        require!(
+            self.fundraiser.voting_period > 0,
+            FundraiserError::VotingDisabled
+        );
+
+        // Votes always apply to the next milestone waiting to be released
+        let index = self.fundraiser.milestones_released as usize;
+        require!(
+            index < self.fundraiser.milestone_count as usize,
+            FundraiserError::MilestonesCompleted
+        );
+
+        // Check if the raised funds are secured, either by meeting the target or by a flexible fundraiser ending
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(
+            self.fundraiser.funds_secured(current_time),
+            FundraiserError::TargetNotMet
+        );
+
+        // Contributors who vote now could still withdraw their stake, so wait for the grace period to end
+        require!(
+            !self.fundraiser.in_grace_period(current_time),
+            FundraiserError::GracePeriodActive
+        );
+
+        // The voting window closes when the milestone unlocks
+        let unlock_time = self.fundraiser.milestones[index].unlock_time;
+        require!(
+            current_time >= unlock_time - self.fundraiser.voting_period && current_time < unlock_time,
+            FundraiserError::VotingWindowClosed
+        );
+
+        // Votes are weighted by the amount contributed
+        require!(
+            self.contributor_account.amount > 0,
+            FundraiserError::NoVotingWeight
+        );
+
+        // Each contributor gets a single vote per milestone
+        require!(
+            self.contributor_account.voted_milestone != index as u8 + 1,
+            FundraiserError::AlreadyVoted
+        );
+
+        if approve {
+            self.fundraiser.votes_for += self.contributor_account.amount;
+        } else {
+            self.fundraiser.votes_against += self.contributor_account.amount;
+        }
+
+        self.contributor_account.voted_milestone = index as u8 + 1;
+
+        Ok(())
+    }
+}
\ No newline at end of file
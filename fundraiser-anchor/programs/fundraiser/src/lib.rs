@@ -6,25 +6,40 @@ mod state;
 mod instructions;
 mod error;
 mod constants;
+mod events;
 
 use instructions::*;
 use error::*;
+use state::{ContributionLimits, FundingMode, Milestone, RewardTier};
 pub use constants::*;
+pub use events::*;
 
 #[program]
 pub mod fundraiser {
     use super::*;
 
-    pub fn initialize(ctx: Context<Initialize>, amount: u64, duration: u8) -> Result<()> {
-
-        ctx.accounts.initialize(amount, duration, &ctx.bumps)?;
+    pub fn initialize(
+        ctx: Context<Initialize>,
+        campaign_id: u64,
+        amount: u64,
+        time_ending: i64,
+        milestones: Vec<Milestone>,
+        voting_period: i64,
+        quorum_bps: u16,
+        approval_bps: u16,
+        funding_mode: FundingMode,
+        contribution_limits: ContributionLimits,
+        max_amendments: u8,
+    ) -> Result<()> {
+
+        ctx.accounts.initialize(campaign_id, amount, time_ending, milestones, voting_period, quorum_bps, approval_bps, funding_mode, contribution_limits, max_amendments, &ctx.bumps)?;
 
         Ok(())
     }
 
-    pub fn contribute(ctx: Context<Contribute>, amount: u64) -> Result<()> {
+    pub fn contribute(ctx: Context<Contribute>, amount: u64, reward_tier: Option<u8>) -> Result<()> {
 
-        ctx.accounts.contribute(amount)?;
+        ctx.accounts.contribute(amount, reward_tier, &ctx.bumps)?;
 
         Ok(())
     }
@@ -42,4 +57,144 @@ pub mod fundraiser {
 
         Ok(())
     }
+
+    pub fn cancel(ctx: Context<Cancel>) -> Result<()> {
+
+        ctx.accounts.cancel()?;
+
+        Ok(())
+    }
+
+    pub fn release_milestone(ctx: Context<ReleaseMilestone>) -> Result<()> {
+
+        ctx.accounts.release_milestone()?;
+
+        Ok(())
+    }
+
+    pub fn vote(ctx: Context<Vote>, approve: bool) -> Result<()> {
+
+        ctx.accounts.vote(approve)?;
+
+        Ok(())
+    }
+
+    pub fn reject_milestone(ctx: Context<RejectMilestone>) -> Result<()> {
+
+        ctx.accounts.reject_milestone()?;
+
+        Ok(())
+    }
+
+    pub fn initialize_sol(ctx: Context<InitializeSol>, campaign_id: u64, amount: u64, time_ending: i64, funding_mode: FundingMode, contribution_limits: ContributionLimits, max_amendments: u8) -> Result<()> {
+
+        ctx.accounts.initialize_sol(campaign_id, amount, time_ending, funding_mode, contribution_limits, max_amendments, &ctx.bumps)?;
+
+        Ok(())
+    }
+
+    pub fn contribute_sol(ctx: Context<ContributeSol>, amount: u64) -> Result<()> {
+
+        ctx.accounts.contribute_sol(amount, &ctx.bumps)?;
+
+        Ok(())
+    }
+
+    pub fn check_contributions_sol(ctx: Context<CheckContributionsSol>) -> Result<()> {
+
+        ctx.accounts.check_contributions_sol()?;
+
+        Ok(())
+    }
+
+    pub fn refund_sol(ctx: Context<RefundSol>) -> Result<()> {
+
+        ctx.accounts.refund_sol()?;
+
+        Ok(())
+    }
+
+    pub fn set_price(ctx: Context<SetPrice>, price: u64) -> Result<()> {
+
+        ctx.accounts.set_price(price, &ctx.bumps)?;
+
+        Ok(())
+    }
+
+    pub fn add_accepted_mint(ctx: Context<AddAcceptedMint>) -> Result<()> {
+
+        ctx.accounts.add_accepted_mint()?;
+
+        Ok(())
+    }
+
+    pub fn contribute_multi(ctx: Context<ContributeMulti>, amount: u64) -> Result<()> {
+
+        ctx.accounts.contribute_multi(amount, &ctx.bumps)?;
+
+        Ok(())
+    }
+
+    pub fn check_contributions_multi<'info>(ctx: Context<'_, '_, '_, 'info, CheckContributionsMulti<'info>>) -> Result<()> {
+
+        ctx.accounts.check_contributions_multi(ctx.remaining_accounts)?;
+
+        Ok(())
+    }
+
+    pub fn refund_multi<'info>(ctx: Context<'_, '_, '_, 'info, RefundMulti<'info>>) -> Result<()> {
+
+        ctx.accounts.refund_multi(ctx.remaining_accounts)?;
+
+        Ok(())
+    }
+
+    pub fn enable_receipts(ctx: Context<EnableReceipts>) -> Result<()> {
+
+        ctx.accounts.enable_receipts()?;
+
+        Ok(())
+    }
+
+    pub fn contribute_receipts(ctx: Context<ContributeReceipts>, amount: u64) -> Result<()> {
+
+        ctx.accounts.contribute_receipts(amount)?;
+
+        Ok(())
+    }
+
+    pub fn refund_receipts(ctx: Context<RefundReceipts>) -> Result<()> {
+
+        ctx.accounts.refund_receipts()?;
+
+        Ok(())
+    }
+
+    pub fn initialize_config(ctx: Context<InitializeConfig>, fee_bps: u16, fee_recipient: Pubkey) -> Result<()> {
+
+        ctx.accounts.initialize_config(fee_bps, fee_recipient, &ctx.bumps)?;
+
+        Ok(())
+    }
+
+    pub fn set_reward_tiers(ctx: Context<SetRewardTiers>, tiers: Vec<RewardTier>) -> Result<()> {
+
+        ctx.accounts.set_reward_tiers(tiers, &ctx.bumps)?;
+
+        Ok(())
+    }
+
+    pub fn extend_deadline(ctx: Context<ExtendDeadline>, time_ending: i64) -> Result<()> {
+
+        ctx.accounts.extend_deadline(time_ending)?;
+
+        Ok(())
+    }
+
+    pub fn amend_goal(ctx: Context<AmendGoal>, amount: u64) -> Result<()> {
+
+        ctx.accounts.amend_goal(amount)?;
+
+        Ok(())
+    }
 }
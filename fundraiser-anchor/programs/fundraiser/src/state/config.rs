@@ -0,0 +1,14 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct Config {
+    pub admin: Pubkey,
+    // Snapshotted into every fundraiser created while it is in force
+    pub fee_bps: u16,
+    pub fee_recipient: Pubkey,
+    pub bump: u8,
+}
+
+// After the discriminator the account has the same layout as in the other implementations
+const _: () = assert!(Config::INIT_SPACE == fundraiser_interface::state::config::LEN);
\ No newline at end of file
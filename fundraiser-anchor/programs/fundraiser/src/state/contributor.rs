@@ -1,7 +1,17 @@
 use anchor_lang::prelude::*;
 
+use crate::MAX_ACCEPTED_MINTS;
+
 #[account]
 #[derive(InitSpace)]
 pub struct Contributor {
     pub amount: u64,
-}
\ No newline at end of file
+    pub voted_milestone: u8,
+    pub amounts: [u64; MAX_ACCEPTED_MINTS],
+    // 0 without a reward, otherwise the index of the tier plus one
+    pub reward_tier: u8,
+    pub bump: u8,
+}
+
+// After the discriminator the account has the same layout as in the other implementations
+const _: () = assert!(Contributor::INIT_SPACE == fundraiser_interface::state::contributor::LEN);
\ No newline at end of file
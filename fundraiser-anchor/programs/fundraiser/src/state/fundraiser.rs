@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+use crate::{state::PriceFeed, FundraiserError, AMENDMENT_GRACE_PERIOD, BPS_SCALER, MAX_ACCEPTED_MINTS, MAX_MILESTONES, PERCENTAGE_SCALER};
+
 #[account]
 #[derive(InitSpace)]
 pub struct Fundraiser {
@@ -7,7 +9,230 @@ pub struct Fundraiser {
     pub mint_to_raise: Pubkey,
     pub amount_to_raise: u64,
     pub current_amount: u64,
-    pub time_started: i64,
-    pub duration: u8,
+    pub time_ending: i64,
     pub bump: u8,
-}
\ No newline at end of file
+    pub campaign_id: u64,
+    pub status: FundraiserStatus,
+    pub milestone_count: u8,
+    pub milestones: [Milestone; MAX_MILESTONES],
+    pub milestones_released: u8,
+    pub amount_released: u64,
+    pub voting_period: i64,
+    pub quorum_bps: u16,
+    pub approval_bps: u16,
+    pub votes_for: u64,
+    pub votes_against: u64,
+    pub funding_mode: FundingMode,
+    pub price_authority: Pubkey,
+    pub accepted_mint_count: u8,
+    pub accepted_mints: [Pubkey; MAX_ACCEPTED_MINTS],
+    pub amounts_raised: [u64; MAX_ACCEPTED_MINTS],
+    pub contribution_limits: ContributionLimits,
+    pub receipt_mint: Pubkey,
+    // The platform fee in force when the fundraiser was created
+    pub fee_bps: u16,
+    // How many times the maker can extend the deadline or lower the goal, and how many times they did
+    pub max_amendments: u8,
+    pub amendment_count: u8,
+    // Until then contributors can withdraw even from a fundraiser that has not failed
+    pub grace_ending: i64,
+}
+
+impl Fundraiser {
+    // Votes always apply to the next milestone waiting to be released
+    pub fn milestone_approved(&self) -> bool {
+        let total_votes = self.votes_for as u128 + self.votes_against as u128;
+
+        // Enough of the raised amount has to take part in the vote...
+        let quorum_met = total_votes * BPS_SCALER as u128
+            >= self.current_amount as u128 * self.quorum_bps as u128;
+
+        // ...and enough of the votes cast have to approve the release
+        let approved = self.votes_for as u128 * BPS_SCALER as u128
+            >= total_votes * self.approval_bps as u128;
+
+        quorum_met && approved
+    }
+
+    // A SOL fundraiser has no mint, the fundraiser account itself holds the raised lamports
+    pub fn is_sol(&self) -> bool {
+        self.mint_to_raise == Pubkey::default()
+    }
+
+    // A multi-mint fundraiser sets its goal in a common unit and values each accepted mint through a price feed
+    pub fn is_multi_mint(&self) -> bool {
+        self.accepted_mint_count > 0
+    }
+
+    // Contributions mint receipt tokens to the contributor, and refunds burn them
+    pub fn issues_receipts(&self) -> bool {
+        self.receipt_mint != Pubkey::default()
+    }
+
+    pub fn accepted_mint_index(&self, mint: &Pubkey) -> Option<usize> {
+        self.accepted_mints[..self.accepted_mint_count as usize]
+            .iter()
+            .position(|accepted_mint| accepted_mint == mint)
+    }
+
+    // Values everything raised at the current prices, the price feeds have to be passed in the order of the accepted mints
+    pub fn value_raised(&self, price_feeds: &[AccountInfo]) -> Result<u64> {
+        require!(
+            price_feeds.len() == self.accepted_mint_count as usize,
+            FundraiserError::InvalidPriceFeed
+        );
+
+        let mut value: u64 = 0;
+        for (index, price_feed_info) in price_feeds.iter().enumerate() {
+            require_keys_eq!(*price_feed_info.owner, crate::ID, FundraiserError::InvalidPriceFeed);
+
+            let price_feed = PriceFeed::try_deserialize(&mut &price_feed_info.try_borrow_data()?[..])?;
+            require!(
+                price_feed.mint == self.accepted_mints[index] && price_feed.authority == self.price_authority,
+                FundraiserError::InvalidPriceFeed
+            );
+
+            value += price_feed.value(self.amounts_raised[index]);
+        }
+
+        Ok(value)
+    }
+
+    // Checks a contribution against the limits the maker set, `contributed` is what the contributor has put in so far
+    pub fn check_contribution(&self, contributed: u64, amount: u64) -> Result<()> {
+        let limits = &self.contribution_limits;
+
+        require!(
+            amount > 0 && amount >= limits.min_contribution,
+            FundraiserError::ContributionTooSmall
+        );
+
+        if let Some(max_contribution) = limits.max_per_contributor(self.amount_to_raise) {
+            require!(
+                contributed.checked_add(amount).ok_or(ProgramError::ArithmeticOverflow)? <= max_contribution,
+                FundraiserError::MaximumContributionsReached
+            );
+        }
+
+        require!(
+            limits.hard_cap == 0
+                || self.current_amount.checked_add(amount).ok_or(ProgramError::ArithmeticOverflow)? <= limits.hard_cap,
+            FundraiserError::HardCapReached
+        );
+
+        Ok(())
+    }
+
+    // Counts an extended deadline or a lowered goal against the maker's allowance and lets contributors reconsider
+    pub fn amend(&mut self, current_time: i64) -> Result<()> {
+        require!(
+            self.status == FundraiserStatus::Active,
+            FundraiserError::FundraiserCancelled
+        );
+
+        // The terms are settled once the fundraiser has ended
+        require!(
+            current_time <= self.time_ending,
+            FundraiserError::FundraiserEnded
+        );
+
+        require!(
+            self.amendment_count < self.max_amendments,
+            FundraiserError::AmendmentsExhausted
+        );
+
+        self.amendment_count += 1;
+        self.grace_ending = current_time + AMENDMENT_GRACE_PERIOD;
+
+        Ok(())
+    }
+
+    // Contributors can withdraw from an active fundraiser for a while after it was amended,
+    // and the maker cannot be paid until they had the chance to
+    pub fn in_grace_period(&self, current_time: i64) -> bool {
+        current_time < self.grace_ending
+    }
+
+    // A fixed fundraiser has to meet its target, a flexible one keeps whatever it raised once the deadline passes
+    pub fn funds_secured(&self, current_time: i64) -> bool {
+        self.current_amount >= self.amount_to_raise
+            || (self.funding_mode == FundingMode::Flexible
+                && current_time >= self.time_ending)
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, InitSpace)]
+pub struct Milestone {
+    pub percentage: u8,
+    pub unlock_time: i64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace)]
+pub struct ContributionLimits {
+    // Smallest single contribution, 0 only rejects empty contributions
+    pub min_contribution: u64,
+    // Most a single contributor can put in, read according to `max_contribution_kind`
+    pub max_contribution: u64,
+    pub max_contribution_kind: MaxContributionKind,
+    // Most the fundraiser can raise in total, 0 leaves it uncapped
+    pub hard_cap: u64,
+}
+
+impl ContributionLimits {
+    pub fn validate(&self, amount_to_raise: u64) -> Result<()> {
+        require!(
+            self.max_contribution_kind != MaxContributionKind::Percentage
+                || self.max_contribution <= PERCENTAGE_SCALER,
+            FundraiserError::InvalidContributionLimits
+        );
+
+        // Every contributor must be able to put in at least the minimum
+        if let Some(max_contribution) = self.max_per_contributor(amount_to_raise) {
+            require!(
+                max_contribution > 0 && max_contribution >= self.min_contribution,
+                FundraiserError::InvalidContributionLimits
+            );
+        }
+
+        // A hard cap below the goal would make the goal unreachable
+        require!(
+            self.hard_cap == 0 || self.hard_cap >= amount_to_raise,
+            FundraiserError::InvalidContributionLimits
+        );
+
+        Ok(())
+    }
+
+    pub fn max_per_contributor(&self, amount_to_raise: u64) -> Option<u64> {
+        match self.max_contribution_kind {
+            MaxContributionKind::None => None,
+            MaxContributionKind::Absolute => Some(self.max_contribution),
+            MaxContributionKind::Percentage => Some(
+                (amount_to_raise as u128 * self.max_contribution as u128 / PERCENTAGE_SCALER as u128) as u64
+            ),
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum MaxContributionKind {
+    None,
+    Absolute,
+    Percentage,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum FundraiserStatus {
+    Active,
+    Cancelled,
+    Rejected,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum FundingMode {
+    Fixed,
+    Flexible,
+}
+
+// After the discriminator the account has the same layout as in the other implementations
+const _: () = assert!(Fundraiser::INIT_SPACE == fundraiser_interface::state::fundraiser::LEN);
\ No newline at end of file
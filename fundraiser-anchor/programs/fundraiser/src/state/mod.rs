@@ -1,5 +1,11 @@
 pub mod fundraiser;
 pub mod contributor;
+pub mod price_feed;
+pub mod reward_tiers;
+pub mod config;
 
 pub use fundraiser::*;
-pub use contributor::*;
\ No newline at end of file
+pub use contributor::*;
+pub use price_feed::*;
+pub use reward_tiers::*;
+pub use config::*;
\ No newline at end of file
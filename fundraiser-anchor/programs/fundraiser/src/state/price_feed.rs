@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct PriceFeed {
+    pub authority: Pubkey,
+    pub mint: Pubkey,
+    pub decimals: u8,
+    pub price: u64,
+    pub bump: u8,
+}
+
+impl PriceFeed {
+    // The price is what one whole token is worth in base units of the common unit
+    pub fn value(&self, amount: u64) -> u64 {
+        (amount as u128 * self.price as u128 / 10u128.pow(self.decimals as u32)) as u64
+    }
+}
+
+// After the discriminator the account has the same layout as in the other implementations
+const _: () = assert!(PriceFeed::INIT_SPACE == fundraiser_interface::state::price_feed::LEN);
\ No newline at end of file
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::{FundraiserError, MAX_REWARD_TIERS};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, InitSpace)]
+pub struct RewardTier {
+    // Smallest pledge, counting everything the contributor has put in, that qualifies for the tier
+    pub min_pledge: u64,
+    pub max_supply: u64,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct RewardTiers {
+    pub fundraiser: Pubkey,
+    pub tier_count: u8,
+    pub tiers: [RewardTier; MAX_REWARD_TIERS],
+    pub claimed: [u64; MAX_REWARD_TIERS],
+    pub bump: u8,
+}
+
+impl RewardTiers {
+    // Moves a contributor from their current tier (0 for none, otherwise the index plus one) to `index`
+    // once their pledge reaches its minimum, staying in the same tier does not take another slot
+    pub fn claim(&mut self, current: u8, index: u8, pledge: u64) -> Result<()> {
+        require!(index < self.tier_count, FundraiserError::InvalidRewardTier);
+
+        let tier = self.tiers[index as usize];
+        require!(pledge >= tier.min_pledge, FundraiserError::PledgeBelowTierMinimum);
+
+        if current == index + 1 {
+            return Ok(());
+        }
+
+        require!(
+            self.claimed[index as usize] < tier.max_supply,
+            FundraiserError::RewardTierSoldOut
+        );
+
+        self.release(current);
+        self.claimed[index as usize] += 1;
+
+        Ok(())
+    }
+
+    // Gives the slot of a contributor's tier back, 0 holds no slot
+    pub fn release(&mut self, current: u8) {
+        if current > 0 {
+            self.claimed[current as usize - 1] -= 1;
+        }
+    }
+}
+
+// After the discriminator the account has the same layout as in the other implementations
+const _: () = assert!(RewardTiers::INIT_SPACE == fundraiser_interface::state::reward_tiers::LEN);
\ No newline at end of file
@@ -1,7 +1,7 @@
 import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
 import { Fundraiser } from "../target/types/fundraiser";
-import { ASSOCIATED_TOKEN_PROGRAM_ID, TOKEN_PROGRAM_ID, createMint, getAssociatedTokenAddressSync, getOrCreateAssociatedTokenAccount, mintTo } from "@solana/spl-token";
+import { ASSOCIATED_TOKEN_PROGRAM_ID, ExtensionType, TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID, createInitializeMintInstruction, createInitializeTransferFeeConfigInstruction, createMint, getAssociatedTokenAddressSync, getMintLen, getOrCreateAssociatedTokenAccount, mintTo, transfer } from "@solana/spl-token";
 import NodeWallet from "@coral-xyz/anchor/dist/cjs/nodewallet";
 
 describe("fundraiser", () => {
@@ -21,10 +21,26 @@ describe("fundraiser", () => {
 
   const wallet = provider.wallet as NodeWallet;
 
-  const fundraiser = anchor.web3.PublicKey.findProgramAddressSync([Buffer.from("fundraiser"), maker.publicKey.toBuffer()], program.programId)[0];
+  const campaignId = new anchor.BN(0);
+
+  const fundraiser = anchor.web3.PublicKey.findProgramAddressSync([Buffer.from("fundraiser"), maker.publicKey.toBuffer(), campaignId.toArrayLike(Buffer, "le", 8)], program.programId)[0];
 
   const contributor = anchor.web3.PublicKey.findProgramAddressSync([Buffer.from("contributor"), fundraiser.toBuffer(), provider.publicKey.toBuffer()], program.programId)[0];
 
+  // Every contributor can put in up to 10% of the goal, with no hard cap on the total
+  const contributionLimits = {
+    minContribution: new anchor.BN(1),
+    maxContribution: new anchor.BN(10),
+    maxContributionKind: { percentage: {} },
+    hardCap: new anchor.BN(0),
+  };
+
+  // Campaign deadlines are unix timestamps, counted from now
+  const deadline = (seconds: number) => new anchor.BN(Math.floor(Date.now() / 1000) + seconds);
+
+  // The first campaign ends shortly so its contributions can be refunded
+  const campaignEnding = deadline(20);
+
   const confirm = async (signature: string): Promise<string> => {
     const block = await provider.connection.getLatestBlockhash();
     await provider.connection.confirmTransaction({
@@ -55,7 +71,7 @@ describe("fundraiser", () => {
 
     const tx = await program
     .methods
-    .initialize(new anchor.BN(30000000), 0)
+    .initialize(campaignId, new anchor.BN(30000000), campaignEnding, [], new anchor.BN(0), 0, 0, { fixed: {} }, contributionLimits, 0)
     .accountsPartial({
       maker: maker.publicKey,
       fundraiser,
@@ -75,11 +91,28 @@ describe("fundraiser", () => {
     console.log("Your transaction signature", tx);
   });
 
+  it("List maker campaigns", async () => {
+    // The maker pubkey sits right after the 8 byte account discriminator
+    const campaigns = await program.account.fundraiser.all([
+      {
+        memcmp: {
+          offset: 8,
+          bytes: maker.publicKey.toBase58(),
+        },
+      },
+    ]);
+
+    console.log("\nCampaigns for maker", maker.publicKey.toBase58());
+    campaigns.forEach((campaign) => {
+      console.log("Campaign", campaign.account.campaignId.toString(), campaign.publicKey.toBase58());
+    });
+  });
+
   it("Contribute to Fundraiser", async () => {
     const vault = getAssociatedTokenAddressSync(mint, fundraiser, true);
 
     const tx = await program.methods
-    .contribute(new anchor.BN(1000000))
+    .contribute(new anchor.BN(1000000), null)
     .accountsPartial({
       contributor: provider.publicKey,
       fundraiser,
@@ -104,7 +137,7 @@ describe("fundraiser", () => {
     const vault = getAssociatedTokenAddressSync(mint, fundraiser, true);
 
     const tx = await program.methods
-    .contribute(new anchor.BN(1000000))
+    .contribute(new anchor.BN(1000000), null)
     .accountsPartial({
       contributor: provider.publicKey,
       fundraiser,
@@ -131,7 +164,7 @@ describe("fundraiser", () => {
       const vault = getAssociatedTokenAddressSync(mint, fundraiser, true);
 
       const tx = await program.methods
-      .contribute(new anchor.BN(2000000))
+      .contribute(new anchor.BN(2000000), null)
       .accountsPartial({
         contributor: provider.publicKey,
         fundraiser,
@@ -186,6 +219,12 @@ describe("fundraiser", () => {
   it("Refund Contributions", async () => {
     const vault = getAssociatedTokenAddressSync(mint, fundraiser, true);
 
+    // Contributions can only be refunded once the fundraiser has ended
+    const waitMs = (campaignEnding.toNumber() + 2) * 1000 - Date.now();
+    if (waitMs > 0) {
+      await new Promise((resolve) => setTimeout(resolve, waitMs));
+    }
+
     let contributorAccount = await program.account.contributor.fetch(contributor);
     console.log("\nContributor balance", contributorAccount.amount.toString());
 
@@ -211,4 +250,732 @@ describe("fundraiser", () => {
     console.log("Your transaction signature", tx);
     console.log("Vault balance", (await provider.connection.getTokenAccountBalance(vault)).value.amount);
   });
+
+  it("Cancel Fundraiser and Refund", async () => {
+    const cancelledCampaignId = new anchor.BN(1);
+    const cancelledFundraiser = anchor.web3.PublicKey.findProgramAddressSync([Buffer.from("fundraiser"), maker.publicKey.toBuffer(), cancelledCampaignId.toArrayLike(Buffer, "le", 8)], program.programId)[0];
+    const cancelledContributor = anchor.web3.PublicKey.findProgramAddressSync([Buffer.from("contributor"), cancelledFundraiser.toBuffer(), provider.publicKey.toBuffer()], program.programId)[0];
+    const vault = getAssociatedTokenAddressSync(mint, cancelledFundraiser, true);
+
+    await program
+    .methods
+    .initialize(cancelledCampaignId, new anchor.BN(30000000), deadline(3600), [], new anchor.BN(0), 0, 0, { fixed: {} }, contributionLimits, 0)
+    .accountsPartial({
+      maker: maker.publicKey,
+      fundraiser: cancelledFundraiser,
+      mintToRaise: mint,
+      vault,
+      systemProgram: anchor.web3.SystemProgram.programId,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+    })
+    .signers([maker])
+    .rpc({
+      skipPreflight: true,
+    })
+    .then(confirm);
+
+    await program.methods
+    .contribute(new anchor.BN(1000000), null)
+    .accountsPartial({
+      contributor: provider.publicKey,
+      fundraiser: cancelledFundraiser,
+      contributorAccount: cancelledContributor,
+      contributorAta: contributorATA,
+      vault,
+      tokenProgram: TOKEN_PROGRAM_ID,
+    })
+    .rpc({
+      skipPreflight: true,
+    })
+    .then(confirm);
+
+    const tx = await program.methods
+    .cancel()
+    .accountsPartial({
+      maker: maker.publicKey,
+      fundraiser: cancelledFundraiser,
+    })
+    .signers([maker])
+    .rpc({
+      skipPreflight: true,
+    })
+    .then(confirm);
+
+    console.log("\nCancelled fundraiser", tx);
+
+    const refundTx = await program.methods
+    .refund()
+    .accountsPartial({
+      contributor: provider.publicKey,
+      maker: maker.publicKey,
+      mintToRaise: mint,
+      fundraiser: cancelledFundraiser,
+      contributorAccount: cancelledContributor,
+      contributorAta: contributorATA,
+      vault,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    })
+    .rpc({
+      skipPreflight: true,
+    })
+    .then(confirm);
+
+    console.log("Refunded contributions", refundTx);
+    console.log("Vault balance", (await provider.connection.getTokenAccountBalance(vault)).value.amount);
+  });
+
+  it("SOL Fundraiser Contribute, Cancel and Refund", async () => {
+    const solCampaignId = new anchor.BN(2);
+    const solFundraiser = anchor.web3.PublicKey.findProgramAddressSync([Buffer.from("fundraiser"), maker.publicKey.toBuffer(), solCampaignId.toArrayLike(Buffer, "le", 8)], program.programId)[0];
+    const solContributor = anchor.web3.PublicKey.findProgramAddressSync([Buffer.from("contributor"), solFundraiser.toBuffer(), provider.publicKey.toBuffer()], program.programId)[0];
+
+    await program
+    .methods
+    .initializeSol(solCampaignId, new anchor.BN(anchor.web3.LAMPORTS_PER_SOL), deadline(3600), { fixed: {} }, contributionLimits, 0)
+    .accountsPartial({
+      maker: maker.publicKey,
+      fundraiser: solFundraiser,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    })
+    .signers([maker])
+    .rpc({
+      skipPreflight: true,
+    })
+    .then(confirm);
+
+    const tx = await program.methods
+    .contributeSol(new anchor.BN(anchor.web3.LAMPORTS_PER_SOL / 20))
+    .accountsPartial({
+      contributor: provider.publicKey,
+      fundraiser: solFundraiser,
+      contributorAccount: solContributor,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    })
+    .rpc({
+      skipPreflight: true,
+    })
+    .then(confirm);
+
+    console.log("\nContributed SOL", tx);
+    console.log("Fundraiser balance", await provider.connection.getBalance(solFundraiser));
+
+    await program.methods
+    .cancel()
+    .accountsPartial({
+      maker: maker.publicKey,
+      fundraiser: solFundraiser,
+    })
+    .signers([maker])
+    .rpc({
+      skipPreflight: true,
+    })
+    .then(confirm);
+
+    const refundTx = await program.methods
+    .refundSol()
+    .accountsPartial({
+      contributor: provider.publicKey,
+      maker: maker.publicKey,
+      fundraiser: solFundraiser,
+      contributorAccount: solContributor,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    })
+    .rpc({
+      skipPreflight: true,
+    })
+    .then(confirm);
+
+    console.log("Refunded SOL contributions", refundTx);
+    console.log("Fundraiser balance", await provider.connection.getBalance(solFundraiser));
+  });
+
+  it("Token-2022 Fundraiser with a transfer fee", async () => {
+    const feeCampaignId = new anchor.BN(3);
+    const feeFundraiser = anchor.web3.PublicKey.findProgramAddressSync([Buffer.from("fundraiser"), maker.publicKey.toBuffer(), feeCampaignId.toArrayLike(Buffer, "le", 8)], program.programId)[0];
+    const feeContributor = anchor.web3.PublicKey.findProgramAddressSync([Buffer.from("contributor"), feeFundraiser.toBuffer(), provider.publicKey.toBuffer()], program.programId)[0];
+
+    // 1% transfer fee, capped at 1 token
+    const feeMint = anchor.web3.Keypair.generate();
+    const mintLen = getMintLen([ExtensionType.TransferFeeConfig]);
+    const createMintTx = new anchor.web3.Transaction().add(
+      anchor.web3.SystemProgram.createAccount({
+        fromPubkey: provider.publicKey,
+        newAccountPubkey: feeMint.publicKey,
+        space: mintLen,
+        lamports: await provider.connection.getMinimumBalanceForRentExemption(mintLen),
+        programId: TOKEN_2022_PROGRAM_ID,
+      }),
+      createInitializeTransferFeeConfigInstruction(feeMint.publicKey, provider.publicKey, provider.publicKey, 100, BigInt(1_000_000), TOKEN_2022_PROGRAM_ID),
+      createInitializeMintInstruction(feeMint.publicKey, 6, provider.publicKey, null, TOKEN_2022_PROGRAM_ID),
+    );
+    await provider.sendAndConfirm(createMintTx, [feeMint]);
+
+    const feeContributorATA = (await getOrCreateAssociatedTokenAccount(provider.connection, wallet.payer, feeMint.publicKey, wallet.publicKey, false, undefined, undefined, TOKEN_2022_PROGRAM_ID)).address;
+    await mintTo(provider.connection, wallet.payer, feeMint.publicKey, feeContributorATA, provider.publicKey, 1_000_000_0, [], undefined, TOKEN_2022_PROGRAM_ID);
+
+    const vault = getAssociatedTokenAddressSync(feeMint.publicKey, feeFundraiser, true, TOKEN_2022_PROGRAM_ID);
+
+    await program
+    .methods
+    .initialize(feeCampaignId, new anchor.BN(30000000), deadline(3600), [], new anchor.BN(0), 0, 0, { fixed: {} }, contributionLimits, 0)
+    .accountsPartial({
+      maker: maker.publicKey,
+      fundraiser: feeFundraiser,
+      mintToRaise: feeMint.publicKey,
+      vault,
+      systemProgram: anchor.web3.SystemProgram.programId,
+      tokenProgram: TOKEN_2022_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+    })
+    .signers([maker])
+    .rpc({
+      skipPreflight: true,
+    })
+    .then(confirm);
+
+    const tx = await program.methods
+    .contribute(new anchor.BN(1000000), null)
+    .accountsPartial({
+      contributor: provider.publicKey,
+      fundraiser: feeFundraiser,
+      contributorAccount: feeContributor,
+      contributorAta: feeContributorATA,
+      vault,
+      tokenProgram: TOKEN_2022_PROGRAM_ID,
+    })
+    .rpc({
+      skipPreflight: true,
+    })
+    .then(confirm);
+
+    console.log("\nContributed to Token-2022 fundraiser", tx);
+    console.log("Vault balance", (await provider.connection.getTokenAccountBalance(vault)).value.amount);
+
+    // The contributor is credited with what the vault received, after the transfer fee
+    let contributorAccount = await program.account.contributor.fetch(feeContributor);
+    console.log("Contributor balance", contributorAccount.amount.toString());
+  });
+
+  it("Multi-mint Fundraiser Contribute, Cancel and Refund", async () => {
+    const multiCampaignId = new anchor.BN(4);
+    const multiFundraiser = anchor.web3.PublicKey.findProgramAddressSync([Buffer.from("fundraiser"), maker.publicKey.toBuffer(), multiCampaignId.toArrayLike(Buffer, "le", 8)], program.programId)[0];
+    const multiContributor = anchor.web3.PublicKey.findProgramAddressSync([Buffer.from("contributor"), multiFundraiser.toBuffer(), provider.publicKey.toBuffer()], program.programId)[0];
+    const priceFeed = (mint: anchor.web3.PublicKey) => anchor.web3.PublicKey.findProgramAddressSync([Buffer.from("price_feed"), provider.publicKey.toBuffer(), mint.toBuffer()], program.programId)[0];
+
+    // A second stablecoin, both are worth 1 unit of the goal
+    const secondMint = await createMint(provider.connection, wallet.payer, provider.publicKey, provider.publicKey, 6);
+    const secondContributorATA = (await getOrCreateAssociatedTokenAccount(provider.connection, wallet.payer, secondMint, wallet.publicKey)).address;
+    await mintTo(provider.connection, wallet.payer, secondMint, secondContributorATA, provider.publicKey, 1_000_000_0);
+
+    for (const priceMint of [mint, secondMint]) {
+      await program.methods
+      .setPrice(new anchor.BN(1_000_000))
+      .accountsPartial({
+        authority: provider.publicKey,
+        mint: priceMint,
+        priceFeed: priceFeed(priceMint),
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc()
+      .then(confirm);
+    }
+
+    const vault = getAssociatedTokenAddressSync(mint, multiFundraiser, true);
+    const secondVault = getAssociatedTokenAddressSync(secondMint, multiFundraiser, true);
+
+    await program
+    .methods
+    .initialize(multiCampaignId, new anchor.BN(30000000), deadline(3600), [], new anchor.BN(0), 0, 0, { fixed: {} }, contributionLimits, 0)
+    .accountsPartial({
+      maker: maker.publicKey,
+      fundraiser: multiFundraiser,
+      mintToRaise: mint,
+      vault,
+      systemProgram: anchor.web3.SystemProgram.programId,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+    })
+    .signers([maker])
+    .rpc()
+    .then(confirm);
+
+    await program.methods
+    .addAcceptedMint()
+    .accountsPartial({
+      maker: maker.publicKey,
+      mint: secondMint,
+      priceFeed: priceFeed(secondMint),
+      fundraiser: multiFundraiser,
+      vault: secondVault,
+      tokenProgram: TOKEN_PROGRAM_ID,
+    })
+    .signers([maker])
+    .rpc()
+    .then(confirm);
+
+    const contributions: [anchor.web3.PublicKey, anchor.web3.PublicKey, anchor.web3.PublicKey][] = [
+      [mint, contributorATA, vault],
+      [secondMint, secondContributorATA, secondVault],
+    ];
+
+    for (const [contributionMint, contributionATA, contributionVault] of contributions) {
+      await program.methods
+      .contributeMulti(new anchor.BN(1000000))
+      .accountsPartial({
+        contributor: provider.publicKey,
+        mint: contributionMint,
+        priceFeed: priceFeed(contributionMint),
+        fundraiser: multiFundraiser,
+        contributorAccount: multiContributor,
+        contributorAta: contributionATA,
+        vault: contributionVault,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc()
+      .then(confirm);
+    }
+
+    let fundraiserAccount = await program.account.fundraiser.fetch(multiFundraiser);
+    console.log("\nRaised in the common unit", fundraiserAccount.currentAmount.toString());
+
+    await program.methods
+    .cancel()
+    .accountsPartial({
+      maker: maker.publicKey,
+      fundraiser: multiFundraiser,
+    })
+    .signers([maker])
+    .rpc()
+    .then(confirm);
+
+    // Each mint is refunded separately, in the tokens that were sent
+    for (const [contributionMint, contributionATA, contributionVault] of contributions) {
+      await program.methods
+      .refundMulti()
+      .accountsPartial({
+        contributor: provider.publicKey,
+        maker: maker.publicKey,
+        mint: contributionMint,
+        priceFeed: priceFeed(contributionMint),
+        fundraiser: multiFundraiser,
+        contributorAccount: multiContributor,
+        contributorAta: contributionATA,
+        vault: contributionVault,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc()
+      .then(confirm);
+    }
+
+    fundraiserAccount = await program.account.fundraiser.fetch(multiFundraiser);
+    console.log("Raised after refunds", fundraiserAccount.currentAmount.toString());
+    console.log("Second vault balance", (await provider.connection.getTokenAccountBalance(secondVault)).value.amount);
+  });
+
+  it("Contribution limits set by the maker", async () => {
+    const cappedCampaignId = new anchor.BN(5);
+    const cappedFundraiser = anchor.web3.PublicKey.findProgramAddressSync([Buffer.from("fundraiser"), maker.publicKey.toBuffer(), cappedCampaignId.toArrayLike(Buffer, "le", 8)], program.programId)[0];
+    const cappedContributor = anchor.web3.PublicKey.findProgramAddressSync([Buffer.from("contributor"), cappedFundraiser.toBuffer(), provider.publicKey.toBuffer()], program.programId)[0];
+    const vault = getAssociatedTokenAddressSync(mint, cappedFundraiser, true);
+
+    // No per-contributor maximum, but the fundraiser can never raise more than its goal
+    await program
+    .methods
+    .initialize(cappedCampaignId, new anchor.BN(1000000), deadline(3600), [], new anchor.BN(0), 0, 0, { fixed: {} }, {
+      minContribution: new anchor.BN(100000),
+      maxContribution: new anchor.BN(0),
+      maxContributionKind: { none: {} },
+      hardCap: new anchor.BN(1000000),
+    }, 0)
+    .accountsPartial({
+      maker: maker.publicKey,
+      fundraiser: cappedFundraiser,
+      mintToRaise: mint,
+      vault,
+      systemProgram: anchor.web3.SystemProgram.programId,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+    })
+    .signers([maker])
+    .rpc()
+    .then(confirm);
+
+    const contribute = (amount: number) => program.methods
+    .contribute(new anchor.BN(amount), null)
+    .accountsPartial({
+      contributor: provider.publicKey,
+      fundraiser: cappedFundraiser,
+      contributorAccount: cappedContributor,
+      contributorAta: contributorATA,
+      vault,
+      tokenProgram: TOKEN_PROGRAM_ID,
+    })
+    .rpc();
+
+    for (const [amount, expected] of [[50000, "ContributionTooSmall"], [1500000, "HardCapReached"]] as const) {
+      try {
+        await contribute(amount);
+        throw new Error(`Contributing ${amount} should have failed`);
+      } catch (error) {
+        if (!(error instanceof anchor.AnchorError)) throw error;
+        console.log(`\nContributing ${amount} failed with`, error.error.errorCode.code);
+        if (error.error.errorCode.code !== expected) throw error;
+      }
+    }
+
+    await contribute(1000000).then(confirm);
+    const fundraiserAccount = await program.account.fundraiser.fetch(cappedFundraiser);
+    console.log("\nRaised up to the hard cap", fundraiserAccount.currentAmount.toString());
+  });
+
+  it("Receipt tokens carry the pledge to whoever holds them", async () => {
+    const receiptCampaignId = new anchor.BN(6);
+    const receiptFundraiser = anchor.web3.PublicKey.findProgramAddressSync([Buffer.from("fundraiser"), maker.publicKey.toBuffer(), receiptCampaignId.toArrayLike(Buffer, "le", 8)], program.programId)[0];
+    const vault = getAssociatedTokenAddressSync(mint, receiptFundraiser, true);
+
+    await program
+    .methods
+    .initialize(receiptCampaignId, new anchor.BN(30000000), deadline(3600), [], new anchor.BN(0), 0, 0, { fixed: {} }, contributionLimits, 0)
+    .accountsPartial({
+      maker: maker.publicKey,
+      fundraiser: receiptFundraiser,
+      mintToRaise: mint,
+      vault,
+      systemProgram: anchor.web3.SystemProgram.programId,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+    })
+    .signers([maker])
+    .rpc()
+    .then(confirm);
+
+    // The fundraiser is the only one able to mint receipts, in the units of the raised mint
+    const receiptMint = await createMint(provider.connection, wallet.payer, receiptFundraiser, null, 6);
+
+    await program.methods
+    .enableReceipts()
+    .accountsPartial({
+      maker: maker.publicKey,
+      fundraiser: receiptFundraiser,
+      mintToRaise: mint,
+      receiptMint,
+      tokenProgram: TOKEN_PROGRAM_ID,
+    })
+    .signers([maker])
+    .rpc()
+    .then(confirm);
+
+    const contributorReceiptATA = getAssociatedTokenAddressSync(receiptMint, provider.publicKey);
+
+    await program.methods
+    .contributeReceipts(new anchor.BN(1000000))
+    .accountsPartial({
+      contributor: provider.publicKey,
+      fundraiser: receiptFundraiser,
+      receiptMint,
+      contributorAta: contributorATA,
+      contributorReceiptAta: contributorReceiptATA,
+      vault,
+      tokenProgram: TOKEN_PROGRAM_ID,
+    })
+    .rpc()
+    .then(confirm);
+
+    console.log("\nReceipts minted", (await provider.connection.getTokenAccountBalance(contributorReceiptATA)).value.amount);
+
+    // Hand half of the pledge to someone else, the refund follows the receipts
+    const holder = anchor.web3.Keypair.generate();
+    await provider.connection.requestAirdrop(holder.publicKey, 1 * anchor.web3.LAMPORTS_PER_SOL).then(confirm);
+    const holderReceiptATA = (await getOrCreateAssociatedTokenAccount(provider.connection, wallet.payer, receiptMint, holder.publicKey)).address;
+    await transfer(provider.connection, wallet.payer, contributorReceiptATA, holderReceiptATA, provider.publicKey, 500000);
+
+    await program.methods
+    .cancel()
+    .accountsPartial({
+      maker: maker.publicKey,
+      fundraiser: receiptFundraiser,
+    })
+    .signers([maker])
+    .rpc()
+    .then(confirm);
+
+    const refundTx = await program.methods
+    .refundReceipts()
+    .accountsPartial({
+      holder: holder.publicKey,
+      mintToRaise: mint,
+      receiptMint,
+      fundraiser: receiptFundraiser,
+      holderReceiptAccount: holderReceiptATA,
+      holderAta: getAssociatedTokenAddressSync(mint, holder.publicKey),
+      vault,
+      tokenProgram: TOKEN_PROGRAM_ID,
+    })
+    .signers([holder])
+    .rpc()
+    .then(confirm);
+
+    console.log("Refunded the receipt holder", refundTx);
+    console.log("Vault balance", (await provider.connection.getTokenAccountBalance(vault)).value.amount);
+  });
+
+  it("Reward tiers hand out a limited number of rewards", async () => {
+    const tierCampaignId = new anchor.BN(7);
+    const tierFundraiser = anchor.web3.PublicKey.findProgramAddressSync([Buffer.from("fundraiser"), maker.publicKey.toBuffer(), tierCampaignId.toArrayLike(Buffer, "le", 8)], program.programId)[0];
+    const tierContributor = anchor.web3.PublicKey.findProgramAddressSync([Buffer.from("contributor"), tierFundraiser.toBuffer(), provider.publicKey.toBuffer()], program.programId)[0];
+    const rewardTiers = anchor.web3.PublicKey.findProgramAddressSync([Buffer.from("reward_tiers"), tierFundraiser.toBuffer()], program.programId)[0];
+    const vault = getAssociatedTokenAddressSync(mint, tierFundraiser, true);
+
+    await program
+    .methods
+    .initialize(tierCampaignId, new anchor.BN(30000000), deadline(3600), [], new anchor.BN(0), 0, 0, { fixed: {} }, contributionLimits, 0)
+    .accountsPartial({
+      maker: maker.publicKey,
+      fundraiser: tierFundraiser,
+      mintToRaise: mint,
+      vault,
+      systemProgram: anchor.web3.SystemProgram.programId,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+    })
+    .signers([maker])
+    .rpc()
+    .then(confirm);
+
+    // A single reward for pledges of at least 1 token
+    await program.methods
+    .setRewardTiers([{ minPledge: new anchor.BN(1000000), maxSupply: new anchor.BN(1) }])
+    .accountsPartial({
+      maker: maker.publicKey,
+      fundraiser: tierFundraiser,
+      rewardTiers,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    })
+    .signers([maker])
+    .rpc()
+    .then(confirm);
+
+    const contribute = (amount: number) => program.methods
+    .contribute(new anchor.BN(amount), 0)
+    .accountsPartial({
+      contributor: provider.publicKey,
+      fundraiser: tierFundraiser,
+      contributorAccount: tierContributor,
+      contributorAta: contributorATA,
+      vault,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      rewardTiers,
+    })
+    .rpc();
+
+    try {
+      await contribute(500000);
+      throw new Error("Pledging below the tier minimum should have failed");
+    } catch (error) {
+      if (!(error instanceof anchor.AnchorError)) throw error;
+      console.log("\nPledging below the tier minimum failed with", error.error.errorCode.code);
+      if (error.error.errorCode.code !== "PledgeBelowTierMinimum") throw error;
+    }
+
+    await contribute(1000000).then(confirm);
+    let tiersAccount = await program.account.rewardTiers.fetch(rewardTiers);
+    console.log("Claimed rewards", tiersAccount.claimed[0].toString());
+
+    await program.methods
+    .cancel()
+    .accountsPartial({
+      maker: maker.publicKey,
+      fundraiser: tierFundraiser,
+    })
+    .signers([maker])
+    .rpc()
+    .then(confirm);
+
+    // The refund gives the reward back
+    await program.methods
+    .refund()
+    .accountsPartial({
+      contributor: provider.publicKey,
+      maker: maker.publicKey,
+      mintToRaise: mint,
+      fundraiser: tierFundraiser,
+      contributorAccount: tierContributor,
+      contributorAta: contributorATA,
+      vault,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      rewardTiers,
+    })
+    .rpc()
+    .then(confirm);
+
+    tiersAccount = await program.account.rewardTiers.fetch(rewardTiers);
+    console.log("Claimed rewards after the refund", tiersAccount.claimed[0].toString());
+  });
+
+  it("Platform fee is split off the payout", async () => {
+    const feeCampaignId = new anchor.BN(8);
+    const feeFundraiser = anchor.web3.PublicKey.findProgramAddressSync([Buffer.from("fundraiser"), maker.publicKey.toBuffer(), feeCampaignId.toArrayLike(Buffer, "le", 8)], program.programId)[0];
+    const feeContributor = anchor.web3.PublicKey.findProgramAddressSync([Buffer.from("contributor"), feeFundraiser.toBuffer(), provider.publicKey.toBuffer()], program.programId)[0];
+    const config = anchor.web3.PublicKey.findProgramAddressSync([Buffer.from("config")], program.programId)[0];
+    const programData = anchor.web3.PublicKey.findProgramAddressSync([program.programId.toBuffer()], anchor.web3.BPF_LOADER_UPGRADEABLE_PROGRAM_ID)[0];
+    const vault = getAssociatedTokenAddressSync(mint, feeFundraiser, true);
+
+    const feeRecipient = anchor.web3.Keypair.generate();
+    const feeRecipientATA = (await getOrCreateAssociatedTokenAccount(provider.connection, wallet.payer, mint, feeRecipient.publicKey)).address;
+
+    // Only the upgrade authority, the provider wallet here, can configure the platform
+    await program.methods
+    .initializeConfig(250, feeRecipient.publicKey)
+    .accountsPartial({
+      admin: provider.publicKey,
+      config,
+      programData,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    })
+    .rpc()
+    .then(confirm);
+
+    // The fee in force is snapshotted when the fundraiser is created
+    await program
+    .methods
+    .initialize(feeCampaignId, new anchor.BN(30000000), deadline(5), [], new anchor.BN(0), 0, 0, { flexible: {} }, contributionLimits, 0)
+    .accountsPartial({
+      maker: maker.publicKey,
+      fundraiser: feeFundraiser,
+      mintToRaise: mint,
+      vault,
+      systemProgram: anchor.web3.SystemProgram.programId,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      config,
+    })
+    .signers([maker])
+    .rpc()
+    .then(confirm);
+
+    const feeFundraiserAccount = await program.account.fundraiser.fetch(feeFundraiser);
+    console.log("\nFee snapshotted into the fundraiser", feeFundraiserAccount.feeBps);
+
+    await program.methods
+    .contribute(new anchor.BN(1000000), null)
+    .accountsPartial({
+      contributor: provider.publicKey,
+      fundraiser: feeFundraiser,
+      contributorAccount: feeContributor,
+      contributorAta: contributorATA,
+      vault,
+      tokenProgram: TOKEN_PROGRAM_ID,
+    })
+    .rpc()
+    .then(confirm);
+
+    // A flexible fundraiser pays out once the deadline passes
+    await new Promise((resolve) => setTimeout(resolve, 7000));
+
+    const makerBalance = (await provider.connection.getTokenAccountBalance(makerATA)).value.amount;
+
+    await program.methods
+    .checkContributions()
+    .accountsPartial({
+      maker: maker.publicKey,
+      mintToRaise: mint,
+      fundraiser: feeFundraiser,
+      makerAta: makerATA,
+      vault,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      config,
+      feeRecipientAta: feeRecipientATA,
+    })
+    .signers([maker])
+    .rpc()
+    .then(confirm);
+
+    console.log("Fee recipient received", (await provider.connection.getTokenAccountBalance(feeRecipientATA)).value.amount);
+    console.log("Maker received", Number((await provider.connection.getTokenAccountBalance(makerATA)).value.amount) - Number(makerBalance));
+  });
+
+  it("Amending a fundraiser lets contributors withdraw", async () => {
+    const amendedCampaignId = new anchor.BN(9);
+    const amendedFundraiser = anchor.web3.PublicKey.findProgramAddressSync([Buffer.from("fundraiser"), maker.publicKey.toBuffer(), amendedCampaignId.toArrayLike(Buffer, "le", 8)], program.programId)[0];
+    const amendedContributor = anchor.web3.PublicKey.findProgramAddressSync([Buffer.from("contributor"), amendedFundraiser.toBuffer(), provider.publicKey.toBuffer()], program.programId)[0];
+
+    // A flexible fundraiser keeps what it raises, and the maker can amend it once
+    await program
+    .methods
+    .initializeSol(amendedCampaignId, new anchor.BN(anchor.web3.LAMPORTS_PER_SOL), deadline(3600), { flexible: {} }, contributionLimits, 1)
+    .accountsPartial({
+      maker: maker.publicKey,
+      fundraiser: amendedFundraiser,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    })
+    .signers([maker])
+    .rpc()
+    .then(confirm);
+
+    await program.methods
+    .contributeSol(new anchor.BN(anchor.web3.LAMPORTS_PER_SOL / 20))
+    .accountsPartial({
+      contributor: provider.publicKey,
+      fundraiser: amendedFundraiser,
+      contributorAccount: amendedContributor,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    })
+    .rpc()
+    .then(confirm);
+
+    const refund = () => program.methods
+    .refundSol()
+    .accountsPartial({
+      contributor: provider.publicKey,
+      maker: maker.publicKey,
+      fundraiser: amendedFundraiser,
+      contributorAccount: amendedContributor,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    })
+    .rpc();
+
+    try {
+      await refund();
+      throw new Error("Refunding a flexible fundraiser should have failed");
+    } catch (error) {
+      if (!(error instanceof anchor.AnchorError)) throw error;
+      console.log("\nRefunding before the amendment failed with", error.error.errorCode.code);
+      if (error.error.errorCode.code !== "RefundsDisabled") throw error;
+    }
+
+    await program.methods
+    .amendGoal(new anchor.BN(anchor.web3.LAMPORTS_PER_SOL / 2))
+    .accountsPartial({
+      maker: maker.publicKey,
+      fundraiser: amendedFundraiser,
+    })
+    .signers([maker])
+    .rpc()
+    .then(confirm);
+
+    const amendedAccount = await program.account.fundraiser.fetch(amendedFundraiser);
+    console.log("Goal lowered to", amendedAccount.amountToRaise.toString(), "grace period ends", amendedAccount.graceEnding.toString());
+
+    try {
+      await program.methods
+      .extendDeadline(deadline(7200))
+      .accountsPartial({
+        maker: maker.publicKey,
+        fundraiser: amendedFundraiser,
+      })
+      .signers([maker])
+      .rpc();
+      throw new Error("A second amendment should have failed");
+    } catch (error) {
+      if (!(error instanceof anchor.AnchorError)) throw error;
+      console.log("A second amendment failed with", error.error.errorCode.code);
+      if (error.error.errorCode.code !== "AmendmentsExhausted") throw error;
+    }
+
+    // Within the grace period the contributor can get out
+    await refund().then(confirm);
+    console.log("Fundraiser balance after the refund", await provider.connection.getBalance(amendedFundraiser));
+  });
 });
@@ -0,0 +1,370 @@
+use std::path::PathBuf;
+
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use fundraiser_client::{Campaign, ContributionLimits, Implementation, MaxContributionKind, Milestone};
+use solana_sdk::{hash::Hash, pubkey::Pubkey};
+
+#[derive(Parser)]
+#[command(name = "fundraiser", about = "Create and operate fundraisers", version)]
+pub struct Cli {
+    /// RPC endpoint to read accounts from and send transactions to
+    #[arg(long, global = true, default_value = "http://127.0.0.1:8899")]
+    pub url: String,
+
+    /// Which program the fundraiser lives in
+    #[arg(long, global = true, value_enum, default_value_t = ProgramArg::Native)]
+    pub program: ProgramArg,
+
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Copy, Clone, ValueEnum)]
+pub enum ProgramArg {
+    Anchor,
+    Native,
+    Optimised,
+}
+
+impl From<ProgramArg> for Implementation {
+    fn from(program: ProgramArg) -> Self {
+        match program {
+            ProgramArg::Anchor => Implementation::Anchor,
+            ProgramArg::Native => Implementation::Native,
+            ProgramArg::Optimised => Implementation::Optimised,
+        }
+    }
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Create a fundraiser, in SOL when no mint is given
+    Create {
+        /// Keypair file of the maker, who pays for the transaction
+        #[arg(long)]
+        maker: PathBuf,
+        #[command(flatten)]
+        campaign: CampaignArgs,
+        /// Goal in base units
+        #[arg(long)]
+        amount: u64,
+        /// Unix timestamp after which contributions close
+        #[arg(long)]
+        time_ending: i64,
+        /// Let the maker keep what was raised even if the goal is missed
+        #[arg(long)]
+        flexible: bool,
+        /// PERCENTAGE:UNLOCK_TIME, repeat for every milestone
+        #[arg(long = "milestone", value_parser = parse_milestone)]
+        milestones: Vec<Milestone>,
+        /// Seconds backers have to vote on each milestone
+        #[arg(long, default_value_t = 0)]
+        voting_period: i64,
+        #[arg(long, default_value_t = 0)]
+        quorum_bps: u16,
+        #[arg(long, default_value_t = 0)]
+        approval_bps: u16,
+        #[command(flatten)]
+        limits: LimitArgs,
+        /// How many times the deadline can be extended or the goal lowered later on
+        #[arg(long, default_value_t = 0)]
+        max_amendments: u8,
+        #[command(flatten)]
+        send: SendArgs,
+    },
+    /// Push the deadline out, contributors get a grace period to withdraw
+    ExtendDeadline {
+        /// Keypair file of the maker, who pays for the transaction
+        #[arg(long)]
+        maker: PathBuf,
+        #[arg(long, default_value_t = 0)]
+        campaign_id: u64,
+        /// New unix timestamp after which contributions close
+        #[arg(long)]
+        time_ending: i64,
+        #[command(flatten)]
+        send: SendArgs,
+    },
+    /// Lower the goal, contributors get a grace period to withdraw
+    AmendGoal {
+        /// Keypair file of the maker, who pays for the transaction
+        #[arg(long)]
+        maker: PathBuf,
+        #[arg(long, default_value_t = 0)]
+        campaign_id: u64,
+        /// New goal in base units
+        #[arg(long)]
+        amount: u64,
+        #[command(flatten)]
+        send: SendArgs,
+    },
+    /// Contribute to a fundraiser
+    Contribute {
+        /// Keypair file of the contributor, who pays for the transaction
+        #[arg(long)]
+        contributor: PathBuf,
+        #[arg(long)]
+        maker: Pubkey,
+        #[command(flatten)]
+        campaign: CampaignArgs,
+        /// Amount in base units
+        #[arg(long)]
+        amount: u64,
+        #[command(flatten)]
+        send: SendArgs,
+    },
+    /// Get a contribution back from a fundraiser that missed its goal, was cancelled or was just amended
+    Refund {
+        /// Keypair file of the contributor, who pays for the transaction
+        #[arg(long)]
+        contributor: PathBuf,
+        #[arg(long)]
+        maker: Pubkey,
+        #[command(flatten)]
+        campaign: CampaignArgs,
+        #[command(flatten)]
+        send: SendArgs,
+    },
+    /// Move the raised funds to the maker once the goal is met
+    Withdraw {
+        /// Keypair file of the maker, who pays for the transaction
+        #[arg(long)]
+        maker: PathBuf,
+        #[command(flatten)]
+        campaign: CampaignArgs,
+        /// Fee recipient of the platform config, needed when the fundraiser pays a platform fee
+        #[arg(long)]
+        fee_recipient: Option<Pubkey>,
+        #[command(flatten)]
+        send: SendArgs,
+    },
+    /// Print the state of a fundraiser
+    Show {
+        #[arg(long)]
+        maker: Pubkey,
+        #[arg(long, default_value_t = 0)]
+        campaign_id: u64,
+    },
+    /// Print everyone who still has a contribution in a fundraiser
+    ListContributors {
+        #[arg(long)]
+        maker: Pubkey,
+        #[arg(long, default_value_t = 0)]
+        campaign_id: u64,
+        /// How many of the latest fundraiser transactions to look through
+        #[arg(long, default_value_t = 1_000)]
+        limit: usize,
+    },
+}
+
+#[derive(Args)]
+pub struct CampaignArgs {
+    #[arg(long, default_value_t = 0)]
+    pub campaign_id: u64,
+    /// Mint being raised, leave out for a SOL fundraiser
+    #[arg(long)]
+    pub mint: Option<Pubkey>,
+    /// Token program of the mint, Token-2022 mints need it
+    #[arg(long)]
+    pub token_program: Option<Pubkey>,
+}
+
+impl CampaignArgs {
+    pub fn campaign(&self, implementation: Implementation, maker: Pubkey) -> Campaign {
+        let campaign = match self.mint {
+            Some(mint) => Campaign::new(implementation, maker, self.campaign_id, mint),
+            None => Campaign::sol(implementation, maker, self.campaign_id),
+        };
+
+        match self.token_program {
+            Some(token_program) => campaign.with_token_program(token_program),
+            None => campaign,
+        }
+    }
+}
+
+#[derive(Args)]
+pub struct LimitArgs {
+    #[arg(long, default_value_t = 0)]
+    pub min_contribution: u64,
+    /// Most a single contributor can put in, in base units
+    #[arg(long, conflicts_with = "max_contribution_percent")]
+    pub max_contribution: Option<u64>,
+    /// Most a single contributor can put in, as a percentage of the goal
+    #[arg(long)]
+    pub max_contribution_percent: Option<u64>,
+    /// Contributions stop once this much has been raised
+    #[arg(long, default_value_t = 0)]
+    pub hard_cap: u64,
+}
+
+impl LimitArgs {
+    pub fn contribution_limits(&self) -> ContributionLimits {
+        let (max_contribution, max_contribution_kind) = match (self.max_contribution, self.max_contribution_percent) {
+            (Some(amount), _) => (amount, MaxContributionKind::Absolute),
+            (_, Some(percentage)) => (percentage, MaxContributionKind::Percentage),
+            _ => (0, MaxContributionKind::None),
+        };
+
+        ContributionLimits {
+            min_contribution: self.min_contribution,
+            max_contribution,
+            max_contribution_kind,
+            hard_cap: self.hard_cap,
+        }
+    }
+}
+
+#[derive(Args)]
+pub struct SendArgs {
+    /// Only sign the transaction and write it, bincode serialized, to this file
+    #[arg(long, requires = "blockhash")]
+    pub sign_only: Option<PathBuf>,
+    /// Blockhash to sign with instead of asking the RPC endpoint for the latest one
+    #[arg(long)]
+    pub blockhash: Option<Hash>,
+}
+
+fn parse_milestone(value: &str) -> Result<Milestone, String> {
+    let (percentage, unlock_time) = value.split_once(':').ok_or("Expected PERCENTAGE:UNLOCK_TIME")?;
+
+    Ok(Milestone {
+        percentage: percentage
+            .parse()
+            .map_err(|_| format!("Invalid percentage {}", percentage))?,
+        unlock_time: unlock_time
+            .parse()
+            .map_err(|_| format!("Invalid unlock time {}", unlock_time))?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Cli, clap::Error> {
+        let maker = Pubkey::new_unique().to_string();
+        let mut argv = vec![
+            "fundraiser",
+            "contribute",
+            "--contributor",
+            "id.json",
+            "--maker",
+            maker.as_str(),
+            "--amount",
+            "1",
+        ];
+        argv.extend_from_slice(args);
+        Cli::try_parse_from(argv)
+    }
+
+    fn create(args: &[&str]) -> Result<Cli, clap::Error> {
+        let mut argv = vec![
+            "fundraiser",
+            "create",
+            "--maker",
+            "id.json",
+            "--amount",
+            "1000",
+            "--time-ending",
+            "100",
+        ];
+        argv.extend_from_slice(args);
+        Cli::try_parse_from(argv)
+    }
+
+    fn limits(cli: Cli) -> ContributionLimits {
+        match cli.command {
+            Command::Create { limits, .. } => limits.contribution_limits(),
+            _ => panic!("Expected create"),
+        }
+    }
+
+    #[test]
+    fn milestone() {
+        assert_eq!(
+            parse_milestone("60:1700000000"),
+            Ok(Milestone {
+                percentage: 60,
+                unlock_time: 1_700_000_000
+            })
+        );
+        assert!(parse_milestone("60").is_err());
+        assert!(parse_milestone("sixty:1700000000").is_err());
+        assert!(parse_milestone("60:soon").is_err());
+        assert!(parse_milestone("300:1700000000").is_err());
+    }
+
+    #[test]
+    fn milestones_repeat() {
+        let cli = create(&["--milestone", "60:2000", "--milestone", "40:3000"]).unwrap();
+        let Command::Create { milestones, .. } = cli.command else {
+            panic!("Expected create");
+        };
+
+        assert_eq!(
+            milestones,
+            vec![
+                Milestone {
+                    percentage: 60,
+                    unlock_time: 2_000
+                },
+                Milestone {
+                    percentage: 40,
+                    unlock_time: 3_000
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn no_limits() {
+        assert_eq!(limits(create(&[]).unwrap()), ContributionLimits::default());
+    }
+
+    #[test]
+    fn absolute_max_contribution() {
+        let limits = limits(
+            create(&[
+                "--min-contribution",
+                "10",
+                "--max-contribution",
+                "500",
+                "--hard-cap",
+                "900",
+            ])
+            .unwrap(),
+        );
+
+        assert_eq!(
+            limits,
+            ContributionLimits {
+                min_contribution: 10,
+                max_contribution: 500,
+                max_contribution_kind: MaxContributionKind::Absolute,
+                hard_cap: 900,
+            }
+        );
+    }
+
+    #[test]
+    fn percentage_max_contribution() {
+        let limits = limits(create(&["--max-contribution-percent", "25"]).unwrap());
+
+        assert_eq!(limits.max_contribution, 25);
+        assert_eq!(limits.max_contribution_kind, MaxContributionKind::Percentage);
+    }
+
+    #[test]
+    fn max_contribution_kinds_conflict() {
+        assert!(create(&["--max-contribution", "500", "--max-contribution-percent", "25"]).is_err());
+    }
+
+    #[test]
+    fn sign_only_needs_a_blockhash() {
+        assert!(parse(&["--sign-only", "tx.bin"]).is_err());
+
+        let blockhash = Hash::new_unique().to_string();
+        assert!(parse(&["--sign-only", "tx.bin", "--blockhash", blockhash.as_str()]).is_ok());
+    }
+}
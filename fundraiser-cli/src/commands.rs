@@ -0,0 +1,471 @@
+use std::{error::Error, fs, path::Path, str::FromStr};
+
+use fundraiser_client::{
+    Campaign, Contributor, FundingMode, Fundraiser, FundraiserInstruction, Implementation, InitializeArgs,
+    InitializeSolArgs,
+};
+use solana_client::{
+    rpc_client::{GetConfirmedSignaturesForAddress2Config, RpcClient},
+    rpc_config::RpcTransactionConfig,
+};
+use solana_sdk::{
+    commitment_config::CommitmentConfig,
+    instruction::Instruction,
+    pubkey::Pubkey,
+    signature::{read_keypair_file, Keypair, Signature},
+    signer::Signer,
+    transaction::Transaction,
+};
+use solana_transaction_status::UiTransactionEncoding;
+use spl_associated_token_account::instruction::create_associated_token_account_idempotent;
+
+use crate::{
+    args::{Cli, Command, SendArgs},
+    output,
+};
+
+type Result<T> = std::result::Result<T, Box<dyn Error>>;
+
+pub fn run(cli: Cli) -> Result<()> {
+    let implementation = Implementation::from(cli.program);
+    let rpc = RpcClient::new_with_commitment(cli.url, CommitmentConfig::confirmed());
+
+    match cli.command {
+        Command::Create {
+            maker,
+            campaign,
+            amount,
+            time_ending,
+            flexible,
+            milestones,
+            voting_period,
+            quorum_bps,
+            approval_bps,
+            limits,
+            max_amendments,
+            send,
+        } => {
+            let maker = read_keypair(&maker)?;
+            let campaign = campaign.campaign(implementation, maker.pubkey());
+            let funding_mode = if flexible {
+                FundingMode::Flexible
+            } else {
+                FundingMode::Fixed
+            };
+
+            let instruction = create_instruction(
+                &campaign,
+                InitializeArgs {
+                    amount_to_raise: amount,
+                    time_ending,
+                    milestones,
+                    voting_period,
+                    quorum_bps,
+                    approval_bps,
+                    funding_mode,
+                    contribution_limits: limits.contribution_limits(),
+                    max_amendments,
+                },
+            );
+
+            println!("Fundraiser: {}", campaign.fundraiser().0);
+            submit(&rpc, &send, &maker, &[instruction])
+        }
+        Command::ExtendDeadline {
+            maker,
+            campaign_id,
+            time_ending,
+            send,
+        } => {
+            let maker = read_keypair(&maker)?;
+            let campaign = Campaign::sol(implementation, maker.pubkey(), campaign_id);
+
+            submit(&rpc, &send, &maker, &[campaign.extend_deadline(time_ending)])
+        }
+        Command::AmendGoal {
+            maker,
+            campaign_id,
+            amount,
+            send,
+        } => {
+            let maker = read_keypair(&maker)?;
+            let campaign = Campaign::sol(implementation, maker.pubkey(), campaign_id);
+
+            submit(&rpc, &send, &maker, &[campaign.amend_goal(amount)])
+        }
+        Command::Contribute {
+            contributor,
+            maker,
+            campaign,
+            amount,
+            send,
+        } => {
+            let contributor = read_keypair(&contributor)?;
+            let campaign = campaign.campaign(implementation, maker);
+
+            let instruction = contribute_instruction(&campaign, &contributor.pubkey(), amount);
+
+            submit(&rpc, &send, &contributor, &[instruction])
+        }
+        Command::Refund {
+            contributor,
+            maker,
+            campaign,
+            send,
+        } => {
+            let contributor = read_keypair(&contributor)?;
+            let campaign = campaign.campaign(implementation, maker);
+
+            let instruction = refund_instruction(&campaign, &contributor.pubkey());
+
+            submit(&rpc, &send, &contributor, &[instruction])
+        }
+        Command::Withdraw {
+            maker,
+            campaign,
+            fee_recipient,
+            send,
+        } => {
+            let maker = read_keypair(&maker)?;
+            let campaign = campaign.campaign(implementation, maker.pubkey());
+
+            let instructions = withdraw_instructions(&campaign, fee_recipient.as_ref());
+
+            submit(&rpc, &send, &maker, &instructions)
+        }
+        Command::Show { maker, campaign_id } => {
+            let campaign = Campaign::sol(implementation, maker, campaign_id);
+            let fundraiser = campaign.fundraiser().0;
+
+            let account = rpc.get_account(&fundraiser)?;
+            let state = Fundraiser::decode(implementation, &account.data)?;
+
+            println!("Fundraiser: {}", fundraiser);
+            output::print_fundraiser(&state);
+            Ok(())
+        }
+        Command::ListContributors {
+            maker,
+            campaign_id,
+            limit,
+        } => {
+            let campaign = Campaign::sol(implementation, maker, campaign_id);
+
+            for contributor in contributors(&rpc, &campaign, limit)? {
+                // Anchor closes the account of a refunded contributor, the other programs zero it
+                let Ok(account) = rpc.get_account(&campaign.contributor_account(&contributor)) else {
+                    continue;
+                };
+                let state = Contributor::decode(implementation, &account.data)?;
+                if state.amount == 0 && state.amounts.iter().all(|amount| *amount == 0) {
+                    continue;
+                }
+                output::print_contributor(&contributor, &state);
+            }
+            Ok(())
+        }
+    }
+}
+
+fn read_keypair(path: &Path) -> Result<Keypair> {
+    read_keypair_file(path).map_err(|error| format!("Failed to read keypair {}: {}", path.display(), error).into())
+}
+
+fn is_sol(campaign: &Campaign) -> bool {
+    campaign.mint == Pubkey::default()
+}
+
+// SOL fundraisers have no milestones or voting, so those arguments are dropped for them
+fn create_instruction(campaign: &Campaign, args: InitializeArgs) -> Instruction {
+    if is_sol(campaign) {
+        campaign.initialize_sol(&InitializeSolArgs {
+            amount_to_raise: args.amount_to_raise,
+            time_ending: args.time_ending,
+            funding_mode: args.funding_mode,
+            contribution_limits: args.contribution_limits,
+            max_amendments: args.max_amendments,
+        })
+    } else {
+        campaign.initialize(&args)
+    }
+}
+
+fn contribute_instruction(campaign: &Campaign, contributor: &Pubkey, amount: u64) -> Instruction {
+    if is_sol(campaign) {
+        campaign.contribute_sol(contributor, amount)
+    } else {
+        campaign.contribute(contributor, amount)
+    }
+}
+
+fn refund_instruction(campaign: &Campaign, contributor: &Pubkey) -> Instruction {
+    if is_sol(campaign) {
+        campaign.refund_sol(contributor)
+    } else {
+        campaign.refund(contributor)
+    }
+}
+
+fn withdraw_instructions(campaign: &Campaign, fee_recipient: Option<&Pubkey>) -> Vec<Instruction> {
+    if is_sol(campaign) {
+        return match fee_recipient {
+            Some(fee_recipient) => vec![campaign.checker_sol_with_fee(fee_recipient)],
+            None => vec![campaign.checker_sol()],
+        };
+    }
+
+    // The maker's token account receives the funds, Anchor creates it if needed
+    let mut instructions = Vec::new();
+    if campaign.implementation != Implementation::Anchor {
+        instructions.push(create_associated_token_account_idempotent(
+            &campaign.maker,
+            &campaign.maker,
+            &campaign.mint,
+            &campaign.token_program,
+        ));
+    }
+    match fee_recipient {
+        Some(fee_recipient) => {
+            instructions.push(create_associated_token_account_idempotent(
+                &campaign.maker,
+                fee_recipient,
+                &campaign.mint,
+                &campaign.token_program,
+            ));
+            instructions.push(campaign.checker_with_fee(fee_recipient));
+        }
+        None => instructions.push(campaign.checker()),
+    }
+    instructions
+}
+
+// Signs with the payer, then either writes the transaction to a file or sends it
+fn submit(rpc: &RpcClient, send: &SendArgs, payer: &Keypair, instructions: &[Instruction]) -> Result<()> {
+    let blockhash = match send.blockhash {
+        Some(blockhash) => blockhash,
+        None => rpc.get_latest_blockhash()?,
+    };
+    let transaction = Transaction::new_signed_with_payer(instructions, Some(&payer.pubkey()), &[payer], blockhash);
+
+    match &send.sign_only {
+        Some(path) => {
+            fs::write(path, bincode::serialize(&transaction)?)?;
+            println!("Signed transaction written to {}", path.display());
+        }
+        None => {
+            let signature = rpc.send_and_confirm_transaction(&transaction)?;
+            println!("Signature: {}", signature);
+        }
+    }
+
+    Ok(())
+}
+
+// Contributor accounts do not record the fundraiser, so the contributors are found in its
+// transaction history: the first account of every contribution is the contributor.
+fn contributors(rpc: &RpcClient, campaign: &Campaign, limit: usize) -> Result<Vec<Pubkey>> {
+    let implementation = campaign.implementation;
+    let program_id = campaign.program_id();
+    let discriminators = [
+        FundraiserInstruction::Contribute,
+        FundraiserInstruction::ContributeSol,
+        FundraiserInstruction::ContributeMulti,
+    ]
+    .map(|instruction| implementation.discriminator(instruction));
+
+    let signatures = rpc.get_signatures_for_address_with_config(
+        &campaign.fundraiser().0,
+        GetConfirmedSignaturesForAddress2Config {
+            limit: Some(limit),
+            ..Default::default()
+        },
+    )?;
+
+    let mut contributors = Vec::new();
+    for status in signatures.iter().filter(|status| status.err.is_none()) {
+        let transaction = rpc.get_transaction_with_config(
+            &Signature::from_str(&status.signature)?,
+            RpcTransactionConfig {
+                encoding: Some(UiTransactionEncoding::Base64),
+                commitment: Some(CommitmentConfig::confirmed()),
+                max_supported_transaction_version: Some(0),
+            },
+        )?;
+        let Some(transaction) = transaction.transaction.transaction.decode() else {
+            continue;
+        };
+
+        let keys = transaction.message.static_account_keys();
+        for instruction in transaction.message.instructions() {
+            if keys.get(instruction.program_id_index as usize) != Some(&program_id)
+                || !discriminators
+                    .iter()
+                    .any(|discriminator| instruction.data.starts_with(discriminator))
+            {
+                continue;
+            }
+
+            let Some(contributor) = instruction.accounts.first().and_then(|index| keys.get(*index as usize)) else {
+                continue;
+            };
+            if !contributors.contains(contributor) {
+                contributors.push(*contributor);
+            }
+        }
+    }
+
+    Ok(contributors)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fundraiser_client::{ContributionLimits, Milestone};
+
+    fn args() -> InitializeArgs {
+        InitializeArgs {
+            amount_to_raise: 1_000_000,
+            time_ending: 1_000,
+            milestones: vec![Milestone {
+                percentage: 100,
+                unlock_time: 2_000,
+            }],
+            voting_period: 500,
+            quorum_bps: 5_000,
+            approval_bps: 6_000,
+            funding_mode: FundingMode::Fixed,
+            contribution_limits: ContributionLimits::default(),
+            max_amendments: 1,
+        }
+    }
+
+    fn token_campaign(implementation: Implementation) -> Campaign {
+        Campaign::new(implementation, Pubkey::new_unique(), 3, Pubkey::new_unique())
+    }
+
+    fn sol_campaign(implementation: Implementation) -> Campaign {
+        Campaign::sol(implementation, Pubkey::new_unique(), 3)
+    }
+
+    #[test]
+    fn create_picks_the_instruction_for_the_currency() {
+        for implementation in Implementation::ALL {
+            let campaign = token_campaign(implementation);
+            assert_eq!(create_instruction(&campaign, args()), campaign.initialize(&args()));
+
+            let campaign = sol_campaign(implementation);
+            let instruction = create_instruction(&campaign, args());
+            assert_eq!(instruction.program_id, implementation.program_id());
+            assert!(instruction
+                .data
+                .starts_with(&implementation.discriminator(FundraiserInstruction::InitializeSol)));
+            assert_eq!(
+                instruction,
+                campaign.initialize_sol(&InitializeSolArgs {
+                    amount_to_raise: 1_000_000,
+                    time_ending: 1_000,
+                    funding_mode: FundingMode::Fixed,
+                    contribution_limits: ContributionLimits::default(),
+                    max_amendments: 1,
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn contribute_and_refund_pick_the_instruction_for_the_currency() {
+        let contributor = Pubkey::new_unique();
+
+        for implementation in Implementation::ALL {
+            let campaign = token_campaign(implementation);
+            let instruction = contribute_instruction(&campaign, &contributor, 500);
+            assert_eq!(instruction.program_id, implementation.program_id());
+            assert!(instruction
+                .data
+                .starts_with(&implementation.discriminator(FundraiserInstruction::Contribute)));
+            assert_eq!(instruction, campaign.contribute(&contributor, 500));
+            assert_eq!(
+                refund_instruction(&campaign, &contributor),
+                campaign.refund(&contributor)
+            );
+
+            let campaign = sol_campaign(implementation);
+            let instruction = contribute_instruction(&campaign, &contributor, 500);
+            assert!(instruction
+                .data
+                .starts_with(&implementation.discriminator(FundraiserInstruction::ContributeSol)));
+            assert_eq!(instruction, campaign.contribute_sol(&contributor, 500));
+            assert_eq!(
+                refund_instruction(&campaign, &contributor),
+                campaign.refund_sol(&contributor)
+            );
+        }
+    }
+
+    #[test]
+    fn withdraw_sol() {
+        let fee_recipient = Pubkey::new_unique();
+
+        for implementation in Implementation::ALL {
+            let campaign = sol_campaign(implementation);
+            assert_eq!(withdraw_instructions(&campaign, None), vec![campaign.checker_sol()]);
+            assert_eq!(
+                withdraw_instructions(&campaign, Some(&fee_recipient)),
+                vec![campaign.checker_sol_with_fee(&fee_recipient)]
+            );
+        }
+    }
+
+    #[test]
+    fn withdraw_creates_the_maker_token_account() {
+        for implementation in [Implementation::Native, Implementation::Optimised] {
+            let campaign = token_campaign(implementation);
+            let instructions = withdraw_instructions(&campaign, None);
+
+            assert_eq!(instructions.len(), 2);
+            assert_eq!(instructions[0].program_id, spl_associated_token_account::ID);
+            assert_eq!(
+                instructions[0],
+                create_associated_token_account_idempotent(
+                    &campaign.maker,
+                    &campaign.maker,
+                    &campaign.mint,
+                    &campaign.token_program
+                )
+            );
+            assert_eq!(instructions[1], campaign.checker());
+        }
+    }
+
+    #[test]
+    fn withdraw_leaves_the_maker_token_account_to_anchor() {
+        let campaign = token_campaign(Implementation::Anchor);
+
+        assert_eq!(withdraw_instructions(&campaign, None), vec![campaign.checker()]);
+    }
+
+    #[test]
+    fn withdraw_creates_the_fee_recipient_token_account() {
+        let fee_recipient = Pubkey::new_unique();
+        let campaign = token_campaign(Implementation::Native);
+        let instructions = withdraw_instructions(&campaign, Some(&fee_recipient));
+
+        assert_eq!(instructions.len(), 3);
+        assert_eq!(
+            instructions[1],
+            create_associated_token_account_idempotent(
+                &campaign.maker,
+                &fee_recipient,
+                &campaign.mint,
+                &campaign.token_program
+            )
+        );
+        assert_eq!(instructions[2], campaign.checker_with_fee(&fee_recipient));
+
+        let campaign = token_campaign(Implementation::Anchor);
+        let instructions = withdraw_instructions(&campaign, Some(&fee_recipient));
+
+        assert_eq!(instructions.len(), 2);
+        assert_eq!(instructions[1], campaign.checker_with_fee(&fee_recipient));
+    }
+}
@@ -0,0 +1,14 @@
+mod args;
+mod commands;
+mod output;
+
+use clap::Parser;
+
+fn main() {
+    let cli = args::Cli::parse();
+
+    if let Err(error) = commands::run(cli) {
+        eprintln!("Error: {}", error);
+        std::process::exit(1);
+    }
+}
\ No newline at end of file
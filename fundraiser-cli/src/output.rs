@@ -0,0 +1,62 @@
+use fundraiser_client::{Contributor, Fundraiser};
+use solana_sdk::pubkey::Pubkey;
+
+pub fn print_fundraiser(fundraiser: &Fundraiser) {
+    println!("Maker: {}", fundraiser.maker);
+    if fundraiser.is_sol() {
+        println!("Raising: SOL");
+    } else {
+        println!("Mint to raise: {}", fundraiser.mint_to_raise);
+    }
+    println!("Campaign id: {}", fundraiser.campaign_id);
+    println!("Status: {:?}", fundraiser.status);
+    println!("Funding mode: {:?}", fundraiser.funding_mode);
+    println!("Amount to raise: {}", fundraiser.amount_to_raise);
+    println!("Current amount: {}", fundraiser.current_amount);
+    println!("Time ending: {}", fundraiser.time_ending);
+    if fundraiser.fee_bps > 0 {
+        println!("Platform fee: {} bps", fundraiser.fee_bps);
+    }
+    if fundraiser.max_amendments > 0 {
+        println!("Amendments: {} of {} used", fundraiser.amendment_count, fundraiser.max_amendments);
+    }
+    if fundraiser.grace_ending > 0 {
+        println!("Contributors can withdraw until: {}", fundraiser.grace_ending);
+    }
+
+    let limits = &fundraiser.contribution_limits;
+    println!(
+        "Contribution limits: min {}, max {} ({:?}), hard cap {}",
+        limits.min_contribution, limits.max_contribution, limits.max_contribution_kind, limits.hard_cap
+    );
+
+    if fundraiser.milestone_count > 0 {
+        println!(
+            "Milestones: {} of {} released, {} released so far",
+            fundraiser.milestones_released, fundraiser.milestone_count, fundraiser.amount_released
+        );
+        for milestone in fundraiser.milestones[..fundraiser.milestone_count as usize].iter() {
+            println!("  {}% unlocking at {}", milestone.percentage, milestone.unlock_time);
+        }
+        println!(
+            "Voting: {} s, quorum {} bps, approval {} bps, {} for, {} against",
+            fundraiser.voting_period,
+            fundraiser.quorum_bps,
+            fundraiser.approval_bps,
+            fundraiser.votes_for,
+            fundraiser.votes_against
+        );
+    }
+
+    if fundraiser.is_multi_mint() {
+        println!("Price authority: {}", fundraiser.price_authority);
+        let accepted = fundraiser.accepted_mints.iter().zip(fundraiser.amounts_raised.iter());
+        for (mint, amount) in accepted.take(fundraiser.accepted_mint_count as usize) {
+            println!("  {}: {} raised", mint, amount);
+        }
+    }
+}
+
+pub fn print_contributor(wallet: &Pubkey, contributor: &Contributor) {
+    println!("{}: {}", wallet, contributor.amount);
+}
\ No newline at end of file
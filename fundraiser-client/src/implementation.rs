@@ -0,0 +1,91 @@
+use fundraiser_interface::{
+    instruction::FundraiserInstruction,
+    state::{ANCHOR_DISCRIMINATOR_LEN, OPTIMISED_HEADER_LEN},
+};
+use solana_program::{hash::hash, pubkey::Pubkey};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Implementation {
+    Anchor,
+    Native,
+    Optimised,
+}
+
+impl Implementation {
+    pub const ALL: [Implementation; 3] = [
+        Implementation::Anchor,
+        Implementation::Native,
+        Implementation::Optimised,
+    ];
+
+    pub fn program_id(&self) -> Pubkey {
+        Pubkey::new_from_array(match self {
+            Implementation::Anchor => five8_const::decode_32_const("Eoiuq1dXvHxh6dLx3wh9gj8kSAUpga11krTrbfF5XYsC"),
+            Implementation::Native => five8_const::decode_32_const("CsPs2Z5QLAuqWCw2enAmCfCNmdGahBYysL3cvxv9mmxH"),
+            Implementation::Optimised => five8_const::decode_32_const("22222222222222222222222222222222222222222222"),
+        })
+    }
+
+    // The bytes that select the instruction, the payload follows them
+    pub fn discriminator(&self, instruction: FundraiserInstruction) -> Vec<u8> {
+        match self {
+            Implementation::Anchor => anchor_instruction_discriminator(anchor_name(instruction)).to_vec(),
+            _ => vec![instruction as u8],
+        }
+    }
+
+    // Where the shared layout starts in a program account
+    pub fn layout_offset(&self) -> usize {
+        match self {
+            Implementation::Anchor => ANCHOR_DISCRIMINATOR_LEN,
+            Implementation::Native => 0,
+            Implementation::Optimised => OPTIMISED_HEADER_LEN,
+        }
+    }
+}
+
+// The name of the handler in the Anchor program
+fn anchor_name(instruction: FundraiserInstruction) -> &'static str {
+    match instruction {
+        FundraiserInstruction::Initialize => "initialize",
+        FundraiserInstruction::Checker => "check_contributions",
+        FundraiserInstruction::Contribute => "contribute",
+        FundraiserInstruction::Refund => "refund",
+        FundraiserInstruction::Cancel => "cancel",
+        FundraiserInstruction::ReleaseMilestone => "release_milestone",
+        FundraiserInstruction::Vote => "vote",
+        FundraiserInstruction::RejectMilestone => "reject_milestone",
+        FundraiserInstruction::InitializeSol => "initialize_sol",
+        FundraiserInstruction::ContributeSol => "contribute_sol",
+        FundraiserInstruction::CheckerSol => "check_contributions_sol",
+        FundraiserInstruction::RefundSol => "refund_sol",
+        FundraiserInstruction::SetPrice => "set_price",
+        FundraiserInstruction::AddAcceptedMint => "add_accepted_mint",
+        FundraiserInstruction::ContributeMulti => "contribute_multi",
+        FundraiserInstruction::CheckerMulti => "check_contributions_multi",
+        FundraiserInstruction::RefundMulti => "refund_multi",
+        FundraiserInstruction::EnableReceipts => "enable_receipts",
+        FundraiserInstruction::ContributeReceipts => "contribute_receipts",
+        FundraiserInstruction::RefundReceipts => "refund_receipts",
+        FundraiserInstruction::SetRewardTiers => "set_reward_tiers",
+        FundraiserInstruction::InitializeConfig => "initialize_config",
+        FundraiserInstruction::ExtendDeadline => "extend_deadline",
+        FundraiserInstruction::AmendGoal => "amend_goal",
+    }
+}
+
+// Anchor selects the instruction with the first 8 bytes of sha256("global:<name>")
+fn anchor_instruction_discriminator(name: &str) -> [u8; ANCHOR_DISCRIMINATOR_LEN] {
+    anchor_discriminator("global", name)
+}
+
+// and tags its accounts with the first 8 bytes of sha256("account:<Name>")
+pub(crate) fn anchor_account_discriminator(name: &str) -> [u8; ANCHOR_DISCRIMINATOR_LEN] {
+    anchor_discriminator("account", name)
+}
+
+fn anchor_discriminator(namespace: &str, name: &str) -> [u8; ANCHOR_DISCRIMINATOR_LEN] {
+    let mut discriminator = [0u8; ANCHOR_DISCRIMINATOR_LEN];
+    discriminator.copy_from_slice(&hash(format!("{namespace}:{name}").as_bytes()).to_bytes()[..ANCHOR_DISCRIMINATOR_LEN]);
+    discriminator
+}
\ No newline at end of file
@@ -0,0 +1,1001 @@
+use fundraiser_interface::{
+    instruction::{pack_initialize_config, FundraiserInstruction, InitializeData, InitializeSolData, SetRewardTiersData},
+    state::{contribution_limits, reward_tier, ContributionLimits, FundingMode, Milestone, RewardTier, MAX_MILESTONES, MAX_REWARD_TIERS},
+};
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    system_program,
+};
+
+use crate::{pda, Implementation};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InitializeArgs {
+    pub amount_to_raise: u64,
+    pub time_ending: i64,
+    // At most MAX_MILESTONES, without any the maker gets everything at once
+    pub milestones: Vec<Milestone>,
+    pub voting_period: i64,
+    pub quorum_bps: u16,
+    pub approval_bps: u16,
+    pub funding_mode: FundingMode,
+    pub contribution_limits: ContributionLimits,
+    // How many times the maker can extend the deadline or lower the goal
+    pub max_amendments: u8,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InitializeSolArgs {
+    pub amount_to_raise: u64,
+    pub time_ending: i64,
+    pub funding_mode: FundingMode,
+    pub contribution_limits: ContributionLimits,
+    pub max_amendments: u8,
+}
+
+// One campaign of a maker on one implementation, every builder derives the PDAs it needs from it
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Campaign {
+    pub implementation: Implementation,
+    pub maker: Pubkey,
+    pub campaign_id: u64,
+    // Pubkey::default() for a SOL campaign
+    pub mint: Pubkey,
+    pub token_program: Pubkey,
+}
+
+impl Campaign {
+    pub fn new(implementation: Implementation, maker: Pubkey, campaign_id: u64, mint: Pubkey) -> Self {
+        Self {
+            implementation,
+            maker,
+            campaign_id,
+            mint,
+            token_program: spl_token::ID,
+        }
+    }
+
+    pub fn sol(implementation: Implementation, maker: Pubkey, campaign_id: u64) -> Self {
+        Self::new(implementation, maker, campaign_id, Pubkey::default())
+    }
+
+    // Token-2022 mints live under their own program, and so do their token accounts
+    pub fn with_token_program(mut self, token_program: Pubkey) -> Self {
+        self.token_program = token_program;
+        self
+    }
+
+    pub fn program_id(&self) -> Pubkey {
+        self.implementation.program_id()
+    }
+
+    pub fn fundraiser(&self) -> (Pubkey, u8) {
+        pda::fundraiser(&self.program_id(), &self.maker, self.campaign_id)
+    }
+
+    pub fn contributor_account(&self, contributor: &Pubkey) -> Pubkey {
+        pda::contributor(&self.program_id(), &self.fundraiser().0, contributor).0
+    }
+
+    pub fn price_feed(&self, price_authority: &Pubkey, mint: &Pubkey) -> Pubkey {
+        pda::price_feed(&self.program_id(), price_authority, mint).0
+    }
+
+    pub fn reward_tiers(&self) -> Pubkey {
+        pda::reward_tiers(&self.program_id(), &self.fundraiser().0).0
+    }
+
+    pub fn config(&self) -> Pubkey {
+        pda::config(&self.program_id()).0
+    }
+
+    pub fn vault(&self, mint: &Pubkey) -> Pubkey {
+        pda::vault(&self.fundraiser().0, mint, &self.token_program)
+    }
+
+    pub fn token_account(&self, owner: &Pubkey, mint: &Pubkey) -> Pubkey {
+        pda::vault(owner, mint, &self.token_program)
+    }
+
+    pub fn initialize(&self, args: &InitializeArgs) -> Instruction {
+        assert!(args.milestones.len() <= MAX_MILESTONES, "At most {} milestones", MAX_MILESTONES);
+        let (fundraiser, bump) = self.fundraiser();
+
+        match self.implementation {
+            Implementation::Anchor => {
+                let mut data = self.implementation.discriminator(FundraiserInstruction::Initialize);
+                data.extend_from_slice(&self.campaign_id.to_le_bytes());
+                data.extend_from_slice(&args.amount_to_raise.to_le_bytes());
+                data.extend_from_slice(&args.time_ending.to_le_bytes());
+                data.extend_from_slice(&(args.milestones.len() as u32).to_le_bytes());
+                for milestone in args.milestones.iter() {
+                    data.push(milestone.percentage);
+                    data.extend_from_slice(&milestone.unlock_time.to_le_bytes());
+                }
+                data.extend_from_slice(&args.voting_period.to_le_bytes());
+                data.extend_from_slice(&args.quorum_bps.to_le_bytes());
+                data.extend_from_slice(&args.approval_bps.to_le_bytes());
+                data.push(args.funding_mode as u8);
+                data.extend_from_slice(&pack_limits(&args.contribution_limits));
+                data.push(args.max_amendments);
+
+                self.build(
+                    data,
+                    vec![
+                        AccountMeta::new(self.maker, true),
+                        AccountMeta::new_readonly(self.mint, false),
+                        AccountMeta::new(fundraiser, false),
+                        AccountMeta::new(self.vault(&self.mint), false),
+                        AccountMeta::new_readonly(system_program::ID, false),
+                        AccountMeta::new_readonly(self.token_program, false),
+                        AccountMeta::new_readonly(spl_associated_token_account::ID, false),
+                        AccountMeta::new_readonly(self.config(), false),
+                    ],
+                )
+            }
+            _ => {
+                let mut milestones = [Milestone::default(); MAX_MILESTONES];
+                milestones[..args.milestones.len()].copy_from_slice(&args.milestones);
+
+                let data = InitializeData {
+                    amount_to_raise: args.amount_to_raise,
+                    time_ending: args.time_ending,
+                    campaign_id: self.campaign_id,
+                    bump,
+                    milestone_count: args.milestones.len() as u8,
+                    milestones,
+                    voting_period: args.voting_period,
+                    quorum_bps: args.quorum_bps,
+                    approval_bps: args.approval_bps,
+                    funding_mode: args.funding_mode,
+                    contribution_limits: args.contribution_limits,
+                    max_amendments: args.max_amendments,
+                };
+
+                self.build(
+                    data.pack().to_vec(),
+                    vec![
+                        AccountMeta::new(self.maker, true),
+                        AccountMeta::new(fundraiser, false),
+                        AccountMeta::new_readonly(self.mint, false),
+                        AccountMeta::new(self.vault(&self.mint), false),
+                        AccountMeta::new_readonly(system_program::ID, false),
+                        AccountMeta::new_readonly(self.token_program, false),
+                        AccountMeta::new_readonly(spl_associated_token_account::ID, false),
+                        AccountMeta::new_readonly(self.config(), false),
+                    ],
+                )
+            }
+        }
+    }
+
+    pub fn contribute(&self, contributor: &Pubkey, amount: u64) -> Instruction {
+        self.contribute_to_tier(contributor, amount, None)
+    }
+
+    // Claims a slot of the tier at `index` once the contributor's pledge reaches its minimum
+    pub fn contribute_with_reward_tier(&self, contributor: &Pubkey, amount: u64, index: u8) -> Instruction {
+        self.contribute_to_tier(contributor, amount, Some(index))
+    }
+
+    fn contribute_to_tier(&self, contributor: &Pubkey, amount: u64, reward_tier: Option<u8>) -> Instruction {
+        let fundraiser = self.fundraiser().0;
+        // The amount followed by the tier as a borsh Option<u8>
+        let payload = match reward_tier {
+            Some(index) => [amount.to_le_bytes().as_ref(), &[1, index]].concat(),
+            None => [amount.to_le_bytes().as_ref(), &[0]].concat(),
+        };
+        let data = self.data(FundraiserInstruction::Contribute, &payload);
+
+        let mut instruction = match self.implementation {
+            Implementation::Anchor => self.build(
+                data,
+                vec![
+                    AccountMeta::new(*contributor, true),
+                    AccountMeta::new_readonly(self.mint, false),
+                    AccountMeta::new(fundraiser, false),
+                    AccountMeta::new(self.contributor_account(contributor), false),
+                    AccountMeta::new(self.token_account(contributor, &self.mint), false),
+                    AccountMeta::new(self.vault(&self.mint), false),
+                    AccountMeta::new_readonly(self.token_program, false),
+                    AccountMeta::new_readonly(system_program::ID, false),
+                ],
+            ),
+            _ => self.build(
+                data,
+                vec![
+                    AccountMeta::new(*contributor, true),
+                    AccountMeta::new(self.contributor_account(contributor), false),
+                    AccountMeta::new(self.token_account(contributor, &self.mint), false),
+                    AccountMeta::new(fundraiser, false),
+                    AccountMeta::new(self.vault(&self.mint), false),
+                    AccountMeta::new_readonly(self.token_program, false),
+                    AccountMeta::new_readonly(self.mint, false),
+                    AccountMeta::new_readonly(system_program::ID, false),
+                ],
+            ),
+        };
+        self.push_reward_tiers(&mut instruction, reward_tier.is_some());
+
+        instruction
+    }
+
+    pub fn checker(&self) -> Instruction {
+        let mut instruction = self.payout(FundraiserInstruction::Checker);
+        instruction.accounts.extend(self.fee_accounts(None));
+
+        instruction
+    }
+
+    // A fundraiser created while the platform charged a fee pays part of it out to the fee recipient
+    pub fn checker_with_fee(&self, fee_recipient: &Pubkey) -> Instruction {
+        let mut instruction = self.payout(FundraiserInstruction::Checker);
+        instruction.accounts.extend(self.fee_accounts(Some(self.token_account(fee_recipient, &self.mint))));
+
+        instruction
+    }
+
+    pub fn release_milestone(&self) -> Instruction {
+        let mut instruction = self.payout(FundraiserInstruction::ReleaseMilestone);
+        instruction.accounts.extend(self.fee_accounts(None));
+
+        instruction
+    }
+
+    // Every release of a fundraiser created while the platform charged a fee pays part of it to the fee recipient
+    pub fn release_milestone_with_fee(&self, fee_recipient: &Pubkey) -> Instruction {
+        let mut instruction = self.payout(FundraiserInstruction::ReleaseMilestone);
+        instruction.accounts.extend(self.fee_accounts(Some(self.token_account(fee_recipient, &self.mint))));
+
+        instruction
+    }
+
+    pub fn refund(&self, contributor: &Pubkey) -> Instruction {
+        self.refund_from_tier(contributor, false)
+    }
+
+    // A contributor holding a reward tier has to hand its slot back along with the refund
+    pub fn refund_with_reward_tier(&self, contributor: &Pubkey) -> Instruction {
+        self.refund_from_tier(contributor, true)
+    }
+
+    fn refund_from_tier(&self, contributor: &Pubkey, reward_tier: bool) -> Instruction {
+        let fundraiser = self.fundraiser().0;
+        let data = self.data(FundraiserInstruction::Refund, &[]);
+
+        let mut instruction = match self.implementation {
+            Implementation::Anchor => self.build(
+                data,
+                vec![
+                    AccountMeta::new(*contributor, true),
+                    AccountMeta::new_readonly(self.maker, false),
+                    AccountMeta::new_readonly(self.mint, false),
+                    AccountMeta::new(fundraiser, false),
+                    AccountMeta::new(self.contributor_account(contributor), false),
+                    AccountMeta::new(self.token_account(contributor, &self.mint), false),
+                    AccountMeta::new(self.vault(&self.mint), false),
+                    AccountMeta::new_readonly(self.token_program, false),
+                    AccountMeta::new_readonly(system_program::ID, false),
+                ],
+            ),
+            _ => self.build(
+                data,
+                vec![
+                    AccountMeta::new(*contributor, true),
+                    AccountMeta::new_readonly(self.maker, false),
+                    AccountMeta::new(fundraiser, false),
+                    AccountMeta::new(self.contributor_account(contributor), false),
+                    AccountMeta::new(self.token_account(contributor, &self.mint), false),
+                    AccountMeta::new(self.vault(&self.mint), false),
+                    AccountMeta::new_readonly(self.token_program, false),
+                    AccountMeta::new_readonly(self.mint, false),
+                ],
+            ),
+        };
+        self.push_reward_tiers(&mut instruction, reward_tier);
+
+        instruction
+    }
+
+    pub fn cancel(&self) -> Instruction {
+        self.build(
+            self.data(FundraiserInstruction::Cancel, &[]),
+            vec![
+                AccountMeta::new_readonly(self.maker, true),
+                AccountMeta::new(self.fundraiser().0, false),
+            ],
+        )
+    }
+
+    // Both amendments open a grace period in which contributors can withdraw
+    pub fn extend_deadline(&self, time_ending: i64) -> Instruction {
+        self.amend(FundraiserInstruction::ExtendDeadline, &time_ending.to_le_bytes())
+    }
+
+    pub fn amend_goal(&self, amount_to_raise: u64) -> Instruction {
+        self.amend(FundraiserInstruction::AmendGoal, &amount_to_raise.to_le_bytes())
+    }
+
+    fn amend(&self, instruction: FundraiserInstruction, payload: &[u8]) -> Instruction {
+        self.build(
+            self.data(instruction, payload),
+            vec![
+                AccountMeta::new_readonly(self.maker, true),
+                AccountMeta::new(self.fundraiser().0, false),
+            ],
+        )
+    }
+
+    pub fn vote(&self, contributor: &Pubkey, approve: bool) -> Instruction {
+        self.build(
+            self.data(FundraiserInstruction::Vote, &[approve as u8]),
+            vec![
+                AccountMeta::new_readonly(*contributor, true),
+                AccountMeta::new(self.fundraiser().0, false),
+                AccountMeta::new(self.contributor_account(contributor), false),
+            ],
+        )
+    }
+
+    // Anyone can reject a milestone once the vote is over, Anchor wants the caller to sign
+    pub fn reject_milestone(&self, signer: &Pubkey) -> Instruction {
+        let data = self.data(FundraiserInstruction::RejectMilestone, &[]);
+
+        match self.implementation {
+            Implementation::Anchor => self.build(
+                data,
+                vec![
+                    AccountMeta::new_readonly(*signer, true),
+                    AccountMeta::new(self.fundraiser().0, false),
+                ],
+            ),
+            _ => self.build(data, vec![AccountMeta::new(self.fundraiser().0, false)]),
+        }
+    }
+
+    pub fn initialize_sol(&self, args: &InitializeSolArgs) -> Instruction {
+        let (fundraiser, bump) = self.fundraiser();
+
+        let data = match self.implementation {
+            Implementation::Anchor => {
+                let mut data = self.implementation.discriminator(FundraiserInstruction::InitializeSol);
+                data.extend_from_slice(&self.campaign_id.to_le_bytes());
+                data.extend_from_slice(&args.amount_to_raise.to_le_bytes());
+                data.extend_from_slice(&args.time_ending.to_le_bytes());
+                data.push(args.funding_mode as u8);
+                data.extend_from_slice(&pack_limits(&args.contribution_limits));
+                data.push(args.max_amendments);
+                data
+            }
+            _ => InitializeSolData {
+                amount_to_raise: args.amount_to_raise,
+                time_ending: args.time_ending,
+                campaign_id: self.campaign_id,
+                bump,
+                funding_mode: args.funding_mode,
+                contribution_limits: args.contribution_limits,
+                max_amendments: args.max_amendments,
+            }
+            .pack()
+            .to_vec(),
+        };
+
+        self.build(
+            data,
+            vec![
+                AccountMeta::new(self.maker, true),
+                AccountMeta::new(fundraiser, false),
+                AccountMeta::new_readonly(system_program::ID, false),
+                AccountMeta::new_readonly(self.config(), false),
+            ],
+        )
+    }
+
+    pub fn contribute_sol(&self, contributor: &Pubkey, amount: u64) -> Instruction {
+        let fundraiser = self.fundraiser().0;
+        let data = self.data(FundraiserInstruction::ContributeSol, &amount.to_le_bytes());
+
+        match self.implementation {
+            Implementation::Anchor => self.build(
+                data,
+                vec![
+                    AccountMeta::new(*contributor, true),
+                    AccountMeta::new(fundraiser, false),
+                    AccountMeta::new(self.contributor_account(contributor), false),
+                    AccountMeta::new_readonly(system_program::ID, false),
+                ],
+            ),
+            _ => self.build(
+                data,
+                vec![
+                    AccountMeta::new(*contributor, true),
+                    AccountMeta::new(self.contributor_account(contributor), false),
+                    AccountMeta::new(fundraiser, false),
+                    AccountMeta::new_readonly(system_program::ID, false),
+                ],
+            ),
+        }
+    }
+
+    pub fn checker_sol(&self) -> Instruction {
+        self.checker_sol_to(None)
+    }
+
+    // The fee of a SOL fundraiser goes to the fee recipient's wallet
+    pub fn checker_sol_with_fee(&self, fee_recipient: &Pubkey) -> Instruction {
+        self.checker_sol_to(Some(*fee_recipient))
+    }
+
+    fn checker_sol_to(&self, fee_recipient: Option<Pubkey>) -> Instruction {
+        let mut accounts = vec![
+            AccountMeta::new(self.maker, true),
+            AccountMeta::new(self.fundraiser().0, false),
+        ];
+        if self.implementation == Implementation::Anchor {
+            accounts.push(AccountMeta::new_readonly(system_program::ID, false));
+        }
+        accounts.extend(self.fee_accounts(fee_recipient));
+
+        self.build(
+            self.data(FundraiserInstruction::CheckerSol, &[]),
+            accounts,
+        )
+    }
+
+    pub fn refund_sol(&self, contributor: &Pubkey) -> Instruction {
+        let fundraiser = self.fundraiser().0;
+        let data = self.data(FundraiserInstruction::RefundSol, &[]);
+
+        match self.implementation {
+            Implementation::Anchor => self.build(
+                data,
+                vec![
+                    AccountMeta::new(*contributor, true),
+                    AccountMeta::new_readonly(self.maker, false),
+                    AccountMeta::new(fundraiser, false),
+                    AccountMeta::new(self.contributor_account(contributor), false),
+                    AccountMeta::new_readonly(system_program::ID, false),
+                ],
+            ),
+            _ => self.build(
+                data,
+                vec![
+                    AccountMeta::new(*contributor, true),
+                    AccountMeta::new(fundraiser, false),
+                    AccountMeta::new(self.contributor_account(contributor), false),
+                ],
+            ),
+        }
+    }
+
+    pub fn add_accepted_mint(&self, mint: &Pubkey, price_authority: &Pubkey) -> Instruction {
+        let fundraiser = self.fundraiser().0;
+        let price_feed = self.price_feed(price_authority, mint);
+        let data = self.data(FundraiserInstruction::AddAcceptedMint, &[]);
+
+        match self.implementation {
+            Implementation::Anchor => self.build(
+                data,
+                vec![
+                    AccountMeta::new(self.maker, true),
+                    AccountMeta::new_readonly(*mint, false),
+                    AccountMeta::new_readonly(price_feed, false),
+                    AccountMeta::new(fundraiser, false),
+                    AccountMeta::new(self.vault(mint), false),
+                    AccountMeta::new_readonly(self.token_program, false),
+                    AccountMeta::new_readonly(system_program::ID, false),
+                    AccountMeta::new_readonly(spl_associated_token_account::ID, false),
+                ],
+            ),
+            _ => self.build(
+                data,
+                vec![
+                    AccountMeta::new_readonly(self.maker, true),
+                    AccountMeta::new_readonly(*mint, false),
+                    AccountMeta::new_readonly(price_feed, false),
+                    AccountMeta::new(fundraiser, false),
+                ],
+            ),
+        }
+    }
+
+    pub fn contribute_multi(&self, contributor: &Pubkey, mint: &Pubkey, price_authority: &Pubkey, amount: u64) -> Instruction {
+        let fundraiser = self.fundraiser().0;
+        let price_feed = self.price_feed(price_authority, mint);
+        let data = self.data(FundraiserInstruction::ContributeMulti, &amount.to_le_bytes());
+
+        match self.implementation {
+            Implementation::Anchor => self.build(
+                data,
+                vec![
+                    AccountMeta::new(*contributor, true),
+                    AccountMeta::new_readonly(*mint, false),
+                    AccountMeta::new_readonly(price_feed, false),
+                    AccountMeta::new(fundraiser, false),
+                    AccountMeta::new(self.contributor_account(contributor), false),
+                    AccountMeta::new(self.token_account(contributor, mint), false),
+                    AccountMeta::new(self.vault(mint), false),
+                    AccountMeta::new_readonly(self.token_program, false),
+                    AccountMeta::new_readonly(system_program::ID, false),
+                ],
+            ),
+            _ => self.build(
+                data,
+                vec![
+                    AccountMeta::new(*contributor, true),
+                    AccountMeta::new(self.contributor_account(contributor), false),
+                    AccountMeta::new(self.token_account(contributor, mint), false),
+                    AccountMeta::new(fundraiser, false),
+                    AccountMeta::new(self.vault(mint), false),
+                    AccountMeta::new_readonly(self.token_program, false),
+                    AccountMeta::new_readonly(*mint, false),
+                    AccountMeta::new_readonly(price_feed, false),
+                    AccountMeta::new_readonly(system_program::ID, false),
+                ],
+            ),
+        }
+    }
+
+    // `accepted_mints` in the order they were added, their price feeds value what has been raised
+    pub fn checker_multi(&self, mint: &Pubkey, price_authority: &Pubkey, accepted_mints: &[Pubkey]) -> Instruction {
+        self.checker_multi_to(mint, price_authority, accepted_mints, None)
+    }
+
+    // A fundraiser created while the platform charged a fee pays part of every vault to the fee recipient
+    pub fn checker_multi_with_fee(
+        &self,
+        mint: &Pubkey,
+        price_authority: &Pubkey,
+        accepted_mints: &[Pubkey],
+        fee_recipient: &Pubkey,
+    ) -> Instruction {
+        let fee_recipient_ta = self.token_account(fee_recipient, mint);
+        self.checker_multi_to(mint, price_authority, accepted_mints, Some(fee_recipient_ta))
+    }
+
+    fn checker_multi_to(
+        &self,
+        mint: &Pubkey,
+        price_authority: &Pubkey,
+        accepted_mints: &[Pubkey],
+        fee_recipient: Option<Pubkey>,
+    ) -> Instruction {
+        let fundraiser = self.fundraiser().0;
+        let price_feed = self.price_feed(price_authority, mint);
+        let data = self.data(FundraiserInstruction::CheckerMulti, &[]);
+
+        let mut accounts = match self.implementation {
+            Implementation::Anchor => vec![
+                AccountMeta::new(self.maker, true),
+                AccountMeta::new_readonly(*mint, false),
+                AccountMeta::new_readonly(price_feed, false),
+                AccountMeta::new(fundraiser, false),
+                AccountMeta::new(self.vault(mint), false),
+                AccountMeta::new(self.token_account(&self.maker, mint), false),
+                AccountMeta::new_readonly(self.token_program, false),
+                AccountMeta::new_readonly(system_program::ID, false),
+                AccountMeta::new_readonly(spl_associated_token_account::ID, false),
+            ],
+            _ => vec![
+                AccountMeta::new(self.maker, true),
+                AccountMeta::new_readonly(*mint, false),
+                AccountMeta::new(fundraiser, false),
+                AccountMeta::new(self.vault(mint), false),
+                AccountMeta::new(self.token_account(&self.maker, mint), false),
+                AccountMeta::new_readonly(self.token_program, false),
+                AccountMeta::new_readonly(price_feed, false),
+            ],
+        };
+        // The price feeds of every accepted mint come after the fee accounts
+        accounts.extend(self.fee_accounts(fee_recipient));
+        accounts.extend(self.price_feeds(price_authority, accepted_mints));
+
+        self.build(data, accounts)
+    }
+
+    // `accepted_mints` in the order they were added, their price feeds value what has been raised
+    pub fn refund_multi(
+        &self,
+        contributor: &Pubkey,
+        mint: &Pubkey,
+        price_authority: &Pubkey,
+        accepted_mints: &[Pubkey],
+    ) -> Instruction {
+        let fundraiser = self.fundraiser().0;
+        let price_feed = self.price_feed(price_authority, mint);
+        let data = self.data(FundraiserInstruction::RefundMulti, &[]);
+
+        let mut accounts = match self.implementation {
+            Implementation::Anchor => vec![
+                AccountMeta::new(*contributor, true),
+                AccountMeta::new_readonly(self.maker, false),
+                AccountMeta::new_readonly(*mint, false),
+                AccountMeta::new_readonly(price_feed, false),
+                AccountMeta::new(fundraiser, false),
+                AccountMeta::new(self.contributor_account(contributor), false),
+                AccountMeta::new(self.token_account(contributor, mint), false),
+                AccountMeta::new(self.vault(mint), false),
+                AccountMeta::new_readonly(self.token_program, false),
+                AccountMeta::new_readonly(system_program::ID, false),
+            ],
+            _ => vec![
+                AccountMeta::new(*contributor, true),
+                AccountMeta::new_readonly(self.maker, false),
+                AccountMeta::new(fundraiser, false),
+                AccountMeta::new(self.contributor_account(contributor), false),
+                AccountMeta::new(self.token_account(contributor, mint), false),
+                AccountMeta::new(self.vault(mint), false),
+                AccountMeta::new_readonly(self.token_program, false),
+                AccountMeta::new_readonly(*mint, false),
+                AccountMeta::new_readonly(price_feed, false),
+            ],
+        };
+        accounts.extend(self.price_feeds(price_authority, accepted_mints));
+
+        self.build(data, accounts)
+    }
+
+    // The receipt mint has to be created beforehand, with the fundraiser PDA as its mint authority
+    pub fn enable_receipts(&self, receipt_mint: &Pubkey) -> Instruction {
+        let mut accounts = vec![
+            AccountMeta::new_readonly(self.maker, true),
+            AccountMeta::new(self.fundraiser().0, false),
+            AccountMeta::new_readonly(self.mint, false),
+            AccountMeta::new_readonly(*receipt_mint, false),
+        ];
+        if self.implementation == Implementation::Anchor {
+            accounts.push(AccountMeta::new_readonly(self.token_program, false));
+        }
+
+        self.build(self.data(FundraiserInstruction::EnableReceipts, &[]), accounts)
+    }
+
+    // Receipts go to the contributor's associated token account of the receipt mint
+    pub fn contribute_receipts(&self, contributor: &Pubkey, receipt_mint: &Pubkey, amount: u64) -> Instruction {
+        let fundraiser = self.fundraiser().0;
+        let data = self.data(FundraiserInstruction::ContributeReceipts, &amount.to_le_bytes());
+
+        match self.implementation {
+            Implementation::Anchor => self.build(
+                data,
+                vec![
+                    AccountMeta::new(*contributor, true),
+                    AccountMeta::new_readonly(self.mint, false),
+                    AccountMeta::new(*receipt_mint, false),
+                    AccountMeta::new(fundraiser, false),
+                    AccountMeta::new(self.token_account(contributor, &self.mint), false),
+                    AccountMeta::new(self.token_account(contributor, receipt_mint), false),
+                    AccountMeta::new(self.vault(&self.mint), false),
+                    AccountMeta::new_readonly(self.token_program, false),
+                    AccountMeta::new_readonly(system_program::ID, false),
+                    AccountMeta::new_readonly(spl_associated_token_account::ID, false),
+                ],
+            ),
+            _ => self.build(
+                data,
+                vec![
+                    AccountMeta::new(*contributor, true),
+                    AccountMeta::new(self.token_account(contributor, &self.mint), false),
+                    AccountMeta::new(fundraiser, false),
+                    AccountMeta::new(self.vault(&self.mint), false),
+                    AccountMeta::new_readonly(self.token_program, false),
+                    AccountMeta::new_readonly(self.mint, false),
+                    AccountMeta::new(*receipt_mint, false),
+                    AccountMeta::new(self.token_account(contributor, receipt_mint), false),
+                    AccountMeta::new_readonly(system_program::ID, false),
+                    AccountMeta::new_readonly(spl_associated_token_account::ID, false),
+                ],
+            ),
+        }
+    }
+
+    // Burns every receipt in the holder's associated token account and refunds as many tokens
+    pub fn refund_receipts(&self, holder: &Pubkey, receipt_mint: &Pubkey) -> Instruction {
+        let fundraiser = self.fundraiser().0;
+        let data = self.data(FundraiserInstruction::RefundReceipts, &[]);
+
+        match self.implementation {
+            Implementation::Anchor => self.build(
+                data,
+                vec![
+                    AccountMeta::new(*holder, true),
+                    AccountMeta::new_readonly(self.mint, false),
+                    AccountMeta::new(*receipt_mint, false),
+                    AccountMeta::new(fundraiser, false),
+                    AccountMeta::new(self.token_account(holder, receipt_mint), false),
+                    AccountMeta::new(self.token_account(holder, &self.mint), false),
+                    AccountMeta::new(self.vault(&self.mint), false),
+                    AccountMeta::new_readonly(self.token_program, false),
+                    AccountMeta::new_readonly(system_program::ID, false),
+                    AccountMeta::new_readonly(spl_associated_token_account::ID, false),
+                ],
+            ),
+            _ => self.build(
+                data,
+                vec![
+                    AccountMeta::new(*holder, true),
+                    AccountMeta::new(self.token_account(holder, &self.mint), false),
+                    AccountMeta::new(fundraiser, false),
+                    AccountMeta::new(self.vault(&self.mint), false),
+                    AccountMeta::new_readonly(self.token_program, false),
+                    AccountMeta::new_readonly(self.mint, false),
+                    AccountMeta::new(*receipt_mint, false),
+                    AccountMeta::new(self.token_account(holder, receipt_mint), false),
+                ],
+            ),
+        }
+    }
+
+    // Up to MAX_REWARD_TIERS tiers, they can change until the first contribution comes in
+    pub fn set_reward_tiers(&self, tiers: &[RewardTier]) -> Instruction {
+        let data = match self.implementation {
+            // A borsh Vec<RewardTier>
+            Implementation::Anchor => {
+                let mut payload = (tiers.len() as u32).to_le_bytes().to_vec();
+                for tier in tiers {
+                    let mut packed = [0u8; reward_tier::LEN];
+                    tier.pack_into(&mut packed);
+                    payload.extend_from_slice(&packed);
+                }
+                self.data(FundraiserInstruction::SetRewardTiers, &payload)
+            }
+            _ => {
+                let mut schedule = [RewardTier::default(); MAX_REWARD_TIERS];
+                for (slot, tier) in schedule.iter_mut().zip(tiers) {
+                    *slot = *tier;
+                }
+
+                SetRewardTiersData {
+                    tier_count: tiers.len() as u8,
+                    tiers: schedule,
+                }
+                .pack()
+                .to_vec()
+            }
+        };
+
+        self.build(
+            data,
+            vec![
+                AccountMeta::new(self.maker, true),
+                AccountMeta::new_readonly(self.fundraiser().0, false),
+                AccountMeta::new(self.reward_tiers(), false),
+                AccountMeta::new_readonly(system_program::ID, false),
+            ],
+        )
+    }
+
+    // The reward tiers account goes last, Anchor takes the program id in place of a missing optional account
+    fn push_reward_tiers(&self, instruction: &mut Instruction, reward_tier: bool) {
+        if reward_tier {
+            instruction.accounts.push(AccountMeta::new(self.reward_tiers(), false));
+        } else if self.implementation == Implementation::Anchor {
+            instruction.accounts.push(AccountMeta::new_readonly(self.program_id(), false));
+        }
+    }
+
+    // Checker and ReleaseMilestone both pay the maker out of the vault
+    fn payout(&self, instruction: FundraiserInstruction) -> Instruction {
+        let mut accounts = vec![
+            AccountMeta::new(self.maker, true),
+            AccountMeta::new_readonly(self.mint, false),
+            AccountMeta::new(self.fundraiser().0, false),
+            AccountMeta::new(self.vault(&self.mint), false),
+            AccountMeta::new(self.token_account(&self.maker, &self.mint), false),
+            AccountMeta::new_readonly(self.token_program, false),
+        ];
+        if self.implementation == Implementation::Anchor {
+            accounts.push(AccountMeta::new_readonly(system_program::ID, false));
+            accounts.push(AccountMeta::new_readonly(spl_associated_token_account::ID, false));
+        }
+
+        self.build(self.data(instruction, &[]), accounts)
+    }
+
+    // The config and the account the platform fee goes to, only passed when the fundraiser charges a fee.
+    // Anchor takes the program id in place of the missing optional accounts
+    fn fee_accounts(&self, fee_recipient: Option<Pubkey>) -> Vec<AccountMeta> {
+        match fee_recipient {
+            Some(fee_recipient) => vec![
+                AccountMeta::new_readonly(self.config(), false),
+                AccountMeta::new(fee_recipient, false),
+            ],
+            None if self.implementation == Implementation::Anchor => vec![
+                AccountMeta::new_readonly(self.program_id(), false),
+                AccountMeta::new_readonly(self.program_id(), false),
+            ],
+            None => Vec::new(),
+        }
+    }
+
+    fn price_feeds(&self, price_authority: &Pubkey, accepted_mints: &[Pubkey]) -> Vec<AccountMeta> {
+        accepted_mints
+            .iter()
+            .map(|mint| AccountMeta::new_readonly(self.price_feed(price_authority, mint), false))
+            .collect()
+    }
+
+    // Every payload other than initialize and set_reward_tiers is encoded the same way by borsh and by the interface
+    fn data(&self, instruction: FundraiserInstruction, payload: &[u8]) -> Vec<u8> {
+        [self.implementation.discriminator(instruction).as_ref(), payload].concat()
+    }
+
+    fn build(&self, data: Vec<u8>, accounts: Vec<AccountMeta>) -> Instruction {
+        Instruction {
+            program_id: self.program_id(),
+            accounts,
+            data,
+        }
+    }
+}
+
+// Price feeds belong to their authority rather than to a campaign
+pub fn set_price(implementation: Implementation, authority: &Pubkey, mint: &Pubkey, price: u64) -> Instruction {
+    let program_id = implementation.program_id();
+    let data = [implementation.discriminator(FundraiserInstruction::SetPrice), price.to_le_bytes().to_vec()].concat();
+
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(*authority, true),
+            AccountMeta::new_readonly(*mint, false),
+            AccountMeta::new(pda::price_feed(&program_id, authority, mint).0, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data,
+    }
+}
+
+// The platform config is created once per program, by its upgrade authority
+pub fn initialize_config(implementation: Implementation, admin: &Pubkey, fee_bps: u16, fee_recipient: &Pubkey) -> Instruction {
+    let program_id = implementation.program_id();
+    // Borsh encodes (u16, Pubkey) the same way as the interface
+    let payload = pack_initialize_config(fee_bps, &fee_recipient.to_bytes());
+    let data = [implementation.discriminator(FundraiserInstruction::InitializeConfig).as_ref(), &payload[1..]].concat();
+
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(*admin, true),
+            AccountMeta::new(pda::config(&program_id).0, false),
+            AccountMeta::new_readonly(pda::program_data(&program_id), false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data,
+    }
+}
+
+fn pack_limits(contribution_limits: &ContributionLimits) -> [u8; contribution_limits::LEN] {
+    let mut data = [0u8; contribution_limits::LEN];
+    contribution_limits.pack_into(&mut data);
+    data
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fundraiser_interface::instruction::initialize;
+
+    fn args() -> InitializeArgs {
+        InitializeArgs {
+            amount_to_raise: 1_000_000,
+            time_ending: 1_000,
+            milestones: vec![
+                Milestone { percentage: 60, unlock_time: 2_000 },
+                Milestone { percentage: 40, unlock_time: 3_000 },
+            ],
+            voting_period: 500,
+            quorum_bps: 5_000,
+            approval_bps: 6_000,
+            funding_mode: FundingMode::Fixed,
+            contribution_limits: ContributionLimits::default(),
+            max_amendments: 2,
+        }
+    }
+
+    #[test]
+    fn initialize_payload() {
+        let campaign = Campaign::new(Implementation::Native, Pubkey::new_unique(), 7, Pubkey::new_unique());
+        let instruction = campaign.initialize(&args());
+
+        assert_eq!(instruction.data.len(), 1 + initialize::LEN);
+        assert_eq!(instruction.data[0], FundraiserInstruction::Initialize as u8);
+
+        let payload = &instruction.data[1..];
+        assert_eq!(payload[initialize::BUMP], campaign.fundraiser().1);
+        assert_eq!(payload[initialize::MILESTONE_COUNT], 2);
+        assert_eq!(&payload[initialize::CAMPAIGN_ID..initialize::BUMP], &7u64.to_le_bytes());
+        assert_eq!(payload[initialize::MAX_AMENDMENTS], 2);
+    }
+
+    #[test]
+    fn anchor_initialize_args() {
+        let campaign = Campaign::new(Implementation::Anchor, Pubkey::new_unique(), 7, Pubkey::new_unique());
+        let instruction = campaign.initialize(&args());
+
+        // discriminator, campaign_id, amount, time_ending, 2 borsh milestones, voting_period, quorum, approval, mode, limits, amendments
+        assert_eq!(instruction.data.len(), 8 + 8 + 8 + 8 + 4 + 2 * 9 + 8 + 2 + 2 + 1 + contribution_limits::LEN + 1);
+        assert_eq!(instruction.data[..8], Implementation::Anchor.discriminator(FundraiserInstruction::Initialize));
+        assert_eq!(instruction.accounts[2].pubkey, campaign.fundraiser().0);
+    }
+
+    #[test]
+    fn native_and_optimised_share_the_format() {
+        let maker = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let contributor = Pubkey::new_unique();
+        let native = Campaign::new(Implementation::Native, maker, 0, mint).contribute(&contributor, 5);
+        let optimised = Campaign::new(Implementation::Optimised, maker, 0, mint).contribute(&contributor, 5);
+
+        assert_eq!(native.data, optimised.data);
+        assert_eq!(native.accounts[0], optimised.accounts[0]);
+        assert_ne!(native.accounts[1].pubkey, optimised.accounts[1].pubkey, "PDAs depend on the program");
+    }
+
+    #[test]
+    fn contribute_with_reward_tier() {
+        let campaign = Campaign::new(Implementation::Native, Pubkey::new_unique(), 0, Pubkey::new_unique());
+        let contributor = Pubkey::new_unique();
+
+        let instruction = campaign.contribute_with_reward_tier(&contributor, 5, 2);
+        assert_eq!(&instruction.data[9..], &[1, 2]);
+        assert_eq!(instruction.accounts.last().unwrap().pubkey, campaign.reward_tiers());
+
+        // Anchor fills the optional account with its program id when no tier is picked
+        let anchor = Campaign::new(Implementation::Anchor, campaign.maker, 0, campaign.mint).contribute(&contributor, 5);
+        assert_eq!(&anchor.data[16..], &[0]);
+        assert_eq!(anchor.accounts.last().unwrap().pubkey, Implementation::Anchor.program_id());
+    }
+
+    #[test]
+    fn initialize_config_payload() {
+        let admin = Pubkey::new_unique();
+        let fee_recipient = Pubkey::new_unique();
+        let native = initialize_config(Implementation::Native, &admin, 250, &fee_recipient);
+        let anchor = initialize_config(Implementation::Anchor, &admin, 250, &fee_recipient);
+
+        assert_eq!(native.data[1..], anchor.data[8..]);
+        assert_eq!(native.data[1..3], 250u16.to_le_bytes());
+        assert_eq!(native.accounts[1].pubkey, pda::config(&Implementation::Native.program_id()).0);
+    }
+
+    #[test]
+    fn checker_fee_accounts() {
+        let campaign = Campaign::new(Implementation::Optimised, Pubkey::new_unique(), 0, Pubkey::new_unique());
+        let fee_recipient = Pubkey::new_unique();
+
+        assert_eq!(campaign.checker().accounts.len(), 6);
+        let instruction = campaign.checker_with_fee(&fee_recipient);
+        assert_eq!(instruction.accounts[6].pubkey, campaign.config());
+        assert_eq!(instruction.accounts[7].pubkey, campaign.token_account(&fee_recipient, &campaign.mint));
+
+        // Anchor always takes both optional accounts
+        let anchor = Campaign { implementation: Implementation::Anchor, ..campaign };
+        assert_eq!(anchor.checker().accounts.len(), anchor.checker_with_fee(&fee_recipient).accounts.len());
+    }
+
+    #[test]
+    fn payout_fee_accounts() {
+        let campaign = Campaign::new(Implementation::Native, Pubkey::new_unique(), 0, Pubkey::new_unique());
+        let fee_recipient = Pubkey::new_unique();
+        let price_authority = Pubkey::new_unique();
+        let mints = [campaign.mint, Pubkey::new_unique()];
+
+        // A SOL fundraiser pays the fee recipient's wallet
+        let instruction = campaign.checker_sol_with_fee(&fee_recipient);
+        assert_eq!(instruction.accounts[2].pubkey, campaign.config());
+        assert_eq!(instruction.accounts[3].pubkey, fee_recipient);
+
+        // The fee accounts come before the price feeds
+        let instruction = campaign.checker_multi_with_fee(&mints[1], &price_authority, &mints, &fee_recipient);
+        assert_eq!(instruction.accounts[7].pubkey, campaign.config());
+        assert_eq!(instruction.accounts[8].pubkey, campaign.token_account(&fee_recipient, &mints[1]));
+        assert_eq!(instruction.accounts[9].pubkey, campaign.price_feed(&price_authority, &mints[0]));
+        assert_eq!(campaign.checker_multi(&mints[1], &price_authority, &mints).accounts.len(), 9);
+
+        let instruction = campaign.release_milestone_with_fee(&fee_recipient);
+        assert_eq!(instruction.accounts[6].pubkey, campaign.config());
+        assert_eq!(campaign.release_milestone().accounts.len(), 6);
+    }
+}
\ No newline at end of file
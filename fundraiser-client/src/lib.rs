@@ -0,0 +1,17 @@
+//! Builds instructions for, and decodes the accounts of, any of the three fundraiser programs.
+//!
+//! `Campaign` derives every PDA a campaign needs from its maker and id and returns ready to send
+//! `Instruction`s in the format of the implementation it targets, `state` turns account data
+//! back into typed structs.
+
+pub mod implementation;
+pub mod instruction;
+pub mod pda;
+pub mod state;
+
+pub use implementation::Implementation;
+pub use instruction::{initialize_config, set_price, Campaign, InitializeArgs, InitializeSolArgs};
+pub use state::{Config, Contributor, DecodeError, Fundraiser, PriceFeed, RewardTiers};
+
+pub use fundraiser_interface::instruction::FundraiserInstruction;
+pub use fundraiser_interface::state::{ContributionLimits, FundingMode, FundraiserStatus, MaxContributionKind, Milestone, RewardTier};
\ No newline at end of file
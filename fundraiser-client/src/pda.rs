@@ -0,0 +1,38 @@
+use fundraiser_interface::state::{CONFIG_SEED, CONTRIBUTOR_SEED, FUNDRAISER_SEED, PRICE_FEED_SEED, REWARD_TIERS_SEED};
+use solana_program::{bpf_loader_upgradeable, pubkey::Pubkey};
+use spl_associated_token_account::get_associated_token_address_with_program_id;
+
+// [FUNDRAISER_SEED, maker, campaign_id (u64 LE)]
+pub fn fundraiser(program_id: &Pubkey, maker: &Pubkey, campaign_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[FUNDRAISER_SEED, maker.as_ref(), &campaign_id.to_le_bytes()], program_id)
+}
+
+// [CONTRIBUTOR_SEED, fundraiser, contributor]
+pub fn contributor(program_id: &Pubkey, fundraiser: &Pubkey, contributor: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[CONTRIBUTOR_SEED, fundraiser.as_ref(), contributor.as_ref()], program_id)
+}
+
+// [PRICE_FEED_SEED, authority, mint]
+pub fn price_feed(program_id: &Pubkey, authority: &Pubkey, mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[PRICE_FEED_SEED, authority.as_ref(), mint.as_ref()], program_id)
+}
+
+// [REWARD_TIERS_SEED, fundraiser]
+pub fn reward_tiers(program_id: &Pubkey, fundraiser: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[REWARD_TIERS_SEED, fundraiser.as_ref()], program_id)
+}
+
+// [CONFIG_SEED], one per program
+pub fn config(program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[CONFIG_SEED], program_id)
+}
+
+// The account of the upgradeable loader holding the program's upgrade authority
+pub fn program_data(program_id: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[program_id.as_ref()], &bpf_loader_upgradeable::ID).0
+}
+
+// The associated token account of the fundraiser, every implementation keeps the raised tokens there
+pub fn vault(fundraiser: &Pubkey, mint: &Pubkey, token_program: &Pubkey) -> Pubkey {
+    get_associated_token_address_with_program_id(fundraiser, mint, token_program)
+}
\ No newline at end of file
@@ -0,0 +1,356 @@
+use std::fmt;
+
+use fundraiser_interface::state::{
+    config, contribution_limits, contributor, fundraiser, milestone, price_feed, reward_tier, reward_tiers, AccountDiscriminator,
+    ContributionLimits, FundingMode, FundraiserStatus, Milestone, RewardTier, MAX_ACCEPTED_MINTS, MAX_MILESTONES,
+    MAX_REWARD_TIERS, OPTIMISED_LAYOUT_VERSION,
+};
+use solana_program::pubkey::Pubkey;
+
+use crate::{implementation::anchor_account_discriminator, Implementation};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum DecodeError {
+    // The account is shorter than the layout
+    TooShort,
+    // The Anchor discriminator or the optimised header belongs to another account type
+    WrongDiscriminator,
+    // The optimised account was written with another layout version
+    UnsupportedVersion(u8),
+    // A byte that should hold an enum has a value none of its variants use
+    InvalidEnum(u8),
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::TooShort => write!(f, "The account data is shorter than the layout"),
+            DecodeError::WrongDiscriminator => write!(f, "The account is not of the expected type"),
+            DecodeError::InvalidEnum(value) => write!(f, "Unknown enum value {}", value),
+            DecodeError::UnsupportedVersion(version) => write!(f, "Unsupported layout version {}", version),
+        }
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fundraiser {
+    pub maker: Pubkey,
+    pub mint_to_raise: Pubkey,
+    pub amount_to_raise: u64,
+    pub current_amount: u64,
+    pub time_ending: i64,
+    pub bump: u8,
+    pub campaign_id: u64,
+    pub status: FundraiserStatus,
+    pub milestone_count: u8,
+    pub milestones: [Milestone; MAX_MILESTONES],
+    pub milestones_released: u8,
+    pub amount_released: u64,
+    pub voting_period: i64,
+    pub quorum_bps: u16,
+    pub approval_bps: u16,
+    pub votes_for: u64,
+    pub votes_against: u64,
+    pub funding_mode: FundingMode,
+    pub price_authority: Pubkey,
+    pub accepted_mint_count: u8,
+    pub accepted_mints: [Pubkey; MAX_ACCEPTED_MINTS],
+    pub amounts_raised: [u64; MAX_ACCEPTED_MINTS],
+    pub contribution_limits: ContributionLimits,
+    // Pubkey::default() unless contributions mint receipt tokens
+    pub receipt_mint: Pubkey,
+    // The platform fee in basis points, fixed when the fundraiser was created
+    pub fee_bps: u16,
+    pub max_amendments: u8,
+    pub amendment_count: u8,
+    // Contributors can withdraw until then, 0 if the fundraiser was never amended
+    pub grace_ending: i64,
+}
+
+impl Fundraiser {
+    pub fn decode(implementation: Implementation, data: &[u8]) -> Result<Self, DecodeError> {
+        use fundraiser::*;
+
+        let data = layout(implementation, data, "Fundraiser", LEN)?;
+
+        let mut milestones = [Milestone::default(); MAX_MILESTONES];
+        for (index, slot) in milestones.iter_mut().enumerate() {
+            *slot = Milestone::unpack_from(&data[MILESTONES + index * milestone::LEN..]);
+        }
+
+        let mut accepted_mints = [Pubkey::default(); MAX_ACCEPTED_MINTS];
+        let mut amounts_raised = [0u64; MAX_ACCEPTED_MINTS];
+        for (index, (mint, amount)) in accepted_mints.iter_mut().zip(amounts_raised.iter_mut()).enumerate() {
+            *mint = read_pubkey(data, ACCEPTED_MINTS + index * 32);
+            *amount = read_u64(data, AMOUNTS_RAISED + index * 8);
+        }
+
+        Ok(Self {
+            maker: read_pubkey(data, MAKER),
+            mint_to_raise: read_pubkey(data, MINT_TO_RAISE),
+            amount_to_raise: read_u64(data, AMOUNT_TO_RAISE),
+            current_amount: read_u64(data, CURRENT_AMOUNT),
+            time_ending: read_u64(data, TIME_ENDING) as i64,
+            bump: data[BUMP],
+            campaign_id: read_u64(data, CAMPAIGN_ID),
+            status: FundraiserStatus::try_from(data[STATUS]).map_err(DecodeError::InvalidEnum)?,
+            milestone_count: data[MILESTONE_COUNT],
+            milestones,
+            milestones_released: data[MILESTONES_RELEASED],
+            amount_released: read_u64(data, AMOUNT_RELEASED),
+            voting_period: read_u64(data, VOTING_PERIOD) as i64,
+            quorum_bps: read_u16(data, QUORUM_BPS),
+            approval_bps: read_u16(data, APPROVAL_BPS),
+            votes_for: read_u64(data, VOTES_FOR),
+            votes_against: read_u64(data, VOTES_AGAINST),
+            funding_mode: FundingMode::try_from(data[FUNDING_MODE]).map_err(DecodeError::InvalidEnum)?,
+            price_authority: read_pubkey(data, PRICE_AUTHORITY),
+            accepted_mint_count: data[ACCEPTED_MINT_COUNT],
+            accepted_mints,
+            amounts_raised,
+            contribution_limits: ContributionLimits::unpack_from(&data[CONTRIBUTION_LIMITS..CONTRIBUTION_LIMITS + contribution_limits::LEN])
+                .map_err(DecodeError::InvalidEnum)?,
+            receipt_mint: read_pubkey(data, RECEIPT_MINT),
+            fee_bps: read_u16(data, FEE_BPS),
+            max_amendments: data[MAX_AMENDMENTS],
+            amendment_count: data[AMENDMENT_COUNT],
+            grace_ending: read_u64(data, GRACE_ENDING) as i64,
+        })
+    }
+
+    pub fn is_sol(&self) -> bool {
+        self.mint_to_raise == Pubkey::default()
+    }
+
+    pub fn is_multi_mint(&self) -> bool {
+        self.accepted_mint_count > 0
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Contributor {
+    pub amount: u64,
+    pub voted_milestone: u8,
+    pub amounts: [u64; MAX_ACCEPTED_MINTS],
+    // 0 without a reward, otherwise the index of the tier plus one
+    pub reward_tier: u8,
+    pub bump: u8,
+}
+
+impl Contributor {
+    pub fn decode(implementation: Implementation, data: &[u8]) -> Result<Self, DecodeError> {
+        use contributor::*;
+
+        let data = layout(implementation, data, "Contributor", LEN)?;
+
+        let mut amounts = [0u64; MAX_ACCEPTED_MINTS];
+        for (index, amount) in amounts.iter_mut().enumerate() {
+            *amount = read_u64(data, AMOUNTS + index * 8);
+        }
+
+        Ok(Self {
+            amount: read_u64(data, AMOUNT),
+            voted_milestone: data[VOTED_MILESTONE],
+            amounts,
+            reward_tier: data[REWARD_TIER],
+            bump: data[BUMP],
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RewardTiers {
+    pub fundraiser: Pubkey,
+    pub tier_count: u8,
+    pub tiers: [RewardTier; MAX_REWARD_TIERS],
+    pub claimed: [u64; MAX_REWARD_TIERS],
+    pub bump: u8,
+}
+
+impl RewardTiers {
+    pub fn decode(implementation: Implementation, data: &[u8]) -> Result<Self, DecodeError> {
+        use reward_tiers::*;
+
+        let data = layout(implementation, data, "RewardTiers", LEN)?;
+
+        let mut tiers = [RewardTier::default(); MAX_REWARD_TIERS];
+        let mut claimed = [0u64; MAX_REWARD_TIERS];
+        for (index, (tier, count)) in tiers.iter_mut().zip(claimed.iter_mut()).enumerate() {
+            *tier = RewardTier::unpack_from(&data[TIERS + index * reward_tier::LEN..]);
+            *count = read_u64(data, CLAIMED + index * 8);
+        }
+
+        Ok(Self {
+            fundraiser: read_pubkey(data, FUNDRAISER),
+            tier_count: data[TIER_COUNT],
+            tiers,
+            claimed,
+            bump: data[BUMP],
+        })
+    }
+
+    // Rewards of the tier at `index` nobody has claimed yet
+    pub fn remaining(&self, index: usize) -> u64 {
+        self.tiers[index].max_supply.saturating_sub(self.claimed[index])
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PriceFeed {
+    pub authority: Pubkey,
+    pub mint: Pubkey,
+    pub decimals: u8,
+    pub price: u64,
+    pub bump: u8,
+}
+
+impl PriceFeed {
+    pub fn decode(implementation: Implementation, data: &[u8]) -> Result<Self, DecodeError> {
+        use price_feed::*;
+
+        let data = layout(implementation, data, "PriceFeed", LEN)?;
+
+        Ok(Self {
+            authority: read_pubkey(data, AUTHORITY),
+            mint: read_pubkey(data, MINT),
+            decimals: data[DECIMALS],
+            price: read_u64(data, PRICE),
+            bump: data[BUMP],
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    pub admin: Pubkey,
+    pub fee_bps: u16,
+    pub fee_recipient: Pubkey,
+    pub bump: u8,
+}
+
+impl Config {
+    pub fn decode(implementation: Implementation, data: &[u8]) -> Result<Self, DecodeError> {
+        use config::*;
+
+        let data = layout(implementation, data, "Config", LEN)?;
+
+        Ok(Self {
+            admin: read_pubkey(data, ADMIN),
+            fee_bps: read_u16(data, FEE_BPS),
+            fee_recipient: read_pubkey(data, FEE_RECIPIENT),
+            bump: data[BUMP],
+        })
+    }
+}
+
+// Strips and checks the Anchor discriminator or the optimised header, what is left is the shared layout
+fn layout<'a>(implementation: Implementation, data: &'a [u8], name: &str, len: usize) -> Result<&'a [u8], DecodeError> {
+    let offset = implementation.layout_offset();
+    if data.len() < offset + len {
+        return Err(DecodeError::TooShort);
+    }
+
+    match implementation {
+        Implementation::Anchor if data[..offset] != anchor_account_discriminator(name) => {
+            return Err(DecodeError::WrongDiscriminator);
+        }
+        Implementation::Optimised => {
+            if data[0] != optimised_account_discriminator(name) as u8 {
+                return Err(DecodeError::WrongDiscriminator);
+            }
+            if data[1] != OPTIMISED_LAYOUT_VERSION {
+                return Err(DecodeError::UnsupportedVersion(data[1]));
+            }
+        }
+        _ => {}
+    }
+
+    Ok(&data[offset..offset + len])
+}
+
+fn optimised_account_discriminator(name: &str) -> AccountDiscriminator {
+    match name {
+        "Fundraiser" => AccountDiscriminator::Fundraiser,
+        "Contributor" => AccountDiscriminator::Contributor,
+        "PriceFeed" => AccountDiscriminator::PriceFeed,
+        "RewardTiers" => AccountDiscriminator::RewardTiers,
+        "Config" => AccountDiscriminator::Config,
+        _ => unreachable!("{} is not a program account", name),
+    }
+}
+
+fn read_pubkey(data: &[u8], offset: usize) -> Pubkey {
+    Pubkey::new_from_array(data[offset..offset + 32].try_into().unwrap())
+}
+
+fn read_u64(data: &[u8], offset: usize) -> u64 {
+    u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap())
+}
+
+fn read_u16(data: &[u8], offset: usize) -> u16 {
+    u16::from_le_bytes(data[offset..offset + 2].try_into().unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_contributor() {
+        let mut data = vec![0u8; contributor::LEN];
+        data[contributor::AMOUNT..contributor::AMOUNT + 8].copy_from_slice(&42u64.to_le_bytes());
+        data[contributor::VOTED_MILESTONE] = 1;
+
+        let native = Contributor::decode(Implementation::Native, &data).unwrap();
+        assert_eq!(native.amount, 42);
+        assert_eq!(native.voted_milestone, 1);
+
+        let anchor_data = [anchor_account_discriminator("Contributor").to_vec(), data.clone()].concat();
+        assert_eq!(Contributor::decode(Implementation::Anchor, &anchor_data), Ok(native));
+
+        let wrong_type = [anchor_account_discriminator("Fundraiser").to_vec(), data].concat();
+        assert_eq!(Contributor::decode(Implementation::Anchor, &wrong_type), Err(DecodeError::WrongDiscriminator));
+    }
+
+    #[test]
+    fn decode_fundraiser() {
+        let mut data = vec![0u8; fundraiser::LEN];
+        data[fundraiser::AMOUNT_TO_RAISE..fundraiser::AMOUNT_TO_RAISE + 8].copy_from_slice(&1_000u64.to_le_bytes());
+        data[fundraiser::FUNDING_MODE] = FundingMode::Flexible as u8;
+
+        let decoded = Fundraiser::decode(Implementation::Native, &data).unwrap();
+        assert_eq!(decoded.amount_to_raise, 1_000);
+        assert_eq!(decoded.funding_mode, FundingMode::Flexible);
+        assert!(decoded.is_sol());
+
+        let mut optimised_data = [vec![AccountDiscriminator::Fundraiser as u8, OPTIMISED_LAYOUT_VERSION], data.clone()].concat();
+        assert_eq!(Fundraiser::decode(Implementation::Optimised, &optimised_data), Ok(decoded));
+
+        optimised_data[0] = AccountDiscriminator::Contributor as u8;
+        assert_eq!(Fundraiser::decode(Implementation::Optimised, &optimised_data), Err(DecodeError::WrongDiscriminator));
+        optimised_data[0] = AccountDiscriminator::Fundraiser as u8;
+        optimised_data[1] = OPTIMISED_LAYOUT_VERSION + 1;
+        assert_eq!(
+            Fundraiser::decode(Implementation::Optimised, &optimised_data),
+            Err(DecodeError::UnsupportedVersion(OPTIMISED_LAYOUT_VERSION + 1))
+        );
+
+        data[fundraiser::STATUS] = 7;
+        assert_eq!(Fundraiser::decode(Implementation::Native, &data), Err(DecodeError::InvalidEnum(7)));
+        assert_eq!(Fundraiser::decode(Implementation::Native, &data[1..]), Err(DecodeError::TooShort));
+    }
+
+    #[test]
+    fn decode_reward_tiers() {
+        let mut data = vec![0u8; reward_tiers::LEN];
+        data[reward_tiers::TIER_COUNT] = 1;
+        RewardTier { min_pledge: 100, max_supply: 3 }.pack_into(&mut data[reward_tiers::TIERS..]);
+        data[reward_tiers::CLAIMED..reward_tiers::CLAIMED + 8].copy_from_slice(&2u64.to_le_bytes());
+
+        let decoded = RewardTiers::decode(Implementation::Native, &data).unwrap();
+        assert_eq!(decoded.tiers[0], RewardTier { min_pledge: 100, max_supply: 3 });
+        assert_eq!(decoded.remaining(0), 1);
+    }
+}
\ No newline at end of file
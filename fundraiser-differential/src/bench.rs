@@ -0,0 +1,139 @@
+use std::fmt::Write;
+
+use fundraiser_interface::state::FundingMode;
+
+use crate::{harness::compute_units, implementation::Implementation, scenario::Scenario};
+
+// Compute unit budgets per instruction, in the order of `Implementation::ALL`.
+// Lower them when an implementation gets cheaper so a regression fails the bench.
+pub const BUDGETS: [(&str, [u64; 3]); 5] = [
+    ("initialize", [60_000, 40_000, 35_000]),
+    ("contribute", [40_000, 18_000, 14_000]),
+    ("checker", [40_000, 12_000, 8_000]),
+    ("refund", [40_000, 12_000, 8_000]),
+    ("cancel", [10_000, 3_000, 1_000]),
+];
+
+const AMOUNT_TO_RAISE: u64 = 1_000_000;
+const TIME_ENDING: i64 = 100;
+
+// One scenario per instruction, the instruction being measured is its last step
+pub fn scenarios() -> [(&'static str, Scenario); 5] {
+    [
+        (
+            "initialize",
+            Scenario::new("bench initialize", 1).initialize(AMOUNT_TO_RAISE, TIME_ENDING, FundingMode::Fixed),
+        ),
+        (
+            "contribute",
+            Scenario::new("bench contribute", 1)
+                .initialize(AMOUNT_TO_RAISE, TIME_ENDING, FundingMode::Fixed)
+                .contribute(0, 50_000),
+        ),
+        (
+            "checker",
+            Scenario::new("bench checker", 1)
+                .initialize(AMOUNT_TO_RAISE, TIME_ENDING, FundingMode::Fixed)
+                .contribute(0, AMOUNT_TO_RAISE)
+                .warp_to(TIME_ENDING + 1)
+                .checker(),
+        ),
+        (
+            "refund",
+            Scenario::new("bench refund", 1)
+                .initialize(AMOUNT_TO_RAISE, TIME_ENDING, FundingMode::Fixed)
+                .contribute(0, 50_000)
+                .warp_to(TIME_ENDING + 1)
+                .refund(0),
+        ),
+        (
+            "cancel",
+            Scenario::new("bench cancel", 1)
+                .initialize(AMOUNT_TO_RAISE, TIME_ENDING, FundingMode::Fixed)
+                .cancel(),
+        ),
+    ]
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Measurement {
+    pub instruction: &'static str,
+    pub implementation: Implementation,
+    // None if the instruction failed, which fails the bench as well
+    pub compute_units: Option<u64>,
+    pub budget: u64,
+}
+
+impl Measurement {
+    pub fn within_budget(&self) -> bool {
+        self.compute_units.is_some_and(|compute_units| compute_units <= self.budget)
+    }
+}
+
+// Runs every instruction against every implementation
+pub fn measure() -> Vec<Measurement> {
+    scenarios()
+        .iter()
+        .flat_map(|(instruction, scenario)| {
+            let (_, budgets) = BUDGETS
+                .iter()
+                .find(|(name, _)| name == instruction)
+                .expect("Every benchmarked instruction has a budget");
+
+            Implementation::ALL
+                .iter()
+                .zip(budgets.iter())
+                .map(|(implementation, budget)| Measurement {
+                    instruction,
+                    implementation: *implementation,
+                    compute_units: *compute_units(*implementation, scenario).last().unwrap(),
+                    budget: *budget,
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+// One row per instruction, one column per implementation
+pub fn to_markdown(measurements: &[Measurement]) -> String {
+    let mut table = String::from("| Instruction |");
+    for implementation in Implementation::ALL.iter() {
+        write!(table, " {:?} |", implementation).unwrap();
+    }
+    table.push_str("\n|---|");
+    table.push_str(&"---:|".repeat(Implementation::ALL.len()));
+    table.push('\n');
+
+    for row in measurements.chunks(Implementation::ALL.len()) {
+        write!(table, "| {} |", row[0].instruction).unwrap();
+        for measurement in row {
+            let compute_units = match measurement.compute_units {
+                Some(compute_units) => compute_units.to_string(),
+                None => "failed".to_string(),
+            };
+            let marker = if measurement.within_budget() { "" } else { " ⚠" };
+            write!(table, " {} / {}{} |", compute_units, measurement.budget, marker).unwrap();
+        }
+        table.push('\n');
+    }
+
+    table
+}
+
+pub fn to_json(measurements: &[Measurement]) -> String {
+    let entries: Vec<String> = measurements
+        .iter()
+        .map(|measurement| {
+            let compute_units = match measurement.compute_units {
+                Some(compute_units) => compute_units.to_string(),
+                None => "null".to_string(),
+            };
+            format!(
+                "  {{\"instruction\": \"{}\", \"implementation\": \"{:?}\", \"compute_units\": {}, \"budget\": {}}}",
+                measurement.instruction, measurement.implementation, compute_units, measurement.budget
+            )
+        })
+        .collect();
+
+    format!("[\n{}\n]\n", entries.join(",\n"))
+}
\ No newline at end of file
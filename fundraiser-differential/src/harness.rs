@@ -0,0 +1,348 @@
+use std::{collections::HashMap, fmt};
+
+use fundraiser_interface::state::{contributor, fundraiser};
+use mollusk_svm::Mollusk;
+use solana_sdk::{
+    account::{AccountSharedData, ReadableAccount, WritableAccount},
+    instruction::Instruction,
+    program_option::COption,
+    program_pack::Pack,
+    pubkey::Pubkey,
+    system_program,
+};
+use spl_token::state::{Account as TokenAccount, AccountState, Mint};
+
+use crate::{
+    implementation::{Implementation, Keys},
+    scenario::{Scenario, Step},
+};
+
+// What every contributor holds before the scenario starts
+pub const CONTRIBUTOR_BALANCE: u64 = 10_000_000;
+pub const MINT_DECIMALS: u8 = 6;
+const WALLET_LAMPORTS: u64 = 10_000_000_000;
+
+// The state after a step, the programs fail in their own way so only the outcome is compared
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Snapshot {
+    pub succeeded: bool,
+    pub vault: u64,
+    pub maker_ta: u64,
+    pub contributor_tas: Vec<u64>,
+    // None once the fundraiser account has been closed
+    pub fundraiser: Option<FundraiserSnapshot>,
+    // What each contributor has in according to the program, 0 once their account is closed
+    pub contributions: Vec<u64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FundraiserSnapshot {
+    pub amount_to_raise: u64,
+    pub current_amount: u64,
+    pub time_ending: i64,
+    pub status: u8,
+    pub funding_mode: u8,
+}
+
+#[derive(Debug)]
+pub struct Divergence {
+    pub step: usize,
+    pub description: String,
+    pub snapshots: Vec<(Implementation, Snapshot)>,
+}
+
+impl fmt::Display for Divergence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "step {} ({}):", self.step, self.description)?;
+        for (implementation, snapshot) in self.snapshots.iter() {
+            writeln!(f, "  {:?}: {:?}", implementation, snapshot)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub struct Report {
+    pub scenario: &'static str,
+    pub divergences: Vec<Divergence>,
+}
+
+impl Report {
+    pub fn is_consistent(&self) -> bool {
+        self.divergences.is_empty()
+    }
+
+    pub fn assert_consistent(&self) {
+        if !self.is_consistent() {
+            let divergences: String = self.divergences.iter().map(|divergence| divergence.to_string()).collect();
+            panic!("The implementations diverge in \"{}\":\n{}", self.scenario, divergences);
+        }
+    }
+}
+
+// Runs the scenario against every implementation and compares the state after each step
+pub fn run(scenario: &Scenario) -> Report {
+    let runs: Vec<(Implementation, Vec<Snapshot>)> = Implementation::ALL
+        .iter()
+        .map(|implementation| (*implementation, run_on(*implementation, scenario)))
+        .collect();
+
+    let divergences = scenario
+        .steps
+        .iter()
+        .enumerate()
+        .filter_map(|(index, step)| {
+            let snapshots: Vec<(Implementation, Snapshot)> = runs
+                .iter()
+                .map(|(implementation, snapshots)| (*implementation, snapshots[index].clone()))
+                .collect();
+
+            let (_, expected) = &snapshots[0];
+            if snapshots.iter().all(|(_, snapshot)| snapshot == expected) {
+                return None;
+            }
+
+            Some(Divergence {
+                step: index,
+                description: format!("{:?}", step),
+                snapshots,
+            })
+        })
+        .collect();
+
+    Report {
+        scenario: scenario.name,
+        divergences,
+    }
+}
+
+// The state after every step of the scenario, for a single implementation
+pub fn run_on(implementation: Implementation, scenario: &Scenario) -> Vec<Snapshot> {
+    Run::new(implementation, scenario).execute(scenario)
+}
+
+// The compute units every step of the scenario consumed, None where it failed and 0 for clock warps
+pub fn compute_units(implementation: Implementation, scenario: &Scenario) -> Vec<Option<u64>> {
+    let mut run = Run::new(implementation, scenario);
+    scenario.steps.iter().map(|step| run.step(step)).collect()
+}
+
+struct Run {
+    implementation: Implementation,
+    mollusk: Mollusk,
+    keys: Keys,
+    accounts: HashMap<Pubkey, AccountSharedData>,
+}
+
+impl Run {
+    fn new(implementation: Implementation, scenario: &Scenario) -> Self {
+        let mut mollusk = Mollusk::new(&implementation.program_id(), implementation.program_path());
+        mollusk_token::token::add_program(&mut mollusk);
+        mollusk_token::associated_token::add_program(&mut mollusk);
+        mollusk.sysvars.clock.unix_timestamp = 0;
+
+        let keys = Keys::new(implementation, scenario.contributors);
+        let rent = mollusk.sysvars.rent.clone();
+        let mut accounts = HashMap::new();
+
+        let (token_program, token_program_account) = mollusk_token::token::keyed_account();
+        let (associated_token_program, associated_token_program_account) =
+            mollusk_token::associated_token::keyed_account();
+        let (system_program_id, system_program_account) = mollusk_svm::program::keyed_account_for_system_program();
+        accounts.insert(token_program, token_program_account);
+        accounts.insert(associated_token_program, associated_token_program_account);
+        accounts.insert(system_program_id, system_program_account);
+
+        let supply = CONTRIBUTOR_BALANCE * scenario.contributors as u64;
+        accounts.insert(keys.mint, pack_mint(&keys.maker, supply, rent.minimum_balance(Mint::LEN)));
+        accounts.insert(keys.maker, AccountSharedData::new(WALLET_LAMPORTS, 0, &system_program::ID));
+        accounts.insert(
+            keys.maker_ta,
+            pack_token_account(&keys.maker, &keys.mint, 0, rent.minimum_balance(TokenAccount::LEN)),
+        );
+
+        for contributor in keys.contributors.iter() {
+            accounts.insert(contributor.wallet, AccountSharedData::new(WALLET_LAMPORTS, 0, &system_program::ID));
+            accounts.insert(
+                contributor.token_account,
+                pack_token_account(
+                    &contributor.wallet,
+                    &keys.mint,
+                    CONTRIBUTOR_BALANCE,
+                    rent.minimum_balance(TokenAccount::LEN),
+                ),
+            );
+        }
+
+        Self {
+            implementation,
+            mollusk,
+            keys,
+            accounts,
+        }
+    }
+
+    fn execute(mut self, scenario: &Scenario) -> Vec<Snapshot> {
+        scenario
+            .steps
+            .iter()
+            .map(|step| {
+                let succeeded = self.step(step).is_some();
+                self.snapshot(succeeded)
+            })
+            .collect()
+    }
+
+    // The compute units the step consumed, None if it failed
+    fn step(&mut self, step: &Step) -> Option<u64> {
+        let implementation = self.implementation;
+        let instruction = match step {
+            Step::Initialize {
+                amount_to_raise,
+                time_ending,
+                funding_mode,
+                contribution_limits,
+            } => implementation.initialize(&self.keys, *amount_to_raise, *time_ending, *funding_mode, *contribution_limits),
+            Step::Contribute { contributor, amount } => implementation.contribute(&self.keys, *contributor, *amount),
+            Step::Checker => implementation.checker(&self.keys),
+            Step::Refund { contributor } => implementation.refund(&self.keys, *contributor),
+            Step::Cancel => implementation.cancel(&self.keys),
+            Step::TopUpVault { contributor, amount } => {
+                let contributor = &self.keys.contributors[*contributor];
+                spl_token::instruction::transfer(
+                    &spl_token::ID,
+                    &contributor.token_account,
+                    &self.keys.vault,
+                    &contributor.wallet,
+                    &[],
+                    *amount,
+                )
+                .unwrap()
+            }
+            Step::WarpTo(unix_timestamp) => {
+                self.mollusk.sysvars.clock.unix_timestamp = *unix_timestamp;
+                return Some(0);
+            }
+        };
+
+        self.process(&instruction)
+    }
+
+    // A failed instruction leaves every account as it was
+    fn process(&mut self, instruction: &Instruction) -> Option<u64> {
+        let mut keys: Vec<Pubkey> = Vec::new();
+        for meta in instruction.accounts.iter() {
+            if !keys.contains(&meta.pubkey) {
+                keys.push(meta.pubkey);
+            }
+        }
+
+        let accounts: Vec<(Pubkey, AccountSharedData)> = keys
+            .into_iter()
+            .map(|key| (key, self.accounts.get(&key).cloned().unwrap_or_default()))
+            .collect();
+
+        let result = self.mollusk.process_instruction(instruction, &accounts);
+        if result.program_result.is_err() {
+            return None;
+        }
+
+        for (key, account) in result.resulting_accounts {
+            self.accounts.insert(key, account);
+        }
+
+        Some(result.compute_units_consumed)
+    }
+
+    fn snapshot(&self, succeeded: bool) -> Snapshot {
+        Snapshot {
+            succeeded,
+            vault: self.token_balance(&self.keys.vault),
+            maker_ta: self.token_balance(&self.keys.maker_ta),
+            contributor_tas: self
+                .keys
+                .contributors
+                .iter()
+                .map(|contributor| self.token_balance(&contributor.token_account))
+                .collect(),
+            fundraiser: self.fundraiser(),
+            contributions: self
+                .keys
+                .contributors
+                .iter()
+                .map(|contributor| {
+                    self.layout(&contributor.account, contributor::LEN)
+                        .map(|data| read_u64(data, contributor::AMOUNT))
+                        .unwrap_or(0)
+                })
+                .collect(),
+        }
+    }
+
+    fn fundraiser(&self) -> Option<FundraiserSnapshot> {
+        let data = self.layout(&self.keys.fundraiser, fundraiser::LEN)?;
+
+        Some(FundraiserSnapshot {
+            amount_to_raise: read_u64(data, fundraiser::AMOUNT_TO_RAISE),
+            current_amount: read_u64(data, fundraiser::CURRENT_AMOUNT),
+            time_ending: read_u64(data, fundraiser::TIME_ENDING) as i64,
+            status: data[fundraiser::STATUS],
+            funding_mode: data[fundraiser::FUNDING_MODE],
+        })
+    }
+
+    // The shared layout of a program account, None while it does not exist or once it is closed
+    fn layout(&self, key: &Pubkey, len: usize) -> Option<&[u8]> {
+        let account = self.accounts.get(key)?;
+        let offset = self.implementation.layout_offset();
+        if account.lamports() == 0
+            || account.owner() != &self.implementation.program_id()
+            || account.data().len() < offset + len
+        {
+            return None;
+        }
+
+        Some(&account.data()[offset..offset + len])
+    }
+
+    fn token_balance(&self, key: &Pubkey) -> u64 {
+        self.accounts
+            .get(key)
+            .and_then(|account| TokenAccount::unpack(account.data()).ok())
+            .map(|token_account| token_account.amount)
+            .unwrap_or(0)
+    }
+}
+
+fn read_u64(data: &[u8], offset: usize) -> u64 {
+    u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap())
+}
+
+fn pack_mint(mint_authority: &Pubkey, supply: u64, lamports: u64) -> AccountSharedData {
+    let mut account = AccountSharedData::new(lamports, Mint::LEN, &spl_token::ID);
+    Mint {
+        mint_authority: COption::Some(*mint_authority),
+        supply,
+        decimals: MINT_DECIMALS,
+        is_initialized: true,
+        freeze_authority: COption::None,
+    }
+    .pack_into_slice(account.data_as_mut_slice());
+    account
+}
+
+fn pack_token_account(owner: &Pubkey, mint: &Pubkey, amount: u64, lamports: u64) -> AccountSharedData {
+    let mut account = AccountSharedData::new(lamports, TokenAccount::LEN, &spl_token::ID);
+    TokenAccount {
+        mint: *mint,
+        owner: *owner,
+        amount,
+        delegate: COption::None,
+        state: AccountState::Initialized,
+        is_native: COption::None,
+        delegated_amount: 0,
+        close_authority: COption::None,
+    }
+    .pack_into_slice(account.data_as_mut_slice());
+    account
+}
\ No newline at end of file
@@ -0,0 +1,327 @@
+use fundraiser_interface::{
+    instruction::{pack_u64, FundraiserInstruction, InitializeData},
+    state::{
+        ContributionLimits, FundingMode, Milestone, ANCHOR_DISCRIMINATOR_LEN, CONFIG_SEED, CONTRIBUTOR_SEED, FUNDRAISER_SEED,
+        MAX_MILESTONES, OPTIMISED_HEADER_LEN,
+    },
+};
+use solana_sdk::{
+    hash::hash,
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    system_program,
+};
+use spl_associated_token_account::get_associated_token_address;
+
+// The campaign every scenario runs, one per program
+pub const CAMPAIGN_ID: u64 = 0;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Implementation {
+    Anchor,
+    Native,
+    Optimised,
+}
+
+impl Implementation {
+    pub const ALL: [Implementation; 3] = [
+        Implementation::Anchor,
+        Implementation::Native,
+        Implementation::Optimised,
+    ];
+
+    pub fn program_id(&self) -> Pubkey {
+        Pubkey::new_from_array(match self {
+            Implementation::Anchor => five8_const::decode_32_const("Eoiuq1dXvHxh6dLx3wh9gj8kSAUpga11krTrbfF5XYsC"),
+            Implementation::Native => five8_const::decode_32_const("CsPs2Z5QLAuqWCw2enAmCfCNmdGahBYysL3cvxv9mmxH"),
+            Implementation::Optimised => five8_const::decode_32_const("22222222222222222222222222222222222222222222"),
+        })
+    }
+
+    // Relative to this crate, without the `.so` extension
+    pub fn program_path(&self) -> &'static str {
+        match self {
+            Implementation::Anchor => "../fundraiser-anchor/target/deploy/fundraiser",
+            Implementation::Native => "../target/deploy/fundraiser",
+            Implementation::Optimised => "../target/deploy/fundraiser_optimised",
+        }
+    }
+
+    // Where the shared layout starts in a program account
+    pub fn layout_offset(&self) -> usize {
+        match self {
+            Implementation::Anchor => ANCHOR_DISCRIMINATOR_LEN,
+            Implementation::Native => 0,
+            Implementation::Optimised => OPTIMISED_HEADER_LEN,
+        }
+    }
+
+    pub fn initialize(
+        &self,
+        keys: &Keys,
+        amount_to_raise: u64,
+        time_ending: i64,
+        funding_mode: FundingMode,
+        contribution_limits: ContributionLimits,
+    ) -> Instruction {
+        match self {
+            Implementation::Anchor => {
+                let mut data = anchor_discriminator("initialize").to_vec();
+                data.extend_from_slice(&CAMPAIGN_ID.to_le_bytes());
+                data.extend_from_slice(&amount_to_raise.to_le_bytes());
+                data.extend_from_slice(&time_ending.to_le_bytes());
+                data.extend_from_slice(&0u32.to_le_bytes()); // milestones
+                data.extend_from_slice(&0i64.to_le_bytes()); // voting_period
+                data.extend_from_slice(&0u16.to_le_bytes()); // quorum_bps
+                data.extend_from_slice(&0u16.to_le_bytes()); // approval_bps
+                data.push(funding_mode as u8);
+                data.extend_from_slice(&pack_limits(&contribution_limits));
+                data.push(0); // max_amendments
+
+                Instruction::new_with_bytes(
+                    self.program_id(),
+                    &data,
+                    vec![
+                        AccountMeta::new(keys.maker, true),
+                        AccountMeta::new_readonly(keys.mint, false),
+                        AccountMeta::new(keys.fundraiser, false),
+                        AccountMeta::new(keys.vault, false),
+                        AccountMeta::new_readonly(system_program::ID, false),
+                        AccountMeta::new_readonly(spl_token::ID, false),
+                        AccountMeta::new_readonly(spl_associated_token_account::ID, false),
+                        AccountMeta::new_readonly(keys.config, false),
+                    ],
+                )
+            }
+            _ => {
+                let data = InitializeData {
+                    amount_to_raise,
+                    time_ending,
+                    campaign_id: CAMPAIGN_ID,
+                    bump: keys.bump,
+                    milestone_count: 0,
+                    milestones: [Milestone::default(); MAX_MILESTONES],
+                    voting_period: 0,
+                    quorum_bps: 0,
+                    approval_bps: 0,
+                    funding_mode,
+                    contribution_limits,
+                    max_amendments: 0,
+                };
+
+                Instruction::new_with_bytes(
+                    self.program_id(),
+                    &data.pack(),
+                    vec![
+                        AccountMeta::new(keys.maker, true),
+                        AccountMeta::new(keys.fundraiser, false),
+                        AccountMeta::new_readonly(keys.mint, false),
+                        AccountMeta::new(keys.vault, false),
+                        AccountMeta::new_readonly(system_program::ID, false),
+                        AccountMeta::new_readonly(spl_token::ID, false),
+                        AccountMeta::new_readonly(spl_associated_token_account::ID, false),
+                        AccountMeta::new_readonly(keys.config, false),
+                    ],
+                )
+            }
+        }
+    }
+
+    pub fn contribute(&self, keys: &Keys, contributor: usize, amount: u64) -> Instruction {
+        let contributor = &keys.contributors[contributor];
+
+        match self {
+            Implementation::Anchor => Instruction::new_with_bytes(
+                self.program_id(),
+                // No reward tier
+                &[anchor_discriminator("contribute").as_ref(), &amount.to_le_bytes(), &[0]].concat(),
+                vec![
+                    AccountMeta::new(contributor.wallet, true),
+                    AccountMeta::new_readonly(keys.mint, false),
+                    AccountMeta::new(keys.fundraiser, false),
+                    AccountMeta::new(contributor.account, false),
+                    AccountMeta::new(contributor.token_account, false),
+                    AccountMeta::new(keys.vault, false),
+                    AccountMeta::new_readonly(spl_token::ID, false),
+                    AccountMeta::new_readonly(system_program::ID, false),
+                    // Anchor takes the program id in place of the optional reward tiers account
+                    AccountMeta::new_readonly(self.program_id(), false),
+                ],
+            ),
+            _ => Instruction::new_with_bytes(
+                self.program_id(),
+                &pack_u64(FundraiserInstruction::Contribute, amount),
+                vec![
+                    AccountMeta::new(contributor.wallet, true),
+                    AccountMeta::new(contributor.account, false),
+                    AccountMeta::new(contributor.token_account, false),
+                    AccountMeta::new(keys.fundraiser, false),
+                    AccountMeta::new(keys.vault, false),
+                    AccountMeta::new_readonly(spl_token::ID, false),
+                    AccountMeta::new_readonly(keys.mint, false),
+                    AccountMeta::new_readonly(system_program::ID, false),
+                ],
+            ),
+        }
+    }
+
+    pub fn checker(&self, keys: &Keys) -> Instruction {
+        match self {
+            Implementation::Anchor => Instruction::new_with_bytes(
+                self.program_id(),
+                &anchor_discriminator("check_contributions"),
+                vec![
+                    AccountMeta::new(keys.maker, true),
+                    AccountMeta::new_readonly(keys.mint, false),
+                    AccountMeta::new(keys.fundraiser, false),
+                    AccountMeta::new(keys.vault, false),
+                    AccountMeta::new(keys.maker_ta, false),
+                    AccountMeta::new_readonly(spl_token::ID, false),
+                    AccountMeta::new_readonly(system_program::ID, false),
+                    AccountMeta::new_readonly(spl_associated_token_account::ID, false),
+                    // No platform fee, the program id stands in for the config and fee recipient accounts
+                    AccountMeta::new_readonly(self.program_id(), false),
+                    AccountMeta::new_readonly(self.program_id(), false),
+                ],
+            ),
+            _ => Instruction::new_with_bytes(
+                self.program_id(),
+                &[FundraiserInstruction::Checker as u8],
+                vec![
+                    AccountMeta::new(keys.maker, true),
+                    AccountMeta::new_readonly(keys.mint, false),
+                    AccountMeta::new(keys.fundraiser, false),
+                    AccountMeta::new(keys.vault, false),
+                    AccountMeta::new(keys.maker_ta, false),
+                    AccountMeta::new_readonly(spl_token::ID, false),
+                ],
+            ),
+        }
+    }
+
+    pub fn refund(&self, keys: &Keys, contributor: usize) -> Instruction {
+        let contributor = &keys.contributors[contributor];
+
+        match self {
+            Implementation::Anchor => Instruction::new_with_bytes(
+                self.program_id(),
+                &anchor_discriminator("refund"),
+                vec![
+                    AccountMeta::new(contributor.wallet, true),
+                    AccountMeta::new_readonly(keys.maker, false),
+                    AccountMeta::new_readonly(keys.mint, false),
+                    AccountMeta::new(keys.fundraiser, false),
+                    AccountMeta::new(contributor.account, false),
+                    AccountMeta::new(contributor.token_account, false),
+                    AccountMeta::new(keys.vault, false),
+                    AccountMeta::new_readonly(spl_token::ID, false),
+                    AccountMeta::new_readonly(system_program::ID, false),
+                    // Anchor takes the program id in place of the optional reward tiers account
+                    AccountMeta::new_readonly(self.program_id(), false),
+                ],
+            ),
+            _ => Instruction::new_with_bytes(
+                self.program_id(),
+                &[FundraiserInstruction::Refund as u8],
+                vec![
+                    AccountMeta::new(contributor.wallet, true),
+                    AccountMeta::new_readonly(keys.maker, false),
+                    AccountMeta::new(keys.fundraiser, false),
+                    AccountMeta::new(contributor.account, false),
+                    AccountMeta::new(contributor.token_account, false),
+                    AccountMeta::new(keys.vault, false),
+                    AccountMeta::new_readonly(spl_token::ID, false),
+                    AccountMeta::new_readonly(keys.mint, false),
+                ],
+            ),
+        }
+    }
+
+    pub fn cancel(&self, keys: &Keys) -> Instruction {
+        let data = match self {
+            Implementation::Anchor => anchor_discriminator("cancel").to_vec(),
+            _ => vec![FundraiserInstruction::Cancel as u8],
+        };
+
+        Instruction::new_with_bytes(
+            self.program_id(),
+            &data,
+            vec![
+                AccountMeta::new_readonly(keys.maker, true),
+                AccountMeta::new(keys.fundraiser, false),
+            ],
+        )
+    }
+}
+
+// The accounts of one scenario, the PDAs depend on the program they run against
+#[derive(Debug, Clone)]
+pub struct Keys {
+    pub maker: Pubkey,
+    pub maker_ta: Pubkey,
+    pub mint: Pubkey,
+    pub fundraiser: Pubkey,
+    pub bump: u8,
+    pub vault: Pubkey,
+    // Never created, every scenario runs without a platform fee
+    pub config: Pubkey,
+    pub contributors: Vec<ContributorKeys>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ContributorKeys {
+    pub wallet: Pubkey,
+    pub token_account: Pubkey,
+    pub account: Pubkey,
+}
+
+impl Keys {
+    pub fn new(implementation: Implementation, contributors: usize) -> Self {
+        let program_id = implementation.program_id();
+        let maker = Pubkey::new_from_array([0x1; 32]);
+        let mint = Pubkey::new_from_array([0x2; 32]);
+        let (fundraiser, bump) = Pubkey::find_program_address(
+            &[FUNDRAISER_SEED, maker.as_ref(), &CAMPAIGN_ID.to_le_bytes()],
+            &program_id,
+        );
+
+        let contributors = (0..contributors)
+            .map(|index| {
+                let wallet = Pubkey::new_from_array([0x10 + index as u8; 32]);
+                ContributorKeys {
+                    wallet,
+                    token_account: get_associated_token_address(&wallet, &mint),
+                    account: Pubkey::find_program_address(
+                        &[CONTRIBUTOR_SEED, fundraiser.as_ref(), wallet.as_ref()],
+                        &program_id,
+                    )
+                    .0,
+                }
+            })
+            .collect();
+
+        Self {
+            maker,
+            maker_ta: get_associated_token_address(&maker, &mint),
+            mint,
+            fundraiser,
+            bump,
+            vault: get_associated_token_address(&fundraiser, &mint),
+            config: Pubkey::find_program_address(&[CONFIG_SEED], &program_id).0,
+            contributors,
+        }
+    }
+}
+
+// Anchor selects the instruction with the first 8 bytes of sha256("global:<name>")
+fn anchor_discriminator(name: &str) -> [u8; 8] {
+    let mut discriminator = [0u8; 8];
+    discriminator.copy_from_slice(&hash(format!("global:{name}").as_bytes()).to_bytes()[..8]);
+    discriminator
+}
+
+fn pack_limits(contribution_limits: &ContributionLimits) -> [u8; fundraiser_interface::state::contribution_limits::LEN] {
+    let mut data = [0u8; fundraiser_interface::state::contribution_limits::LEN];
+    contribution_limits.pack_into(&mut data);
+    data
+}
\ No newline at end of file
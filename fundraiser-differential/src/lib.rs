@@ -0,0 +1,18 @@
+//! Runs one scenario through fundraiser-anchor, fundraiser-native and fundraiser-optimised and
+//! reports every step where they disagree.
+//!
+//! The programs are loaded from their `target/deploy` directories, build them first with
+//! `anchor build` and `cargo build-sbf`. The same harness measures the compute units of each
+//! instruction in `bench`.
+
+pub mod bench;
+pub mod harness;
+pub mod implementation;
+pub mod scenario;
+
+pub use harness::{compute_units, run, run_on, Divergence, FundraiserSnapshot, Report, Snapshot};
+pub use implementation::Implementation;
+pub use scenario::{Scenario, Step};
+
+#[cfg(test)]
+mod tests;
\ No newline at end of file
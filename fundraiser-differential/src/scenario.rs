@@ -0,0 +1,95 @@
+use fundraiser_interface::state::{ContributionLimits, FundingMode};
+
+// Every step is sent to all three programs, contributors are referred to by their index
+#[derive(Debug, Clone)]
+pub enum Step {
+    Initialize {
+        amount_to_raise: u64,
+        time_ending: i64,
+        funding_mode: FundingMode,
+        contribution_limits: ContributionLimits,
+    },
+    Contribute { contributor: usize, amount: u64 },
+    Checker,
+    Refund { contributor: usize },
+    Cancel,
+    // Sends tokens straight to the vault with the token program, the fundraiser is not involved
+    TopUpVault { contributor: usize, amount: u64 },
+    // Moves the clock, nothing is sent to the programs
+    WarpTo(i64),
+}
+
+#[derive(Debug, Clone)]
+pub struct Scenario {
+    pub name: &'static str,
+    pub contributors: usize,
+    pub steps: Vec<Step>,
+}
+
+impl Scenario {
+    pub fn new(name: &'static str, contributors: usize) -> Self {
+        Self {
+            name,
+            contributors,
+            steps: Vec::new(),
+        }
+    }
+
+    pub fn initialize(mut self, amount_to_raise: u64, time_ending: i64, funding_mode: FundingMode) -> Self {
+        self.steps.push(Step::Initialize {
+            amount_to_raise,
+            time_ending,
+            funding_mode,
+            contribution_limits: ContributionLimits::default(),
+        });
+        self
+    }
+
+    pub fn initialize_with_limits(
+        mut self,
+        amount_to_raise: u64,
+        time_ending: i64,
+        contribution_limits: ContributionLimits,
+    ) -> Self {
+        self.steps.push(Step::Initialize {
+            amount_to_raise,
+            time_ending,
+            funding_mode: FundingMode::Fixed,
+            contribution_limits,
+        });
+        self
+    }
+
+    pub fn contribute(mut self, contributor: usize, amount: u64) -> Self {
+        assert!(contributor < self.contributors, "Unknown contributor");
+        self.steps.push(Step::Contribute { contributor, amount });
+        self
+    }
+
+    pub fn checker(mut self) -> Self {
+        self.steps.push(Step::Checker);
+        self
+    }
+
+    pub fn refund(mut self, contributor: usize) -> Self {
+        assert!(contributor < self.contributors, "Unknown contributor");
+        self.steps.push(Step::Refund { contributor });
+        self
+    }
+
+    pub fn cancel(mut self) -> Self {
+        self.steps.push(Step::Cancel);
+        self
+    }
+
+    pub fn top_up_vault(mut self, contributor: usize, amount: u64) -> Self {
+        assert!(contributor < self.contributors, "Unknown contributor");
+        self.steps.push(Step::TopUpVault { contributor, amount });
+        self
+    }
+
+    pub fn warp_to(mut self, unix_timestamp: i64) -> Self {
+        self.steps.push(Step::WarpTo(unix_timestamp));
+        self
+    }
+}
\ No newline at end of file
@@ -0,0 +1,122 @@
+use fundraiser_interface::state::{ContributionLimits, FundingMode, MaxContributionKind};
+
+use crate::{run, Scenario};
+
+const GOAL: u64 = 1_000_000;
+const TIME_ENDING: i64 = 1_000;
+
+#[test]
+fn target_met_test() {
+    let scenario = Scenario::new("target met", 2)
+        .initialize(GOAL, TIME_ENDING, FundingMode::Fixed)
+        .contribute(0, 600_000)
+        .contribute(1, 400_000)
+        .warp_to(TIME_ENDING + 1)
+        .checker();
+
+    run(&scenario).assert_consistent();
+}
+
+#[test]
+fn target_missed_test() {
+    let scenario = Scenario::new("target missed", 2)
+        .initialize(GOAL, TIME_ENDING, FundingMode::Fixed)
+        .contribute(0, 300_000)
+        .contribute(1, 200_000)
+        .checker()
+        .warp_to(TIME_ENDING + 1)
+        .contribute(0, 100_000)
+        .checker()
+        .refund(0)
+        .refund(1);
+
+    run(&scenario).assert_consistent();
+}
+
+#[test]
+fn refund_before_deadline_test() {
+    let scenario = Scenario::new("refund before deadline", 1)
+        .initialize(GOAL, TIME_ENDING, FundingMode::Fixed)
+        .contribute(0, 300_000)
+        .refund(0)
+        .warp_to(TIME_ENDING)
+        .refund(0);
+
+    run(&scenario).assert_consistent();
+}
+
+#[test]
+fn checker_before_deadline_test() {
+    // A fixed fundraiser pays out as soon as its target is met, the deadline does not matter
+    let scenario = Scenario::new("checker before deadline", 2)
+        .initialize(GOAL, TIME_ENDING, FundingMode::Fixed)
+        .contribute(0, 600_000)
+        .checker()
+        .contribute(1, 400_000)
+        .checker();
+
+    run(&scenario).assert_consistent();
+}
+
+#[test]
+fn vault_top_up_test() {
+    // Tokens sent straight to the vault are not credited, the maker and the platform are paid on what was contributed
+    let scenario = Scenario::new("vault top up", 2)
+        .initialize(GOAL, TIME_ENDING, FundingMode::Fixed)
+        .contribute(0, 600_000)
+        .top_up_vault(1, 400_000)
+        .checker()
+        .contribute(1, 400_000)
+        .top_up_vault(1, 100_000)
+        .checker();
+
+    run(&scenario).assert_consistent();
+}
+
+#[test]
+fn cancel_test() {
+    let scenario = Scenario::new("cancel", 2)
+        .initialize(GOAL, TIME_ENDING, FundingMode::Fixed)
+        .contribute(0, 500_000)
+        .cancel()
+        .contribute(1, 500_000)
+        .refund(0)
+        .checker();
+
+    run(&scenario).assert_consistent();
+}
+
+#[test]
+fn flexible_test() {
+    let scenario = Scenario::new("flexible", 1)
+        .initialize(GOAL, TIME_ENDING, FundingMode::Flexible)
+        .contribute(0, 300_000)
+        .checker()
+        .refund(0)
+        .warp_to(TIME_ENDING + 1)
+        .checker();
+
+    run(&scenario).assert_consistent();
+}
+
+#[test]
+fn contribution_limits_test() {
+    let scenario = Scenario::new("contribution limits", 2)
+        .initialize_with_limits(
+            GOAL,
+            TIME_ENDING,
+            ContributionLimits {
+                min_contribution: 100_000,
+                max_contribution: 60,
+                max_contribution_kind: MaxContributionKind::Percentage,
+                hard_cap: GOAL,
+            },
+        )
+        .contribute(0, 50_000)
+        .contribute(0, 600_000)
+        .contribute(0, 100_000)
+        .contribute(1, 500_000)
+        .contribute(1, 400_000);
+
+    run(&scenario).assert_consistent();
+}
\ No newline at end of file
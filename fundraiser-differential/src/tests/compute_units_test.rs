@@ -0,0 +1,30 @@
+use std::fs;
+
+use crate::bench::{measure, to_json, to_markdown};
+
+// Writes the report to target/compute-units.{md,json} and fails if any instruction is over budget
+#[test]
+fn compute_units_test() {
+    let measurements = measure();
+
+    let markdown = to_markdown(&measurements);
+    println!("{}", markdown);
+
+    let target = concat!(env!("CARGO_MANIFEST_DIR"), "/../target");
+    fs::create_dir_all(target).unwrap();
+    fs::write(format!("{}/compute-units.md", target), &markdown).unwrap();
+    fs::write(format!("{}/compute-units.json", target), to_json(&measurements)).unwrap();
+
+    let over_budget: Vec<String> = measurements
+        .iter()
+        .filter(|measurement| !measurement.within_budget())
+        .map(|measurement| {
+            format!(
+                "{} on {:?}: {:?} CU, budget {}",
+                measurement.instruction, measurement.implementation, measurement.compute_units, measurement.budget
+            )
+        })
+        .collect();
+
+    assert!(over_budget.is_empty(), "Over budget:\n{}", over_budget.join("\n"));
+}
\ No newline at end of file
@@ -0,0 +1,6 @@
+#[cfg(test)]
+mod campaign_test;
+#[cfg(test)]
+mod compute_units_test;
+#[cfg(test)]
+mod state_machine_test;
\ No newline at end of file
@@ -0,0 +1,115 @@
+use fundraiser_interface::state::FundingMode;
+use proptest::prelude::*;
+
+use crate::{
+    harness::CONTRIBUTOR_BALANCE,
+    run_on, Implementation, Scenario, Snapshot, Step,
+};
+
+const CONTRIBUTORS: usize = 4;
+const GOAL: u64 = 3 * CONTRIBUTOR_BALANCE / 2;
+const TIME_ENDING: i64 = 1_000;
+
+#[derive(Debug, Clone)]
+enum Operation {
+    Contribute { contributor: usize, amount: u64 },
+    Refund { contributor: usize },
+    Checker,
+    Advance(i64),
+}
+
+fn operation() -> impl Strategy<Value = Operation> {
+    prop_oneof![
+        4 => (0..CONTRIBUTORS, 1..CONTRIBUTOR_BALANCE / 2)
+            .prop_map(|(contributor, amount)| Operation::Contribute { contributor, amount }),
+        2 => (0..CONTRIBUTORS).prop_map(|contributor| Operation::Refund { contributor }),
+        1 => Just(Operation::Checker),
+        2 => (1..TIME_ENDING / 2).prop_map(Operation::Advance),
+    ]
+}
+
+fn scenario(funding_mode: FundingMode, operations: &[Operation]) -> Scenario {
+    let mut scenario =
+        Scenario::new("state machine", CONTRIBUTORS).initialize(GOAL, TIME_ENDING, funding_mode);
+    let mut now = 0;
+
+    for operation in operations {
+        scenario = match operation {
+            Operation::Contribute { contributor, amount } => scenario.contribute(*contributor, *amount),
+            Operation::Refund { contributor } => scenario.refund(*contributor),
+            Operation::Checker => scenario.checker(),
+            Operation::Advance(seconds) => {
+                now += seconds;
+                scenario.warp_to(now)
+            }
+        };
+    }
+
+    scenario
+}
+
+fn check_invariants(implementation: Implementation, steps: &[Step], snapshots: &[Snapshot]) -> Result<(), TestCaseError> {
+    prop_assert!(snapshots[0].succeeded, "{:?}: initialize failed", implementation);
+
+    for (index, snapshot) in snapshots.iter().enumerate() {
+        let context = format!("{:?} after step {} ({:?})", implementation, index, steps[index]);
+
+        // Tokens only move between the contributors, the vault and the maker
+        let total = snapshot.vault + snapshot.maker_ta + snapshot.contributor_tas.iter().sum::<u64>();
+        prop_assert_eq!(total, CONTRIBUTOR_BALANCE * CONTRIBUTORS as u64, "{}: tokens not conserved", context);
+
+        // Nobody gets back more than they put in
+        for balance in snapshot.contributor_tas.iter() {
+            prop_assert!(*balance <= CONTRIBUTOR_BALANCE, "{}: a contributor withdrew more than they deposited", context);
+        }
+
+        if let Some(fundraiser) = snapshot.fundraiser {
+            // The vault holds exactly what the fundraiser counts as raised, and that is what the contributors put in
+            prop_assert_eq!(snapshot.vault, fundraiser.current_amount, "{}: vault and current_amount differ", context);
+            prop_assert_eq!(
+                snapshot.contributions.iter().sum::<u64>(),
+                fundraiser.current_amount,
+                "{}: contributions and current_amount differ",
+                context
+            );
+
+            for (contribution, balance) in snapshot.contributions.iter().zip(snapshot.contributor_tas.iter()) {
+                prop_assert_eq!(
+                    *contribution,
+                    CONTRIBUTOR_BALANCE - balance,
+                    "{}: a contribution does not match the tokens sent",
+                    context
+                );
+            }
+        }
+
+        // Closing the fundraiser pays the whole vault out to the maker
+        if index > 0 {
+            let previous = &snapshots[index - 1];
+            if previous.fundraiser.is_some() && snapshot.fundraiser.is_none() {
+                prop_assert_eq!(snapshot.vault, 0, "{}: the vault was not emptied", context);
+                prop_assert_eq!(snapshot.maker_ta, previous.vault, "{}: the maker was not paid the vault", context);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(32))]
+
+    #[test]
+    fn state_machine_test(
+        flexible in any::<bool>(),
+        operations in prop::collection::vec(operation(), 1..40),
+    ) {
+        let funding_mode = if flexible { FundingMode::Flexible } else { FundingMode::Fixed };
+        let scenario = scenario(funding_mode, &operations);
+
+        for implementation in Implementation::ALL {
+            let snapshots = run_on(implementation, &scenario);
+            check_invariants(implementation, &scenario.steps, &snapshots)?;
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,349 @@
+use std::path::Path;
+
+use fundraiser_interface::event::FundraiserEvent;
+use rusqlite::{params, Connection};
+use solana_sdk::pubkey::Pubkey;
+
+use crate::Error;
+
+// Keys are stored base58 encoded, amounts as they were logged. SOL fundraisers have the default
+// pubkey as their mint. A campaign created before the indexer started only has the columns its
+// later events fill in.
+const SCHEMA: &str = "
+    CREATE TABLE IF NOT EXISTS transactions (
+        signature TEXT PRIMARY KEY,
+        slot INTEGER
+    );
+
+    CREATE TABLE IF NOT EXISTS campaigns (
+        fundraiser TEXT PRIMARY KEY,
+        maker TEXT NOT NULL,
+        mint TEXT,
+        amount_to_raise INTEGER,
+        time_ending INTEGER,
+        cancelled INTEGER NOT NULL DEFAULT 0
+    );
+
+    CREATE TABLE IF NOT EXISTS contributions (
+        signature TEXT NOT NULL,
+        fundraiser TEXT NOT NULL,
+        contributor TEXT NOT NULL,
+        mint TEXT NOT NULL,
+        amount INTEGER NOT NULL
+    );
+
+    CREATE TABLE IF NOT EXISTS refunds (
+        signature TEXT NOT NULL,
+        fundraiser TEXT NOT NULL,
+        contributor TEXT NOT NULL,
+        mint TEXT NOT NULL,
+        amount INTEGER NOT NULL
+    );
+
+    CREATE TABLE IF NOT EXISTS withdrawals (
+        signature TEXT NOT NULL,
+        fundraiser TEXT NOT NULL,
+        maker TEXT NOT NULL,
+        mint TEXT NOT NULL,
+        amount INTEGER NOT NULL
+    );
+
+    CREATE INDEX IF NOT EXISTS contributions_fundraiser ON contributions (fundraiser);
+    CREATE INDEX IF NOT EXISTS refunds_fundraiser ON refunds (fundraiser);
+    CREATE INDEX IF NOT EXISTS withdrawals_fundraiser ON withdrawals (fundraiser);
+";
+
+// What a campaign raised in one mint, multi-mint campaigns have a total per mint
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CampaignTotal {
+    pub fundraiser: String,
+    pub mint: String,
+    // None when the indexer did not see the campaign being created
+    pub amount_to_raise: Option<u64>,
+    pub cancelled: bool,
+    pub contributed: u64,
+    pub refunded: u64,
+    pub withdrawn: u64,
+}
+
+impl CampaignTotal {
+    // What is still in the campaign, or was paid out to the maker
+    pub fn raised(&self) -> u64 {
+        self.contributed.saturating_sub(self.refunded)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContributorTotal {
+    pub contributor: String,
+    pub mint: String,
+    pub contributed: u64,
+    pub refunded: u64,
+}
+
+impl ContributorTotal {
+    pub fn net(&self) -> u64 {
+        self.contributed.saturating_sub(self.refunded)
+    }
+}
+
+pub struct Database {
+    connection: Connection,
+}
+
+impl Database {
+    pub fn open(path: &Path) -> Result<Self, Error> {
+        Self::with_connection(Connection::open(path)?)
+    }
+
+    pub fn open_in_memory() -> Result<Self, Error> {
+        Self::with_connection(Connection::open_in_memory()?)
+    }
+
+    fn with_connection(connection: Connection) -> Result<Self, Error> {
+        connection.execute_batch(SCHEMA)?;
+        Ok(Self { connection })
+    }
+
+    // Stores a transaction's events. A transaction is only recorded once, so re-reading a file or
+    // overlapping subscriptions do not count anything twice. Returns false if it was already recorded.
+    pub fn record(&mut self, signature: &str, slot: Option<u64>, events: &[FundraiserEvent]) -> Result<bool, Error> {
+        let transaction = self.connection.transaction()?;
+
+        let inserted = transaction.execute(
+            "INSERT OR IGNORE INTO transactions (signature, slot) VALUES (?1, ?2)",
+            params![signature, slot],
+        )?;
+        if inserted == 0 {
+            return Ok(false);
+        }
+
+        for event in events {
+            match *event {
+                FundraiserEvent::FundraiserCreated {
+                    fundraiser,
+                    maker,
+                    mint_to_raise,
+                    amount_to_raise,
+                    time_ending,
+                } => {
+                    transaction.execute(
+                        "INSERT INTO campaigns (fundraiser, maker, mint, amount_to_raise, time_ending)
+                         VALUES (?1, ?2, ?3, ?4, ?5)
+                         ON CONFLICT (fundraiser) DO UPDATE SET
+                             maker = excluded.maker,
+                             mint = excluded.mint,
+                             amount_to_raise = excluded.amount_to_raise,
+                             time_ending = excluded.time_ending",
+                        params![key(fundraiser), key(maker), key(mint_to_raise), amount_to_raise, time_ending],
+                    )?;
+                }
+                FundraiserEvent::Contributed {
+                    fundraiser,
+                    contributor,
+                    mint,
+                    amount,
+                } => {
+                    transaction.execute(
+                        "INSERT INTO contributions (signature, fundraiser, contributor, mint, amount) VALUES (?1, ?2, ?3, ?4, ?5)",
+                        params![signature, key(fundraiser), key(contributor), key(mint), amount],
+                    )?;
+                }
+                FundraiserEvent::Refunded {
+                    fundraiser,
+                    contributor,
+                    mint,
+                    amount,
+                } => {
+                    transaction.execute(
+                        "INSERT INTO refunds (signature, fundraiser, contributor, mint, amount) VALUES (?1, ?2, ?3, ?4, ?5)",
+                        params![signature, key(fundraiser), key(contributor), key(mint), amount],
+                    )?;
+                }
+                FundraiserEvent::Withdrawn {
+                    fundraiser,
+                    maker,
+                    mint,
+                    amount,
+                } => {
+                    transaction.execute(
+                        "INSERT INTO withdrawals (signature, fundraiser, maker, mint, amount) VALUES (?1, ?2, ?3, ?4, ?5)",
+                        params![signature, key(fundraiser), key(maker), key(mint), amount],
+                    )?;
+                }
+                FundraiserEvent::Cancelled { fundraiser, maker } => {
+                    transaction.execute(
+                        "INSERT INTO campaigns (fundraiser, maker, cancelled) VALUES (?1, ?2, 1)
+                         ON CONFLICT (fundraiser) DO UPDATE SET cancelled = 1",
+                        params![key(fundraiser), key(maker)],
+                    )?;
+                }
+                FundraiserEvent::Amended {
+                    fundraiser,
+                    maker,
+                    amount_to_raise,
+                    time_ending,
+                } => {
+                    transaction.execute(
+                        "INSERT INTO campaigns (fundraiser, maker, amount_to_raise, time_ending) VALUES (?1, ?2, ?3, ?4)
+                         ON CONFLICT (fundraiser) DO UPDATE SET
+                             amount_to_raise = excluded.amount_to_raise,
+                             time_ending = excluded.time_ending",
+                        params![key(fundraiser), key(maker), amount_to_raise, time_ending],
+                    )?;
+                }
+            }
+        }
+
+        transaction.commit()?;
+        Ok(true)
+    }
+
+    // Total raised per campaign and mint
+    pub fn campaign_totals(&self) -> Result<Vec<CampaignTotal>, Error> {
+        let mut statement = self.connection.prepare(
+            "SELECT totals.fundraiser, totals.mint, campaigns.amount_to_raise, COALESCE(campaigns.cancelled, 0),
+                    SUM(totals.contributed), SUM(totals.refunded), SUM(totals.withdrawn)
+             FROM (
+                 SELECT fundraiser, mint, amount AS contributed, 0 AS refunded, 0 AS withdrawn FROM contributions
+                 UNION ALL SELECT fundraiser, mint, 0, amount, 0 FROM refunds
+                 UNION ALL SELECT fundraiser, mint, 0, 0, amount FROM withdrawals
+             ) AS totals
+             LEFT JOIN campaigns ON campaigns.fundraiser = totals.fundraiser
+             GROUP BY totals.fundraiser, totals.mint
+             ORDER BY totals.fundraiser, totals.mint",
+        )?;
+
+        let totals = statement
+            .query_map([], |row| {
+                Ok(CampaignTotal {
+                    fundraiser: row.get(0)?,
+                    mint: row.get(1)?,
+                    amount_to_raise: row.get(2)?,
+                    cancelled: row.get(3)?,
+                    contributed: row.get(4)?,
+                    refunded: row.get(5)?,
+                    withdrawn: row.get(6)?,
+                })
+            })?
+            .collect::<Result<_, _>>()?;
+
+        Ok(totals)
+    }
+
+    // The largest contributors to a campaign, by what they still have in it
+    pub fn top_contributors(&self, fundraiser: &str, limit: usize) -> Result<Vec<ContributorTotal>, Error> {
+        let mut statement = self.connection.prepare(
+            "SELECT contributor, mint, SUM(contributed), SUM(refunded)
+             FROM (
+                 SELECT contributor, mint, amount AS contributed, 0 AS refunded FROM contributions WHERE fundraiser = ?1
+                 UNION ALL SELECT contributor, mint, 0, amount FROM refunds WHERE fundraiser = ?1
+             )
+             GROUP BY contributor, mint
+             ORDER BY MAX(SUM(contributed) - SUM(refunded), 0) DESC, SUM(contributed) DESC, contributor
+             LIMIT ?2",
+        )?;
+
+        let contributors = statement
+            .query_map(params![fundraiser, limit], |row| {
+                Ok(ContributorTotal {
+                    contributor: row.get(0)?,
+                    mint: row.get(1)?,
+                    contributed: row.get(2)?,
+                    refunded: row.get(3)?,
+                })
+            })?
+            .collect::<Result<_, _>>()?;
+
+        Ok(contributors)
+    }
+
+    // The share of a campaign's contributors who took a refund, None if nobody contributed
+    pub fn refund_rate(&self, fundraiser: &str) -> Result<Option<f64>, Error> {
+        let (refunded, contributors): (u64, u64) = self.connection.query_row(
+            "SELECT (SELECT COUNT(DISTINCT contributor) FROM refunds WHERE fundraiser = ?1),
+                    (SELECT COUNT(DISTINCT contributor) FROM contributions WHERE fundraiser = ?1)",
+            params![fundraiser],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )?;
+
+        if contributors == 0 {
+            return Ok(None);
+        }
+        Ok(Some(refunded as f64 / contributors as f64))
+    }
+}
+
+fn key(bytes: [u8; 32]) -> String {
+    Pubkey::new_from_array(bytes).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn contributed(contributor: u8, amount: u64) -> FundraiserEvent {
+        FundraiserEvent::Contributed {
+            fundraiser: [1; 32],
+            contributor: [contributor; 32],
+            mint: [3; 32],
+            amount,
+        }
+    }
+
+    #[test]
+    fn queries() {
+        let mut database = Database::open_in_memory().unwrap();
+        let fundraiser = key([1; 32]);
+
+        let created = FundraiserEvent::FundraiserCreated {
+            fundraiser: [1; 32],
+            maker: [2; 32],
+            mint_to_raise: [3; 32],
+            amount_to_raise: 1_000,
+            time_ending: 100,
+        };
+        assert!(database.record("a", Some(1), &[created]).unwrap());
+        assert!(database.record("b", Some(2), &[contributed(4, 300)]).unwrap());
+        assert!(database.record("c", Some(3), &[contributed(5, 500), contributed(4, 100)]).unwrap());
+
+        // Seen before, nothing is counted twice
+        assert!(!database.record("b", Some(2), &[contributed(4, 300)]).unwrap());
+
+        // The maker lowers the goal, the campaign picks up the new terms
+        let amended = FundraiserEvent::Amended {
+            fundraiser: [1; 32],
+            maker: [2; 32],
+            amount_to_raise: 800,
+            time_ending: 100,
+        };
+        assert!(database.record("e", Some(3), &[amended]).unwrap());
+
+        let refunded = FundraiserEvent::Refunded {
+            fundraiser: [1; 32],
+            contributor: [5; 32],
+            mint: [3; 32],
+            amount: 500,
+        };
+        let cancelled = FundraiserEvent::Cancelled {
+            fundraiser: [1; 32],
+            maker: [2; 32],
+        };
+        database.record("d", Some(4), &[cancelled, refunded]).unwrap();
+
+        let totals = database.campaign_totals().unwrap();
+        assert_eq!(totals.len(), 1);
+        assert_eq!(totals[0].fundraiser, fundraiser);
+        assert_eq!(totals[0].amount_to_raise, Some(800));
+        assert!(totals[0].cancelled);
+        assert_eq!((totals[0].contributed, totals[0].refunded, totals[0].withdrawn), (900, 500, 0));
+        assert_eq!(totals[0].raised(), 400);
+
+        let top = database.top_contributors(&fundraiser, 10).unwrap();
+        assert_eq!(top.iter().map(|total| total.net()).collect::<Vec<_>>(), vec![400, 0]);
+        assert_eq!(top[0].contributor, key([4; 32]));
+
+        assert_eq!(database.refund_rate(&fundraiser).unwrap(), Some(0.5));
+        assert_eq!(database.refund_rate(&key([9; 32])).unwrap(), None);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,78 @@
+//! Rebuilds fundraiser state off-chain from the events the programs log.
+//!
+//! `source` reads transaction logs from a file of JSON RPC responses or a websocket subscription,
+//! `Transaction::events` picks out what the fundraiser programs logged, and `Database` keeps the
+//! campaigns, contributions, refunds and withdrawals in SQLite and answers queries over them.
+
+pub mod db;
+pub mod logs;
+pub mod source;
+
+use std::{fmt, io};
+
+use fundraiser_client::Implementation;
+use solana_sdk::pubkey::Pubkey;
+
+pub use db::{CampaignTotal, ContributorTotal, Database};
+pub use logs::Transaction;
+
+pub use fundraiser_interface::event::FundraiserEvent;
+
+#[derive(Debug)]
+pub enum Error {
+    Io(io::Error),
+    Json(serde_json::Error),
+    Sqlite(rusqlite::Error),
+    // The websocket subscription could not be set up
+    Subscription(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(error) => write!(f, "{}", error),
+            Error::Json(error) => write!(f, "Invalid JSON: {}", error),
+            Error::Sqlite(error) => write!(f, "Database error: {}", error),
+            Error::Subscription(error) => write!(f, "Failed to subscribe: {}", error),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Error::Io(error)
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(error: serde_json::Error) -> Self {
+        Error::Json(error)
+    }
+}
+
+impl From<rusqlite::Error> for Error {
+    fn from(error: rusqlite::Error) -> Self {
+        Error::Sqlite(error)
+    }
+}
+
+// The three implementations log the same events, by default all of them are indexed
+pub fn program_ids() -> Vec<Pubkey> {
+    Implementation::ALL
+        .iter()
+        .map(|implementation| implementation.program_id())
+        .collect()
+}
+
+// Records the events of one transaction, returns how many were stored
+pub fn ingest(database: &mut Database, transaction: &Transaction, program_ids: &[Pubkey]) -> Result<usize, Error> {
+    let events = transaction.events(program_ids);
+    if events.is_empty() {
+        return Ok(0);
+    }
+
+    let recorded = database.record(&transaction.signature, transaction.slot, &events)?;
+    Ok(if recorded { events.len() } else { 0 })
+}
\ No newline at end of file
@@ -0,0 +1,107 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+use fundraiser_interface::event::FundraiserEvent;
+use solana_sdk::pubkey::Pubkey;
+
+// One transaction's logs, however they were fetched
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Transaction {
+    pub signature: String,
+    pub slot: Option<u64>,
+    pub failed: bool,
+    pub logs: Vec<String>,
+}
+
+impl Transaction {
+    // The events the fundraiser programs logged, in order. A failed transaction changed nothing,
+    // so the events it logged before failing are dropped.
+    pub fn events(&self, program_ids: &[Pubkey]) -> Vec<FundraiserEvent> {
+        if self.failed {
+            return Vec::new();
+        }
+
+        let program_ids: Vec<String> = program_ids.iter().map(|id| id.to_string()).collect();
+
+        // "Program data:" lines do not name the program, the invoke stack tells who is running
+        let mut stack: Vec<&str> = Vec::new();
+        let mut events = Vec::new();
+
+        for line in self.logs.iter() {
+            let Some(rest) = line.strip_prefix("Program ") else {
+                continue;
+            };
+
+            if let Some(data) = rest.strip_prefix("data: ") {
+                let Some(program) = stack.last() else {
+                    continue;
+                };
+                if !program_ids.iter().any(|id| id == program) {
+                    continue;
+                }
+
+                // Every field is base64 on its own, the programs log the whole event as one
+                if let Some(event) = data
+                    .split(' ')
+                    .next()
+                    .and_then(|field| STANDARD.decode(field).ok())
+                    .and_then(|bytes| FundraiserEvent::unpack(&bytes))
+                {
+                    events.push(event);
+                }
+                continue;
+            }
+
+            let mut words = rest.split(' ');
+            match (words.next(), words.next()) {
+                (Some(program), Some("invoke")) => stack.push(program),
+                (Some(_), Some("success")) | (Some(_), Some("failed:")) => {
+                    stack.pop();
+                }
+                _ => {}
+            }
+        }
+
+        events
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn events_from_the_invoke_stack() {
+        let program_id = Pubkey::new_unique();
+        let token_program = Pubkey::new_unique();
+
+        let cancelled = FundraiserEvent::Cancelled {
+            fundraiser: [1; 32],
+            maker: [2; 32],
+        };
+        let (data, len) = cancelled.pack();
+        let data = STANDARD.encode(&data[..len]);
+
+        let mut transaction = Transaction {
+            signature: "signature".to_string(),
+            slot: Some(1),
+            failed: false,
+            logs: vec![
+                format!("Program {} invoke [1]", program_id),
+                format!("Program {} invoke [2]", token_program),
+                // Logged by another program, even though the bytes decode
+                format!("Program data: {}", data),
+                format!("Program {} success", token_program),
+                "Program log: Instruction: Cancel".to_string(),
+                format!("Program data: {}", data),
+                format!("Program {} consumed 1000 of 200000 compute units", program_id),
+                format!("Program {} success", program_id),
+                format!("Program data: {}", data),
+            ],
+        };
+
+        assert_eq!(transaction.events(&[program_id]), vec![cancelled]);
+        assert!(transaction.events(&[]).is_empty());
+
+        transaction.failed = true;
+        assert!(transaction.events(&[program_id]).is_empty());
+    }
+}
\ No newline at end of file
@@ -0,0 +1,135 @@
+use std::path::PathBuf;
+
+use clap::{Parser, Subcommand, ValueEnum};
+use fundraiser_client::Implementation;
+use fundraiser_indexer::{ingest, program_ids, source, Database, Error};
+
+#[derive(Parser)]
+#[command(name = "fundraiser-indexer", about = "Index fundraiser events into SQLite", version)]
+struct Cli {
+    /// SQLite database to write to and query
+    #[arg(long, global = true, default_value = "fundraiser-indexer.db")]
+    db: PathBuf,
+
+    /// Only index these programs, all three by default
+    #[arg(long, global = true, value_enum)]
+    program: Vec<ProgramArg>,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Copy, Clone, ValueEnum)]
+enum ProgramArg {
+    Anchor,
+    Native,
+    Optimised,
+}
+
+impl From<ProgramArg> for Implementation {
+    fn from(program: ProgramArg) -> Self {
+        match program {
+            ProgramArg::Anchor => Implementation::Anchor,
+            ProgramArg::Native => Implementation::Native,
+            ProgramArg::Optimised => Implementation::Optimised,
+        }
+    }
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Index files of JSON RPC responses, getTransaction results or logsNotification messages
+    Ingest { files: Vec<PathBuf> },
+    /// Index transactions as they are confirmed, until the connection drops
+    Watch {
+        /// Websocket endpoint of the validator
+        #[arg(long, default_value = "ws://127.0.0.1:8900")]
+        url: String,
+    },
+    /// Print what every campaign raised, per mint
+    Totals,
+    /// Print the largest contributors to a campaign
+    TopContributors {
+        #[arg(long)]
+        fundraiser: String,
+        #[arg(long, default_value_t = 10)]
+        limit: usize,
+    },
+    /// Print the share of a campaign's contributors who took a refund
+    RefundRate {
+        #[arg(long)]
+        fundraiser: String,
+    },
+}
+
+fn main() {
+    let cli = Cli::parse();
+
+    if let Err(error) = run(cli) {
+        eprintln!("Error: {}", error);
+        std::process::exit(1);
+    }
+}
+
+fn run(cli: Cli) -> Result<(), Error> {
+    let mut database = Database::open(&cli.db)?;
+    let program_ids = if cli.program.is_empty() {
+        program_ids()
+    } else {
+        cli.program
+            .iter()
+            .map(|program| Implementation::from(*program).program_id())
+            .collect()
+    };
+
+    match cli.command {
+        Command::Ingest { files } => {
+            for file in files {
+                let mut events = 0;
+                for transaction in source::read_file(&file)? {
+                    events += ingest(&mut database, &transaction, &program_ids)?;
+                }
+                println!("{}: {} events", file.display(), events);
+            }
+        }
+        Command::Watch { url } => {
+            source::watch(&url, &program_ids, |transaction| {
+                let events = ingest(&mut database, &transaction, &program_ids)?;
+                if events > 0 {
+                    println!("{}: {} events", transaction.signature, events);
+                }
+                Ok(())
+            })?;
+        }
+        Command::Totals => {
+            for total in database.campaign_totals()? {
+                let goal = match total.amount_to_raise {
+                    Some(amount_to_raise) => amount_to_raise.to_string(),
+                    None => "unknown".to_string(),
+                };
+                println!(
+                    "{} {}: raised {} of {} (contributed {}, refunded {}, withdrawn {}){}",
+                    total.fundraiser,
+                    total.mint,
+                    total.raised(),
+                    goal,
+                    total.contributed,
+                    total.refunded,
+                    total.withdrawn,
+                    if total.cancelled { ", cancelled" } else { "" }
+                );
+            }
+        }
+        Command::TopContributors { fundraiser, limit } => {
+            for total in database.top_contributors(&fundraiser, limit)? {
+                println!("{} {}: {}", total.contributor, total.mint, total.net());
+            }
+        }
+        Command::RefundRate { fundraiser } => match database.refund_rate(&fundraiser)? {
+            Some(rate) => println!("{:.2}%", rate * 100.0),
+            None => println!("No contributions"),
+        },
+    }
+
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,143 @@
+use std::{fs::File, io::BufReader, path::Path, sync::mpsc, thread};
+
+use serde_json::Value;
+use solana_pubsub_client::pubsub_client::{PubsubClient, PubsubLogsClientSubscription};
+use solana_rpc_client_api::config::{RpcTransactionLogsConfig, RpcTransactionLogsFilter};
+use solana_sdk::{commitment_config::CommitmentConfig, pubkey::Pubkey};
+
+use crate::{logs::Transaction, Error};
+
+// Reads a file of JSON RPC responses, one after the other or in batches. getTransaction responses
+// (json encoding) and logsNotification messages are understood, anything else is skipped.
+pub fn read_file(path: &Path) -> Result<Vec<Transaction>, Error> {
+    let reader = BufReader::new(File::open(path)?);
+
+    let mut transactions = Vec::new();
+    for value in serde_json::Deserializer::from_reader(reader).into_iter::<Value>() {
+        match value? {
+            Value::Array(batch) => transactions.extend(batch.iter().filter_map(parse_response)),
+            response => transactions.extend(parse_response(&response)),
+        }
+    }
+
+    Ok(transactions)
+}
+
+pub fn parse_response(response: &Value) -> Option<Transaction> {
+    // getTransaction
+    if let Some(result) = response.get("result").filter(|result| result.get("meta").is_some()) {
+        let meta = &result["meta"];
+        return Some(Transaction {
+            signature: result["transaction"]["signatures"][0].as_str()?.to_string(),
+            slot: result["slot"].as_u64(),
+            failed: !meta["err"].is_null(),
+            logs: strings(&meta["logMessages"])?,
+        });
+    }
+
+    // logsNotification
+    let result = &response["params"]["result"];
+    let value = &result["value"];
+    Some(Transaction {
+        signature: value["signature"].as_str()?.to_string(),
+        slot: result["context"]["slot"].as_u64(),
+        failed: !value["err"].is_null(),
+        logs: strings(&value["logs"])?,
+    })
+}
+
+fn strings(value: &Value) -> Option<Vec<String>> {
+    value
+        .as_array()?
+        .iter()
+        .map(|line| line.as_str().map(str::to_string))
+        .collect()
+}
+
+// Subscribes to the logs of every program and hands each transaction to `handle` as it is confirmed.
+// Runs until the connection drops or `handle` fails.
+pub fn watch(
+    url: &str,
+    program_ids: &[Pubkey],
+    mut handle: impl FnMut(Transaction) -> Result<(), Error>,
+) -> Result<(), Error> {
+    let (sender, receiver) = mpsc::channel();
+
+    // A logs subscription can only mention a single account, so every program gets its own
+    let mut subscriptions: Vec<PubsubLogsClientSubscription> = Vec::new();
+    for program_id in program_ids {
+        let (subscription, notifications) = PubsubClient::logs_subscribe(
+            url,
+            RpcTransactionLogsFilter::Mentions(vec![program_id.to_string()]),
+            RpcTransactionLogsConfig {
+                commitment: Some(CommitmentConfig::confirmed()),
+            },
+        )
+        .map_err(|error| Error::Subscription(error.to_string()))?;
+        subscriptions.push(subscription);
+
+        let sender = sender.clone();
+        thread::spawn(move || {
+            for notification in notifications.iter() {
+                let transaction = Transaction {
+                    signature: notification.value.signature,
+                    slot: Some(notification.context.slot),
+                    failed: notification.value.err.is_some(),
+                    logs: notification.value.logs,
+                };
+                if sender.send(transaction).is_err() {
+                    break;
+                }
+            }
+        });
+    }
+    drop(sender);
+
+    for transaction in receiver.iter() {
+        handle(transaction)?;
+    }
+
+    for subscription in subscriptions {
+        let _ = subscription.send_unsubscribe();
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_responses() {
+        let get_transaction: Value = serde_json::from_str(
+            r#"{"jsonrpc":"2.0","id":1,"result":{"slot":42,"blockTime":null,
+                "transaction":{"signatures":["sig1"],"message":{}},
+                "meta":{"err":null,"logMessages":["Program log: hello"]}}}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            parse_response(&get_transaction),
+            Some(Transaction {
+                signature: "sig1".to_string(),
+                slot: Some(42),
+                failed: false,
+                logs: vec!["Program log: hello".to_string()],
+            })
+        );
+
+        let notification: Value = serde_json::from_str(
+            r#"{"jsonrpc":"2.0","method":"logsNotification","params":{"subscription":0,
+                "result":{"context":{"slot":7},"value":{"signature":"sig2","err":{"InstructionError":[0,"Custom"]},"logs":[]}}}}"#,
+        )
+        .unwrap();
+        let transaction = parse_response(&notification).unwrap();
+        assert_eq!(transaction.signature, "sig2");
+        assert_eq!(transaction.slot, Some(7));
+        assert!(transaction.failed);
+
+        // A transaction that was not found
+        let not_found: Value = serde_json::from_str(r#"{"jsonrpc":"2.0","id":1,"result":null}"#).unwrap();
+        assert_eq!(parse_response(&not_found), None);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,169 @@
+// Custom program error codes, they start where Anchor starts its own so every implementation
+// reports the same code for the same failure.
+pub const ERROR_CODE_OFFSET: u32 = 6000;
+
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum FundraiserError {
+    TargetNotMet = ERROR_CODE_OFFSET,
+    TargetMet,
+    ContributionTooBig,
+    ContributionTooSmall,
+    MaximumContributionsReached,
+    FundraiserNotEnded,
+    FundraiserEnded,
+    InvalidAmount,
+    FundraiserCancelled,
+    InvalidMilestones,
+    NoMilestones,
+    MilestoneLocked,
+    MilestonesCompleted,
+    MilestonesConfigured,
+    InvalidVotingConfig,
+    VotingDisabled,
+    VotingWindowClosed,
+    VotingWindowOpen,
+    AlreadyVoted,
+    NoVotingWeight,
+    MilestoneNotApproved,
+    MilestoneApproved,
+    RefundsDisabled,
+    NotSolFundraiser,
+    TransferHookNotSupported,
+    InvalidPriceFeed,
+    MintNotAccepted,
+    CannotAddMint,
+    MultiMintFundraiser,
+    InvalidContributionLimits,
+    HardCapReached,
+    InvalidFundraiserAccount,
+    ReceiptsEnabled,
+    ReceiptsDisabled,
+    CannotEnableReceipts,
+    InvalidReceiptMint,
+    InvalidRewardTiers,
+    RewardTiersLocked,
+    InvalidRewardTier,
+    RewardTierSoldOut,
+    PledgeBelowTierMinimum,
+    InvalidFee,
+    InvalidFeeRecipient,
+    NotUpgradeAuthority,
+    AmendmentsExhausted,
+    InvalidAmendment,
+    GracePeriodActive,
+}
+
+impl FundraiserError {
+    pub const ALL: [FundraiserError; 47] = [
+        FundraiserError::TargetNotMet,
+        FundraiserError::TargetMet,
+        FundraiserError::ContributionTooBig,
+        FundraiserError::ContributionTooSmall,
+        FundraiserError::MaximumContributionsReached,
+        FundraiserError::FundraiserNotEnded,
+        FundraiserError::FundraiserEnded,
+        FundraiserError::InvalidAmount,
+        FundraiserError::FundraiserCancelled,
+        FundraiserError::InvalidMilestones,
+        FundraiserError::NoMilestones,
+        FundraiserError::MilestoneLocked,
+        FundraiserError::MilestonesCompleted,
+        FundraiserError::MilestonesConfigured,
+        FundraiserError::InvalidVotingConfig,
+        FundraiserError::VotingDisabled,
+        FundraiserError::VotingWindowClosed,
+        FundraiserError::VotingWindowOpen,
+        FundraiserError::AlreadyVoted,
+        FundraiserError::NoVotingWeight,
+        FundraiserError::MilestoneNotApproved,
+        FundraiserError::MilestoneApproved,
+        FundraiserError::RefundsDisabled,
+        FundraiserError::NotSolFundraiser,
+        FundraiserError::TransferHookNotSupported,
+        FundraiserError::InvalidPriceFeed,
+        FundraiserError::MintNotAccepted,
+        FundraiserError::CannotAddMint,
+        FundraiserError::MultiMintFundraiser,
+        FundraiserError::InvalidContributionLimits,
+        FundraiserError::HardCapReached,
+        FundraiserError::InvalidFundraiserAccount,
+        FundraiserError::ReceiptsEnabled,
+        FundraiserError::ReceiptsDisabled,
+        FundraiserError::CannotEnableReceipts,
+        FundraiserError::InvalidReceiptMint,
+        FundraiserError::InvalidRewardTiers,
+        FundraiserError::RewardTiersLocked,
+        FundraiserError::InvalidRewardTier,
+        FundraiserError::RewardTierSoldOut,
+        FundraiserError::PledgeBelowTierMinimum,
+        FundraiserError::InvalidFee,
+        FundraiserError::InvalidFeeRecipient,
+        FundraiserError::NotUpgradeAuthority,
+        FundraiserError::AmendmentsExhausted,
+        FundraiserError::InvalidAmendment,
+        FundraiserError::GracePeriodActive,
+    ];
+
+    pub fn from_code(code: u32) -> Option<Self> {
+        Self::ALL.iter().copied().find(|error| *error as u32 == code)
+    }
+
+    pub fn message(&self) -> &'static str {
+        match self {
+            FundraiserError::TargetNotMet => "The amount to raise has not been met",
+            FundraiserError::TargetMet => "The amount to raise has been achieved",
+            FundraiserError::ContributionTooBig => "The contribution is too big",
+            FundraiserError::ContributionTooSmall => "The contribution is too small",
+            FundraiserError::MaximumContributionsReached => "The maximum amount to contribute has been reached",
+            FundraiserError::FundraiserNotEnded => "The fundraiser has not ended yet",
+            FundraiserError::FundraiserEnded => "The fundraiser has ended",
+            FundraiserError::InvalidAmount => "Invalid total amount. It should be bigger than 3",
+            FundraiserError::FundraiserCancelled => "The fundraiser has been cancelled",
+            FundraiserError::InvalidMilestones => "Invalid milestone schedule",
+            FundraiserError::NoMilestones => "The fundraiser has no milestones",
+            FundraiserError::MilestoneLocked => "The milestone is still locked",
+            FundraiserError::MilestonesCompleted => "All milestones have been released",
+            FundraiserError::MilestonesConfigured => "Funds are released through milestones",
+            FundraiserError::InvalidVotingConfig => "Invalid voting configuration",
+            FundraiserError::VotingDisabled => "Voting is disabled for this fundraiser",
+            FundraiserError::VotingWindowClosed => "The voting window is closed",
+            FundraiserError::VotingWindowOpen => "The voting window is still open",
+            FundraiserError::AlreadyVoted => "The contributor has already voted on this milestone",
+            FundraiserError::NoVotingWeight => "The contributor has no voting weight",
+            FundraiserError::MilestoneNotApproved => "The milestone has not been approved",
+            FundraiserError::MilestoneApproved => "The milestone has been approved",
+            FundraiserError::RefundsDisabled => "Refunds are disabled for flexible fundraisers",
+            FundraiserError::NotSolFundraiser => "The fundraiser does not raise SOL",
+            FundraiserError::TransferHookNotSupported => "Mints with a transfer hook are not supported",
+            FundraiserError::InvalidPriceFeed => "Invalid price feed",
+            FundraiserError::MintNotAccepted => "The mint is not accepted by this fundraiser",
+            FundraiserError::CannotAddMint => "The mint cannot be added to this fundraiser",
+            FundraiserError::MultiMintFundraiser => "The fundraiser accepts multiple mints",
+            FundraiserError::InvalidContributionLimits => "Invalid contribution limits",
+            FundraiserError::HardCapReached => "The contribution would exceed the hard cap",
+            FundraiserError::InvalidFundraiserAccount => "Invalid fundraiser account",
+            FundraiserError::ReceiptsEnabled => "The fundraiser issues receipt tokens",
+            FundraiserError::ReceiptsDisabled => "The fundraiser does not issue receipt tokens",
+            FundraiserError::CannotEnableReceipts => "Receipt tokens cannot be enabled for this fundraiser",
+            FundraiserError::InvalidReceiptMint => "Invalid receipt mint",
+            FundraiserError::InvalidRewardTiers => "Invalid reward tiers",
+            FundraiserError::RewardTiersLocked => "Reward tiers cannot change once contributions came in",
+            FundraiserError::InvalidRewardTier => "Invalid reward tier",
+            FundraiserError::RewardTierSoldOut => "The reward tier is sold out",
+            FundraiserError::PledgeBelowTierMinimum => "The pledge is below the minimum of the reward tier",
+            FundraiserError::InvalidFee => "The platform fee is above the maximum",
+            FundraiserError::InvalidFeeRecipient => "Invalid fee recipient",
+            FundraiserError::NotUpgradeAuthority => "Only the upgrade authority can configure the platform",
+            FundraiserError::AmendmentsExhausted => "The fundraiser has no amendments left",
+            FundraiserError::InvalidAmendment => "An amendment can only extend the deadline or lower the goal",
+            FundraiserError::GracePeriodActive => "Contributors can still withdraw after the last amendment",
+        }
+    }
+}
+
+impl core::fmt::Display for FundraiserError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.message())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,236 @@
+// Every state transition is logged as a single `sol_log_data` buffer: an 8 byte discriminator
+// followed by the fields, little-endian. These are the bytes Anchor's `emit!` logs for the matching
+// `#[event]` struct (discriminator sha256("event:<Name>")[..8], then borsh), so an indexer reads the
+// "Program data:" log lines of all three programs the same way.
+// SOL fundraisers log the default pubkey as their mint.
+
+pub const DISCRIMINATOR_LEN: usize = 8;
+
+pub const FUNDRAISER_CREATED: [u8; DISCRIMINATOR_LEN] = [157, 38, 233, 84, 181, 25, 165, 7];
+pub const CONTRIBUTED: [u8; DISCRIMINATOR_LEN] = [196, 199, 157, 136, 180, 222, 100, 118];
+pub const REFUNDED: [u8; DISCRIMINATOR_LEN] = [35, 103, 149, 246, 196, 123, 221, 99];
+pub const WITHDRAWN: [u8; DISCRIMINATOR_LEN] = [20, 89, 223, 198, 194, 124, 219, 13];
+pub const CANCELLED: [u8; DISCRIMINATOR_LEN] = [136, 23, 42, 65, 143, 233, 234, 46];
+pub const AMENDED: [u8; DISCRIMINATOR_LEN] = [247, 255, 158, 203, 140, 60, 239, 255];
+
+// The longest event, FundraiserCreated
+pub const MAX_LEN: usize = DISCRIMINATOR_LEN + 32 * 3 + 8 + 8;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum FundraiserEvent {
+    FundraiserCreated {
+        fundraiser: [u8; 32],
+        maker: [u8; 32],
+        mint_to_raise: [u8; 32],
+        amount_to_raise: u64,
+        time_ending: i64,
+    },
+    // What reached the vault, after any transfer fee
+    Contributed {
+        fundraiser: [u8; 32],
+        contributor: [u8; 32],
+        mint: [u8; 32],
+        amount: u64,
+    },
+    Refunded {
+        fundraiser: [u8; 32],
+        contributor: [u8; 32],
+        mint: [u8; 32],
+        amount: u64,
+    },
+    // Paid out to the maker, by the checkers or a milestone release
+    Withdrawn {
+        fundraiser: [u8; 32],
+        maker: [u8; 32],
+        mint: [u8; 32],
+        amount: u64,
+    },
+    Cancelled {
+        fundraiser: [u8; 32],
+        maker: [u8; 32],
+    },
+    // The goal and deadline after the maker extended the deadline or lowered the goal
+    Amended {
+        fundraiser: [u8; 32],
+        maker: [u8; 32],
+        amount_to_raise: u64,
+        time_ending: i64,
+    },
+}
+
+impl FundraiserEvent {
+    pub fn discriminator(&self) -> [u8; DISCRIMINATOR_LEN] {
+        match self {
+            FundraiserEvent::FundraiserCreated { .. } => FUNDRAISER_CREATED,
+            FundraiserEvent::Contributed { .. } => CONTRIBUTED,
+            FundraiserEvent::Refunded { .. } => REFUNDED,
+            FundraiserEvent::Withdrawn { .. } => WITHDRAWN,
+            FundraiserEvent::Cancelled { .. } => CANCELLED,
+            FundraiserEvent::Amended { .. } => AMENDED,
+        }
+    }
+
+    // The buffer to log and how much of it the event uses
+    pub fn pack(&self) -> ([u8; MAX_LEN], usize) {
+        let mut data = [0u8; MAX_LEN];
+        data[..DISCRIMINATOR_LEN].copy_from_slice(&self.discriminator());
+
+        let mut len = DISCRIMINATOR_LEN;
+        let mut put = |bytes: &[u8]| {
+            data[len..len + bytes.len()].copy_from_slice(bytes);
+            len += bytes.len();
+        };
+
+        match self {
+            FundraiserEvent::FundraiserCreated {
+                fundraiser,
+                maker,
+                mint_to_raise,
+                amount_to_raise,
+                time_ending,
+            } => {
+                put(fundraiser);
+                put(maker);
+                put(mint_to_raise);
+                put(&amount_to_raise.to_le_bytes());
+                put(&time_ending.to_le_bytes());
+            }
+            FundraiserEvent::Contributed {
+                fundraiser,
+                contributor: account,
+                mint,
+                amount,
+            }
+            | FundraiserEvent::Refunded {
+                fundraiser,
+                contributor: account,
+                mint,
+                amount,
+            }
+            | FundraiserEvent::Withdrawn {
+                fundraiser,
+                maker: account,
+                mint,
+                amount,
+            } => {
+                put(fundraiser);
+                put(account);
+                put(mint);
+                put(&amount.to_le_bytes());
+            }
+            FundraiserEvent::Cancelled { fundraiser, maker } => {
+                put(fundraiser);
+                put(maker);
+            }
+            FundraiserEvent::Amended {
+                fundraiser,
+                maker,
+                amount_to_raise,
+                time_ending,
+            } => {
+                put(fundraiser);
+                put(maker);
+                put(&amount_to_raise.to_le_bytes());
+                put(&time_ending.to_le_bytes());
+            }
+        }
+
+        (data, len)
+    }
+
+    // None if the data is not one of these events, e.g. another program's log
+    pub fn unpack(data: &[u8]) -> Option<Self> {
+        if data.len() < DISCRIMINATOR_LEN {
+            return None;
+        }
+        let (discriminator, fields) = data.split_at(DISCRIMINATOR_LEN);
+
+        let pubkey = |index: usize| -> Option<[u8; 32]> { fields.get(index * 32..index * 32 + 32)?.try_into().ok() };
+        let word = |offset: usize| -> Option<[u8; 8]> { fields.get(offset..offset + 8)?.try_into().ok() };
+
+        let discriminator: [u8; DISCRIMINATOR_LEN] = discriminator.try_into().ok()?;
+        let event = match discriminator {
+            FUNDRAISER_CREATED => FundraiserEvent::FundraiserCreated {
+                fundraiser: pubkey(0)?,
+                maker: pubkey(1)?,
+                mint_to_raise: pubkey(2)?,
+                amount_to_raise: u64::from_le_bytes(word(96)?),
+                time_ending: i64::from_le_bytes(word(104)?),
+            },
+            CONTRIBUTED => FundraiserEvent::Contributed {
+                fundraiser: pubkey(0)?,
+                contributor: pubkey(1)?,
+                mint: pubkey(2)?,
+                amount: u64::from_le_bytes(word(96)?),
+            },
+            REFUNDED => FundraiserEvent::Refunded {
+                fundraiser: pubkey(0)?,
+                contributor: pubkey(1)?,
+                mint: pubkey(2)?,
+                amount: u64::from_le_bytes(word(96)?),
+            },
+            WITHDRAWN => FundraiserEvent::Withdrawn {
+                fundraiser: pubkey(0)?,
+                maker: pubkey(1)?,
+                mint: pubkey(2)?,
+                amount: u64::from_le_bytes(word(96)?),
+            },
+            CANCELLED => FundraiserEvent::Cancelled {
+                fundraiser: pubkey(0)?,
+                maker: pubkey(1)?,
+            },
+            AMENDED => FundraiserEvent::Amended {
+                fundraiser: pubkey(0)?,
+                maker: pubkey(1)?,
+                amount_to_raise: u64::from_le_bytes(word(64)?),
+                time_ending: i64::from_le_bytes(word(72)?),
+            },
+            _ => return None,
+        };
+
+        Some(event)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pack_round_trip() {
+        let events = [
+            FundraiserEvent::FundraiserCreated {
+                fundraiser: [1; 32],
+                maker: [2; 32],
+                mint_to_raise: [3; 32],
+                amount_to_raise: 1_000,
+                time_ending: -1,
+            },
+            FundraiserEvent::Contributed {
+                fundraiser: [1; 32],
+                contributor: [4; 32],
+                mint: [3; 32],
+                amount: 10,
+            },
+            FundraiserEvent::Cancelled {
+                fundraiser: [1; 32],
+                maker: [2; 32],
+            },
+            FundraiserEvent::Amended {
+                fundraiser: [1; 32],
+                maker: [2; 32],
+                amount_to_raise: 500,
+                time_ending: 1_700_000_000,
+            },
+        ];
+
+        for event in events {
+            let (data, len) = event.pack();
+            assert_eq!(FundraiserEvent::unpack(&data[..len]), Some(event));
+        }
+
+        let (data, len) = events[0].pack();
+        assert_eq!(len, MAX_LEN);
+        assert_eq!(FundraiserEvent::unpack(&data[..len - 1]), None);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,307 @@
+use crate::state::{
+    milestone, reward_tier, ContributionLimits, FundingMode, Milestone, RewardTier, MAX_MILESTONES, MAX_REWARD_TIERS,
+};
+
+// The first byte of the instruction data selects the instruction, the payload follows it.
+// Accounts are listed in the order the programs expect them.
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum FundraiserInstruction {
+    // [maker (signer), fundraiser, mint_to_raise, vault, system_program, token_program, associated_token_program, config],
+    // payload: `InitializeData`. The fundraiser and its vault are created, the maker pays for both
+    Initialize = 0,
+    // [maker (signer), mint_to_raise, fundraiser, vault, maker_ta, token_program, config, fee_recipient_ta],
+    // the last two only when the fundraiser pays a platform fee
+    Checker = 1,
+    // [contributor (signer), contributor_account, contributor_ta, fundraiser, vault, token_program, mint_to_raise, system_program],
+    // payload: amount (u64). The first contribution creates the contributor account
+    Contribute = 2,
+    // [contributor (signer), maker, fundraiser, contributor_account, contributor_ta, vault, token_program, mint_to_raise]
+    Refund = 3,
+    // [maker (signer), fundraiser]
+    Cancel = 4,
+    // [maker (signer), mint_to_raise, fundraiser, vault, maker_ta, token_program]
+    ReleaseMilestone = 5,
+    // [contributor (signer), fundraiser, contributor_account], payload: approve (u8)
+    Vote = 6,
+    // [fundraiser]
+    RejectMilestone = 7,
+    // [maker (signer), fundraiser, system_program], payload: `InitializeSolData`
+    InitializeSol = 8,
+    // [contributor (signer), contributor_account, fundraiser, system_program], payload: amount (u64)
+    ContributeSol = 9,
+    // [maker (signer), fundraiser]
+    CheckerSol = 10,
+    // [contributor (signer), fundraiser, contributor_account]
+    RefundSol = 11,
+    // [authority (signer), mint, price_feed, system_program], payload: price (u64)
+    SetPrice = 12,
+    // [maker (signer), mint, price_feed, fundraiser]
+    AddAcceptedMint = 13,
+    // [contributor (signer), contributor_account, contributor_ta, fundraiser, vault, token_program, mint, price_feed], payload: amount (u64)
+    ContributeMulti = 14,
+    // [maker (signer), mint, fundraiser, vault, maker_ta, token_program, price_feed, ...price feeds of every accepted mint]
+    CheckerMulti = 15,
+    // [contributor (signer), maker, fundraiser, contributor_account, contributor_ta, vault, token_program, mint, price_feed, ...price feeds of every accepted mint]
+    RefundMulti = 16,
+    // [maker (signer), fundraiser, mint_to_raise, receipt_mint]
+    EnableReceipts = 17,
+    // [contributor (signer), contributor_ta, fundraiser, vault, token_program, mint_to_raise, receipt_mint, contributor_receipt_ta], payload: amount (u64)
+    ContributeReceipts = 18,
+    // [holder (signer), holder_ta, fundraiser, vault, token_program, mint_to_raise, receipt_mint, holder_receipt_ta]
+    RefundReceipts = 19,
+    // [maker (signer), fundraiser, reward_tiers, system_program], payload: tier_count (u8), tiers
+    SetRewardTiers = 20,
+    // [admin (signer), config, program_data, system_program], payload: fee_bps (u16), fee_recipient
+    InitializeConfig = 21,
+    // [maker (signer), fundraiser], payload: time_ending (i64). Opens a grace window for contributors
+    ExtendDeadline = 22,
+    // [maker (signer), fundraiser], payload: amount_to_raise (u64), only lower. Opens a grace window for contributors
+    AmendGoal = 23,
+}
+
+impl TryFrom<u8> for FundraiserInstruction {
+    type Error = u8;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(FundraiserInstruction::Initialize),
+            1 => Ok(FundraiserInstruction::Checker),
+            2 => Ok(FundraiserInstruction::Contribute),
+            3 => Ok(FundraiserInstruction::Refund),
+            4 => Ok(FundraiserInstruction::Cancel),
+            5 => Ok(FundraiserInstruction::ReleaseMilestone),
+            6 => Ok(FundraiserInstruction::Vote),
+            7 => Ok(FundraiserInstruction::RejectMilestone),
+            8 => Ok(FundraiserInstruction::InitializeSol),
+            9 => Ok(FundraiserInstruction::ContributeSol),
+            10 => Ok(FundraiserInstruction::CheckerSol),
+            11 => Ok(FundraiserInstruction::RefundSol),
+            12 => Ok(FundraiserInstruction::SetPrice),
+            13 => Ok(FundraiserInstruction::AddAcceptedMint),
+            14 => Ok(FundraiserInstruction::ContributeMulti),
+            15 => Ok(FundraiserInstruction::CheckerMulti),
+            16 => Ok(FundraiserInstruction::RefundMulti),
+            17 => Ok(FundraiserInstruction::EnableReceipts),
+            18 => Ok(FundraiserInstruction::ContributeReceipts),
+            19 => Ok(FundraiserInstruction::RefundReceipts),
+            20 => Ok(FundraiserInstruction::SetRewardTiers),
+            21 => Ok(FundraiserInstruction::InitializeConfig),
+            22 => Ok(FundraiserInstruction::ExtendDeadline),
+            23 => Ok(FundraiserInstruction::AmendGoal),
+            _ => Err(value),
+        }
+    }
+}
+
+// Payload offsets, counted from the first byte after the discriminator
+pub mod initialize {
+    use crate::state::{contribution_limits, milestone, MAX_MILESTONES};
+
+    pub const AMOUNT_TO_RAISE: usize = 0;
+    pub const TIME_ENDING: usize = AMOUNT_TO_RAISE + 8;
+    pub const CAMPAIGN_ID: usize = TIME_ENDING + 8;
+    pub const BUMP: usize = CAMPAIGN_ID + 8;
+    pub const MILESTONE_COUNT: usize = BUMP + 1;
+    pub const MILESTONES: usize = MILESTONE_COUNT + 1;
+    pub const VOTING_PERIOD: usize = MILESTONES + milestone::LEN * MAX_MILESTONES;
+    pub const QUORUM_BPS: usize = VOTING_PERIOD + 8;
+    pub const APPROVAL_BPS: usize = QUORUM_BPS + 2;
+    pub const FUNDING_MODE: usize = APPROVAL_BPS + 2;
+    pub const CONTRIBUTION_LIMITS: usize = FUNDING_MODE + 1;
+    pub const MAX_AMENDMENTS: usize = CONTRIBUTION_LIMITS + contribution_limits::LEN;
+    pub const LEN: usize = MAX_AMENDMENTS + 1;
+}
+
+pub mod initialize_sol {
+    use crate::state::contribution_limits;
+
+    pub const AMOUNT_TO_RAISE: usize = 0;
+    pub const TIME_ENDING: usize = AMOUNT_TO_RAISE + 8;
+    pub const CAMPAIGN_ID: usize = TIME_ENDING + 8;
+    pub const BUMP: usize = CAMPAIGN_ID + 8;
+    pub const FUNDING_MODE: usize = BUMP + 1;
+    pub const CONTRIBUTION_LIMITS: usize = FUNDING_MODE + 1;
+    pub const MAX_AMENDMENTS: usize = CONTRIBUTION_LIMITS + contribution_limits::LEN;
+    pub const LEN: usize = MAX_AMENDMENTS + 1;
+}
+
+// Contribute, ContributeSol, ContributeMulti and ContributeReceipts
+pub mod contribute {
+    pub const AMOUNT: usize = 0;
+    pub const LEN: usize = AMOUNT + 8;
+    // Contribute can pick a reward tier after the amount, encoded as a borsh Option<u8>, and then
+    // takes the reward_tiers account last
+    pub const REWARD_TIER: usize = LEN;
+}
+
+pub mod vote {
+    pub const APPROVE: usize = 0;
+    pub const LEN: usize = APPROVE + 1;
+}
+
+pub mod set_price {
+    pub const PRICE: usize = 0;
+    pub const LEN: usize = PRICE + 8;
+}
+
+pub mod initialize_config {
+    pub const FEE_BPS: usize = 0;
+    pub const FEE_RECIPIENT: usize = FEE_BPS + 2;
+    pub const LEN: usize = FEE_RECIPIENT + 32;
+}
+
+pub mod extend_deadline {
+    pub const TIME_ENDING: usize = 0;
+    pub const LEN: usize = TIME_ENDING + 8;
+}
+
+pub mod amend_goal {
+    pub const AMOUNT_TO_RAISE: usize = 0;
+    pub const LEN: usize = AMOUNT_TO_RAISE + 8;
+}
+
+pub mod set_reward_tiers {
+    use crate::state::{reward_tier, MAX_REWARD_TIERS};
+
+    pub const TIER_COUNT: usize = 0;
+    pub const TIERS: usize = TIER_COUNT + 1;
+    pub const LEN: usize = TIERS + reward_tier::LEN * MAX_REWARD_TIERS;
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct InitializeData {
+    pub amount_to_raise: u64,
+    pub time_ending: i64,
+    pub campaign_id: u64,
+    pub bump: u8,
+    pub milestone_count: u8,
+    pub milestones: [Milestone; MAX_MILESTONES],
+    pub voting_period: i64,
+    pub quorum_bps: u16,
+    pub approval_bps: u16,
+    pub funding_mode: FundingMode,
+    pub contribution_limits: ContributionLimits,
+    pub max_amendments: u8,
+}
+
+impl InitializeData {
+    // Discriminator followed by the payload
+    pub fn pack(&self) -> [u8; 1 + initialize::LEN] {
+        use initialize::*;
+
+        let mut data = [0u8; 1 + LEN];
+        data[0] = FundraiserInstruction::Initialize as u8;
+
+        let payload = &mut data[1..];
+        payload[AMOUNT_TO_RAISE..TIME_ENDING].copy_from_slice(&self.amount_to_raise.to_le_bytes());
+        payload[TIME_ENDING..CAMPAIGN_ID].copy_from_slice(&self.time_ending.to_le_bytes());
+        payload[CAMPAIGN_ID..BUMP].copy_from_slice(&self.campaign_id.to_le_bytes());
+        payload[BUMP] = self.bump;
+        payload[MILESTONE_COUNT] = self.milestone_count;
+        for (index, milestone) in self.milestones.iter().enumerate() {
+            milestone.pack_into(&mut payload[MILESTONES + index * milestone::LEN..]);
+        }
+        payload[VOTING_PERIOD..QUORUM_BPS].copy_from_slice(&self.voting_period.to_le_bytes());
+        payload[QUORUM_BPS..APPROVAL_BPS].copy_from_slice(&self.quorum_bps.to_le_bytes());
+        payload[APPROVAL_BPS..FUNDING_MODE].copy_from_slice(&self.approval_bps.to_le_bytes());
+        payload[FUNDING_MODE] = self.funding_mode as u8;
+        self.contribution_limits.pack_into(&mut payload[CONTRIBUTION_LIMITS..MAX_AMENDMENTS]);
+        payload[MAX_AMENDMENTS] = self.max_amendments;
+
+        data
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct InitializeSolData {
+    pub amount_to_raise: u64,
+    pub time_ending: i64,
+    pub campaign_id: u64,
+    pub bump: u8,
+    pub funding_mode: FundingMode,
+    pub contribution_limits: ContributionLimits,
+    pub max_amendments: u8,
+}
+
+impl InitializeSolData {
+    // Discriminator followed by the payload
+    pub fn pack(&self) -> [u8; 1 + initialize_sol::LEN] {
+        use initialize_sol::*;
+
+        let mut data = [0u8; 1 + LEN];
+        data[0] = FundraiserInstruction::InitializeSol as u8;
+
+        let payload = &mut data[1..];
+        payload[AMOUNT_TO_RAISE..TIME_ENDING].copy_from_slice(&self.amount_to_raise.to_le_bytes());
+        payload[TIME_ENDING..CAMPAIGN_ID].copy_from_slice(&self.time_ending.to_le_bytes());
+        payload[CAMPAIGN_ID..BUMP].copy_from_slice(&self.campaign_id.to_le_bytes());
+        payload[BUMP] = self.bump;
+        payload[FUNDING_MODE] = self.funding_mode as u8;
+        self.contribution_limits.pack_into(&mut payload[CONTRIBUTION_LIMITS..MAX_AMENDMENTS]);
+        payload[MAX_AMENDMENTS] = self.max_amendments;
+
+        data
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct SetRewardTiersData {
+    pub tier_count: u8,
+    pub tiers: [RewardTier; MAX_REWARD_TIERS],
+}
+
+impl SetRewardTiersData {
+    // Discriminator followed by the payload
+    pub fn pack(&self) -> [u8; 1 + set_reward_tiers::LEN] {
+        use set_reward_tiers::*;
+
+        let mut data = [0u8; 1 + LEN];
+        data[0] = FundraiserInstruction::SetRewardTiers as u8;
+
+        let payload = &mut data[1..];
+        payload[TIER_COUNT] = self.tier_count;
+        for (index, tier) in self.tiers.iter().enumerate() {
+            tier.pack_into(&mut payload[TIERS + index * reward_tier::LEN..]);
+        }
+
+        data
+    }
+}
+
+// Contribute, ContributeSol, ContributeMulti, ContributeReceipts, SetPrice and AmendGoal all carry a single u64
+pub fn pack_u64(instruction: FundraiserInstruction, value: u64) -> [u8; 9] {
+    let mut data = [0u8; 9];
+    data[0] = instruction as u8;
+    data[1..].copy_from_slice(&value.to_le_bytes());
+    data
+}
+
+// Reads the reward tier of a Contribute payload, None if the trailing bytes are not a borsh Option<u8>.
+// Without any trailing bytes the contribution does not pick a tier.
+pub fn unpack_reward_tier(payload: &[u8]) -> Option<Option<u8>> {
+    match payload.get(contribute::REWARD_TIER..) {
+        None | Some([]) | Some([0]) => Some(None),
+        Some([1, index]) => Some(Some(*index)),
+        _ => None,
+    }
+}
+
+pub fn pack_initialize_config(fee_bps: u16, fee_recipient: &[u8; 32]) -> [u8; 1 + initialize_config::LEN] {
+    let mut data = [0u8; 1 + initialize_config::LEN];
+    data[0] = FundraiserInstruction::InitializeConfig as u8;
+    data[1 + initialize_config::FEE_BPS..1 + initialize_config::FEE_RECIPIENT].copy_from_slice(&fee_bps.to_le_bytes());
+    data[1 + initialize_config::FEE_RECIPIENT..].copy_from_slice(fee_recipient);
+    data
+}
+
+pub fn pack_extend_deadline(time_ending: i64) -> [u8; 1 + extend_deadline::LEN] {
+    let mut data = [0u8; 1 + extend_deadline::LEN];
+    data[0] = FundraiserInstruction::ExtendDeadline as u8;
+    data[1..].copy_from_slice(&time_ending.to_le_bytes());
+    data
+}
+
+pub fn pack_vote(approve: bool) -> [u8; 1 + vote::LEN] {
+    [FundraiserInstruction::Vote as u8, approve as u8]
+}
\ No newline at end of file
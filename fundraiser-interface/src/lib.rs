@@ -0,0 +1,9 @@
+#![no_std]
+
+// Account layouts, instruction data, events and error codes shared by every implementation of the
+// fundraiser program, so that a client built against this crate can talk to any of them.
+
+pub mod error;
+pub mod event;
+pub mod instruction;
+pub mod state;
\ No newline at end of file
@@ -0,0 +1,381 @@
+// Byte layouts of the program accounts, every field is little-endian and unaligned.
+// Anchor accounts start with an 8 byte discriminator, their layout follows it.
+// Optimised accounts start with a one byte discriminator and a one byte layout version.
+
+pub const ANCHOR_DISCRIMINATOR_LEN: usize = 8;
+pub const OPTIMISED_HEADER_LEN: usize = 2;
+pub const OPTIMISED_LAYOUT_VERSION: u8 = 1;
+
+pub const MAX_MILESTONES: usize = 4;
+pub const MAX_ACCEPTED_MINTS: usize = 3;
+pub const MAX_REWARD_TIERS: usize = 4;
+pub const BPS_SCALER: u64 = 10_000;
+// The platform fee can take at most 10% of a payout
+pub const MAX_FEE_BPS: u16 = 1_000;
+pub const PERCENTAGE_SCALER: u64 = 100;
+// How long contributors can withdraw after the maker extends the deadline or lowers the goal
+pub const AMENDMENT_GRACE_PERIOD: i64 = 3 * 24 * 60 * 60;
+
+pub const FUNDRAISER_SEED: &[u8] = b"fundraiser";
+pub const CONTRIBUTOR_SEED: &[u8] = b"contributor";
+pub const PRICE_FEED_SEED: &[u8] = b"price_feed";
+pub const REWARD_TIERS_SEED: &[u8] = b"reward_tiers";
+pub const CONFIG_SEED: &[u8] = b"config";
+
+// The part of a payout that goes to the fee recipient, the maker gets the rest
+pub fn fee_amount(amount: u64, fee_bps: u16) -> u64 {
+    (amount as u128 * fee_bps as u128 / BPS_SCALER as u128) as u64
+}
+
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum FundraiserStatus {
+    Active = 0,
+    Cancelled = 1,
+    Rejected = 2,
+}
+
+impl TryFrom<u8> for FundraiserStatus {
+    type Error = u8;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(FundraiserStatus::Active),
+            1 => Ok(FundraiserStatus::Cancelled),
+            2 => Ok(FundraiserStatus::Rejected),
+            _ => Err(value),
+        }
+    }
+}
+
+// The first byte of an optimised account, 0 is left for an account the program has not written yet
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum AccountDiscriminator {
+    Fundraiser = 1,
+    Contributor = 2,
+    PriceFeed = 3,
+    RewardTiers = 4,
+    Config = 5,
+}
+
+impl TryFrom<u8> for AccountDiscriminator {
+    type Error = u8;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(AccountDiscriminator::Fundraiser),
+            2 => Ok(AccountDiscriminator::Contributor),
+            3 => Ok(AccountDiscriminator::PriceFeed),
+            4 => Ok(AccountDiscriminator::RewardTiers),
+            5 => Ok(AccountDiscriminator::Config),
+            _ => Err(value),
+        }
+    }
+}
+
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum FundingMode {
+    Fixed = 0,
+    Flexible = 1,
+}
+
+impl TryFrom<u8> for FundingMode {
+    type Error = u8;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(FundingMode::Fixed),
+            1 => Ok(FundingMode::Flexible),
+            _ => Err(value),
+        }
+    }
+}
+
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum MaxContributionKind {
+    None = 0,
+    Absolute = 1,
+    Percentage = 2,
+}
+
+impl TryFrom<u8> for MaxContributionKind {
+    type Error = u8;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(MaxContributionKind::None),
+            1 => Ok(MaxContributionKind::Absolute),
+            2 => Ok(MaxContributionKind::Percentage),
+            _ => Err(value),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct Milestone {
+    pub percentage: u8,
+    pub unlock_time: i64,
+}
+
+impl Milestone {
+    pub fn pack_into(&self, dst: &mut [u8]) {
+        dst[milestone::PERCENTAGE] = self.percentage;
+        dst[milestone::UNLOCK_TIME..milestone::LEN].copy_from_slice(&self.unlock_time.to_le_bytes());
+    }
+
+    pub fn unpack_from(src: &[u8]) -> Self {
+        Self {
+            percentage: src[milestone::PERCENTAGE],
+            unlock_time: i64::from_le_bytes(src[milestone::UNLOCK_TIME..milestone::LEN].try_into().unwrap()),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct RewardTier {
+    // Smallest pledge, counting everything the contributor has put in, that qualifies for the tier
+    pub min_pledge: u64,
+    pub max_supply: u64,
+}
+
+impl RewardTier {
+    pub fn pack_into(&self, dst: &mut [u8]) {
+        dst[reward_tier::MIN_PLEDGE..reward_tier::MAX_SUPPLY].copy_from_slice(&self.min_pledge.to_le_bytes());
+        dst[reward_tier::MAX_SUPPLY..reward_tier::LEN].copy_from_slice(&self.max_supply.to_le_bytes());
+    }
+
+    pub fn unpack_from(src: &[u8]) -> Self {
+        Self {
+            min_pledge: u64::from_le_bytes(src[reward_tier::MIN_PLEDGE..reward_tier::MAX_SUPPLY].try_into().unwrap()),
+            max_supply: u64::from_le_bytes(src[reward_tier::MAX_SUPPLY..reward_tier::LEN].try_into().unwrap()),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ContributionLimits {
+    pub min_contribution: u64,
+    pub max_contribution: u64,
+    pub max_contribution_kind: MaxContributionKind,
+    pub hard_cap: u64,
+}
+
+impl Default for ContributionLimits {
+    fn default() -> Self {
+        Self {
+            min_contribution: 0,
+            max_contribution: 0,
+            max_contribution_kind: MaxContributionKind::None,
+            hard_cap: 0,
+        }
+    }
+}
+
+impl ContributionLimits {
+    pub fn pack_into(&self, dst: &mut [u8]) {
+        use contribution_limits::*;
+
+        dst[MIN_CONTRIBUTION..MAX_CONTRIBUTION].copy_from_slice(&self.min_contribution.to_le_bytes());
+        dst[MAX_CONTRIBUTION..MAX_CONTRIBUTION_KIND].copy_from_slice(&self.max_contribution.to_le_bytes());
+        dst[MAX_CONTRIBUTION_KIND] = self.max_contribution_kind as u8;
+        dst[HARD_CAP..LEN].copy_from_slice(&self.hard_cap.to_le_bytes());
+    }
+
+    // Fails with the byte found if the max contribution kind is unknown
+    pub fn unpack_from(src: &[u8]) -> Result<Self, u8> {
+        use contribution_limits::*;
+
+        Ok(Self {
+            min_contribution: u64::from_le_bytes(src[MIN_CONTRIBUTION..MAX_CONTRIBUTION].try_into().unwrap()),
+            max_contribution: u64::from_le_bytes(src[MAX_CONTRIBUTION..MAX_CONTRIBUTION_KIND].try_into().unwrap()),
+            max_contribution_kind: MaxContributionKind::try_from(src[MAX_CONTRIBUTION_KIND])?,
+            hard_cap: u64::from_le_bytes(src[HARD_CAP..LEN].try_into().unwrap()),
+        })
+    }
+}
+
+pub mod milestone {
+    pub const PERCENTAGE: usize = 0;
+    pub const UNLOCK_TIME: usize = PERCENTAGE + 1;
+    pub const LEN: usize = UNLOCK_TIME + 8;
+}
+
+pub mod reward_tier {
+    pub const MIN_PLEDGE: usize = 0;
+    pub const MAX_SUPPLY: usize = MIN_PLEDGE + 8;
+    pub const LEN: usize = MAX_SUPPLY + 8;
+}
+
+pub mod contribution_limits {
+    pub const MIN_CONTRIBUTION: usize = 0;
+    pub const MAX_CONTRIBUTION: usize = MIN_CONTRIBUTION + 8;
+    pub const MAX_CONTRIBUTION_KIND: usize = MAX_CONTRIBUTION + 8;
+    pub const HARD_CAP: usize = MAX_CONTRIBUTION_KIND + 1;
+    pub const LEN: usize = HARD_CAP + 8;
+}
+
+// PDA at [FUNDRAISER_SEED, maker, campaign_id (u64 LE)]
+pub mod fundraiser {
+    use super::{contribution_limits, milestone, MAX_ACCEPTED_MINTS, MAX_MILESTONES};
+
+    pub const MAKER: usize = 0;
+    pub const MINT_TO_RAISE: usize = MAKER + 32;
+    pub const AMOUNT_TO_RAISE: usize = MINT_TO_RAISE + 32;
+    pub const CURRENT_AMOUNT: usize = AMOUNT_TO_RAISE + 8;
+    pub const TIME_ENDING: usize = CURRENT_AMOUNT + 8;
+    pub const BUMP: usize = TIME_ENDING + 8;
+    pub const CAMPAIGN_ID: usize = BUMP + 1;
+    pub const STATUS: usize = CAMPAIGN_ID + 8;
+    pub const MILESTONE_COUNT: usize = STATUS + 1;
+    pub const MILESTONES: usize = MILESTONE_COUNT + 1;
+    pub const MILESTONES_RELEASED: usize = MILESTONES + milestone::LEN * MAX_MILESTONES;
+    pub const AMOUNT_RELEASED: usize = MILESTONES_RELEASED + 1;
+    pub const VOTING_PERIOD: usize = AMOUNT_RELEASED + 8;
+    pub const QUORUM_BPS: usize = VOTING_PERIOD + 8;
+    pub const APPROVAL_BPS: usize = QUORUM_BPS + 2;
+    pub const VOTES_FOR: usize = APPROVAL_BPS + 2;
+    pub const VOTES_AGAINST: usize = VOTES_FOR + 8;
+    pub const FUNDING_MODE: usize = VOTES_AGAINST + 8;
+    pub const PRICE_AUTHORITY: usize = FUNDING_MODE + 1;
+    pub const ACCEPTED_MINT_COUNT: usize = PRICE_AUTHORITY + 32;
+    pub const ACCEPTED_MINTS: usize = ACCEPTED_MINT_COUNT + 1;
+    pub const AMOUNTS_RAISED: usize = ACCEPTED_MINTS + 32 * MAX_ACCEPTED_MINTS;
+    pub const CONTRIBUTION_LIMITS: usize = AMOUNTS_RAISED + 8 * MAX_ACCEPTED_MINTS;
+    pub const RECEIPT_MINT: usize = CONTRIBUTION_LIMITS + contribution_limits::LEN;
+    // The platform fee in force when the fundraiser was created
+    pub const FEE_BPS: usize = RECEIPT_MINT + 32;
+    // How many times the maker can extend the deadline or lower the goal, and how many times they did
+    pub const MAX_AMENDMENTS: usize = FEE_BPS + 2;
+    pub const AMENDMENT_COUNT: usize = MAX_AMENDMENTS + 1;
+    // Until then contributors can withdraw even from a fundraiser that has not failed, 0 before any amendment
+    pub const GRACE_ENDING: usize = AMENDMENT_COUNT + 1;
+    pub const LEN: usize = GRACE_ENDING + 8;
+}
+
+// PDA at [CONTRIBUTOR_SEED, fundraiser, contributor]
+pub mod contributor {
+    use super::MAX_ACCEPTED_MINTS;
+
+    pub const AMOUNT: usize = 0;
+    pub const VOTED_MILESTONE: usize = AMOUNT + 8;
+    pub const AMOUNTS: usize = VOTED_MILESTONE + 1;
+    // 0 without a reward, otherwise the index of the tier plus one
+    pub const REWARD_TIER: usize = AMOUNTS + 8 * MAX_ACCEPTED_MINTS;
+    pub const BUMP: usize = REWARD_TIER + 1;
+    pub const LEN: usize = BUMP + 1;
+}
+
+// PDA at [PRICE_FEED_SEED, authority, mint]
+pub mod price_feed {
+    pub const AUTHORITY: usize = 0;
+    pub const MINT: usize = AUTHORITY + 32;
+    pub const DECIMALS: usize = MINT + 32;
+    pub const PRICE: usize = DECIMALS + 1;
+    pub const BUMP: usize = PRICE + 8;
+    pub const LEN: usize = BUMP + 1;
+}
+
+// PDA at [REWARD_TIERS_SEED, fundraiser]
+pub mod reward_tiers {
+    use super::{reward_tier, MAX_REWARD_TIERS};
+
+    pub const FUNDRAISER: usize = 0;
+    pub const TIER_COUNT: usize = FUNDRAISER + 32;
+    pub const TIERS: usize = TIER_COUNT + 1;
+    pub const CLAIMED: usize = TIERS + reward_tier::LEN * MAX_REWARD_TIERS;
+    pub const BUMP: usize = CLAIMED + 8 * MAX_REWARD_TIERS;
+    pub const LEN: usize = BUMP + 1;
+}
+
+// PDA at [CONFIG_SEED], one for the whole program
+pub mod config {
+    pub const ADMIN: usize = 0;
+    pub const FEE_BPS: usize = ADMIN + 32;
+    pub const FEE_RECIPIENT: usize = FEE_BPS + 2;
+    pub const BUMP: usize = FEE_RECIPIENT + 32;
+    pub const LEN: usize = BUMP + 1;
+}
+
+// Start of the upgradeable loader's ProgramData account, the upgrade authority gates InitializeConfig
+pub mod program_data {
+    // A u32 enum tag, 3 for ProgramData, then the slot of the last deployment
+    pub const TAG: usize = 0;
+    pub const SLOT: usize = TAG + 4;
+    // An Option<Pubkey>, 1 when the program can still be upgraded
+    pub const HAS_UPGRADE_AUTHORITY: usize = SLOT + 8;
+    pub const UPGRADE_AUTHORITY: usize = HAS_UPGRADE_AUTHORITY + 1;
+    pub const LEN: usize = UPGRADE_AUTHORITY + 32;
+
+    pub const PROGRAM_DATA_TAG: u32 = 3;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn layout_lengths() {
+        assert_eq!(fundraiser::LEN, 395);
+        assert_eq!(contributor::LEN, 35);
+        assert_eq!(price_feed::LEN, 74);
+        assert_eq!(reward_tiers::LEN, 130);
+        assert_eq!(config::LEN, 67);
+        assert_eq!(program_data::LEN, 45);
+        assert_eq!(crate::instruction::initialize::LEN, 101);
+        assert_eq!(crate::instruction::initialize_sol::LEN, 52);
+    }
+
+    #[test]
+    fn pack_round_trip() {
+        let milestone = Milestone { percentage: 40, unlock_time: -7 };
+        let mut data = [0u8; milestone::LEN];
+        milestone.pack_into(&mut data);
+        assert_eq!(Milestone::unpack_from(&data), milestone);
+
+        let tier = RewardTier { min_pledge: 500, max_supply: 12 };
+        let mut data = [0u8; reward_tier::LEN];
+        tier.pack_into(&mut data);
+        assert_eq!(RewardTier::unpack_from(&data), tier);
+
+        let limits = ContributionLimits {
+            min_contribution: 1,
+            max_contribution: 2_500,
+            max_contribution_kind: MaxContributionKind::Percentage,
+            hard_cap: u64::MAX,
+        };
+        let mut data = [0u8; contribution_limits::LEN];
+        limits.pack_into(&mut data);
+        assert_eq!(ContributionLimits::unpack_from(&data), Ok(limits));
+
+        data[contribution_limits::MAX_CONTRIBUTION_KIND] = 9;
+        assert_eq!(ContributionLimits::unpack_from(&data), Err(9));
+    }
+
+    #[test]
+    fn account_discriminator() {
+        assert_eq!(OPTIMISED_HEADER_LEN, 2);
+        assert_eq!(AccountDiscriminator::try_from(AccountDiscriminator::Config as u8), Ok(AccountDiscriminator::Config));
+        assert_eq!(AccountDiscriminator::try_from(0), Err(0));
+    }
+
+    #[test]
+    fn platform_fee() {
+        assert_eq!(fee_amount(1_000_000, 250), 25_000);
+        assert_eq!(fee_amount(999, 1), 0);
+        assert_eq!(fee_amount(u64::MAX, MAX_FEE_BPS), u64::MAX / 10);
+    }
+
+    #[test]
+    fn contribute_reward_tier() {
+        use crate::instruction::unpack_reward_tier;
+
+        let amount = 5u64.to_le_bytes();
+        assert_eq!(unpack_reward_tier(&amount), Some(None));
+        assert_eq!(unpack_reward_tier(&[&amount[..], &[0]].concat()), Some(None));
+        assert_eq!(unpack_reward_tier(&[&amount[..], &[1, 2]].concat()), Some(Some(2)));
+        assert_eq!(unpack_reward_tier(&[&amount[..], &[1]].concat()), None);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,87 @@
+#![no_main]
+
+use arbitrary::Arbitrary;
+use libfuzzer_sys::fuzz_target;
+
+// Accounts and instruction data laid out the way the runtime passes them to the entrypoint
+#[derive(Arbitrary, Debug)]
+struct Input {
+    accounts: Vec<Account>,
+    instruction_data: Vec<u8>,
+}
+
+#[derive(Arbitrary, Debug)]
+struct Account {
+    // Keys come from a small pool so the same account shows up in several slots
+    key: u8,
+    owner: Owner,
+    is_signer: bool,
+    is_writable: bool,
+    lamports: u64,
+    data: Vec<u8>,
+}
+
+#[derive(Arbitrary, Debug)]
+enum Owner {
+    Program,
+    Token,
+    System,
+    Other(u8),
+}
+
+const MAX_ACCOUNTS: usize = 16;
+const NON_DUP_MARKER: u8 = u8::MAX;
+const MAX_PERMITTED_DATA_INCREASE: usize = 10 * 1024;
+const BPF_ALIGN_OF_U128: usize = 8;
+const TOKEN_PROGRAM_ID: [u8; 32] = five8_const::decode_32_const("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+
+impl Input {
+    fn serialize(&self, program_id: &[u8; 32]) -> Vec<u64> {
+        let accounts = &self.accounts[..self.accounts.len().min(MAX_ACCOUNTS)];
+
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&(accounts.len() as u64).to_le_bytes());
+
+        for account in accounts {
+            let owner = match account.owner {
+                Owner::Program => *program_id,
+                Owner::Token => TOKEN_PROGRAM_ID,
+                Owner::System => [0; 32],
+                Owner::Other(byte) => [byte; 32],
+            };
+
+            bytes.push(NON_DUP_MARKER);
+            bytes.push(account.is_signer as u8);
+            bytes.push(account.is_writable as u8);
+            bytes.push(0); // executable
+            bytes.extend_from_slice(&(account.data.len() as u32).to_le_bytes()); // original_data_len
+            bytes.extend_from_slice(&[account.key; 32]);
+            bytes.extend_from_slice(&owner);
+            bytes.extend_from_slice(&account.lamports.to_le_bytes());
+            bytes.extend_from_slice(&(account.data.len() as u64).to_le_bytes());
+            bytes.extend_from_slice(&account.data);
+            bytes.resize(bytes.len() + MAX_PERMITTED_DATA_INCREASE, 0);
+            bytes.resize(bytes.len().next_multiple_of(BPF_ALIGN_OF_U128), 0);
+            bytes.extend_from_slice(&u64::MAX.to_le_bytes()); // rent_epoch
+        }
+
+        bytes.extend_from_slice(&(self.instruction_data.len() as u64).to_le_bytes());
+        bytes.extend_from_slice(&self.instruction_data);
+        bytes.extend_from_slice(program_id);
+
+        // The entrypoint reads the input as aligned u64s
+        let mut input = vec![0u64; bytes.len().div_ceil(8)];
+        unsafe {
+            core::ptr::copy_nonoverlapping(bytes.as_ptr(), input.as_mut_ptr() as *mut u8, bytes.len());
+        }
+        input
+    }
+}
+
+// Every failed check returns an error, so any panic is a bug
+fuzz_target!(|input: Input| {
+    let mut input = input.serialize(&fundraiser::ID.to_bytes());
+    unsafe {
+        fundraiser::entrypoint(input.as_mut_ptr() as *mut u8);
+    }
+});
@@ -0,0 +1,131 @@
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction},
+    program::{invoke, invoke_signed},
+    pubkey,
+    pubkey::Pubkey,
+    rent::Rent,
+    system_instruction,
+    sysvar::Sysvar,
+};
+
+use crate::{
+    state::{Contributor, MAX_ACCEPTED_MINTS},
+    validation::check_pda,
+};
+
+pub const ASSOCIATED_TOKEN_PROGRAM_ID: Pubkey = pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
+
+// Creates a rent-exempt account owned by the program at the PDA of `seeds`, like Anchor's `init`.
+// Anyone can send lamports to the address first, such an account is topped up, allocated and assigned instead.
+pub fn create_pda_account<'a>(
+    payer: &AccountInfo<'a>,
+    account: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    space: usize,
+    seeds: &[&[u8]],
+) -> ProgramResult {
+    let lamports = Rent::get()?.minimum_balance(space);
+
+    if account.lamports() == 0 {
+        return invoke_signed(
+            &system_instruction::create_account(payer.key, account.key, lamports, space as u64, &crate::ID),
+            &[payer.clone(), account.clone(), system_program.clone()],
+            &[seeds],
+        );
+    }
+
+    let top_up = lamports.saturating_sub(account.lamports());
+    if top_up > 0 {
+        invoke(
+            &system_instruction::transfer(payer.key, account.key, top_up),
+            &[payer.clone(), account.clone(), system_program.clone()],
+        )?;
+    }
+    invoke_signed(
+        &system_instruction::allocate(account.key, space as u64),
+        &[account.clone(), system_program.clone()],
+        &[seeds],
+    )?;
+    invoke_signed(
+        &system_instruction::assign(account.key, &crate::ID),
+        &[account.clone(), system_program.clone()],
+        &[seeds],
+    )
+}
+
+// The first contribution creates the contributor account, the contributor pays for it.
+// Either way the account has to be the signer's contributor account for this fundraiser.
+pub fn create_contributor<'a>(
+    signer: &AccountInfo<'a>,
+    contributor: &AccountInfo<'a>,
+    fundraiser: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+) -> ProgramResult {
+    let bump = check_pda(contributor, &[b"contributor", fundraiser.key.as_ref(), signer.key.as_ref()])?;
+    if contributor.owner == &crate::ID {
+        return Ok(());
+    }
+
+    create_pda_account(
+        signer,
+        contributor,
+        system_program,
+        Contributor::LEN,
+        &[b"contributor", fundraiser.key.as_ref(), signer.key.as_ref(), &[bump]],
+    )?;
+
+    // Keep the canonical bump with the account, like the other PDAs of the program
+    Contributor {
+        amount: 0,
+        voted_milestone: 0,
+        amounts: [0; MAX_ACCEPTED_MINTS],
+        reward_tier: 0,
+        bump,
+    }
+    .serialize(&mut &mut contributor.data.borrow_mut()[..])?;
+
+    Ok(())
+}
+
+// Creates the associated token account of `owner` for the mint, the associated token program checks the address.
+// Idempotent, so an account someone created ahead of the maker does not block the fundraiser,
+// and a contributor's existing account is reused.
+pub fn create_associated_token_account<'a>(
+    payer: &AccountInfo<'a>,
+    account: &AccountInfo<'a>,
+    owner: &AccountInfo<'a>,
+    mint: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    token_program: &AccountInfo<'a>,
+    associated_token_program: &AccountInfo<'a>,
+) -> ProgramResult {
+    let instruction = Instruction {
+        program_id: ASSOCIATED_TOKEN_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*payer.key, true),
+            AccountMeta::new(*account.key, false),
+            AccountMeta::new_readonly(*owner.key, false),
+            AccountMeta::new_readonly(*mint.key, false),
+            AccountMeta::new_readonly(*system_program.key, false),
+            AccountMeta::new_readonly(*token_program.key, false),
+        ],
+        // CreateIdempotent
+        data: vec![1],
+    };
+
+    invoke(
+        &instruction,
+        &[
+            payer.clone(),
+            account.clone(),
+            owner.clone(),
+            mint.clone(),
+            system_program.clone(),
+            token_program.clone(),
+            associated_token_program.clone(),
+        ],
+    )
+}
\ No newline at end of file
@@ -0,0 +1,9 @@
+use solana_program::log::sol_log_data;
+
+pub use fundraiser_interface::event::FundraiserEvent;
+
+// Logged as "Program data: <base64>", the same line Anchor's emit! writes for the event
+pub fn emit(event: FundraiserEvent) {
+    let (data, len) = event.pack();
+    sol_log_data(&[&data[..len]]);
+}
\ No newline at end of file
@@ -0,0 +1,81 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+};
+
+use crate::{
+    instructions::check_transfer_hook,
+    state::{FundraiserStatus, PriceFeed, MAX_ACCEPTED_MINTS},
+    validation::{check_maker, check_owner, check_signer, load_fundraiser},
+    error::FundraiserError,
+    ID,
+};
+
+pub fn process_add_accepted_mint(
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let [
+        maker_info,
+        mint_info,
+        price_feed_info,
+        fundraiser_info
+    ] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // **1. Verify the maker is a signer**
+    check_signer(maker_info)?;
+
+    // **2. Verify ownership of the mint account**
+    if mint_info.owner != &spl_token::ID && mint_info.owner != &spl_token_2022::ID {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    check_transfer_hook(mint_info)?;
+
+    // **3. Load the fundraiser, it has to sit at its PDA**
+    let mut fundraiser = load_fundraiser(fundraiser_info)?;
+
+    // **4. Only the maker of the fundraiser can add mints**
+    check_maker(maker_info, &fundraiser)?;
+
+    // **5. Mints can only be added to a token fundraiser paying out in one go, before anyone has contributed**
+    if fundraiser.status != FundraiserStatus::Active
+        || fundraiser.is_sol()
+        || fundraiser.issues_receipts()
+        || fundraiser.current_amount != 0
+        || fundraiser.milestone_count != 0
+        || fundraiser.voting_period != 0
+    {
+        return Err(ProgramError::Custom(FundraiserError::CannotAddMint as u32));
+    }
+
+    // **6. The first extra mint turns the fundraiser into a multi-mint one, the original mint stays accepted**
+    if !fundraiser.is_multi_mint() {
+        check_owner(price_feed_info, &ID)?;
+        let price_feed = PriceFeed::try_from_slice(&price_feed_info.data.borrow())?;
+        fundraiser.accepted_mints[0] = fundraiser.mint_to_raise;
+        fundraiser.accepted_mint_count = 1;
+        fundraiser.price_authority = price_feed.authority;
+    }
+
+    // **7. Every accepted mint is valued by the same price authority**
+    PriceFeed::load(price_feed_info, &fundraiser.price_authority, mint_info.key)?;
+
+    if fundraiser.accepted_mint_count as usize >= MAX_ACCEPTED_MINTS
+        || fundraiser.accepted_mint_index(mint_info.key).is_some()
+    {
+        return Err(ProgramError::Custom(FundraiserError::CannotAddMint as u32));
+    }
+
+    // **8. Accept the mint**
+    let index = fundraiser.accepted_mint_count as usize;
+    fundraiser.accepted_mints[index] = *mint_info.key;
+    fundraiser.accepted_mint_count += 1;
+
+    fundraiser.serialize(&mut &mut fundraiser_info.data.borrow_mut()[..])?;
+
+    Ok(())
+}
\ No newline at end of file
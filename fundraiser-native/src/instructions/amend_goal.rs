@@ -0,0 +1,60 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    sysvar::Sysvar,
+};
+
+use crate::{
+    events::{emit, FundraiserEvent},
+    validation::{check_maker, check_signer, load_fundraiser},
+    error::FundraiserError,
+};
+
+pub fn process_amend_goal(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let maker_info = next_account_info(account_info_iter)?;
+    let fundraiser_info = next_account_info(account_info_iter)?;
+
+    let amount_to_raise = u64::try_from_slice(instruction_data)?;
+
+    // **1. Verify the maker is a signer**
+    check_signer(maker_info)?;
+
+    // **2. Load the fundraiser, it has to sit at its PDA**
+    let mut fundraiser = load_fundraiser(fundraiser_info)?;
+
+    // **3. Only the maker of the fundraiser can amend it**
+    check_maker(maker_info, &fundraiser)?;
+
+    // **4. The goal can only come down, and never to nothing**
+    if amount_to_raise == 0 {
+        return Err(ProgramError::Custom(FundraiserError::InvalidAmount as u32));
+    }
+    if amount_to_raise >= fundraiser.amount_to_raise {
+        return Err(ProgramError::Custom(FundraiserError::InvalidAmendment as u32));
+    }
+
+    // **5. The contribution limits have to hold for the new goal too**
+    fundraiser.contribution_limits.validate(amount_to_raise)?;
+
+    // **6. Count the amendment, contributors can withdraw until the grace period is over**
+    fundraiser.amend(Clock::get()?.unix_timestamp)?;
+    fundraiser.amount_to_raise = amount_to_raise;
+    fundraiser.serialize(&mut &mut fundraiser_info.data.borrow_mut()[..])?;
+
+    emit(FundraiserEvent::Amended {
+        fundraiser: fundraiser_info.key.to_bytes(),
+        maker: maker_info.key.to_bytes(),
+        amount_to_raise,
+        time_ending: fundraiser.time_ending,
+    });
+
+    Ok(())
+}
\ No newline at end of file
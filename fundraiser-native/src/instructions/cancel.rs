@@ -0,0 +1,52 @@
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+};
+
+use crate::{
+    events::{emit, FundraiserEvent},
+    state::FundraiserStatus,
+    validation::{check_maker, check_signer, load_fundraiser},
+    error::FundraiserError,
+};
+
+pub fn process_cancel(
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let maker_info = next_account_info(account_info_iter)?;
+    let fundraiser_info = next_account_info(account_info_iter)?;
+
+    // **1. Verify the maker is a signer**
+    check_signer(maker_info)?;
+
+    // **2. Load the fundraiser, it has to sit at its PDA**
+    let mut fundraiser = load_fundraiser(fundraiser_info)?;
+
+    // **3. Only the maker of the fundraiser can cancel it**
+    check_maker(maker_info, &fundraiser)?;
+
+    // **4. Check the fundraiser is still active**
+    if fundraiser.status != FundraiserStatus::Active {
+        return Err(ProgramError::Custom(FundraiserError::FundraiserCancelled as u32));
+    }
+
+    // **5. Once a tranche has left the vault, refunds could no longer be honoured in full**
+    if fundraiser.milestones_released > 0 {
+        return Err(ProgramError::Custom(FundraiserError::MilestonesConfigured as u32));
+    }
+
+    // **6. Flip the fundraiser into the cancelled state, contributors can now refund right away**
+    fundraiser.status = FundraiserStatus::Cancelled;
+    fundraiser.serialize(&mut &mut fundraiser_info.data.borrow_mut()[..])?;
+
+    emit(FundraiserEvent::Cancelled {
+        fundraiser: fundraiser_info.key.to_bytes(),
+        maker: maker_info.key.to_bytes(),
+    });
+
+    Ok(())
+}
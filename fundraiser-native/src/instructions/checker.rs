@@ -1,19 +1,28 @@
-use borsh::BorshDeserialize;
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
     entrypoint::ProgramResult,
     program::invoke_signed,
     program_error::ProgramError,
-    pubkey::Pubkey,
-    program_pack::Pack,
     sysvar::{clock::Clock, Sysvar},
 };
-use spl_token::instruction as token_instruction;
-use spl_token::state::Account as TokenAccount;
-use crate::{state::Fundraiser, error::FundraiserError};
+use spl_token_2022::{
+    extension::StateWithExtensions,
+    instruction as token_instruction,
+    state::Mint,
+};
+use fundraiser_interface::state::fee_amount;
+
+use crate::{
+    events::{emit, FundraiserEvent},
+    state::{FundraiserStatus, FundingMode},
+    validation::{
+        check_fee_recipient_ta, check_maker, check_mint, check_signer, check_token_program, load_fee_recipient,
+        load_fundraiser, load_token_account,
+    },
+    error::FundraiserError,
+};
 
 pub fn check_contributions(
-    program_id: &Pubkey,
     accounts: &[AccountInfo],
     _instruction_data: &[u8],
 ) -> ProgramResult {
@@ -28,82 +37,133 @@ pub fn check_contributions(
     let token_program_info = next_account_info(account_info_iter)?;
 
     // **1. Verify the maker is a signer**
-    if !maker_info.is_signer {
-        return Err(ProgramError::MissingRequiredSignature);
-    }
+    check_signer(maker_info)?;
+    check_token_program(token_program_info)?;
 
-    // **2. Verify ownership of the fundraiser account**
-    if fundraiser_info.owner != program_id {
-        return Err(ProgramError::IncorrectProgramId);
-    }
+    // **2. Load the fundraiser, it has to sit at its PDA**
+    let fundraiser = load_fundraiser(fundraiser_info)?;
 
-    // **3. Deserialize the fundraiser account**
-    let fundraiser: Fundraiser = Fundraiser::try_from_slice(&fundraiser_info.data.borrow())?;
+    // **3. Only the maker of the fundraiser can withdraw**
+    check_maker(maker_info, &fundraiser)?;
 
-    // **4. Verify the fundraiser PDA**
-    let (fundraiser_pda, bump_seed) = Pubkey::find_program_address(
-        &[b"fundraiser", maker_info.key.as_ref()],
-        program_id,
-    );
-    if fundraiser_pda != *fundraiser_info.key {
-        return Err(ProgramError::InvalidSeeds);
+    // **4. Verify the mint is the one raised**
+    check_mint(mint_to_raise_info, &fundraiser.mint_to_raise, token_program_info)?;
+
+    // **5. A fixed fundraiser needs its target met, a flexible one pays out whatever was raised once it has ended**
+    // Contributors get to withdraw after an amendment before the maker is paid
+    let current_time = Clock::get()?.unix_timestamp;
+    if fundraiser.in_grace_period(current_time) {
+        return Err(ProgramError::Custom(FundraiserError::GracePeriodActive as u32));
+    }
+
+    match fundraiser.funding_mode {
+        FundingMode::Fixed => {
+            if fundraiser.current_amount < fundraiser.amount_to_raise {
+                return Err(ProgramError::Custom(FundraiserError::TargetNotMet as u32));
+            }
+        }
+        FundingMode::Flexible => {
+            if current_time < fundraiser.time_ending {
+                return Err(ProgramError::Custom(FundraiserError::FundraiserNotEnded as u32));
+            }
+        }
     }
 
-    // **5. Check if the fundraiser has ended (no need to check if the target is reached)**
-    // let current_time = Clock::get()?.unix_timestamp;
-    // if current_time < fundraiser.time_ending {
-    //     return Err(ProgramError::Custom(FundraiserError::FundraiserNotEnded as u32));
-    // }
+    // **6. A cancelled fundraiser can only be refunded**
+    if fundraiser.status != FundraiserStatus::Active {
+        return Err(ProgramError::Custom(FundraiserError::FundraiserCancelled as u32));
+    }
 
-    // **6. Verify vault ownership (vault should be owned by the fundraiser PDA)**
-    let vault_data = TokenAccount::unpack(&vault_info.try_borrow_data()?)?;
-    if vault_data.owner != fundraiser_pda {
-        return Err(ProgramError::InvalidAccountData);
+    // **7. A multi-mint fundraiser pays out each mint through the multi-mint checker**
+    if fundraiser.is_multi_mint() {
+        return Err(ProgramError::Custom(FundraiserError::MultiMintFundraiser as u32));
     }
 
-    // **7. Verify the maker TA uses the correct mint**
-    let maker_ata_data = TokenAccount::unpack(&maker_ta_info.try_borrow_data()?)?;
-    if maker_ata_data.mint != *mint_to_raise_info.key {
-        return Err(ProgramError::InvalidAccountData);
+    // **8. A fundraiser with a milestone schedule pays out through release_milestone**
+    if fundraiser.milestone_count > 0 {
+        return Err(ProgramError::Custom(FundraiserError::MilestonesConfigured as u32));
     }
 
-    // **8. Check if the vault has sufficient balance for transfer**
-    let transfer_amount = vault_data.amount;
+    // **9. Verify vault ownership (vault should be owned by the fundraiser PDA)**
+    load_token_account(vault_info, &fundraiser.mint_to_raise, fundraiser_info.key)?;
+
+    // **10. Verify the maker TA belongs to the maker and uses the correct mint**
+    load_token_account(maker_ta_info, &fundraiser.mint_to_raise, maker_info.key)?;
+
+    // **11. Pay out what the contributions were credited with, tokens sent straight to the vault don't count**
+    let transfer_amount = fundraiser.current_amount;
     if transfer_amount == 0 {
         return Err(ProgramError::InsufficientFunds);
     }
 
-    // **9. Transfer all tokens from vault to maker's ATA**
-    let transfer_ix = token_instruction::transfer(
-        token_program_info.key,
-        vault_info.key,
-        maker_ta_info.key,
-        &fundraiser_pda,
-        &[],
-        transfer_amount,
-    )?;
-
-    // Sign the transfer with the fundraiser PDA
-    invoke_signed(
-        &transfer_ix,
-        &[
-            vault_info.clone(),
-            maker_ta_info.clone(),
-            fundraiser_info.clone(),
-            token_program_info.clone(),
-        ],
-        &[&[b"fundraiser", maker_info.key.as_ref(), &[bump_seed]]],
-    )?;
-
-    // **10. Close the fundraiser account by transferring its lamports to the maker**
+    // **12. A fundraiser created with a platform fee pays it to the fee recipient of the config**
+    let fee = fee_amount(transfer_amount, fundraiser.fee_bps);
+    let fee_recipient_ta_info = if fundraiser.fee_bps > 0 {
+        let fee_recipient = load_fee_recipient(account_info_iter.next())?;
+        let fee_recipient_ta_info = next_account_info(account_info_iter)?;
+        check_fee_recipient_ta(fee_recipient_ta_info, mint_to_raise_info.key, &fee_recipient)?;
+
+        Some(fee_recipient_ta_info)
+    } else {
+        None
+    };
+
+    // **13. Transfer the fee to the fee recipient and the rest from the vault to the maker's ATA**
+    let decimals = StateWithExtensions::<Mint>::unpack(&mint_to_raise_info.try_borrow_data()?)?.base.decimals;
+    let transfer = |destination: &AccountInfo, amount: u64| -> ProgramResult {
+        let transfer_ix = token_instruction::transfer_checked(
+            token_program_info.key,
+            vault_info.key,
+            mint_to_raise_info.key,
+            destination.key,
+            fundraiser_info.key,
+            &[],
+            amount,
+            decimals,
+        )?;
+
+        // Sign the transfer with the fundraiser PDA
+        invoke_signed(
+            &transfer_ix,
+            &[
+                vault_info.clone(),
+                mint_to_raise_info.clone(),
+                destination.clone(),
+                fundraiser_info.clone(),
+                token_program_info.clone(),
+            ],
+            &[&[
+                b"fundraiser",
+                fundraiser.maker.as_ref(),
+                fundraiser.campaign_id.to_le_bytes().as_ref(),
+                &[fundraiser.bump],
+            ]],
+        )
+    };
+
+    if let Some(fee_recipient_ta_info) = fee_recipient_ta_info {
+        if fee > 0 {
+            transfer(fee_recipient_ta_info, fee)?;
+        }
+    }
+    transfer(maker_ta_info, transfer_amount - fee)?;
+
+    // **14. Close the fundraiser account by transferring its lamports to the maker**
     let dest_starting_lamports = maker_info.lamports();
     **maker_info.lamports.borrow_mut() = dest_starting_lamports
         .checked_add(fundraiser_info.lamports())
         .ok_or(ProgramError::ArithmeticOverflow)?;
     **fundraiser_info.lamports.borrow_mut() = 0;
 
-    // **11. Clear the fundraiser data to prevent reuse**
+    // **15. Clear the fundraiser data to prevent reuse**
     fundraiser_info.data.borrow_mut().fill(0);
 
+    emit(FundraiserEvent::Withdrawn {
+        fundraiser: fundraiser_info.key.to_bytes(),
+        maker: maker_info.key.to_bytes(),
+        mint: mint_to_raise_info.key.to_bytes(),
+        amount: transfer_amount - fee,
+    });
+
     Ok(())
 }
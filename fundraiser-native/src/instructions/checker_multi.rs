@@ -0,0 +1,158 @@
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    program::invoke_signed,
+    program_error::ProgramError,
+    sysvar::{clock::Clock, Sysvar},
+};
+use spl_token_2022::{
+    extension::StateWithExtensions,
+    instruction as token_instruction,
+    state::Mint,
+};
+use fundraiser_interface::state::fee_amount;
+
+use crate::{
+    events::{emit, FundraiserEvent},
+    state::{FundraiserStatus, FundingMode, PriceFeed},
+    validation::{
+        check_fee_recipient_ta, check_maker, check_owner, check_signer, check_token_program, load_fee_recipient,
+        load_fundraiser, load_token_account,
+    },
+    error::FundraiserError,
+};
+
+// Pays out the vault of one accepted mint, the maker calls this once per mint
+pub fn check_contributions_multi(
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    // Get all account infos, the config and fee recipient of a fundraiser with a platform fee and then the
+    // price feeds of every accepted mint come last
+    let maker_info = next_account_info(account_info_iter)?;
+    let mint_info = next_account_info(account_info_iter)?;
+    let fundraiser_info = next_account_info(account_info_iter)?;
+    let vault_info = next_account_info(account_info_iter)?;
+    let maker_ta_info = next_account_info(account_info_iter)?;
+    let token_program_info = next_account_info(account_info_iter)?;
+    let price_feed_info = next_account_info(account_info_iter)?;
+
+    // **1. Verify the maker is a signer**
+    check_signer(maker_info)?;
+    check_token_program(token_program_info)?;
+    check_owner(mint_info, token_program_info.key)?;
+
+    // **2. Load the fundraiser, it has to sit at its PDA**
+    let mut fundraiser = load_fundraiser(fundraiser_info)?;
+
+    // **3. Only the maker of the fundraiser can withdraw**
+    check_maker(maker_info, &fundraiser)?;
+
+    // A fundraiser created with a platform fee pays it to the fee recipient of the config
+    let fee_recipient_ta_info = if fundraiser.fee_bps > 0 {
+        let fee_recipient = load_fee_recipient(account_info_iter.next())?;
+        let fee_recipient_ta_info = next_account_info(account_info_iter)?;
+        check_fee_recipient_ta(fee_recipient_ta_info, mint_info.key, &fee_recipient)?;
+
+        Some(fee_recipient_ta_info)
+    } else {
+        None
+    };
+    let price_feeds = account_info_iter.as_slice();
+
+    // **4. A cancelled fundraiser can only be refunded**
+    if fundraiser.status != FundraiserStatus::Active {
+        return Err(ProgramError::Custom(FundraiserError::FundraiserCancelled as u32));
+    }
+
+    // **5. Verify the mint is accepted and load its price**
+    if fundraiser.accepted_mint_index(mint_info.key).is_none() {
+        return Err(ProgramError::Custom(FundraiserError::MintNotAccepted as u32));
+    }
+    let price_feed = PriceFeed::load(price_feed_info, &fundraiser.price_authority, mint_info.key)?;
+
+    // **6. A fixed fundraiser values every vault at the current prices, once the first payout went through the target counts as met**
+    // Contributors get to withdraw after an amendment before the maker is paid
+    let current_time = Clock::get()?.unix_timestamp;
+    if fundraiser.in_grace_period(current_time) {
+        return Err(ProgramError::Custom(FundraiserError::GracePeriodActive as u32));
+    }
+
+    match fundraiser.funding_mode {
+        FundingMode::Fixed => {
+            if fundraiser.amount_released == 0 && fundraiser.value_raised(price_feeds)? < fundraiser.amount_to_raise {
+                return Err(ProgramError::Custom(FundraiserError::TargetNotMet as u32));
+            }
+        }
+        FundingMode::Flexible => {
+            if current_time < fundraiser.time_ending {
+                return Err(ProgramError::Custom(FundraiserError::FundraiserNotEnded as u32));
+            }
+        }
+    }
+
+    // **7. Verify the vault holds this mint for the fundraiser PDA**
+    let vault_data = load_token_account(vault_info, mint_info.key, fundraiser_info.key)?;
+
+    // **8. Verify the maker TA belongs to the maker and uses the correct mint**
+    load_token_account(maker_ta_info, mint_info.key, maker_info.key)?;
+
+    // **9. Transfer the fee to the fee recipient and the rest of the vault to the maker's TA**
+    let transfer_amount = vault_data.amount;
+    let fee = fee_amount(transfer_amount, fundraiser.fee_bps);
+    let decimals = StateWithExtensions::<Mint>::unpack(&mint_info.try_borrow_data()?)?.base.decimals;
+    let transfer = |destination: &AccountInfo, amount: u64| -> ProgramResult {
+        let transfer_ix = token_instruction::transfer_checked(
+            token_program_info.key,
+            vault_info.key,
+            mint_info.key,
+            destination.key,
+            fundraiser_info.key,
+            &[],
+            amount,
+            decimals,
+        )?;
+
+        invoke_signed(
+            &transfer_ix,
+            &[
+                vault_info.clone(),
+                mint_info.clone(),
+                destination.clone(),
+                fundraiser_info.clone(),
+                token_program_info.clone(),
+            ],
+            &[&[
+                b"fundraiser",
+                fundraiser.maker.as_ref(),
+                fundraiser.campaign_id.to_le_bytes().as_ref(),
+                &[fundraiser.bump],
+            ]],
+        )
+    };
+
+    if let Some(fee_recipient_ta_info) = fee_recipient_ta_info {
+        if fee > 0 {
+            transfer(fee_recipient_ta_info, fee)?;
+        }
+    }
+    transfer(maker_ta_info, transfer_amount - fee)?;
+
+    // **10. Track the value paid out so far in the common unit, the fee included**
+    fundraiser.amount_released = fundraiser
+        .amount_released
+        .checked_add(price_feed.value(transfer_amount))
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    fundraiser.serialize(&mut &mut fundraiser_info.data.borrow_mut()[..])?;
+
+    emit(FundraiserEvent::Withdrawn {
+        fundraiser: fundraiser_info.key.to_bytes(),
+        maker: maker_info.key.to_bytes(),
+        mint: mint_info.key.to_bytes(),
+        amount: transfer_amount - fee,
+    });
+
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,102 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::{clock::Clock, Sysvar},
+};
+use fundraiser_interface::state::fee_amount;
+
+use crate::{
+    events::{emit, FundraiserEvent},
+    state::{FundraiserStatus, FundingMode},
+    validation::{check_maker, check_signer, load_fee_recipient, load_fundraiser},
+    error::FundraiserError,
+};
+
+pub fn check_contributions_sol(
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    // Get all account infos
+    let maker_info = next_account_info(account_info_iter)?;
+    let fundraiser_info = next_account_info(account_info_iter)?;
+
+    // **1. Verify the maker is a signer**
+    check_signer(maker_info)?;
+
+    // **2. Load the fundraiser, it has to sit at its PDA**
+    let fundraiser = load_fundraiser(fundraiser_info)?;
+
+    // **3. Only the maker of the fundraiser can withdraw**
+    check_maker(maker_info, &fundraiser)?;
+
+    // **4. Only SOL fundraisers hold the raised lamports themselves**
+    if !fundraiser.is_sol() {
+        return Err(ProgramError::Custom(FundraiserError::NotSolFundraiser as u32));
+    }
+
+    // **5. A cancelled fundraiser can only be refunded**
+    if fundraiser.status != FundraiserStatus::Active {
+        return Err(ProgramError::Custom(FundraiserError::FundraiserCancelled as u32));
+    }
+
+    // **6. A fixed fundraiser needs its target met, a flexible one pays out whatever was raised once it has ended**
+    // Contributors get to withdraw after an amendment before the maker is paid
+    let current_time = Clock::get()?.unix_timestamp;
+    if fundraiser.in_grace_period(current_time) {
+        return Err(ProgramError::Custom(FundraiserError::GracePeriodActive as u32));
+    }
+
+    match fundraiser.funding_mode {
+        FundingMode::Fixed => {
+            if fundraiser.current_amount < fundraiser.amount_to_raise {
+                return Err(ProgramError::Custom(FundraiserError::TargetNotMet as u32));
+            }
+        }
+        FundingMode::Flexible => {
+            if current_time < fundraiser.time_ending {
+                return Err(ProgramError::Custom(FundraiserError::FundraiserNotEnded as u32));
+            }
+        }
+    }
+
+    // **7. A fundraiser created with a platform fee pays it to the fee recipient of the config**
+    let fee = fee_amount(fundraiser.current_amount, fundraiser.fee_bps);
+    if fundraiser.fee_bps > 0 {
+        let fee_recipient = load_fee_recipient(account_info_iter.next())?;
+        let fee_recipient_info = next_account_info(account_info_iter)?;
+        if *fee_recipient_info.key != fee_recipient {
+            return Err(ProgramError::Custom(FundraiserError::InvalidFeeRecipient as u32));
+        }
+
+        **fundraiser_info.lamports.borrow_mut() = fundraiser_info
+            .lamports()
+            .checked_sub(fee)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        **fee_recipient_info.lamports.borrow_mut() = fee_recipient_info
+            .lamports()
+            .checked_add(fee)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+    }
+
+    // **8. Close the fundraiser account, the maker gets the rest of the raised lamports along with the rent**
+    let dest_starting_lamports = maker_info.lamports();
+    **maker_info.lamports.borrow_mut() = dest_starting_lamports
+        .checked_add(fundraiser_info.lamports())
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    **fundraiser_info.lamports.borrow_mut() = 0;
+
+    // **9. Clear the fundraiser data to prevent reuse**
+    fundraiser_info.data.borrow_mut().fill(0);
+
+    emit(FundraiserEvent::Withdrawn {
+        fundraiser: fundraiser_info.key.to_bytes(),
+        maker: maker_info.key.to_bytes(),
+        mint: Pubkey::default().to_bytes(),
+        amount: fundraiser.current_amount - fee,
+    });
+
+    Ok(())
+}
\ No newline at end of file
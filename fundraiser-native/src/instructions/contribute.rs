@@ -1,18 +1,29 @@
-use borsh::{BorshDeserialize, BorshSerialize};
+use borsh::BorshSerialize;
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
     entrypoint::ProgramResult,
     msg,
     program::invoke,
-    program_pack::Pack,
     program_error::ProgramError,
     sysvar::{clock::Clock, Sysvar},
 };
-use spl_token::instruction::transfer;
+use spl_token_2022::{
+    extension::StateWithExtensions,
+    instruction::transfer_checked,
+    state::Mint,
+};
+
 
+use fundraiser_interface::instruction::{contribute as contribute_data, unpack_reward_tier};
 
 use crate::{
-    state::{Fundraiser, Contributor},
+    create::create_contributor,
+    events::{emit, FundraiserEvent},
+    state::{FundraiserStatus, RewardTiers},
+    validation::{
+        check_mint, check_signer, check_system_program, check_token_program, load_contributor, load_fundraiser,
+        load_token_account,
+    },
     error::*,
 };
 
@@ -28,25 +39,44 @@ pub fn contribute(
     let fundraiser_account = next_account_info(accounts_iter)?;
     let vault = next_account_info(accounts_iter)?;
     let token_program = next_account_info(accounts_iter)?;
+    let mint_to_raise = next_account_info(accounts_iter)?;
+    let system_program = next_account_info(accounts_iter)?;
+
+    check_signer(signer)?;
+    check_system_program(system_program)?;
+    check_token_program(token_program)?;
+
+    let mut fundraiser = load_fundraiser(fundraiser_account)?;
+
+    // The contributor account has to belong to the signer
+    create_contributor(signer, contributor_account_info, fundraiser_account, system_program)?;
+    let mut contributor_account = load_contributor(contributor_account_info, fundraiser_account.key, signer.key)?;
+    let amount = u64::from_le_bytes(
+        instruction_data
+            .get(contribute_data::AMOUNT..contribute_data::LEN)
+            .and_then(|amount| amount.try_into().ok())
+            .ok_or(ProgramError::InvalidInstructionData)?,
+    );
+    let reward_tier = unpack_reward_tier(instruction_data).ok_or(ProgramError::InvalidInstructionData)?;
 
-
-    if fundraiser_account.owner != &crate::ID {
-        msg!("Invalid owner for fundraiser account");
-        return Err(ProgramError::IncorrectProgramId);
+    if amount == 0 {
+        msg!("Contribution amount must be greater than zero");
+        return Err(ProgramError::InvalidInstructionData);
     }
 
-    if contributor_account_info.owner != &crate::ID {
-        msg!("Invalid owner for contributor account");
-        return Err(ProgramError::IncorrectProgramId);
+    if fundraiser.is_multi_mint() {
+        msg!("Fundraiser accepts multiple mints");
+        return Err(ProgramError::Custom(FundraiserError::MultiMintFundraiser as u32));
     }
 
-    let mut fundraiser: Fundraiser = Fundraiser::try_from_slice(&fundraiser_account.data.borrow())?;
-    let mut contributor_account: Contributor = Contributor::try_from_slice(&contributor_account_info.data.borrow())?;
-    let amount = u64::from_le_bytes(instruction_data.try_into().unwrap());
+    if fundraiser.issues_receipts() {
+        msg!("Fundraiser issues receipt tokens");
+        return Err(ProgramError::Custom(FundraiserError::ReceiptsEnabled as u32));
+    }
 
-    if amount == 0 {
-        msg!("Contribution amount must be greater than zero");
-        return Err(ProgramError::InvalidInstructionData);
+    if fundraiser.status != FundraiserStatus::Active {
+        msg!("Fundraiser has been cancelled");
+        return Err(ProgramError::Custom(FundraiserError::FundraiserCancelled as u32));
     }
 
     // Fundraiser duration check
@@ -56,43 +86,80 @@ pub fn contribute(
         return Err(ProgramError::Custom(FundraiserError::FundraiserEnded as u32));
     }
 
-    let contributor_ta_data = spl_token::state::Account::unpack(&signer_ta.try_borrow_data()?)?;
-    if contributor_ta_data.mint != fundraiser.mint_to_raise {
-        msg!("Contributor token account mint does not match the fundraiser mint");
-        return Err(ProgramError::InvalidAccountData);
-    }
+    // Check the contribution against the limits the maker set
+    fundraiser.check_contribution(contributor_account.amount, amount)?;
+
+    check_mint(mint_to_raise, &fundraiser.mint_to_raise, token_program)?;
+
+    let contributor_ta_data = load_token_account(signer_ta, &fundraiser.mint_to_raise, signer.key)?;
 
     if contributor_ta_data.amount < amount {
         msg!("Insufficient token balance in contributor's token account");
         return Err(ProgramError::InsufficientFunds);
     }
 
+    let decimals = StateWithExtensions::<Mint>::unpack(&mint_to_raise.try_borrow_data()?)?.base.decimals;
+
+    // Remember the vault balance, a transfer fee can make the vault receive less than the amount sent
+    let vault_amount_before = load_token_account(vault, &fundraiser.mint_to_raise, fundraiser_account.key)?.amount;
+
     // Transfer funds from contributor to the vault
-    let transfer_ix = transfer(
+    let transfer_ix = transfer_checked(
         token_program.key,
         signer_ta.key,
+        mint_to_raise.key,
         vault.key,
         signer.key,
-        &[signer.key],
+        &[],
         amount,
+        decimals,
     )?;
     invoke(
         &transfer_ix,
         &[
             signer_ta.clone(),
+            mint_to_raise.clone(),
             vault.clone(),
             signer.clone(),
             token_program.clone(),
         ],
     )?;
 
+    // Only credit what actually landed in the vault
+    let vault_amount_after = load_token_account(vault, &fundraiser.mint_to_raise, fundraiser_account.key)?.amount;
+    let amount_received = vault_amount_after
+        .checked_sub(vault_amount_before)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
     // Update state data
-    fundraiser.current_amount += amount;
-    contributor_account.amount += amount;
+    fundraiser.current_amount = fundraiser
+        .current_amount
+        .checked_add(amount_received)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    contributor_account.amount = contributor_account
+        .amount
+        .checked_add(amount_received)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    // The pledge has to reach the minimum of the tier the contributor picked, with a slot left
+    if let Some(index) = reward_tier {
+        let reward_tiers_info = next_account_info(accounts_iter)?;
+        let mut reward_tiers = RewardTiers::load(reward_tiers_info, fundraiser_account.key)?;
+        reward_tiers.claim(contributor_account.reward_tier, index, contributor_account.amount)?;
+        contributor_account.reward_tier = index + 1;
+        reward_tiers.serialize(&mut &mut reward_tiers_info.data.borrow_mut()[..])?;
+    }
 
     // Serialize state back to account data
     fundraiser.serialize(&mut *fundraiser_account.data.borrow_mut())?;
     contributor_account.serialize(&mut *contributor_account_info.data.borrow_mut())?;
 
+    emit(FundraiserEvent::Contributed {
+        fundraiser: fundraiser_account.key.to_bytes(),
+        contributor: signer.key.to_bytes(),
+        mint: mint_to_raise.key.to_bytes(),
+        amount: amount_received,
+    });
+
     Ok(())
 }
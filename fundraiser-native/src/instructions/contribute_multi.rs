@@ -0,0 +1,151 @@
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program::invoke,
+    program_error::ProgramError,
+    sysvar::{clock::Clock, Sysvar},
+};
+use spl_token_2022::{
+    extension::StateWithExtensions,
+    instruction::transfer_checked,
+    state::Mint,
+};
+
+use crate::{
+    create::create_contributor,
+    events::{emit, FundraiserEvent},
+    state::{FundraiserStatus, PriceFeed},
+    validation::{check_owner, check_signer, check_system_program, check_token_program, load_contributor, load_fundraiser, load_token_account},
+    error::*,
+};
+
+pub fn contribute_multi(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let signer = next_account_info(accounts_iter)?;
+    let contributor_account_info = next_account_info(accounts_iter)?;
+    let signer_ta = next_account_info(accounts_iter)?;
+    let fundraiser_account = next_account_info(accounts_iter)?;
+    let vault = next_account_info(accounts_iter)?;
+    let token_program = next_account_info(accounts_iter)?;
+    let mint = next_account_info(accounts_iter)?;
+    let price_feed_info = next_account_info(accounts_iter)?;
+    let system_program = next_account_info(accounts_iter)?;
+
+    check_signer(signer)?;
+    check_system_program(system_program)?;
+    check_token_program(token_program)?;
+    check_owner(mint, token_program.key)?;
+
+    // The contributor account has to belong to the signer
+    let mut fundraiser = load_fundraiser(fundraiser_account)?;
+    create_contributor(signer, contributor_account_info, fundraiser_account, system_program)?;
+    let mut contributor_account = load_contributor(contributor_account_info, fundraiser_account.key, signer.key)?;
+    let amount = u64::from_le_bytes(
+        instruction_data
+            .try_into()
+            .map_err(|_| ProgramError::InvalidInstructionData)?,
+    );
+
+    if fundraiser.status != FundraiserStatus::Active {
+        msg!("Fundraiser has been cancelled");
+        return Err(ProgramError::Custom(FundraiserError::FundraiserCancelled as u32));
+    }
+
+    // Fundraiser duration check
+    let current_time = Clock::get()?.unix_timestamp;
+    if current_time > fundraiser.time_ending {
+        msg!("Fundraiser has ended");
+        return Err(ProgramError::Custom(FundraiserError::FundraiserEnded as u32));
+    }
+
+    let Some(index) = fundraiser.accepted_mint_index(mint.key) else {
+        msg!("Mint is not accepted by the fundraiser");
+        return Err(ProgramError::Custom(FundraiserError::MintNotAccepted as u32));
+    };
+
+    let price_feed = PriceFeed::load(price_feed_info, &fundraiser.price_authority, mint.key)?;
+
+    // The contribution is valued in the common unit the goal is set in
+    if amount == 0 || price_feed.value(amount) == 0 {
+        msg!("Contribution is worth nothing");
+        return Err(ProgramError::Custom(FundraiserError::ContributionTooSmall as u32));
+    }
+
+    // Limits apply to the contribution's value in the common unit, not to the token amount
+    fundraiser.check_contribution(contributor_account.amount, price_feed.value(amount))?;
+
+    // Every accepted mint has its own vault
+    let vault_data = load_token_account(vault, mint.key, fundraiser_account.key)?;
+    let contributor_ta_data = load_token_account(signer_ta, mint.key, signer.key)?;
+
+    if contributor_ta_data.amount < amount {
+        msg!("Insufficient token balance in contributor's token account");
+        return Err(ProgramError::InsufficientFunds);
+    }
+
+    let decimals = StateWithExtensions::<Mint>::unpack(&mint.try_borrow_data()?)?.base.decimals;
+
+    // Transfer funds from contributor to the vault of this mint
+    let transfer_ix = transfer_checked(
+        token_program.key,
+        signer_ta.key,
+        mint.key,
+        vault.key,
+        signer.key,
+        &[],
+        amount,
+        decimals,
+    )?;
+    invoke(
+        &transfer_ix,
+        &[
+            signer_ta.clone(),
+            mint.clone(),
+            vault.clone(),
+            signer.clone(),
+            token_program.clone(),
+        ],
+    )?;
+
+    // Only credit what actually landed in the vault
+    let vault_amount_after = load_token_account(vault, mint.key, fundraiser_account.key)?.amount;
+    let amount_received = vault_amount_after
+        .checked_sub(vault_data.amount)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    let value_received = price_feed.value(amount_received);
+
+    // Track the tokens per mint so refunds return them as they came in, and their value towards the goal
+    fundraiser.amounts_raised[index] = fundraiser.amounts_raised[index]
+        .checked_add(amount_received)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    fundraiser.current_amount = fundraiser
+        .current_amount
+        .checked_add(value_received)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    contributor_account.amounts[index] = contributor_account.amounts[index]
+        .checked_add(amount_received)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    contributor_account.amount = contributor_account
+        .amount
+        .checked_add(value_received)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    // Serialize state back to account data
+    fundraiser.serialize(&mut *fundraiser_account.data.borrow_mut())?;
+    contributor_account.serialize(&mut *contributor_account_info.data.borrow_mut())?;
+
+    emit(FundraiserEvent::Contributed {
+        fundraiser: fundraiser_account.key.to_bytes(),
+        contributor: signer.key.to_bytes(),
+        mint: mint.key.to_bytes(),
+        amount: amount_received,
+    });
+
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,181 @@
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program::{invoke, invoke_signed},
+    program_error::ProgramError,
+    sysvar::{clock::Clock, Sysvar},
+};
+use spl_token_2022::{
+    extension::StateWithExtensions,
+    instruction::{mint_to_checked, transfer_checked},
+    state::Mint,
+};
+
+
+use crate::{
+    create::create_associated_token_account,
+    events::{emit, FundraiserEvent},
+    state::FundraiserStatus,
+    validation::{check_mint, check_signer, check_system_program, check_token_program, load_fundraiser, load_token_account},
+    error::*,
+};
+
+pub fn contribute_receipts(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let signer = next_account_info(accounts_iter)?;
+    let signer_ta = next_account_info(accounts_iter)?;
+    let fundraiser_account = next_account_info(accounts_iter)?;
+    let vault = next_account_info(accounts_iter)?;
+    let token_program = next_account_info(accounts_iter)?;
+    let mint_to_raise = next_account_info(accounts_iter)?;
+    let receipt_mint = next_account_info(accounts_iter)?;
+    let signer_receipt_ta = next_account_info(accounts_iter)?;
+    let system_program = next_account_info(accounts_iter)?;
+    let associated_token_program = next_account_info(accounts_iter)?;
+
+    check_signer(signer)?;
+    check_system_program(system_program)?;
+    check_token_program(token_program)?;
+
+    let mut fundraiser = load_fundraiser(fundraiser_account)?;
+    let amount = u64::from_le_bytes(
+        instruction_data
+            .try_into()
+            .map_err(|_| ProgramError::InvalidInstructionData)?,
+    );
+
+    if !fundraiser.issues_receipts() {
+        msg!("Fundraiser does not issue receipt tokens");
+        return Err(ProgramError::Custom(FundraiserError::ReceiptsDisabled as u32));
+    }
+
+    if fundraiser.status != FundraiserStatus::Active {
+        msg!("Fundraiser has been cancelled");
+        return Err(ProgramError::Custom(FundraiserError::FundraiserCancelled as u32));
+    }
+
+    // Fundraiser duration check
+    let current_time = Clock::get()?.unix_timestamp;
+    if current_time > fundraiser.time_ending {
+        msg!("Fundraiser has ended");
+        return Err(ProgramError::Custom(FundraiserError::FundraiserEnded as u32));
+    }
+
+    check_mint(mint_to_raise, &fundraiser.mint_to_raise, token_program)?;
+
+    if *receipt_mint.key != fundraiser.receipt_mint {
+        msg!("Receipt mint does not match the fundraiser receipt mint");
+        return Err(ProgramError::Custom(FundraiserError::InvalidReceiptMint as u32));
+    }
+
+    // The first contribution creates the contributor's receipt account, the contributor pays for it
+    create_associated_token_account(
+        signer,
+        signer_receipt_ta,
+        signer,
+        receipt_mint,
+        system_program,
+        token_program,
+        associated_token_program,
+    )?;
+
+    // The receipts held by the contributor take the place of the contributor account
+    let signer_receipt_ta_data = load_token_account(signer_receipt_ta, &fundraiser.receipt_mint, signer.key)?;
+
+    // Check the contribution against the limits the maker set
+    fundraiser.check_contribution(signer_receipt_ta_data.amount, amount)?;
+
+    let contributor_ta_data = load_token_account(signer_ta, &fundraiser.mint_to_raise, signer.key)?;
+
+    if contributor_ta_data.amount < amount {
+        msg!("Insufficient token balance in contributor's token account");
+        return Err(ProgramError::InsufficientFunds);
+    }
+
+    let decimals = StateWithExtensions::<Mint>::unpack(&mint_to_raise.try_borrow_data()?)?.base.decimals;
+
+    // Remember the vault balance, a transfer fee can make the vault receive less than the amount sent
+    let vault_amount_before = load_token_account(vault, &fundraiser.mint_to_raise, fundraiser_account.key)?.amount;
+
+    // Transfer funds from contributor to the vault
+    let transfer_ix = transfer_checked(
+        token_program.key,
+        signer_ta.key,
+        mint_to_raise.key,
+        vault.key,
+        signer.key,
+        &[],
+        amount,
+        decimals,
+    )?;
+    invoke(
+        &transfer_ix,
+        &[
+            signer_ta.clone(),
+            mint_to_raise.clone(),
+            vault.clone(),
+            signer.clone(),
+            token_program.clone(),
+        ],
+    )?;
+
+    // Only what actually landed in the vault is backed by receipts
+    let vault_amount_after = load_token_account(vault, &fundraiser.mint_to_raise, fundraiser_account.key)?.amount;
+    let amount_received = vault_amount_after
+        .checked_sub(vault_amount_before)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    // Mint the receipts, the fundraiser PDA is the mint authority
+    let mint_ix = mint_to_checked(
+        token_program.key,
+        receipt_mint.key,
+        signer_receipt_ta.key,
+        fundraiser_account.key,
+        &[],
+        amount_received,
+        decimals,
+    )?;
+
+    let campaign_id = fundraiser.campaign_id.to_le_bytes();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        b"fundraiser",
+        fundraiser.maker.as_ref(),
+        campaign_id.as_ref(),
+        &[fundraiser.bump],
+    ]];
+
+    invoke_signed(
+        &mint_ix,
+        &[
+            receipt_mint.clone(),
+            signer_receipt_ta.clone(),
+            fundraiser_account.clone(),
+            token_program.clone(),
+        ],
+        signer_seeds,
+    )?;
+
+    // Update state data
+    fundraiser.current_amount = fundraiser
+        .current_amount
+        .checked_add(amount_received)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    // Serialize state back to account data
+    fundraiser.serialize(&mut *fundraiser_account.data.borrow_mut())?;
+
+    emit(FundraiserEvent::Contributed {
+        fundraiser: fundraiser_account.key.to_bytes(),
+        contributor: signer.key.to_bytes(),
+        mint: mint_to_raise.key.to_bytes(),
+        amount: amount_received,
+    });
+
+    Ok(())
+}
\ No newline at end of file
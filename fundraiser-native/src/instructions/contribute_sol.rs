@@ -0,0 +1,102 @@
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program::invoke,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    system_instruction,
+    sysvar::{clock::Clock, Sysvar},
+};
+
+use crate::{
+    create::create_contributor,
+    events::{emit, FundraiserEvent},
+    state::FundraiserStatus,
+    validation::{check_signer, check_system_program, load_contributor, load_fundraiser},
+    error::*,
+};
+
+pub fn contribute_sol(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let signer = next_account_info(accounts_iter)?;
+    let contributor_account_info = next_account_info(accounts_iter)?;
+    let fundraiser_account = next_account_info(accounts_iter)?;
+    let system_program = next_account_info(accounts_iter)?;
+
+    check_signer(signer)?;
+    check_system_program(system_program)?;
+
+    // The contributor account has to belong to the signer
+    let mut fundraiser = load_fundraiser(fundraiser_account)?;
+    create_contributor(signer, contributor_account_info, fundraiser_account, system_program)?;
+    let mut contributor_account = load_contributor(contributor_account_info, fundraiser_account.key, signer.key)?;
+    let amount = u64::from_le_bytes(
+        instruction_data
+            .try_into()
+            .map_err(|_| ProgramError::InvalidInstructionData)?,
+    );
+
+    if !fundraiser.is_sol() {
+        msg!("Fundraiser does not raise SOL");
+        return Err(ProgramError::Custom(FundraiserError::NotSolFundraiser as u32));
+    }
+
+    if amount == 0 {
+        msg!("Contribution amount must be greater than zero");
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    if fundraiser.status != FundraiserStatus::Active {
+        msg!("Fundraiser has been cancelled");
+        return Err(ProgramError::Custom(FundraiserError::FundraiserCancelled as u32));
+    }
+
+    // Fundraiser duration check
+    let current_time = Clock::get()?.unix_timestamp;
+    if current_time > fundraiser.time_ending {
+        msg!("Fundraiser has ended");
+        return Err(ProgramError::Custom(FundraiserError::FundraiserEnded as u32));
+    }
+
+    // Check the contribution against the limits the maker set
+    fundraiser.check_contribution(contributor_account.amount, amount)?;
+
+    // Transfer lamports from contributor to the fundraiser account
+    invoke(
+        &system_instruction::transfer(signer.key, fundraiser_account.key, amount),
+        &[
+            signer.clone(),
+            fundraiser_account.clone(),
+            system_program.clone(),
+        ],
+    )?;
+
+    // Update state data
+    fundraiser.current_amount = fundraiser
+        .current_amount
+        .checked_add(amount)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    contributor_account.amount = contributor_account
+        .amount
+        .checked_add(amount)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    // Serialize state back to account data
+    fundraiser.serialize(&mut *fundraiser_account.data.borrow_mut())?;
+    contributor_account.serialize(&mut *contributor_account_info.data.borrow_mut())?;
+
+    emit(FundraiserEvent::Contributed {
+        fundraiser: fundraiser_account.key.to_bytes(),
+        contributor: signer.key.to_bytes(),
+        mint: Pubkey::default().to_bytes(),
+        amount,
+    });
+
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,79 @@
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    program_option::COption,
+    program_pack::Pack,
+};
+use spl_token_2022::{extension::StateWithExtensions, state::Mint};
+
+use crate::{
+    state::FundraiserStatus,
+    validation::{check_maker, check_signer, load_fundraiser},
+    error::FundraiserError,
+};
+
+pub fn process_enable_receipts(
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let [
+        maker_info,
+        fundraiser_info,
+        mint_to_raise_info,
+        receipt_mint_info
+    ] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // **1. Verify the maker is a signer**
+    check_signer(maker_info)?;
+
+    // **2. Load the fundraiser, it has to sit at its PDA**
+    let mut fundraiser = load_fundraiser(fundraiser_info)?;
+
+    // **3. Only the maker of the fundraiser can enable receipts**
+    check_maker(maker_info, &fundraiser)?;
+
+    if fundraiser.issues_receipts() {
+        return Err(ProgramError::Custom(FundraiserError::ReceiptsEnabled as u32));
+    }
+
+    // **4. Receipts stand for a share of a single mint paid out in one go, and have to be enabled before anyone has contributed**
+    if fundraiser.status != FundraiserStatus::Active
+        || fundraiser.is_sol()
+        || fundraiser.is_multi_mint()
+        || fundraiser.current_amount != 0
+        || fundraiser.milestone_count != 0
+        || fundraiser.voting_period != 0
+    {
+        return Err(ProgramError::Custom(FundraiserError::CannotEnableReceipts as u32));
+    }
+
+    if *mint_to_raise_info.key != fundraiser.mint_to_raise {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // **5. Only the fundraiser can mint receipts, and they are counted in the units of the raised mint**
+    // A mint without extensions also rules out a permanent delegate moving or burning receipts on a holder's behalf
+    if receipt_mint_info.owner != mint_to_raise_info.owner || receipt_mint_info.data_len() != Mint::LEN {
+        return Err(ProgramError::Custom(FundraiserError::InvalidReceiptMint as u32));
+    }
+
+    let receipt_mint = Mint::unpack(&receipt_mint_info.data.borrow())?;
+    let decimals = StateWithExtensions::<Mint>::unpack(&mint_to_raise_info.try_borrow_data()?)?.base.decimals;
+    if receipt_mint.mint_authority != COption::Some(*fundraiser_info.key)
+        || receipt_mint.freeze_authority.is_some()
+        || receipt_mint.supply != 0
+        || receipt_mint.decimals != decimals
+    {
+        return Err(ProgramError::Custom(FundraiserError::InvalidReceiptMint as u32));
+    }
+
+    // **6. Contributions mint receipts from now on**
+    fundraiser.receipt_mint = *receipt_mint_info.key;
+
+    fundraiser.serialize(&mut &mut fundraiser_info.data.borrow_mut()[..])?;
+
+    Ok(())
+}
\ No newline at end of file
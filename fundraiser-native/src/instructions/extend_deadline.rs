@@ -0,0 +1,61 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    sysvar::Sysvar,
+};
+
+use crate::{
+    events::{emit, FundraiserEvent},
+    validation::{check_maker, check_signer, load_fundraiser},
+    error::FundraiserError,
+};
+
+pub fn process_extend_deadline(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let maker_info = next_account_info(account_info_iter)?;
+    let fundraiser_info = next_account_info(account_info_iter)?;
+
+    let time_ending = i64::try_from_slice(instruction_data)?;
+
+    // **1. Verify the maker is a signer**
+    check_signer(maker_info)?;
+
+    // **2. Load the fundraiser, it has to sit at its PDA**
+    let mut fundraiser = load_fundraiser(fundraiser_info)?;
+
+    // **3. Only the maker of the fundraiser can amend it**
+    check_maker(maker_info, &fundraiser)?;
+
+    // **4. The deadline can only move out**
+    if time_ending <= fundraiser.time_ending {
+        return Err(ProgramError::Custom(FundraiserError::InvalidAmendment as u32));
+    }
+
+    // **5. The first milestone, and the vote on it, still have to come after the fundraiser ends**
+    if fundraiser.milestone_count > 0
+        && time_ending > fundraiser.milestones[0].unlock_time - fundraiser.voting_period
+    {
+        return Err(ProgramError::Custom(FundraiserError::InvalidMilestones as u32));
+    }
+
+    // **6. Count the amendment, contributors can withdraw until the grace period is over**
+    fundraiser.amend(Clock::get()?.unix_timestamp)?;
+    fundraiser.time_ending = time_ending;
+    fundraiser.serialize(&mut &mut fundraiser_info.data.borrow_mut()[..])?;
+
+    emit(FundraiserEvent::Amended {
+        fundraiser: fundraiser_info.key.to_bytes(),
+        maker: maker_info.key.to_bytes(),
+        amount_to_raise: fundraiser.amount_to_raise,
+        time_ending,
+    });
+
+    Ok(())
+}
\ No newline at end of file
@@ -4,10 +4,20 @@ use solana_program::{
     program_error::ProgramError,
     pubkey::Pubkey,
 };
+use spl_token_2022::{
+    extension::{transfer_hook::TransferHook, BaseStateWithExtensions, StateWithExtensions},
+    state::Mint,
+};
+
 
+use fundraiser_interface::instruction::initialize;
 
 use crate::{
-    state::Fundraiser,
+    create::{create_associated_token_account, create_pda_account},
+    events::{emit, FundraiserEvent},
+    state::{Config, ContributionLimits, Fundraiser, FundingMode, Milestone, BPS_SCALER, MAX_MILESTONES},
+    validation::{check_signer, check_system_program},
+    error::FundraiserError,
     ID,
 };
 
@@ -19,27 +29,109 @@ pub fn process_initialize(
         maker,
         fundraiser,
         mint_to_raise,
-        _system_program
+        vault,
+        system_program,
+        token_program,
+        associated_token_program,
+        config
     ] = accounts else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
-    // Deserialize instruction data
-    let amount = u64::try_from_slice(&instruction_data[..8])?;
-    let time_ending = i64::try_from_slice(&instruction_data[8..])?;
+    // Deserialize instruction data, the layout is shared with the other implementations
+    if instruction_data.len() != initialize::LEN {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+    let amount = u64::try_from_slice(&instruction_data[initialize::AMOUNT_TO_RAISE..initialize::TIME_ENDING])?;
+    let time_ending = i64::try_from_slice(&instruction_data[initialize::TIME_ENDING..initialize::CAMPAIGN_ID])?;
+    let campaign_id = u64::try_from_slice(&instruction_data[initialize::CAMPAIGN_ID..initialize::BUMP])?;
+    let bump = instruction_data[initialize::BUMP];
+    let milestone_count = instruction_data[initialize::MILESTONE_COUNT] as usize;
 
-    if !maker.is_signer {
-        return Err(ProgramError::MissingRequiredSignature);
+    // An empty milestone schedule releases everything at once through the checker
+    // The milestones always take MAX_MILESTONES slots, only the first milestone_count are used
+    if milestone_count > MAX_MILESTONES {
+        return Err(ProgramError::Custom(FundraiserError::InvalidMilestones as u32));
     }
 
+    let milestones = instruction_data[initialize::MILESTONES..initialize::VOTING_PERIOD]
+        .chunks(Milestone::LEN)
+        .take(milestone_count)
+        .map(Milestone::try_from_slice)
+        .collect::<Result<Vec<Milestone>, _>>()?;
+
+    if !milestones.is_empty() {
+        let mut total_percentage: u64 = 0;
+        let mut previous_unlock_time = time_ending;
+
+        for milestone in milestones.iter() {
+            // Every tranche must release something and unlock in order, after the fundraiser ends
+            if milestone.percentage == 0 || milestone.unlock_time < previous_unlock_time {
+                return Err(ProgramError::Custom(FundraiserError::InvalidMilestones as u32));
+            }
+
+            total_percentage += milestone.percentage as u64;
+            previous_unlock_time = milestone.unlock_time;
+        }
+
+        // The tranches must add up to the whole amount raised
+        if total_percentage != 100 {
+            return Err(ProgramError::Custom(FundraiserError::InvalidMilestones as u32));
+        }
+    }
+
+    let voting_period = i64::try_from_slice(&instruction_data[initialize::VOTING_PERIOD..initialize::QUORUM_BPS])?;
+    let quorum_bps = u16::try_from_slice(&instruction_data[initialize::QUORUM_BPS..initialize::APPROVAL_BPS])?;
+    let approval_bps = u16::try_from_slice(&instruction_data[initialize::APPROVAL_BPS..initialize::FUNDING_MODE])?;
+    let funding_mode = FundingMode::try_from_slice(&instruction_data[initialize::FUNDING_MODE..initialize::CONTRIBUTION_LIMITS])?;
+    let contribution_limits = ContributionLimits::try_from_slice(&instruction_data[initialize::CONTRIBUTION_LIMITS..initialize::MAX_AMENDMENTS])?;
+    let max_amendments = instruction_data[initialize::MAX_AMENDMENTS];
+
+    // A voting period of 0 disables voting, otherwise backers vote once the contributions are settled
+    if voting_period < 0
+        || quorum_bps as u64 > BPS_SCALER
+        || approval_bps as u64 > BPS_SCALER
+        || (voting_period > 0
+            && (milestones.is_empty() || milestones[0].unlock_time - voting_period < time_ending))
+    {
+        return Err(ProgramError::Custom(FundraiserError::InvalidVotingConfig as u32));
+    }
+
+    contribution_limits.validate(amount)?;
+
+    check_signer(maker)?;
+    check_system_program(system_program)?;
+
     if mint_to_raise.owner != &spl_token::ID && mint_to_raise.owner != &spl_token_2022::ID {
         return Err(ProgramError::InvalidAccountData);
     }
+    if token_program.key != mint_to_raise.owner {
+        return Err(ProgramError::IncorrectProgramId);
+    }
 
-    let (_fundraiser_pda, bump) = Pubkey::find_program_address(
-        &[b"fundraiser", maker.key.as_ref()],
+    check_transfer_hook(mint_to_raise)?;
+
+    // The bump comes with the instruction, so the address is derived once instead of searched for
+    let fundraiser_pda = Pubkey::create_program_address(
+        &[b"fundraiser", maker.key.as_ref(), campaign_id.to_le_bytes().as_ref(), &[bump]],
         &ID,
-    );
+    )?;
+    if fundraiser_pda != *fundraiser.key {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    // The fee in force now applies for the whole campaign, there is none until the platform is configured
+    let fee_bps = Config::load(config)?.map_or(0, |config| config.fee_bps);
+
+    // The maker pays for the fundraiser account and its vault
+    create_pda_account(
+        maker,
+        fundraiser,
+        system_program,
+        Fundraiser::LEN,
+        &[b"fundraiser", maker.key.as_ref(), campaign_id.to_le_bytes().as_ref(), &[bump]],
+    )?;
+    create_associated_token_account(maker, vault, fundraiser, mint_to_raise, system_program, token_program, associated_token_program)?;
 
     Fundraiser::init(
         fundraiser,
@@ -48,7 +140,39 @@ pub fn process_initialize(
         amount,
         time_ending,
         bump,
+        campaign_id,
+        &milestones,
+        voting_period,
+        quorum_bps,
+        approval_bps,
+        funding_mode,
+        contribution_limits,
+        fee_bps,
+        max_amendments,
     )?;
 
+    emit(FundraiserEvent::FundraiserCreated {
+        fundraiser: fundraiser.key.to_bytes(),
+        maker: maker.key.to_bytes(),
+        mint_to_raise: mint_to_raise.key.to_bytes(),
+        amount_to_raise: amount,
+        time_ending,
+    });
+
+    Ok(())
+}
+
+// Transfer hooks run another program on every transfer, mints that use them are not supported
+pub fn check_transfer_hook(mint_info: &AccountInfo) -> Result<(), ProgramError> {
+    if mint_info.owner == &spl_token_2022::ID {
+        let mint_data = mint_info.try_borrow_data()?;
+        let mint = StateWithExtensions::<Mint>::unpack(&mint_data)?;
+        if let Ok(transfer_hook) = mint.get_extension::<TransferHook>() {
+            if Option::<Pubkey>::from(transfer_hook.program_id).is_some() {
+                return Err(ProgramError::Custom(FundraiserError::TransferHookNotSupported as u32));
+            }
+        }
+    }
+
     Ok(())
 }
\ No newline at end of file
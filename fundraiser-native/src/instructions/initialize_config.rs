@@ -0,0 +1,88 @@
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::AccountInfo,
+    bpf_loader_upgradeable,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use fundraiser_interface::{instruction::initialize_config, state::program_data};
+
+use crate::{
+    create::create_pda_account,
+    state::{Config, MAX_FEE_BPS},
+    validation::{check_signer, check_system_program, check_uninitialized},
+    error::FundraiserError,
+};
+
+pub fn process_initialize_config(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let [
+        admin_info,
+        config_info,
+        program_data_info,
+        system_program
+    ] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // **1. Verify the admin is a signer**
+    check_signer(admin_info)?;
+    check_system_program(system_program)?;
+
+    // **2. Only the upgrade authority of this program can configure the platform**
+    let (program_data_address, _) = Pubkey::find_program_address(&[program_id.as_ref()], &bpf_loader_upgradeable::ID);
+    if *program_data_info.key != program_data_address || program_data_info.owner != &bpf_loader_upgradeable::ID {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    let data = program_data_info.data.borrow();
+    let is_upgrade_authority = data.len() >= program_data::LEN
+        && data[program_data::TAG..program_data::SLOT] == program_data::PROGRAM_DATA_TAG.to_le_bytes()
+        && data[program_data::HAS_UPGRADE_AUTHORITY] == 1
+        && data[program_data::UPGRADE_AUTHORITY..program_data::LEN] == admin_info.key.to_bytes();
+    if !is_upgrade_authority {
+        return Err(ProgramError::Custom(FundraiserError::NotUpgradeAuthority as u32));
+    }
+
+    // **3. The config sits at its PDA and can only be created once**
+    let (config_address, bump) = Config::pda();
+    if *config_info.key != config_address {
+        return Err(ProgramError::InvalidSeeds);
+    }
+    check_uninitialized(config_info)?;
+
+    // **4. Deserialize the instruction data**
+    if instruction_data.len() != initialize_config::LEN {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+    let fee_bps = u16::from_le_bytes(
+        instruction_data[initialize_config::FEE_BPS..initialize_config::FEE_RECIPIENT].try_into().unwrap(),
+    );
+    let fee_recipient = Pubkey::try_from(&instruction_data[initialize_config::FEE_RECIPIENT..]).unwrap();
+
+    // **5. The fee is capped, and it has to go somewhere**
+    if fee_bps > MAX_FEE_BPS {
+        return Err(ProgramError::Custom(FundraiserError::InvalidFee as u32));
+    }
+    if fee_recipient == Pubkey::default() {
+        return Err(ProgramError::Custom(FundraiserError::InvalidFeeRecipient as u32));
+    }
+
+    // **6. Create the config, the admin pays for it**
+    create_pda_account(admin_info, config_info, system_program, Config::LEN, &[b"config", &[bump]])?;
+
+    // **7. Write the config**
+    Config {
+        admin: *admin_info.key,
+        fee_bps,
+        fee_recipient,
+        bump,
+    }
+    .serialize(&mut &mut config_info.data.borrow_mut()[..])?;
+
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,102 @@
+use borsh::BorshDeserialize;
+use solana_program::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use fundraiser_interface::instruction::initialize_sol;
+
+use crate::{
+    create::create_pda_account,
+    events::{emit, FundraiserEvent},
+    state::{Config, ContributionLimits, Fundraiser, FundingMode},
+    validation::{check_signer, check_system_program, check_uninitialized},
+    error::FundraiserError,
+    ID,
+};
+
+pub fn process_initialize_sol(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> Result<(), ProgramError> {
+    let [
+        maker,
+        fundraiser,
+        system_program,
+        config
+    ] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Deserialize instruction data, the layout is shared with the other implementations
+    if instruction_data.len() != initialize_sol::LEN {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+    let amount = u64::try_from_slice(&instruction_data[initialize_sol::AMOUNT_TO_RAISE..initialize_sol::TIME_ENDING])?;
+    let time_ending = i64::try_from_slice(&instruction_data[initialize_sol::TIME_ENDING..initialize_sol::CAMPAIGN_ID])?;
+    let campaign_id = u64::try_from_slice(&instruction_data[initialize_sol::CAMPAIGN_ID..initialize_sol::BUMP])?;
+    let bump = instruction_data[initialize_sol::BUMP];
+    let funding_mode = FundingMode::try_from_slice(&instruction_data[initialize_sol::FUNDING_MODE..initialize_sol::CONTRIBUTION_LIMITS])?;
+    let contribution_limits = ContributionLimits::try_from_slice(&instruction_data[initialize_sol::CONTRIBUTION_LIMITS..initialize_sol::MAX_AMENDMENTS])?;
+    let max_amendments = instruction_data[initialize_sol::MAX_AMENDMENTS];
+
+    if amount == 0 {
+        return Err(ProgramError::Custom(FundraiserError::InvalidAmount as u32));
+    }
+
+    contribution_limits.validate(amount)?;
+
+    check_signer(maker)?;
+    check_system_program(system_program)?;
+
+    let fundraiser_pda = Pubkey::create_program_address(
+        &[b"fundraiser", maker.key.as_ref(), campaign_id.to_le_bytes().as_ref(), &[bump]],
+        &ID,
+    )?;
+    if fundraiser_pda != *fundraiser.key {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    // The fee in force now applies for the whole campaign, there is none until the platform is configured
+    let fee_bps = Config::load(config)?.map_or(0, |config| config.fee_bps);
+
+    // An existing fundraiser keeps its terms and its lamports, the maker pays for a new one
+    check_uninitialized(fundraiser)?;
+    create_pda_account(
+        maker,
+        fundraiser,
+        system_program,
+        Fundraiser::LEN,
+        &[b"fundraiser", maker.key.as_ref(), campaign_id.to_le_bytes().as_ref(), &[bump]],
+    )?;
+
+    // A SOL fundraiser has no mint, milestones or voting, the raised lamports sit on top of the rent-exempt balance
+    Fundraiser::init(
+        fundraiser,
+        *maker.key,
+        Pubkey::default(),
+        amount,
+        time_ending,
+        bump,
+        campaign_id,
+        &[],
+        0,
+        0,
+        0,
+        funding_mode,
+        contribution_limits,
+        fee_bps,
+        max_amendments,
+    )?;
+
+    emit(FundraiserEvent::FundraiserCreated {
+        fundraiser: fundraiser.key.to_bytes(),
+        maker: maker.key.to_bytes(),
+        mint_to_raise: Pubkey::default().to_bytes(),
+        amount_to_raise: amount,
+        time_ending,
+    });
+
+    Ok(())
+}
\ No newline at end of file
@@ -1,14 +1,22 @@
-use borsh::{BorshSerialize, BorshDeserialize};
+use borsh::BorshSerialize;
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
     entrypoint::ProgramResult,
     program::invoke_signed,
-    program_pack::Pack,
     program_error::ProgramError,
     sysvar::{clock::Clock, Sysvar},
 };
-use spl_token::instruction::transfer;
-use crate::{state::{Contributor, Fundraiser}, error::FundraiserError};
+use spl_token_2022::{
+    extension::StateWithExtensions,
+    instruction::transfer_checked,
+    state::Mint,
+};
+use crate::{
+    events::{emit, FundraiserEvent},
+    state::{FundraiserStatus, FundingMode, RewardTiers},
+    validation::{check_maker, check_mint, check_signer, check_token_program, load_contributor, load_fundraiser, load_token_account},
+    error::FundraiserError,
+};
 
 pub fn refund_instruction(
     accounts: &[AccountInfo],
@@ -16,59 +24,90 @@ pub fn refund_instruction(
 ) -> ProgramResult {
     let account_info_iter = &mut accounts.iter();
 
-    
+    let contributor = next_account_info(account_info_iter)?;
     let maker = next_account_info(account_info_iter)?;
     let fundraiser_account = next_account_info(account_info_iter)?;
     let contributor_account_info = next_account_info(account_info_iter)?;
     let contributor_ta = next_account_info(account_info_iter)?;
     let vault = next_account_info(account_info_iter)?;
     let token_program = next_account_info(account_info_iter)?;
+    let mint_to_raise = next_account_info(account_info_iter)?;
 
-  
+    // The tokens go back to the signer, so the contributor account has to be theirs
+    check_signer(contributor)?;
+    check_token_program(token_program)?;
 
-    // Ownership checks
-    if fundraiser_account.owner != &crate::ID {
-        return Err(ProgramError::IncorrectProgramId);
-    }
+    // Deserialize the Fundraiser and Contributor state
+    let mut fundraiser = load_fundraiser(fundraiser_account)?;
+    let mut contributor_account = load_contributor(contributor_account_info, fundraiser_account.key, contributor.key)?;
+    check_maker(maker, &fundraiser)?;
 
-    if contributor_account_info.owner != &crate::ID {
-        return Err(ProgramError::IncorrectProgramId);
+    // A multi-mint fundraiser refunds each mint through refund_multi
+    if fundraiser.is_multi_mint() {
+        return Err(ProgramError::Custom(FundraiserError::MultiMintFundraiser as u32));
     }
 
-    // Deserialize the Fundraiser and Contributor state
-    let mut fundraiser = Fundraiser::try_from_slice(&fundraiser_account.data.borrow())?;
-    let mut contributor_account = Contributor::try_from_slice(&contributor_account_info.data.borrow())?;
+    // Receipt holders refund through refund_receipts
+    if fundraiser.issues_receipts() {
+        return Err(ProgramError::Custom(FundraiserError::ReceiptsEnabled as u32));
+    }
 
     // Ensure the contributor has a positive balance
     if contributor_account.amount == 0 {
         return Err(ProgramError::InsufficientFunds);
     }
 
-    // Fundraiser status check
-    // let current_time = Clock::get()?.unix_timestamp;
-    // if current_time < fundraiser.time_ending && fundraiser.current_amount < fundraiser.amount_to_raise {
-    //     return Err(ProgramError::Custom(FundraiserError::FundraiserNotEnded as u32));
-    // }
-
     // Token Mint Verification
-    let contributor_ta_data = spl_token::state::Account::unpack(&contributor_ta.try_borrow_data()?)?;
-    if contributor_ta_data.mint != fundraiser.mint_to_raise {
-        return Err(ProgramError::InvalidAccountData);
+    check_mint(mint_to_raise, &fundraiser.mint_to_raise, token_program)?;
+    load_token_account(contributor_ta, &fundraiser.mint_to_raise, contributor.key)?;
+    let vault_data = load_token_account(vault, &fundraiser.mint_to_raise, fundraiser_account.key)?;
+
+    // A cancelled or rejected fundraiser can be refunded right away, regardless of time or vault balance,
+    // and so can an active one for a while after the maker amended it
+    let current_time = Clock::get()?.unix_timestamp;
+    if fundraiser.status == FundraiserStatus::Active && !fundraiser.in_grace_period(current_time) {
+        // A flexible fundraiser keeps what it raises, contributors can only get out if it is cancelled or rejected
+        if fundraiser.funding_mode == FundingMode::Flexible {
+            return Err(ProgramError::Custom(FundraiserError::RefundsDisabled as u32));
+        }
+
+        if current_time < fundraiser.time_ending {
+            return Err(ProgramError::Custom(FundraiserError::FundraiserNotEnded as u32));
+        }
+
+        // Nothing can be refunded once the maker has been paid
+        if fundraiser.amount_released != 0 || fundraiser.current_amount >= fundraiser.amount_to_raise {
+            return Err(ProgramError::Custom(FundraiserError::TargetMet as u32));
+        }
     }
 
+    // Once backers reject a milestone, each of them gets their pro-rata share of what is still in the vault
+    let refund_amount = if fundraiser.status == FundraiserStatus::Rejected {
+        ((contributor_account.amount as u128 * vault_data.amount as u128)
+            .checked_div(fundraiser.current_amount as u128)
+            .ok_or(ProgramError::ArithmeticOverflow)?) as u64
+    } else {
+        contributor_account.amount
+    };
+
     // Transfer funds from the vault to the contributor's ATA
-    let transfer_ix = transfer(
+    let decimals = StateWithExtensions::<Mint>::unpack(&mint_to_raise.try_borrow_data()?)?.base.decimals;
+    let transfer_ix = transfer_checked(
         token_program.key,
         vault.key,
+        mint_to_raise.key,
         contributor_ta.key,
         fundraiser_account.key,
         &[],
-        contributor_account.amount,
+        refund_amount,
+        decimals,
     )?;
 
+    let campaign_id = fundraiser.campaign_id.to_le_bytes();
     let signer_seeds: &[&[&[u8]]] = &[&[
         b"fundraiser",
-        maker.key.as_ref(),
+        fundraiser.maker.as_ref(),
+        campaign_id.as_ref(),
         &[fundraiser.bump],
     ]];
 
@@ -76,6 +115,7 @@ pub fn refund_instruction(
         &transfer_ix,
         &[
             vault.clone(),
+            mint_to_raise.clone(),
             contributor_ta.clone(),
             fundraiser_account.clone(),
             token_program.clone(),
@@ -89,9 +129,25 @@ pub fn refund_instruction(
     // Reset contributor's amount to zero
     contributor_account.amount = 0;
 
+    // Give the reward tier slot back, the tiers are passed after the other accounts
+    if contributor_account.reward_tier != 0 {
+        let reward_tiers_info = next_account_info(account_info_iter)?;
+        let mut reward_tiers = RewardTiers::load(reward_tiers_info, fundraiser_account.key)?;
+        reward_tiers.release(contributor_account.reward_tier);
+        contributor_account.reward_tier = 0;
+        reward_tiers.serialize(&mut &mut reward_tiers_info.data.borrow_mut()[..])?;
+    }
+
     // Serialize the updated state back to the account data
     fundraiser.serialize(&mut &mut fundraiser_account.data.borrow_mut()[..])?;
     contributor_account.serialize(&mut &mut contributor_account_info.data.borrow_mut()[..])?;
 
+    emit(FundraiserEvent::Refunded {
+        fundraiser: fundraiser_account.key.to_bytes(),
+        contributor: contributor.key.to_bytes(),
+        mint: mint_to_raise.key.to_bytes(),
+        amount: refund_amount,
+    });
+
     Ok(())
 }
@@ -0,0 +1,137 @@
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    program::invoke_signed,
+    program_error::ProgramError,
+    sysvar::{clock::Clock, Sysvar},
+};
+use spl_token_2022::{
+    extension::StateWithExtensions,
+    instruction::transfer_checked,
+    state::Mint,
+};
+use crate::{
+    events::{emit, FundraiserEvent},
+    state::{FundraiserStatus, FundingMode, PriceFeed},
+    validation::{
+        check_maker, check_owner, check_signer, check_token_program, load_contributor, load_fundraiser,
+        load_token_account,
+    },
+    error::FundraiserError,
+};
+
+// Returns what the contributor sent in one accepted mint, the contributor calls this once per mint
+pub fn refund_multi(
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let contributor = next_account_info(account_info_iter)?;
+    let maker = next_account_info(account_info_iter)?;
+    let fundraiser_account = next_account_info(account_info_iter)?;
+    let contributor_account_info = next_account_info(account_info_iter)?;
+    let contributor_ta = next_account_info(account_info_iter)?;
+    let vault = next_account_info(account_info_iter)?;
+    let token_program = next_account_info(account_info_iter)?;
+    let mint = next_account_info(account_info_iter)?;
+    let price_feed_info = next_account_info(account_info_iter)?;
+    let price_feeds = account_info_iter.as_slice();
+
+    check_signer(contributor)?;
+    check_token_program(token_program)?;
+    check_owner(mint, token_program.key)?;
+
+    // The tokens go back to the signer, so the contributor account has to be theirs
+    let mut fundraiser = load_fundraiser(fundraiser_account)?;
+    let mut contributor_account = load_contributor(contributor_account_info, fundraiser_account.key, contributor.key)?;
+    check_maker(maker, &fundraiser)?;
+
+    let Some(index) = fundraiser.accepted_mint_index(mint.key) else {
+        return Err(ProgramError::Custom(FundraiserError::MintNotAccepted as u32));
+    };
+    let price_feed = PriceFeed::load(price_feed_info, &fundraiser.price_authority, mint.key)?;
+
+    // Refunds return the original tokens, not their value
+    let refund_amount = contributor_account.amounts[index];
+    if refund_amount == 0 {
+        return Err(ProgramError::InsufficientFunds);
+    }
+
+    // A cancelled fundraiser can be refunded right away, regardless of time or prices,
+    // and so can an active one for a while after the maker amended it
+    let current_time = Clock::get()?.unix_timestamp;
+    if fundraiser.status == FundraiserStatus::Active && !fundraiser.in_grace_period(current_time) {
+        // A flexible fundraiser keeps what it raises, contributors can only get out if it is cancelled
+        if fundraiser.funding_mode == FundingMode::Flexible {
+            return Err(ProgramError::Custom(FundraiserError::RefundsDisabled as u32));
+        }
+
+        if current_time < fundraiser.time_ending {
+            return Err(ProgramError::Custom(FundraiserError::FundraiserNotEnded as u32));
+        }
+
+        // Value every vault at the current prices, nothing can be refunded once the maker has been paid
+        if fundraiser.amount_released != 0 || fundraiser.value_raised(price_feeds)? >= fundraiser.amount_to_raise {
+            return Err(ProgramError::Custom(FundraiserError::TargetMet as u32));
+        }
+    }
+
+    // Token Mint Verification
+    load_token_account(contributor_ta, mint.key, contributor.key)?;
+    load_token_account(vault, mint.key, fundraiser_account.key)?;
+
+    // Transfer funds from the vault of this mint to the contributor's TA
+    let decimals = StateWithExtensions::<Mint>::unpack(&mint.try_borrow_data()?)?.base.decimals;
+    let transfer_ix = transfer_checked(
+        token_program.key,
+        vault.key,
+        mint.key,
+        contributor_ta.key,
+        fundraiser_account.key,
+        &[],
+        refund_amount,
+        decimals,
+    )?;
+
+    let campaign_id = fundraiser.campaign_id.to_le_bytes();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        b"fundraiser",
+        fundraiser.maker.as_ref(),
+        campaign_id.as_ref(),
+        &[fundraiser.bump],
+    ]];
+
+    invoke_signed(
+        &transfer_ix,
+        &[
+            vault.clone(),
+            mint.clone(),
+            contributor_ta.clone(),
+            fundraiser_account.clone(),
+            token_program.clone(),
+        ],
+        signer_seeds,
+    )?;
+
+    // The value is taken off at the current price, the tokens themselves are tracked exactly
+    let value = price_feed.value(refund_amount);
+
+    fundraiser.amounts_raised[index] -= refund_amount;
+    fundraiser.current_amount = fundraiser.current_amount.saturating_sub(value);
+    contributor_account.amounts[index] = 0;
+    contributor_account.amount = contributor_account.amount.saturating_sub(value);
+
+    // Serialize the updated state back to the account data
+    fundraiser.serialize(&mut &mut fundraiser_account.data.borrow_mut()[..])?;
+    contributor_account.serialize(&mut &mut contributor_account_info.data.borrow_mut()[..])?;
+
+    emit(FundraiserEvent::Refunded {
+        fundraiser: fundraiser_account.key.to_bytes(),
+        contributor: contributor.key.to_bytes(),
+        mint: mint.key.to_bytes(),
+        amount: refund_amount,
+    });
+
+    Ok(())
+}
\ No newline at end of file
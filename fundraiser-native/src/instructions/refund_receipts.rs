@@ -0,0 +1,146 @@
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    program::{invoke, invoke_signed},
+    program_error::ProgramError,
+    sysvar::{clock::Clock, Sysvar},
+};
+use spl_token_2022::{
+    extension::StateWithExtensions,
+    instruction::{burn_checked, transfer_checked},
+    state::Mint,
+};
+use crate::{
+    events::{emit, FundraiserEvent},
+    state::{FundraiserStatus, FundingMode},
+    validation::{check_mint, check_signer, check_token_program, load_fundraiser, load_token_account},
+    error::FundraiserError,
+};
+
+pub fn refund_receipts(
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let holder = next_account_info(account_info_iter)?;
+    let holder_ta = next_account_info(account_info_iter)?;
+    let fundraiser_account = next_account_info(account_info_iter)?;
+    let vault = next_account_info(account_info_iter)?;
+    let token_program = next_account_info(account_info_iter)?;
+    let mint_to_raise = next_account_info(account_info_iter)?;
+    let receipt_mint = next_account_info(account_info_iter)?;
+    let holder_receipt_ta = next_account_info(account_info_iter)?;
+
+    // The holder burns their receipts, so they have to sign
+    check_signer(holder)?;
+    check_token_program(token_program)?;
+
+    let mut fundraiser = load_fundraiser(fundraiser_account)?;
+
+    if !fundraiser.issues_receipts() {
+        return Err(ProgramError::Custom(FundraiserError::ReceiptsDisabled as u32));
+    }
+
+    if *receipt_mint.key != fundraiser.receipt_mint {
+        return Err(ProgramError::Custom(FundraiserError::InvalidReceiptMint as u32));
+    }
+
+    // Token Mint Verification
+    check_mint(mint_to_raise, &fundraiser.mint_to_raise, token_program)?;
+    load_token_account(holder_ta, &fundraiser.mint_to_raise, holder.key)?;
+    load_token_account(vault, &fundraiser.mint_to_raise, fundraiser_account.key)?;
+
+    // A cancelled fundraiser can be refunded right away, regardless of time or vault balance,
+    // and so can an active one for a while after the maker amended it
+    let current_time = Clock::get()?.unix_timestamp;
+    if fundraiser.status == FundraiserStatus::Active && !fundraiser.in_grace_period(current_time) {
+        // A flexible fundraiser keeps what it raises, holders can only get out if it is cancelled
+        if fundraiser.funding_mode == FundingMode::Flexible {
+            return Err(ProgramError::Custom(FundraiserError::RefundsDisabled as u32));
+        }
+
+        if current_time < fundraiser.time_ending {
+            return Err(ProgramError::Custom(FundraiserError::FundraiserNotEnded as u32));
+        }
+
+        // Nothing can be refunded once the maker has been paid
+        if fundraiser.amount_released != 0 || fundraiser.current_amount >= fundraiser.amount_to_raise {
+            return Err(ProgramError::Custom(FundraiserError::TargetMet as u32));
+        }
+    }
+
+    // Refunds follow the receipts, whoever holds them gets the tokens they stand for
+    let refund_amount = load_token_account(holder_receipt_ta, &fundraiser.receipt_mint, holder.key)?.amount;
+    if refund_amount == 0 {
+        return Err(ProgramError::InsufficientFunds);
+    }
+
+    let decimals = StateWithExtensions::<Mint>::unpack(&mint_to_raise.try_borrow_data()?)?.base.decimals;
+
+    // Burn the receipts, the token program checks the holder owns them
+    let burn_ix = burn_checked(
+        token_program.key,
+        holder_receipt_ta.key,
+        receipt_mint.key,
+        holder.key,
+        &[],
+        refund_amount,
+        decimals,
+    )?;
+    invoke(
+        &burn_ix,
+        &[
+            holder_receipt_ta.clone(),
+            receipt_mint.clone(),
+            holder.clone(),
+            token_program.clone(),
+        ],
+    )?;
+
+    // Transfer funds from the vault to the holder's ATA
+    let transfer_ix = transfer_checked(
+        token_program.key,
+        vault.key,
+        mint_to_raise.key,
+        holder_ta.key,
+        fundraiser_account.key,
+        &[],
+        refund_amount,
+        decimals,
+    )?;
+
+    let campaign_id = fundraiser.campaign_id.to_le_bytes();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        b"fundraiser",
+        fundraiser.maker.as_ref(),
+        campaign_id.as_ref(),
+        &[fundraiser.bump],
+    ]];
+
+    invoke_signed(
+        &transfer_ix,
+        &[
+            vault.clone(),
+            mint_to_raise.clone(),
+            holder_ta.clone(),
+            fundraiser_account.clone(),
+            token_program.clone(),
+        ],
+        signer_seeds,
+    )?;
+
+    // Update state: reduce the current amount in the fundraiser
+    fundraiser.current_amount -= refund_amount;
+
+    fundraiser.serialize(&mut &mut fundraiser_account.data.borrow_mut()[..])?;
+
+    emit(FundraiserEvent::Refunded {
+        fundraiser: fundraiser_account.key.to_bytes(),
+        contributor: holder.key.to_bytes(),
+        mint: mint_to_raise.key.to_bytes(),
+        amount: refund_amount,
+    });
+
+    Ok(())
+}
\ No newline at end of file
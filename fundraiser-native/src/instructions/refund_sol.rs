@@ -0,0 +1,93 @@
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    rent::Rent,
+    sysvar::{clock::Clock, Sysvar},
+};
+use crate::{
+    events::{emit, FundraiserEvent},
+    state::{FundraiserStatus, FundingMode},
+    validation::{check_signer, load_contributor, load_fundraiser},
+    error::FundraiserError,
+};
+
+pub fn refund_sol(
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let contributor = next_account_info(account_info_iter)?;
+    let fundraiser_account = next_account_info(account_info_iter)?;
+    let contributor_account_info = next_account_info(account_info_iter)?;
+
+    check_signer(contributor)?;
+
+    // The lamports go straight to the signer, so the contributor account has to be theirs
+    let mut fundraiser = load_fundraiser(fundraiser_account)?;
+    let mut contributor_account = load_contributor(contributor_account_info, fundraiser_account.key, contributor.key)?;
+
+    if !fundraiser.is_sol() {
+        return Err(ProgramError::Custom(FundraiserError::NotSolFundraiser as u32));
+    }
+
+    // Ensure the contributor has a positive balance
+    if contributor_account.amount == 0 {
+        return Err(ProgramError::InsufficientFunds);
+    }
+
+    // A cancelled fundraiser can be refunded right away, regardless of time or amount raised,
+    // and so can an active one for a while after the maker amended it
+    let current_time = Clock::get()?.unix_timestamp;
+    if fundraiser.status == FundraiserStatus::Active && !fundraiser.in_grace_period(current_time) {
+        // A flexible fundraiser keeps what it raises, contributors can only get out if it is cancelled
+        if fundraiser.funding_mode == FundingMode::Flexible {
+            return Err(ProgramError::Custom(FundraiserError::RefundsDisabled as u32));
+        }
+
+        if current_time < fundraiser.time_ending {
+            return Err(ProgramError::Custom(FundraiserError::FundraiserNotEnded as u32));
+        }
+
+        if fundraiser.current_amount >= fundraiser.amount_to_raise {
+            return Err(ProgramError::Custom(FundraiserError::TargetMet as u32));
+        }
+    }
+
+    // Move the lamports back directly, the fundraiser account is owned by the program.
+    // Only the contributed lamports leave, the account has to stay rent-exempt
+    let remaining_lamports = fundraiser_account
+        .lamports()
+        .checked_sub(contributor_account.amount)
+        .ok_or(ProgramError::InsufficientFunds)?;
+    if remaining_lamports < Rent::get()?.minimum_balance(fundraiser_account.data_len()) {
+        return Err(ProgramError::InsufficientFunds);
+    }
+
+    **fundraiser_account.lamports.borrow_mut() = remaining_lamports;
+    **contributor.lamports.borrow_mut() = contributor
+        .lamports()
+        .checked_add(contributor_account.amount)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    emit(FundraiserEvent::Refunded {
+        fundraiser: fundraiser_account.key.to_bytes(),
+        contributor: contributor.key.to_bytes(),
+        mint: Pubkey::default().to_bytes(),
+        amount: contributor_account.amount,
+    });
+
+    // Update state: reduce the current amount in the fundraiser
+    fundraiser.current_amount -= contributor_account.amount;
+
+    // Reset contributor's amount to zero
+    contributor_account.amount = 0;
+
+    // Serialize the updated state back to the account data
+    fundraiser.serialize(&mut &mut fundraiser_account.data.borrow_mut()[..])?;
+    contributor_account.serialize(&mut &mut contributor_account_info.data.borrow_mut()[..])?;
+
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,59 @@
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    sysvar::{clock::Clock, Sysvar},
+};
+
+use crate::{
+    state::FundraiserStatus,
+    validation::load_fundraiser,
+    error::FundraiserError,
+};
+
+pub fn process_reject_milestone(
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let fundraiser_info = next_account_info(account_info_iter)?;
+
+    // **1. Load the fundraiser, it has to sit at its PDA**
+    let mut fundraiser = load_fundraiser(fundraiser_info)?;
+
+    // **2. A cancelled or rejected fundraiser can only be refunded**
+    if fundraiser.status != FundraiserStatus::Active {
+        return Err(ProgramError::Custom(FundraiserError::FundraiserCancelled as u32));
+    }
+
+    if fundraiser.voting_period == 0 {
+        return Err(ProgramError::Custom(FundraiserError::VotingDisabled as u32));
+    }
+
+    let index = fundraiser.milestones_released as usize;
+    if index >= fundraiser.milestone_count as usize {
+        return Err(ProgramError::Custom(FundraiserError::MilestonesCompleted as u32));
+    }
+
+    // **3. The outcome is only final once the voting window has closed**
+    let current_time = Clock::get()?.unix_timestamp;
+    if current_time < fundraiser.milestones[index].unlock_time {
+        return Err(ProgramError::Custom(FundraiserError::VotingWindowOpen as u32));
+    }
+
+    // The quorum is measured against what is raised, which can still shrink during the grace period
+    if fundraiser.in_grace_period(current_time) {
+        return Err(ProgramError::Custom(FundraiserError::GracePeriodActive as u32));
+    }
+
+    if fundraiser.milestone_approved() {
+        return Err(ProgramError::Custom(FundraiserError::MilestoneApproved as u32));
+    }
+
+    // **4. Anyone can settle a failed vote, contributors can then refund what is left in the vault**
+    fundraiser.status = FundraiserStatus::Rejected;
+    fundraiser.serialize(&mut &mut fundraiser_info.data.borrow_mut()[..])?;
+
+    Ok(())
+}
\ No newline at end of file
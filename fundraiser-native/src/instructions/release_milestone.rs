@@ -0,0 +1,174 @@
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    program::invoke_signed,
+    program_error::ProgramError,
+    sysvar::{clock::Clock, Sysvar},
+};
+use spl_token_2022::{
+    extension::StateWithExtensions,
+    instruction as token_instruction,
+    state::Mint,
+};
+use fundraiser_interface::state::fee_amount;
+
+use crate::{
+    events::{emit, FundraiserEvent},
+    state::FundraiserStatus,
+    validation::{
+        check_fee_recipient_ta, check_maker, check_mint, check_signer, check_token_program, load_fee_recipient,
+        load_fundraiser, load_token_account,
+    },
+    error::FundraiserError,
+};
+
+pub fn process_release_milestone(
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    // Get all account infos
+    let maker_info = next_account_info(account_info_iter)?;
+    let mint_to_raise_info = next_account_info(account_info_iter)?;
+    let fundraiser_info = next_account_info(account_info_iter)?;
+    let vault_info = next_account_info(account_info_iter)?;
+    let maker_ta_info = next_account_info(account_info_iter)?;
+    let token_program_info = next_account_info(account_info_iter)?;
+
+    // **1. Verify the maker is a signer**
+    check_signer(maker_info)?;
+    check_token_program(token_program_info)?;
+
+    // **2. Load the fundraiser, it has to sit at its PDA**
+    let mut fundraiser = load_fundraiser(fundraiser_info)?;
+
+    // **3. Only the maker of the fundraiser can release milestones**
+    check_maker(maker_info, &fundraiser)?;
+
+    // **4. Verify the mint is the one raised**
+    check_mint(mint_to_raise_info, &fundraiser.mint_to_raise, token_program_info)?;
+
+    // **5. A cancelled or rejected fundraiser can only be refunded**
+    if fundraiser.status != FundraiserStatus::Active {
+        return Err(ProgramError::Custom(FundraiserError::FundraiserCancelled as u32));
+    }
+
+    // **6. Milestones are released in order, one at a time**
+    if fundraiser.milestone_count == 0 {
+        return Err(ProgramError::Custom(FundraiserError::NoMilestones as u32));
+    }
+    let index = fundraiser.milestones_released as usize;
+    if index >= fundraiser.milestone_count as usize {
+        return Err(ProgramError::Custom(FundraiserError::MilestonesCompleted as u32));
+    }
+
+    // **7. Check if the raised funds are secured, either by meeting the target or by a flexible fundraiser ending**
+    let current_time = Clock::get()?.unix_timestamp;
+    if !fundraiser.funds_secured(current_time) {
+        return Err(ProgramError::Custom(FundraiserError::TargetNotMet as u32));
+    }
+    if fundraiser.in_grace_period(current_time) {
+        return Err(ProgramError::Custom(FundraiserError::GracePeriodActive as u32));
+    }
+
+    // **8. Check if the milestone has unlocked**
+    let milestone = fundraiser.milestones[index];
+    if current_time < milestone.unlock_time {
+        return Err(ProgramError::Custom(FundraiserError::MilestoneLocked as u32));
+    }
+
+    // **9. When backers vote, the milestone needs their approval before it leaves the vault**
+    if fundraiser.voting_period > 0 && !fundraiser.milestone_approved() {
+        return Err(ProgramError::Custom(FundraiserError::MilestoneNotApproved as u32));
+    }
+
+    // **10. Verify vault ownership (vault should be owned by the fundraiser PDA)**
+    let vault_data = load_token_account(vault_info, &fundraiser.mint_to_raise, fundraiser_info.key)?;
+
+    // **11. Verify the maker TA belongs to the maker and uses the correct mint**
+    load_token_account(maker_ta_info, &fundraiser.mint_to_raise, maker_info.key)?;
+
+    // **12. Work out the unlocked share, the last milestone sweeps the vault so no dust is left behind**
+    let transfer_amount = if index + 1 == fundraiser.milestone_count as usize {
+        vault_data.amount
+    } else {
+        fundraiser
+            .current_amount
+            .checked_mul(milestone.percentage as u64)
+            .ok_or(ProgramError::ArithmeticOverflow)?
+            / 100
+    };
+
+    // **13. A fundraiser created with a platform fee pays it on every release to the fee recipient of the config**
+    let fee = fee_amount(transfer_amount, fundraiser.fee_bps);
+    let fee_recipient_ta_info = if fundraiser.fee_bps > 0 {
+        let fee_recipient = load_fee_recipient(account_info_iter.next())?;
+        let fee_recipient_ta_info = next_account_info(account_info_iter)?;
+        check_fee_recipient_ta(fee_recipient_ta_info, mint_to_raise_info.key, &fee_recipient)?;
+
+        Some(fee_recipient_ta_info)
+    } else {
+        None
+    };
+
+    // **14. Transfer the fee to the fee recipient and the rest of the unlocked share to the maker's TA**
+    let decimals = StateWithExtensions::<Mint>::unpack(&mint_to_raise_info.try_borrow_data()?)?.base.decimals;
+    let transfer = |destination: &AccountInfo, amount: u64| -> ProgramResult {
+        let transfer_ix = token_instruction::transfer_checked(
+            token_program_info.key,
+            vault_info.key,
+            mint_to_raise_info.key,
+            destination.key,
+            fundraiser_info.key,
+            &[],
+            amount,
+            decimals,
+        )?;
+
+        invoke_signed(
+            &transfer_ix,
+            &[
+                vault_info.clone(),
+                mint_to_raise_info.clone(),
+                destination.clone(),
+                fundraiser_info.clone(),
+                token_program_info.clone(),
+            ],
+            &[&[
+                b"fundraiser",
+                fundraiser.maker.as_ref(),
+                fundraiser.campaign_id.to_le_bytes().as_ref(),
+                &[fundraiser.bump],
+            ]],
+        )
+    };
+
+    if let Some(fee_recipient_ta_info) = fee_recipient_ta_info {
+        if fee > 0 {
+            transfer(fee_recipient_ta_info, fee)?;
+        }
+    }
+    transfer(maker_ta_info, transfer_amount - fee)?;
+
+    // **15. Track what has already been released, the fee included**
+    fundraiser.milestones_released += 1;
+    fundraiser.amount_released = fundraiser
+        .amount_released
+        .checked_add(transfer_amount)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    // **16. Reset the tally for the next milestone**
+    fundraiser.votes_for = 0;
+    fundraiser.votes_against = 0;
+    fundraiser.serialize(&mut &mut fundraiser_info.data.borrow_mut()[..])?;
+
+    emit(FundraiserEvent::Withdrawn {
+        fundraiser: fundraiser_info.key.to_bytes(),
+        maker: maker_info.key.to_bytes(),
+        mint: mint_to_raise_info.key.to_bytes(),
+        amount: transfer_amount - fee,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,62 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+};
+use spl_token_2022::{extension::StateWithExtensions, state::Mint};
+
+use crate::{
+    state::PriceFeed,
+    validation::{check_owner, check_pda, check_signer, check_system_program},
+    error::FundraiserError,
+    ID,
+};
+
+pub fn process_set_price(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let [
+        authority,
+        mint,
+        price_feed_info,
+        system_program
+    ] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // **1. Verify the authority is a signer**
+    check_signer(authority)?;
+    check_system_program(system_program)?;
+
+    // **2. Verify ownership of the price feed and mint accounts**
+    check_owner(price_feed_info, &ID)?;
+
+    if mint.owner != &spl_token::ID && mint.owner != &spl_token_2022::ID {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // **3. A mint without a price could never count towards a goal**
+    let price = u64::try_from_slice(instruction_data)?;
+    if price == 0 {
+        return Err(ProgramError::Custom(FundraiserError::InvalidPriceFeed as u32));
+    }
+
+    // **4. Every authority keeps one price feed per mint**
+    let bump = check_pda(price_feed_info, &[b"price_feed", authority.key.as_ref(), mint.key.as_ref()])?;
+
+    // **5. Write the price, along with the decimals it applies to**
+    let decimals = StateWithExtensions::<Mint>::unpack(&mint.try_borrow_data()?)?.base.decimals;
+
+    PriceFeed {
+        authority: *authority.key,
+        mint: *mint.key,
+        decimals,
+        price,
+        bump,
+    }
+    .serialize(&mut &mut price_feed_info.data.borrow_mut()[..])?;
+
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,68 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+};
+
+use crate::{
+    state::{FundraiserStatus, RewardTier, RewardTiers, MAX_REWARD_TIERS},
+    validation::{check_maker, check_owner, check_pda, check_signer, check_system_program, load_fundraiser},
+    error::FundraiserError,
+    ID,
+};
+
+pub fn process_set_reward_tiers(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let [
+        maker_info,
+        fundraiser_info,
+        reward_tiers_info,
+        system_program
+    ] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // **1. Verify the maker is a signer**
+    check_signer(maker_info)?;
+    check_system_program(system_program)?;
+
+    // **2. Verify ownership of the reward tiers account**
+    check_owner(reward_tiers_info, &ID)?;
+
+    // **3. Load the fundraiser, it has to sit at its PDA**
+    let fundraiser = load_fundraiser(fundraiser_info)?;
+
+    // **4. Only the maker of the fundraiser can set its reward tiers**
+    check_maker(maker_info, &fundraiser)?;
+
+    // **5. Every fundraiser keeps one set of reward tiers**
+    let bump = check_pda(reward_tiers_info, &[b"reward_tiers", fundraiser_info.key.as_ref()])?;
+
+    // **6. Contributors pick a tier as they pledge, so the tiers are fixed once the first contribution came in**
+    if fundraiser.status != FundraiserStatus::Active || fundraiser.current_amount != 0 {
+        return Err(ProgramError::Custom(FundraiserError::RewardTiersLocked as u32));
+    }
+
+    // **7. Every tier in use has to offer at least one reward**
+    let (tier_count, tiers) = <(u8, [RewardTier; MAX_REWARD_TIERS])>::try_from_slice(instruction_data)?;
+    if tier_count as usize > MAX_REWARD_TIERS
+        || tiers[..tier_count as usize].iter().any(|tier| tier.max_supply == 0)
+    {
+        return Err(ProgramError::Custom(FundraiserError::InvalidRewardTiers as u32));
+    }
+
+    // **8. Write the tiers, nothing has been claimed yet**
+    RewardTiers {
+        fundraiser: *fundraiser_info.key,
+        tier_count,
+        tiers,
+        claimed: [0; MAX_REWARD_TIERS],
+        bump,
+    }
+    .serialize(&mut &mut reward_tiers_info.data.borrow_mut()[..])?;
+
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,97 @@
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    sysvar::{clock::Clock, Sysvar},
+};
+
+use crate::{
+    state::FundraiserStatus,
+    validation::{check_signer, load_contributor, load_fundraiser},
+    error::FundraiserError,
+};
+
+pub fn process_vote(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let contributor_info = next_account_info(account_info_iter)?;
+    let fundraiser_info = next_account_info(account_info_iter)?;
+    let contributor_account_info = next_account_info(account_info_iter)?;
+
+    let approve = *instruction_data
+        .first()
+        .ok_or(ProgramError::InvalidInstructionData)?
+        != 0;
+
+    // **1. Verify the contributor is a signer**
+    check_signer(contributor_info)?;
+
+    // **2. Load the fundraiser, it has to sit at its PDA**
+    let mut fundraiser = load_fundraiser(fundraiser_info)?;
+
+    // **3. Verify the contributor PDA belongs to the signer**
+    let mut contributor_account = load_contributor(contributor_account_info, fundraiser_info.key, contributor_info.key)?;
+
+    // **4. A cancelled or rejected fundraiser can only be refunded**
+    if fundraiser.status != FundraiserStatus::Active {
+        return Err(ProgramError::Custom(FundraiserError::FundraiserCancelled as u32));
+    }
+
+    if fundraiser.voting_period == 0 {
+        return Err(ProgramError::Custom(FundraiserError::VotingDisabled as u32));
+    }
+
+    // **5. Votes always apply to the next milestone waiting to be released**
+    let index = fundraiser.milestones_released as usize;
+    if index >= fundraiser.milestone_count as usize {
+        return Err(ProgramError::Custom(FundraiserError::MilestonesCompleted as u32));
+    }
+
+    // **6. Check if the raised funds are secured, either by meeting the target or by a flexible fundraiser ending**
+    let current_time = Clock::get()?.unix_timestamp;
+    if !fundraiser.funds_secured(current_time) {
+        return Err(ProgramError::Custom(FundraiserError::TargetNotMet as u32));
+    }
+
+    // Contributors who vote now could still withdraw their stake, so wait for the grace period to end
+    if fundraiser.in_grace_period(current_time) {
+        return Err(ProgramError::Custom(FundraiserError::GracePeriodActive as u32));
+    }
+
+    // **7. The voting window closes when the milestone unlocks**
+    let unlock_time = fundraiser.milestones[index].unlock_time;
+    if current_time < unlock_time - fundraiser.voting_period || current_time >= unlock_time {
+        return Err(ProgramError::Custom(FundraiserError::VotingWindowClosed as u32));
+    }
+
+    // **8. Votes are weighted by the amount contributed, one vote per milestone**
+    if contributor_account.amount == 0 {
+        return Err(ProgramError::Custom(FundraiserError::NoVotingWeight as u32));
+    }
+    if contributor_account.voted_milestone == index as u8 + 1 {
+        return Err(ProgramError::Custom(FundraiserError::AlreadyVoted as u32));
+    }
+
+    // **9. Record the vote**
+    if approve {
+        fundraiser.votes_for = fundraiser
+            .votes_for
+            .checked_add(contributor_account.amount)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+    } else {
+        fundraiser.votes_against = fundraiser
+            .votes_against
+            .checked_add(contributor_account.amount)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+    }
+    contributor_account.voted_milestone = index as u8 + 1;
+
+    fundraiser.serialize(&mut &mut fundraiser_info.data.borrow_mut()[..])?;
+    contributor_account.serialize(&mut &mut contributor_account_info.data.borrow_mut()[..])?;
+
+    Ok(())
+}
\ No newline at end of file
@@ -5,7 +5,10 @@ declare_id!("CsPs2Z5QLAuqWCw2enAmCfCNmdGahBYysL3cvxv9mmxH");
 
 mod state;
 mod instructions;
+mod create;
+mod validation;
 mod error;
+mod events;
 
 
 use instructions::*;
@@ -22,13 +25,35 @@ pub fn process_instruction(
         return Err(ProgramError::IncorrectProgramId);
     }
 
-    let (instruction_discriminant, instruction_inner_data) = instruction_data.split_at(1);
+    let (instruction_discriminant, instruction_inner_data) = instruction_data
+        .split_first()
+        .ok_or(ProgramError::InvalidInstructionData)?;
 
-    match FundraiserInstruction::try_from(instruction_discriminant[0]).unwrap() {
-        FundraiserInstruction::InitializeInstruction => process_initialize( accounts, instruction_inner_data)?,
-        FundraiserInstruction::CheckerInstruction => check_contributions(program_id,accounts, instruction_data)?,
-        FundraiserInstruction::RefundInstruction => refund_instruction(accounts, instruction_data)?,
-        FundraiserInstruction::ContributeInstruction => contribute(accounts, instruction_inner_data)?
+    match FundraiserInstruction::try_from(*instruction_discriminant).map_err(|_| ProgramError::InvalidInstructionData)? {
+        FundraiserInstruction::Initialize => process_initialize( accounts, instruction_inner_data)?,
+        FundraiserInstruction::Checker => check_contributions(accounts, instruction_data)?,
+        FundraiserInstruction::Refund => refund_instruction(accounts, instruction_data)?,
+        FundraiserInstruction::Contribute => contribute(accounts, instruction_inner_data)?,
+        FundraiserInstruction::Cancel => process_cancel(accounts)?,
+        FundraiserInstruction::ReleaseMilestone => process_release_milestone(accounts)?,
+        FundraiserInstruction::Vote => process_vote(accounts, instruction_inner_data)?,
+        FundraiserInstruction::RejectMilestone => process_reject_milestone(accounts)?,
+        FundraiserInstruction::InitializeSol => process_initialize_sol(accounts, instruction_inner_data)?,
+        FundraiserInstruction::ContributeSol => contribute_sol(accounts, instruction_inner_data)?,
+        FundraiserInstruction::CheckerSol => check_contributions_sol(accounts)?,
+        FundraiserInstruction::RefundSol => refund_sol(accounts)?,
+        FundraiserInstruction::SetPrice => process_set_price(accounts, instruction_inner_data)?,
+        FundraiserInstruction::AddAcceptedMint => process_add_accepted_mint(accounts)?,
+        FundraiserInstruction::ContributeMulti => contribute_multi(accounts, instruction_inner_data)?,
+        FundraiserInstruction::CheckerMulti => check_contributions_multi(accounts)?,
+        FundraiserInstruction::RefundMulti => refund_multi(accounts)?,
+        FundraiserInstruction::EnableReceipts => process_enable_receipts(accounts)?,
+        FundraiserInstruction::ContributeReceipts => contribute_receipts(accounts, instruction_inner_data)?,
+        FundraiserInstruction::RefundReceipts => refund_receipts(accounts)?,
+        FundraiserInstruction::SetRewardTiers => process_set_reward_tiers(accounts, instruction_inner_data)?,
+        FundraiserInstruction::InitializeConfig => process_initialize_config(program_id, accounts, instruction_inner_data)?,
+        FundraiserInstruction::ExtendDeadline => process_extend_deadline(accounts, instruction_inner_data)?,
+        FundraiserInstruction::AmendGoal => process_amend_goal(accounts, instruction_inner_data)?,
     }
 
     Ok(())
@@ -0,0 +1,45 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+pub use fundraiser_interface::state::MAX_FEE_BPS;
+
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
+pub struct Config {
+    pub admin: Pubkey,
+    pub fee_bps: u16,
+    pub fee_recipient: Pubkey,
+    pub bump: u8,
+}
+
+impl Config {
+    pub const LEN: usize = 32 + // admin
+                          2 +  // fee_bps
+                          32 + // fee_recipient
+                          1;   // bump
+
+    // The program keeps a single config at [b"config"]
+    pub fn pda() -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[b"config"], &crate::ID)
+    }
+
+    // Loads the config, None until the upgrade authority has created it
+    pub fn load(account: &AccountInfo) -> Result<Option<Self>, ProgramError> {
+        if *account.key != Self::pda().0 {
+            return Err(ProgramError::InvalidSeeds);
+        }
+
+        if account.owner != &crate::ID || account.data_len() != Self::LEN {
+            return Ok(None);
+        }
+
+        let config = Config::try_from_slice(&account.data.borrow())?;
+        Ok((config.admin != Pubkey::default()).then_some(config))
+    }
+}
+
+// The account has to match the shared layout byte for byte
+const _: () = assert!(Config::LEN == fundraiser_interface::state::config::LEN);
\ No newline at end of file
@@ -1,7 +1,24 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 
+use crate::state::MAX_ACCEPTED_MINTS;
+
 #[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
 pub struct Contributor {
     pub amount: u64,
+    pub voted_milestone: u8,
+    pub amounts: [u64; MAX_ACCEPTED_MINTS],
+    // 0 without a reward, otherwise the index of the tier plus one
+    pub reward_tier: u8,
+    pub bump: u8,
+}
+
+impl Contributor {
+    pub const LEN: usize = 8 + // amount
+                          1 + // voted_milestone
+                          8 * MAX_ACCEPTED_MINTS + // amounts
+                          1 + // reward_tier
+                          1;  // bump
 }
 
+// The account has to match the shared layout byte for byte
+const _: () = assert!(Contributor::LEN == fundraiser_interface::state::contributor::LEN);
\ No newline at end of file
@@ -4,6 +4,9 @@ use solana_program::{
     program_error::ProgramError,
     pubkey::Pubkey,
 };
+
+use crate::{error::FundraiserError, state::PriceFeed};
+
 #[derive(BorshSerialize, BorshDeserialize)]
 pub struct Fundraiser {
     pub maker: Pubkey,
@@ -12,6 +15,114 @@ pub struct Fundraiser {
     pub current_amount: u64,
     pub time_ending: i64,
     pub bump: u8,
+    pub campaign_id: u64,
+    pub status: FundraiserStatus,
+    pub milestone_count: u8,
+    pub milestones: [Milestone; MAX_MILESTONES],
+    pub milestones_released: u8,
+    pub amount_released: u64,
+    pub voting_period: i64,
+    pub quorum_bps: u16,
+    pub approval_bps: u16,
+    pub votes_for: u64,
+    pub votes_against: u64,
+    pub funding_mode: FundingMode,
+    pub price_authority: Pubkey,
+    pub accepted_mint_count: u8,
+    pub accepted_mints: [Pubkey; MAX_ACCEPTED_MINTS],
+    pub amounts_raised: [u64; MAX_ACCEPTED_MINTS],
+    pub contribution_limits: ContributionLimits,
+    pub receipt_mint: Pubkey,
+    // The platform fee in force when the fundraiser was created
+    pub fee_bps: u16,
+    // How many times the maker can extend the deadline or lower the goal, and how many times they did
+    pub max_amendments: u8,
+    pub amendment_count: u8,
+    // Until then contributors can withdraw even from a fundraiser that has not failed
+    pub grace_ending: i64,
+}
+
+pub use fundraiser_interface::state::{AMENDMENT_GRACE_PERIOD, BPS_SCALER, MAX_ACCEPTED_MINTS, MAX_MILESTONES, PERCENTAGE_SCALER};
+
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, Default)]
+pub struct Milestone {
+    pub percentage: u8,
+    pub unlock_time: i64,
+}
+
+impl Milestone {
+    pub const LEN: usize = 1 + // percentage
+                          8;   // unlock_time
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy)]
+pub struct ContributionLimits {
+    // Smallest single contribution, 0 only rejects empty contributions
+    pub min_contribution: u64,
+    // Most a single contributor can put in, read according to `max_contribution_kind`
+    pub max_contribution: u64,
+    pub max_contribution_kind: MaxContributionKind,
+    // Most the fundraiser can raise in total, 0 leaves it uncapped
+    pub hard_cap: u64,
+}
+
+impl ContributionLimits {
+    pub const LEN: usize = 8 + // min_contribution
+                          8 + // max_contribution
+                          1 + // max_contribution_kind
+                          8;  // hard_cap
+
+    pub fn validate(&self, amount_to_raise: u64) -> Result<(), ProgramError> {
+        if self.max_contribution_kind == MaxContributionKind::Percentage
+            && self.max_contribution > PERCENTAGE_SCALER
+        {
+            return Err(ProgramError::Custom(FundraiserError::InvalidContributionLimits as u32));
+        }
+
+        // Every contributor must be able to put in at least the minimum
+        if let Some(max_contribution) = self.max_per_contributor(amount_to_raise) {
+            if max_contribution == 0 || max_contribution < self.min_contribution {
+                return Err(ProgramError::Custom(FundraiserError::InvalidContributionLimits as u32));
+            }
+        }
+
+        // A hard cap below the goal would make the goal unreachable
+        if self.hard_cap != 0 && self.hard_cap < amount_to_raise {
+            return Err(ProgramError::Custom(FundraiserError::InvalidContributionLimits as u32));
+        }
+
+        Ok(())
+    }
+
+    pub fn max_per_contributor(&self, amount_to_raise: u64) -> Option<u64> {
+        match self.max_contribution_kind {
+            MaxContributionKind::None => None,
+            MaxContributionKind::Absolute => Some(self.max_contribution),
+            MaxContributionKind::Percentage => Some(
+                (amount_to_raise as u128 * self.max_contribution as u128 / PERCENTAGE_SCALER as u128) as u64
+            ),
+        }
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MaxContributionKind {
+    None,
+    Absolute,
+    Percentage,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FundraiserStatus {
+    Active,
+    Cancelled,
+    Rejected,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FundingMode {
+    Fixed,
+    Flexible,
 }
 
 impl Fundraiser {
@@ -20,7 +131,29 @@ impl Fundraiser {
                           8 +  // amount_to_raise
                           8 +  // current_amount
                           8 +  // time_ending
-                          1;   // bump
+                          1 +  // bump
+                          8 +  // campaign_id
+                          1 +  // status
+                          1 +  // milestone_count
+                          Milestone::LEN * MAX_MILESTONES + // milestones
+                          1 +  // milestones_released
+                          8 +  // amount_released
+                          8 +  // voting_period
+                          2 +  // quorum_bps
+                          2 +  // approval_bps
+                          8 +  // votes_for
+                          8 +  // votes_against
+                          1 +  // funding_mode
+                          32 + // price_authority
+                          1 +  // accepted_mint_count
+                          32 * MAX_ACCEPTED_MINTS + // accepted_mints
+                          8 * MAX_ACCEPTED_MINTS + // amounts_raised
+                          ContributionLimits::LEN + // contribution_limits
+                          32 + // receipt_mint
+                          2 +  // fee_bps
+                          1 +  // max_amendments
+                          1 +  // amendment_count
+                          8;   // grace_ending
 
     pub fn init(    
         account: &AccountInfo,
@@ -29,7 +162,19 @@ impl Fundraiser {
         amount_to_raise: u64,
         time_ending: i64, 
         bump: u8,
+        campaign_id: u64,
+        milestones: &[Milestone],
+        voting_period: i64,
+        quorum_bps: u16,
+        approval_bps: u16,
+        funding_mode: FundingMode,
+        contribution_limits: ContributionLimits,
+        fee_bps: u16,
+        max_amendments: u8,
     ) -> Result<(), ProgramError> {
+        let mut schedule = [Milestone::default(); MAX_MILESTONES];
+        schedule[..milestones.len()].copy_from_slice(milestones);
+
         let fundraiser = Fundraiser {
             maker,
             mint_to_raise,
@@ -37,9 +182,144 @@ impl Fundraiser {
             current_amount: 0,
             time_ending,
             bump,
+            campaign_id,
+            status: FundraiserStatus::Active,
+            milestone_count: milestones.len() as u8,
+            milestones: schedule,
+            milestones_released: 0,
+            amount_released: 0,
+            voting_period,
+            quorum_bps,
+            approval_bps,
+            votes_for: 0,
+            votes_against: 0,
+            funding_mode,
+            price_authority: Pubkey::default(),
+            accepted_mint_count: 0,
+            accepted_mints: [Pubkey::default(); MAX_ACCEPTED_MINTS],
+            amounts_raised: [0; MAX_ACCEPTED_MINTS],
+            contribution_limits,
+            receipt_mint: Pubkey::default(),
+            fee_bps,
+            max_amendments,
+            amendment_count: 0,
+            grace_ending: 0,
         };
 
         fundraiser.serialize(&mut *account.try_borrow_mut_data()?)?;
         Ok(())
     }
-}
\ No newline at end of file
+
+    // Votes always apply to the next milestone waiting to be released
+    pub fn milestone_approved(&self) -> bool {
+        let total_votes = self.votes_for as u128 + self.votes_against as u128;
+
+        // Enough of the raised amount has to take part in the vote...
+        let quorum_met = total_votes * BPS_SCALER as u128
+            >= self.current_amount as u128 * self.quorum_bps as u128;
+
+        // ...and enough of the votes cast have to approve the release
+        let approved = self.votes_for as u128 * BPS_SCALER as u128
+            >= total_votes * self.approval_bps as u128;
+
+        quorum_met && approved
+    }
+
+    // A SOL fundraiser has no mint, the fundraiser account itself holds the raised lamports
+    pub fn is_sol(&self) -> bool {
+        self.mint_to_raise == Pubkey::default()
+    }
+
+    // A multi-mint fundraiser sets its goal in a common unit and values each accepted mint through a price feed
+    pub fn is_multi_mint(&self) -> bool {
+        self.accepted_mint_count > 0
+    }
+
+    // Contributions mint receipt tokens to the contributor, and refunds burn them
+    pub fn issues_receipts(&self) -> bool {
+        self.receipt_mint != Pubkey::default()
+    }
+
+    pub fn accepted_mint_index(&self, mint: &Pubkey) -> Option<usize> {
+        self.accepted_mints[..self.accepted_mint_count as usize]
+            .iter()
+            .position(|accepted_mint| accepted_mint == mint)
+    }
+
+    // Values everything raised at the current prices, the price feeds have to be passed in the order of the accepted mints
+    pub fn value_raised(&self, price_feeds: &[AccountInfo]) -> Result<u64, ProgramError> {
+        if price_feeds.len() != self.accepted_mint_count as usize {
+            return Err(ProgramError::Custom(FundraiserError::InvalidPriceFeed as u32));
+        }
+
+        let mut value: u64 = 0;
+        for (index, price_feed_info) in price_feeds.iter().enumerate() {
+            let price_feed = PriceFeed::load(price_feed_info, &self.price_authority, &self.accepted_mints[index])?;
+            value = value
+                .checked_add(price_feed.value(self.amounts_raised[index]))
+                .ok_or(ProgramError::ArithmeticOverflow)?;
+        }
+
+        Ok(value)
+    }
+
+    // Checks a contribution against the limits the maker set, `contributed` is what the contributor has put in so far
+    pub fn check_contribution(&self, contributed: u64, amount: u64) -> Result<(), ProgramError> {
+        let limits = &self.contribution_limits;
+
+        if amount == 0 || amount < limits.min_contribution {
+            return Err(ProgramError::Custom(FundraiserError::ContributionTooSmall as u32));
+        }
+
+        if let Some(max_contribution) = limits.max_per_contributor(self.amount_to_raise) {
+            if contributed.checked_add(amount).ok_or(ProgramError::ArithmeticOverflow)? > max_contribution {
+                return Err(ProgramError::Custom(FundraiserError::MaximumContributionsReached as u32));
+            }
+        }
+
+        if limits.hard_cap != 0
+            && self.current_amount.checked_add(amount).ok_or(ProgramError::ArithmeticOverflow)? > limits.hard_cap
+        {
+            return Err(ProgramError::Custom(FundraiserError::HardCapReached as u32));
+        }
+
+        Ok(())
+    }
+
+    // Counts an extended deadline or a lowered goal against the maker's allowance and lets contributors reconsider
+    pub fn amend(&mut self, current_time: i64) -> Result<(), ProgramError> {
+        if self.status != FundraiserStatus::Active {
+            return Err(ProgramError::Custom(FundraiserError::FundraiserCancelled as u32));
+        }
+
+        // The terms are settled once the fundraiser has ended
+        if current_time > self.time_ending {
+            return Err(ProgramError::Custom(FundraiserError::FundraiserEnded as u32));
+        }
+
+        if self.amendment_count >= self.max_amendments {
+            return Err(ProgramError::Custom(FundraiserError::AmendmentsExhausted as u32));
+        }
+
+        self.amendment_count += 1;
+        self.grace_ending = current_time + AMENDMENT_GRACE_PERIOD;
+        Ok(())
+    }
+
+    // Contributors can withdraw from an active fundraiser for a while after it was amended,
+    // and the maker cannot be paid until they had the chance to
+    pub fn in_grace_period(&self, current_time: i64) -> bool {
+        current_time < self.grace_ending
+    }
+
+    // A fixed fundraiser has to meet its target, a flexible one keeps whatever it raised once the deadline passes
+    pub fn funds_secured(&self, current_time: i64) -> bool {
+        self.current_amount >= self.amount_to_raise
+            || (self.funding_mode == FundingMode::Flexible && current_time >= self.time_ending)
+    }
+}
+
+// The account has to match the shared layout byte for byte
+const _: () = assert!(Fundraiser::LEN == fundraiser_interface::state::fundraiser::LEN);
+const _: () = assert!(Milestone::LEN == fundraiser_interface::state::milestone::LEN);
+const _: () = assert!(ContributionLimits::LEN == fundraiser_interface::state::contribution_limits::LEN);
\ No newline at end of file
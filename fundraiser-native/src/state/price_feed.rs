@@ -0,0 +1,47 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::error::FundraiserError;
+
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
+pub struct PriceFeed {
+    pub authority: Pubkey,
+    pub mint: Pubkey,
+    pub decimals: u8,
+    pub price: u64,
+    pub bump: u8,
+}
+
+impl PriceFeed {
+    pub const LEN: usize = 32 + // authority
+                          32 + // mint
+                          1 +  // decimals
+                          8 +  // price
+                          1;   // bump
+
+    // Loads a price feed and checks it is the one the authority keeps for this mint
+    pub fn load(account: &AccountInfo, authority: &Pubkey, mint: &Pubkey) -> Result<Self, ProgramError> {
+        if account.owner != &crate::ID {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+
+        let price_feed = PriceFeed::try_from_slice(&account.data.borrow())?;
+        if price_feed.authority != *authority || price_feed.mint != *mint || price_feed.price == 0 {
+            return Err(ProgramError::Custom(FundraiserError::InvalidPriceFeed as u32));
+        }
+
+        Ok(price_feed)
+    }
+
+    // The price is what one whole token is worth in base units of the common unit
+    pub fn value(&self, amount: u64) -> u64 {
+        (amount as u128 * self.price as u128 / 10u128.pow(self.decimals as u32)) as u64
+    }
+}
+
+// The account has to match the shared layout byte for byte
+const _: () = assert!(PriceFeed::LEN == fundraiser_interface::state::price_feed::LEN);
\ No newline at end of file
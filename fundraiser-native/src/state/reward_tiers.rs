@@ -0,0 +1,88 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::error::FundraiserError;
+
+pub use fundraiser_interface::state::MAX_REWARD_TIERS;
+
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, Default)]
+pub struct RewardTier {
+    pub min_pledge: u64,
+    pub max_supply: u64,
+}
+
+impl RewardTier {
+    pub const LEN: usize = 8 + // min_pledge
+                          8;   // max_supply
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
+pub struct RewardTiers {
+    pub fundraiser: Pubkey,
+    pub tier_count: u8,
+    pub tiers: [RewardTier; MAX_REWARD_TIERS],
+    pub claimed: [u64; MAX_REWARD_TIERS],
+    pub bump: u8,
+}
+
+impl RewardTiers {
+    pub const LEN: usize = 32 + // fundraiser
+                          1 +  // tier_count
+                          RewardTier::LEN * MAX_REWARD_TIERS + // tiers
+                          8 * MAX_REWARD_TIERS + // claimed
+                          1;   // bump
+
+    // Loads the reward tiers and checks they belong to the fundraiser
+    pub fn load(account: &AccountInfo, fundraiser: &Pubkey) -> Result<Self, ProgramError> {
+        if account.owner != &crate::ID {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+
+        let reward_tiers = RewardTiers::try_from_slice(&account.data.borrow())?;
+        if reward_tiers.fundraiser != *fundraiser {
+            return Err(ProgramError::Custom(FundraiserError::InvalidRewardTier as u32));
+        }
+
+        Ok(reward_tiers)
+    }
+
+    // Moves a contributor from their current tier (0 for none, otherwise the index plus one) to `index`
+    // once their pledge reaches its minimum, staying in the same tier does not take another slot
+    pub fn claim(&mut self, current: u8, index: u8, pledge: u64) -> Result<(), ProgramError> {
+        if index >= self.tier_count {
+            return Err(ProgramError::Custom(FundraiserError::InvalidRewardTier as u32));
+        }
+
+        let tier = self.tiers[index as usize];
+        if pledge < tier.min_pledge {
+            return Err(ProgramError::Custom(FundraiserError::PledgeBelowTierMinimum as u32));
+        }
+
+        if current == index + 1 {
+            return Ok(());
+        }
+
+        if self.claimed[index as usize] >= tier.max_supply {
+            return Err(ProgramError::Custom(FundraiserError::RewardTierSoldOut as u32));
+        }
+
+        self.release(current);
+        self.claimed[index as usize] += 1;
+
+        Ok(())
+    }
+
+    // Gives the slot of a contributor's tier back, 0 holds no slot
+    pub fn release(&mut self, current: u8) {
+        if current > 0 {
+            self.claimed[current as usize - 1] -= 1;
+        }
+    }
+}
+
+// The account has to match the shared layout byte for byte
+const _: () = assert!(RewardTiers::LEN == fundraiser_interface::state::reward_tiers::LEN);
\ No newline at end of file
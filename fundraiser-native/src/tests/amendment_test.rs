@@ -0,0 +1,199 @@
+use crate::{
+    error::FundraiserError,
+    state::{Contributor, Fundraiser, AMENDMENT_GRACE_PERIOD},
+    tests::setup,
+};
+use mollusk_svm::result::Check;
+use solana_sdk::{
+    account::{AccountSharedData, ReadableAccount},
+    instruction::{AccountMeta, Instruction},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    system_program,
+};
+
+#[test]
+fn amendment_test() {
+    let (program_id, mollusk) = setup();
+
+    let maker = Pubkey::new_from_array([0x1; 32]);
+    let signer = Pubkey::new_from_array([0x2; 32]);
+    let campaign_id = 0u64;
+    let (fundraiser, bump) =
+        Pubkey::find_program_address(&[b"fundraiser".as_ref(), &maker.to_bytes().as_ref(), &campaign_id.to_le_bytes()], &program_id);
+    let (contributor, contributor_bump) = Pubkey::find_program_address(
+        &[
+            b"contributor",
+            fundraiser.as_ref(),
+            signer.to_bytes().as_ref(),
+        ],
+        &program_id,
+    );
+
+    let current_time = mollusk.sysvars.clock.unix_timestamp;
+    let time_ending = current_time + 1_000;
+
+    // A flexible SOL fundraiser that allows a single amendment, with one contribution in it
+    let rent_exempt_lamports = mollusk.sysvars.rent.minimum_balance(Fundraiser::LEN);
+    let mut fundraiser_account = AccountSharedData::new(
+        rent_exempt_lamports + 100_000_000,
+        Fundraiser::LEN,
+        &program_id,
+    );
+    fundraiser_account.set_data_from_slice(
+        &[
+            maker.to_bytes().to_vec(),
+            Pubkey::default().to_bytes().to_vec(), // mint_to_raise
+            1_000_000_000u64.to_le_bytes().to_vec(), // amount_to_raise
+            100_000_000u64.to_le_bytes().to_vec(), // current_amount
+            time_ending.to_le_bytes().to_vec(), // time_ending
+            bump.to_le_bytes().to_vec(),   // bump
+            campaign_id.to_le_bytes().to_vec(), // campaign_id
+            0u8.to_le_bytes().to_vec(), // status
+            0u8.to_le_bytes().to_vec(), // milestone_count
+            vec![0u8; 36], // milestones
+            0u8.to_le_bytes().to_vec(), // milestones_released
+            0u64.to_le_bytes().to_vec(), // amount_released
+            0i64.to_le_bytes().to_vec(), // voting_period
+            0u16.to_le_bytes().to_vec(), // quorum_bps
+            0u16.to_le_bytes().to_vec(), // approval_bps
+            0u64.to_le_bytes().to_vec(), // votes_for
+            0u64.to_le_bytes().to_vec(), // votes_against
+            1u8.to_le_bytes().to_vec(), // funding_mode
+            Pubkey::default().to_bytes().to_vec(), // price_authority
+            0u8.to_le_bytes().to_vec(), // accepted_mint_count
+            vec![0u8; 96], // accepted_mints
+            vec![0u8; 24], // amounts_raised
+            vec![0u8; 25], // contribution_limits
+            Pubkey::default().to_bytes().to_vec(), // receipt_mint
+            0u16.to_le_bytes().to_vec(), // fee_bps
+            1u8.to_le_bytes().to_vec(), // max_amendments
+            0u8.to_le_bytes().to_vec(), // amendment_count
+            0i64.to_le_bytes().to_vec(), // grace_ending
+        ]
+        .concat(),
+    );
+
+    let mut contributor_account = crate::tests::create_account(
+        mollusk.sysvars.rent.minimum_balance(Contributor::LEN),
+        Contributor::LEN,
+        &program_id,
+    );
+    contributor_account.set_data_from_slice(&[100_000_000u64.to_le_bytes().to_vec(), vec![0u8], vec![0u8; 24], vec![0u8], vec![contributor_bump]].concat());
+
+    let amend = |data: Vec<u8>| {
+        Instruction::new_with_bytes(
+            program_id,
+            &data,
+            vec![
+                AccountMeta::new(maker, true),
+                AccountMeta::new(fundraiser, false),
+            ],
+        )
+    };
+    let refund_instruction = Instruction::new_with_bytes(
+        program_id,
+        &[vec![11]].concat(),
+        vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(fundraiser, false),
+            AccountMeta::new(contributor, false),
+        ],
+    );
+    let signer_account = AccountSharedData::new(0, 0, &system_program::id());
+
+    // A flexible fundraiser keeps what it raises until it is amended
+    mollusk.process_and_validate_instruction(
+        &refund_instruction,
+        &vec![
+            (signer, signer_account.clone()),
+            (fundraiser, fundraiser_account.clone()),
+            (contributor, contributor_account.clone()),
+        ],
+        &[Check::err(ProgramError::Custom(FundraiserError::RefundsDisabled as u32))],
+    );
+
+    // The deadline can only move out and the goal can only come down
+    for (data, error) in [
+        ([vec![22], (time_ending - 1).to_le_bytes().to_vec()].concat(), FundraiserError::InvalidAmendment),
+        ([vec![23], 2_000_000_000u64.to_le_bytes().to_vec()].concat(), FundraiserError::InvalidAmendment),
+        ([vec![23], 0u64.to_le_bytes().to_vec()].concat(), FundraiserError::InvalidAmount),
+    ] {
+        mollusk.process_and_validate_instruction(
+            &amend(data),
+            &vec![
+                (maker, AccountSharedData::new(0, 0, &system_program::id())),
+                (fundraiser, fundraiser_account.clone()),
+            ],
+            &[Check::err(ProgramError::Custom(error as u32))],
+        );
+    }
+
+    let result = mollusk.process_and_validate_instruction(
+        &amend([vec![23], 500_000_000u64.to_le_bytes().to_vec()].concat()),
+        &vec![
+            (maker, AccountSharedData::new(0, 0, &system_program::id())),
+            (fundraiser, fundraiser_account.clone()),
+        ],
+        &[Check::success()],
+    );
+    assert!(!result.program_result.is_err(), "amend_goal failed.");
+    println!("Compute Units: {}", result.compute_units_consumed);
+
+    let fundraiser_result = result
+        .get_account(&fundraiser)
+        .expect("Failed to find fundraiser account")
+        .clone();
+    let data = fundraiser_result.data();
+    assert_eq!(u64::from_le_bytes(data[64..72].try_into().unwrap()), 500_000_000, "The goal should be lowered");
+    assert_eq!(data[Fundraiser::LEN - 9], 1, "The amendment should be counted");
+    assert_eq!(
+        i64::from_le_bytes(data[Fundraiser::LEN - 8..].try_into().unwrap()),
+        current_time + AMENDMENT_GRACE_PERIOD,
+        "The grace period should start with the amendment"
+    );
+
+    // The single amendment is used up
+    mollusk.process_and_validate_instruction(
+        &amend([vec![22], (time_ending + 1_000).to_le_bytes().to_vec()].concat()),
+        &vec![
+            (maker, AccountSharedData::new(0, 0, &system_program::id())),
+            (fundraiser, fundraiser_result.clone()),
+        ],
+        &[Check::err(ProgramError::Custom(FundraiserError::AmendmentsExhausted as u32))],
+    );
+
+    // The maker cannot be paid while contributors can still withdraw
+    mollusk.process_and_validate_instruction(
+        &Instruction::new_with_bytes(
+            program_id,
+            &[vec![10]].concat(),
+            vec![
+                AccountMeta::new(maker, true),
+                AccountMeta::new(fundraiser, false),
+            ],
+        ),
+        &vec![
+            (maker, AccountSharedData::new(0, 0, &system_program::id())),
+            (fundraiser, fundraiser_result.clone()),
+        ],
+        &[Check::err(ProgramError::Custom(FundraiserError::GracePeriodActive as u32))],
+    );
+
+    // Within the grace period the contributor can get out of the flexible fundraiser
+    let result = mollusk.process_and_validate_instruction(
+        &refund_instruction,
+        &vec![
+            (signer, signer_account),
+            (fundraiser, fundraiser_result),
+            (contributor, contributor_account),
+        ],
+        &[Check::success()],
+    );
+    assert!(!result.program_result.is_err(), "refund_sol failed.");
+    assert_eq!(
+        result.get_account(&signer).unwrap().lamports(),
+        100_000_000,
+        "Contributor should have received their refund"
+    );
+}
\ No newline at end of file
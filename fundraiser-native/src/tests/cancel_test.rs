@@ -0,0 +1,148 @@
+use crate::{
+    state::{Contributor, Fundraiser},
+    tests::setup,
+};
+use mollusk_svm::result::Check;
+use solana_sdk::{
+    account::{AccountSharedData, ReadableAccount},
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    system_program,
+};
+
+#[test]
+fn cancel_test() {
+    let (program_id, mollusk) = setup();
+    let (token_program, token_program_account) = mollusk_token::token::keyed_account();
+    let (system_program, system_program_account) = mollusk_svm::program::keyed_account_for_system_program();
+
+    let maker = Pubkey::new_from_array([0x1; 32]);
+    let campaign_id = 0u64;
+    let (fundraiser, bump) =
+        Pubkey::find_program_address(&[b"fundraiser".as_ref(), &maker.to_bytes().as_ref(), &campaign_id.to_le_bytes()], &program_id);
+    let mint = Pubkey::new_from_array([0x4; 32]);
+
+    let mut fundraiser_account = AccountSharedData::new(
+        mollusk.sysvars.rent.minimum_balance(Fundraiser::LEN),
+        Fundraiser::LEN,
+        &program_id,
+    );
+
+    fundraiser_account.set_data_from_slice(
+        &[
+            maker.to_bytes().to_vec(),
+            mint.to_bytes().to_vec(),
+            100_000_000u64.to_le_bytes().to_vec(), // amount_to_raise
+            0u64.to_le_bytes().to_vec(), // current_amount
+            i64::MAX.to_le_bytes().to_vec(), // time_ending
+            bump.to_le_bytes().to_vec(),   // bump
+            campaign_id.to_le_bytes().to_vec(), // campaign_id
+            0u8.to_le_bytes().to_vec(), // status
+            0u8.to_le_bytes().to_vec(), // milestone_count
+            vec![0u8; 36], // milestones
+            0u8.to_le_bytes().to_vec(), // milestones_released
+            0u64.to_le_bytes().to_vec(), // amount_released
+            0i64.to_le_bytes().to_vec(), // voting_period
+            0u16.to_le_bytes().to_vec(), // quorum_bps
+            0u16.to_le_bytes().to_vec(), // approval_bps
+            0u64.to_le_bytes().to_vec(), // votes_for
+            0u64.to_le_bytes().to_vec(), // votes_against
+            0u8.to_le_bytes().to_vec(), // funding_mode
+            Pubkey::default().to_bytes().to_vec(), // price_authority
+            0u8.to_le_bytes().to_vec(), // accepted_mint_count
+            vec![0u8; 96], // accepted_mints
+            vec![0u8; 24], // amounts_raised
+            vec![0u8; 25], // contribution_limits
+            Pubkey::default().to_bytes().to_vec(), // receipt_mint
+            0u16.to_le_bytes().to_vec(), // fee_bps
+            0u8.to_le_bytes().to_vec(), // max_amendments
+            0u8.to_le_bytes().to_vec(), // amendment_count
+            0i64.to_le_bytes().to_vec(), // grace_ending
+        ]
+        .concat(),
+    );
+
+    let cancel_instruction = Instruction::new_with_bytes(
+        program_id,
+        &[vec![4]].concat(),
+        vec![
+            AccountMeta::new(maker, true),
+            AccountMeta::new(fundraiser, false),
+        ],
+    );
+
+    let result = mollusk.process_and_validate_instruction(
+        &cancel_instruction,
+        &vec![
+            (maker, AccountSharedData::new(100_000_000, 0, &system_program::id())),
+            (fundraiser, fundraiser_account),
+        ],
+        &[Check::success()],
+    );
+    assert!(
+        !result.program_result.is_err(),
+        "process_cancel failed."
+    );
+    println!("Compute Units: {}", result.compute_units_consumed);
+
+    let cancelled_fundraiser_account = result
+        .get_account(&fundraiser)
+        .expect("Failed to find fundraiser account")
+        .clone();
+    let data = cancelled_fundraiser_account.data();
+    assert_eq!(data[97], 1, "Fundraiser should be cancelled");
+
+    // Contributions are rejected once the fundraiser is cancelled
+    let signer = Pubkey::new_from_array([0x2; 32]);
+    let signer_ta = Pubkey::new_from_array([0x3; 32]);
+    let vault = Pubkey::new_from_array([0x5; 32]);
+    let contributor = Pubkey::find_program_address(
+        &[
+            b"contributor",
+            fundraiser.as_ref(),
+            signer.to_bytes().as_ref(),
+        ],
+        &program_id,
+    )
+    .0;
+
+    let contribute_instruction = Instruction::new_with_bytes(
+        program_id,
+        &[vec![2], 300_000u64.to_le_bytes().to_vec()].concat(),
+        vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(contributor, true),
+            AccountMeta::new(signer_ta, false),
+            AccountMeta::new(fundraiser, false),
+            AccountMeta::new(vault, false),
+            AccountMeta::new(token_program, false),
+            AccountMeta::new_readonly(mint, false),
+            AccountMeta::new_readonly(system_program, false),
+        ],
+    );
+
+    let result = mollusk.process_instruction(
+        &contribute_instruction,
+        &vec![
+            (signer, AccountSharedData::new(100_000_000, 0, &system_program::id())),
+            (
+                contributor,
+                crate::tests::create_account(
+                    mollusk.sysvars.rent.minimum_balance(Contributor::LEN),
+                    Contributor::LEN,
+                    &program_id,
+                ),
+            ),
+            (signer_ta, crate::tests::pack_token_account(&signer, &mint, 2_000_000_000)),
+            (fundraiser, cancelled_fundraiser_account),
+            (vault, crate::tests::pack_token_account(&fundraiser, &mint, 0)),
+            (token_program, token_program_account),
+            (mint, crate::tests::pack_mint(&maker, 2_000_000_000)),
+            (system_program, system_program_account.clone()),
+        ],
+    );
+    assert!(
+        result.program_result.is_err(),
+        "Contributions should be rejected after cancellation"
+    );
+}
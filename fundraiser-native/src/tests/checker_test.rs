@@ -1,5 +1,5 @@
 use crate::{
-    state::Fundraiser,
+    state::{Contributor, Fundraiser},
     tests::setup,
 };
 use mollusk_svm::result::Check;
@@ -15,8 +15,10 @@ use solana_sdk::{
 fn check_test() {
     let (program_id, mollusk) = setup();
     let (token_program, token_program_account) = mollusk_token::token::keyed_account();
+    let (system_program, system_program_account) = mollusk_svm::program::keyed_account_for_system_program();
 
     let maker = Pubkey::new_from_array([0x1; 32]);
+    let campaign_id = 0u64;
     let signer = maker;
     let signer_account = crate::tests::create_account(
         mollusk
@@ -28,7 +30,7 @@ fn check_test() {
     );
     let signer_ta = Pubkey::new_from_array([0x3; 32]);
     let (fundraiser, bump) =
-        Pubkey::find_program_address(&[b"fundraiser".as_ref(), &maker.to_bytes().as_ref()], &program_id);
+        Pubkey::find_program_address(&[b"fundraiser".as_ref(), &maker.to_bytes().as_ref(), &campaign_id.to_le_bytes()], &program_id);
     let contributor = Pubkey::find_program_address(
         &[
             b"contributor",
@@ -56,8 +58,8 @@ fn check_test() {
         &program_id,
     );
     let contributor_account = crate::tests::create_account(
-        mollusk.sysvars.rent.minimum_balance(8),
-        8,
+        mollusk.sysvars.rent.minimum_balance(Contributor::LEN),
+        Contributor::LEN,
         &program_id,
     );
 
@@ -65,10 +67,32 @@ fn check_test() {
         &[
             maker.to_bytes().to_vec(),
             mint.to_bytes().to_vec(),
-            200_000u64.to_le_bytes().to_vec(),
+            100_000u64.to_le_bytes().to_vec(),
             0u64.to_le_bytes().to_vec(),
             i64::MAX.to_le_bytes().to_vec(), 
             bump.to_le_bytes().to_vec(),
+            campaign_id.to_le_bytes().to_vec(),
+            0u8.to_le_bytes().to_vec(), // status
+            0u8.to_le_bytes().to_vec(), // milestone_count
+            vec![0u8; 36], // milestones
+            0u8.to_le_bytes().to_vec(), // milestones_released
+            0u64.to_le_bytes().to_vec(), // amount_released
+            0i64.to_le_bytes().to_vec(), // voting_period
+            0u16.to_le_bytes().to_vec(), // quorum_bps
+            0u16.to_le_bytes().to_vec(), // approval_bps
+            0u64.to_le_bytes().to_vec(), // votes_for
+            0u64.to_le_bytes().to_vec(), // votes_against
+            0u8.to_le_bytes().to_vec(), // funding_mode
+            Pubkey::default().to_bytes().to_vec(), // price_authority
+            0u8.to_le_bytes().to_vec(), // accepted_mint_count
+            vec![0u8; 96], // accepted_mints
+            vec![0u8; 24], // amounts_raised
+            vec![0u8; 25], // contribution_limits
+            Pubkey::default().to_bytes().to_vec(), // receipt_mint
+            0u16.to_le_bytes().to_vec(), // fee_bps
+            0u8.to_le_bytes().to_vec(), // max_amendments
+            0u8.to_le_bytes().to_vec(), // amendment_count
+            0i64.to_le_bytes().to_vec(), // grace_ending
         ]
         .concat(),
     );
@@ -92,6 +116,8 @@ fn check_test() {
             AccountMeta::new(fundraiser, false),
             AccountMeta::new(vault, false),
             AccountMeta::new(token_program, false),
+            AccountMeta::new_readonly(mint, false),
+            AccountMeta::new_readonly(system_program, false),
         ],
     );
 
@@ -104,6 +130,8 @@ fn check_test() {
             (fundraiser, fundraiser_account.clone()),
             (vault, vault_account.clone()),
             (token_program, token_program_account.clone()),
+            (mint, mint_account.clone()),
+            (system_program, system_program_account.clone()),
         ],
         &[Check::success()],
     );
@@ -161,7 +189,7 @@ fn check_test() {
         &vec![
             (signer, signer_account),
             (mint, mint_account),
-            (fundraiser, fundraiser_account),
+            (fundraiser, fundraiser_result_account.clone()),
             (vault, vault_result_account.clone()),
             (signer_ta, signer_ta_account),
             (token_program, token_program_account),
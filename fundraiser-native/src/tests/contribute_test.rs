@@ -1,17 +1,21 @@
+use mollusk_svm::result::Check;
 use solana_sdk::{
-    account::ReadableAccount,
+    account::{AccountSharedData, ReadableAccount},
     instruction::{AccountMeta, Instruction},
+    program_error::ProgramError,
     program_pack::Pack,
     pubkey::Pubkey,
 };
-use crate::{state::Fundraiser, tests::setup};
+use crate::{error::FundraiserError, state::{Contributor, Fundraiser}, tests::setup};
 
 #[test]
 fn contribute_test() {
     let (program_id, mollusk) = setup();
     let (token_program, token_program_account) = mollusk_token::token::keyed_account();
+    let (system_program, system_program_account) = mollusk_svm::program::keyed_account_for_system_program();
 
     let maker = Pubkey::new_from_array([0x1; 32]);
+    let campaign_id = 0u64;
     let signer = Pubkey::new_from_array([0x2; 32]);
     let signer_account = crate::tests::create_account(
         mollusk
@@ -22,8 +26,8 @@ fn contribute_test() {
         &program_id,
     );
     let signer_ta = Pubkey::new_from_array([0x3; 32]);
-    let fundraiser =
-        Pubkey::find_program_address(&[b"fundraiser", &maker.to_bytes()], &program_id).0;
+    let (fundraiser, bump) =
+        Pubkey::find_program_address(&[b"fundraiser", &maker.to_bytes(), &campaign_id.to_le_bytes()], &program_id);
     let contributor = Pubkey::find_program_address(
         &[
             b"contributor",
@@ -45,8 +49,8 @@ fn contribute_test() {
         &program_id,
     );
     let contributor_account = crate::tests::create_account(
-        mollusk.sysvars.rent.minimum_balance(8),
-        8,
+        mollusk.sysvars.rent.minimum_balance(Contributor::LEN),
+        Contributor::LEN,
         &program_id,
     );
 
@@ -58,7 +62,29 @@ fn contribute_test() {
             100_000_000u64.to_le_bytes().to_vec(),
             0u64.to_le_bytes().to_vec(),
             i64::MAX.to_le_bytes().to_vec(), 
-            0u8.to_le_bytes().to_vec(),
+            bump.to_le_bytes().to_vec(),
+            campaign_id.to_le_bytes().to_vec(),
+            0u8.to_le_bytes().to_vec(), // status
+            0u8.to_le_bytes().to_vec(), // milestone_count
+            vec![0u8; 36], // milestones
+            0u8.to_le_bytes().to_vec(), // milestones_released
+            0u64.to_le_bytes().to_vec(), // amount_released
+            0i64.to_le_bytes().to_vec(), // voting_period
+            0u16.to_le_bytes().to_vec(), // quorum_bps
+            0u16.to_le_bytes().to_vec(), // approval_bps
+            0u64.to_le_bytes().to_vec(), // votes_for
+            0u64.to_le_bytes().to_vec(), // votes_against
+            0u8.to_le_bytes().to_vec(), // funding_mode
+            Pubkey::default().to_bytes().to_vec(), // price_authority
+            0u8.to_le_bytes().to_vec(), // accepted_mint_count
+            vec![0u8; 96], // accepted_mints
+            vec![0u8; 24], // amounts_raised
+            vec![0u8; 25], // contribution_limits
+            Pubkey::default().to_bytes().to_vec(), // receipt_mint
+            0u16.to_le_bytes().to_vec(), // fee_bps
+            0u8.to_le_bytes().to_vec(), // max_amendments
+            0u8.to_le_bytes().to_vec(), // amendment_count
+            0i64.to_le_bytes().to_vec(), // grace_ending
         ]
         .concat(),
     );
@@ -76,18 +102,22 @@ fn contribute_test() {
             AccountMeta::new(fundraiser, false),
             AccountMeta::new(vault, false),
             AccountMeta::new(token_program, false),
+            AccountMeta::new_readonly(mint, false),
+            AccountMeta::new_readonly(system_program, false),
         ],
     );
 
     let result = mollusk.process_instruction_chain(
-        &[contribute_instruction],
+        &[contribute_instruction.clone()],
         &vec![
             (signer, signer_account),
             (contributor, contributor_account),
             (signer_ta, signer_ta_account),
             (fundraiser, fundraiser_account.clone()),
             (vault, vault_account.clone()),
-            (token_program, token_program_account),
+            (token_program, token_program_account.clone()),
+            (mint, crate::tests::pack_mint(&maker, 2_000_000_000)),
+            (system_program, system_program_account.clone()),
         ],
     );
     assert!(
@@ -115,4 +145,145 @@ fn contribute_test() {
         amount,
         "Contributor amount should be updated after contribution"
     );
+
+    // The first contribution creates the contributor account, paid for by the contributor
+    let result = mollusk.process_instruction(
+        &contribute_instruction,
+        &[
+            (signer, crate::tests::create_account(1_000_000_000, 0, &solana_sdk::system_program::ID)),
+            (contributor, AccountSharedData::default()),
+            (signer_ta, crate::tests::pack_token_account(&signer, &mint, 2_000_000_000)),
+            (fundraiser, fundraiser_account.clone()),
+            (vault, vault_account.clone()),
+            (token_program, token_program_account.clone()),
+            (mint, crate::tests::pack_mint(&maker, 2_000_000_000)),
+            (system_program, system_program_account.clone()),
+        ],
+    );
+    assert!(
+        !result.program_result.is_err(),
+        "Contribute should create the contributor account."
+    );
+
+    let contributor_result_account = result
+        .get_account(&contributor)
+        .expect("Failed to find contributor account");
+    assert_eq!(contributor_result_account.owner(), &program_id);
+    assert_eq!(
+        u64::from_le_bytes(contributor_result_account.data()[0..8].try_into().unwrap()),
+        amount,
+        "Contributor amount should be set by the first contribution"
+    );
+}
+
+#[test]
+fn contribute_limits_test() {
+    let (program_id, mollusk) = setup();
+    let (token_program, token_program_account) = mollusk_token::token::keyed_account();
+    let (system_program, system_program_account) = mollusk_svm::program::keyed_account_for_system_program();
+
+    let maker = Pubkey::new_from_array([0x1; 32]);
+    let campaign_id = 0u64;
+    let signer = Pubkey::new_from_array([0x2; 32]);
+    let signer_ta = Pubkey::new_from_array([0x3; 32]);
+    let (fundraiser, bump) =
+        Pubkey::find_program_address(&[b"fundraiser", &maker.to_bytes(), &campaign_id.to_le_bytes()], &program_id);
+    let contributor = Pubkey::find_program_address(
+        &[
+            b"contributor",
+            fundraiser.as_ref(),
+            signer.to_bytes().as_ref(),
+        ],
+        &program_id,
+    )
+    .0;
+    let mint = Pubkey::new_from_array([0x4; 32]);
+    let vault = Pubkey::new_from_array([0x5; 32]);
+
+    let mut fundraiser_account = crate::tests::create_account(
+        mollusk.sysvars.rent.minimum_balance(Fundraiser::LEN),
+        Fundraiser::LEN,
+        &program_id,
+    );
+
+    // 80_000_000 of the 100_000_000 goal is already raised, and the goal is also the hard cap
+    fundraiser_account.set_data_from_slice(
+        &[
+            maker.to_bytes().to_vec(),
+            mint.to_bytes().to_vec(),
+            100_000_000u64.to_le_bytes().to_vec(), // amount_to_raise
+            80_000_000u64.to_le_bytes().to_vec(), // current_amount
+            i64::MAX.to_le_bytes().to_vec(), // time_ending
+            bump.to_le_bytes().to_vec(), // bump
+            campaign_id.to_le_bytes().to_vec(),
+            0u8.to_le_bytes().to_vec(), // status
+            0u8.to_le_bytes().to_vec(), // milestone_count
+            vec![0u8; 36], // milestones
+            0u8.to_le_bytes().to_vec(), // milestones_released
+            0u64.to_le_bytes().to_vec(), // amount_released
+            0i64.to_le_bytes().to_vec(), // voting_period
+            0u16.to_le_bytes().to_vec(), // quorum_bps
+            0u16.to_le_bytes().to_vec(), // approval_bps
+            0u64.to_le_bytes().to_vec(), // votes_for
+            0u64.to_le_bytes().to_vec(), // votes_against
+            0u8.to_le_bytes().to_vec(), // funding_mode
+            Pubkey::default().to_bytes().to_vec(), // price_authority
+            0u8.to_le_bytes().to_vec(), // accepted_mint_count
+            vec![0u8; 96], // accepted_mints
+            vec![0u8; 24], // amounts_raised
+            10_000_000u64.to_le_bytes().to_vec(), // min_contribution
+            50_000_000u64.to_le_bytes().to_vec(), // max_contribution
+            1u8.to_le_bytes().to_vec(), // max_contribution_kind (Absolute)
+            100_000_000u64.to_le_bytes().to_vec(), // hard_cap
+            Pubkey::default().to_bytes().to_vec(), // receipt_mint
+            0u16.to_le_bytes().to_vec(), // fee_bps
+            0u8.to_le_bytes().to_vec(), // max_amendments
+            0u8.to_le_bytes().to_vec(), // amendment_count
+            0i64.to_le_bytes().to_vec(), // grace_ending
+        ]
+        .concat(),
+    );
+
+    for (amount, error) in [
+        (1_000_000u64, FundraiserError::ContributionTooSmall),
+        (60_000_000u64, FundraiserError::MaximumContributionsReached),
+        (30_000_000u64, FundraiserError::HardCapReached),
+    ] {
+        let contribute_instruction = Instruction::new_with_bytes(
+            program_id,
+            &[vec![2], amount.to_le_bytes().to_vec()].concat(),
+            vec![
+                AccountMeta::new(signer, true),
+                AccountMeta::new(contributor, true),
+                AccountMeta::new(signer_ta, false),
+                AccountMeta::new(fundraiser, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new(token_program, false),
+                AccountMeta::new_readonly(mint, false),
+                AccountMeta::new_readonly(system_program, false),
+            ],
+        );
+
+        mollusk.process_and_validate_instruction(
+            &contribute_instruction,
+            &vec![
+                (signer, crate::tests::create_account(0, 0, &program_id)),
+                (
+                    contributor,
+                    crate::tests::create_account(
+                        mollusk.sysvars.rent.minimum_balance(Contributor::LEN),
+                        Contributor::LEN,
+                        &program_id,
+                    ),
+                ),
+                (signer_ta, crate::tests::pack_token_account(&signer, &mint, 2_000_000_000)),
+                (fundraiser, fundraiser_account.clone()),
+                (vault, crate::tests::pack_token_account(&fundraiser, &mint, 80_000_000)),
+                (token_program, token_program_account.clone()),
+                (mint, crate::tests::pack_mint(&maker, 2_000_000_000)),
+                (system_program, system_program_account.clone()),
+            ],
+            &[Check::err(ProgramError::Custom(error as u32))],
+        );
+    }
 }
\ No newline at end of file
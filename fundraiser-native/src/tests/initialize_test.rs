@@ -11,38 +11,35 @@ use solana_sdk::{
     system_program
 };
 use spl_token::state::Mint;
+use spl_token_2022::{
+    extension::{transfer_hook::TransferHook, BaseStateWithExtensionsMut, ExtensionType, StateWithExtensionsMut},
+    state::Mint as Mint2022,
+};
 
 #[test]
 pub fn initialize_test() {
     let (program_id, mollusk) = setup();
+    let (token_program, token_program_account) = mollusk_token::token::keyed_account();
+    let (associated_token_program, associated_token_program_account) =
+        mollusk_token::associated_token::keyed_account();
+    let (system_program, system_program_account) = mollusk_svm::program::keyed_account_for_system_program();
 
     let maker = Pubkey::new_from_array([0x01; 32]);
-    let maker_account = crate::tests::create_account(
-        mollusk
-            .sysvars
-            .rent
-            .minimum_balance(spl_token::state::Account::LEN),
-        spl_token::state::Account::LEN,
-        &program_id,
-    );
+    let campaign_id = 0u64;
+    let maker_account = AccountSharedData::new(1_000_000_000, 0, &system_program::ID);
     
-    let (fundraiser, _) =
-        Pubkey::find_program_address(&[b"fundraiser", &maker.to_bytes()], &program_id);
+    let (fundraiser, bump) =
+        Pubkey::find_program_address(&[b"fundraiser", &maker.to_bytes(), &campaign_id.to_le_bytes()], &program_id);
+    let config = Pubkey::find_program_address(&[b"config"], &program_id).0;
 
-    // Create fundraiser account    
-    let fundraiser_data = vec![0; Fundraiser::LEN];
-    
-    // Create fundraiser account with data
-    let fundraiser_account = AccountSharedData::from(solana_sdk::account::Account {
-        lamports: mollusk.sysvars.rent.minimum_balance(Fundraiser::LEN),
-        data: fundraiser_data,
-        owner: program_id,
-        executable: false,
-        rent_epoch: 0,
-    });
-    
     // Create mint account
     let mint = Pubkey::new_from_array([0x02; 32]);
+
+    // The fundraiser and its vault do not exist yet, initialize creates both
+    let vault = Pubkey::find_program_address(
+        &[&fundraiser.to_bytes(), &token_program.to_bytes(), &mint.to_bytes()],
+        &associated_token_program,
+    ).0;
     let mut mint_data = vec![0; Mint::LEN];
     let mint_state = Mint {
         mint_authority: Some(maker).into(),
@@ -71,6 +68,16 @@ pub fn initialize_test() {
         0u8.to_le_bytes().to_vec(),
         amount.to_le_bytes().to_vec(),
         time_ending.to_le_bytes().to_vec(),
+        campaign_id.to_le_bytes().to_vec(),
+        bump.to_le_bytes().to_vec(),
+        0u8.to_le_bytes().to_vec(), // milestone_count
+        vec![0u8; 36], // milestones
+        0i64.to_le_bytes().to_vec(), // voting_period
+        0u16.to_le_bytes().to_vec(), // quorum_bps
+        0u16.to_le_bytes().to_vec(), // approval_bps
+        0u8.to_le_bytes().to_vec(), // funding_mode
+        vec![0u8; 25], // contribution_limits
+        vec![0], // max_amendments
     ].concat();
 
     let instruction = Instruction::new_with_bytes(
@@ -78,9 +85,13 @@ pub fn initialize_test() {
         &instruction_data,
         vec![
             AccountMeta::new(maker, true),  // Maker is signer
-            AccountMeta::new(fundraiser, true),  // Fundraiser is not signer
+            AccountMeta::new(fundraiser, false),  // Fundraiser is signed for by the program
             AccountMeta::new_readonly(mint, false),  
-            AccountMeta::new_readonly(system_program::ID, false)
+            AccountMeta::new(vault, false),
+            AccountMeta::new_readonly(system_program, false),
+            AccountMeta::new_readonly(token_program, false),
+            AccountMeta::new_readonly(associated_token_program, false),
+            AccountMeta::new_readonly(config, false),  // The platform is not configured yet
         ],
     );
 
@@ -88,9 +99,13 @@ pub fn initialize_test() {
         &instruction,
         &[
             (maker, maker_account),
-            (fundraiser, fundraiser_account),
+            (fundraiser, AccountSharedData::default()),
             (mint, mint_account), 
-            (system_program::ID, AccountSharedData::default()), 
+            (vault, AccountSharedData::default()),
+            (system_program, system_program_account),
+            (token_program, token_program_account),
+            (associated_token_program, associated_token_program_account),
+            (config, AccountSharedData::default()),
         ],
     );
 
@@ -103,11 +118,112 @@ pub fn initialize_test() {
     let fundraiser_result_account = result
         .get_account(&fundraiser)
         .expect("Failed to find fundraiser account");
+    assert_eq!(fundraiser_result_account.owner(), &program_id);
     let data = fundraiser_result_account.data();
+    assert_eq!(data.len(), Fundraiser::LEN);
     println!("{:?}", data);
+
+    let vault_result_account = result.get_account(&vault).expect("Failed to find vault account");
+    assert_eq!(vault_result_account.owner(), &spl_token::ID);
     println!(
         "Amount to raise {}, Mint to raise {}",
         amount,
         mint
     );
+}
+
+#[test]
+pub fn initialize_transfer_hook_test() {
+    let (program_id, mollusk) = setup();
+    let (associated_token_program, associated_token_program_account) =
+        mollusk_token::associated_token::keyed_account();
+    let (system_program, system_program_account) = mollusk_svm::program::keyed_account_for_system_program();
+
+    let maker = Pubkey::new_from_array([0x01; 32]);
+    let campaign_id = 0u64;
+    let (fundraiser, bump) =
+        Pubkey::find_program_address(&[b"fundraiser", &maker.to_bytes(), &campaign_id.to_le_bytes()], &program_id);
+    let config = Pubkey::find_program_address(&[b"config"], &program_id).0;
+
+    // Create a Token-2022 mint with a transfer hook pointing at some other program
+    let mint = Pubkey::new_from_array([0x02; 32]);
+    let mint_len = ExtensionType::try_calculate_account_len::<Mint2022>(&[ExtensionType::TransferHook]).unwrap();
+    let mut mint_data = vec![0; mint_len];
+    {
+        let mut state = StateWithExtensionsMut::<Mint2022>::unpack_uninitialized(&mut mint_data).unwrap();
+        let transfer_hook = state.init_extension::<TransferHook>(true).unwrap();
+        transfer_hook.program_id = Some(Pubkey::new_from_array([0x03; 32])).try_into().unwrap();
+        state.base = Mint2022 {
+            mint_authority: Some(maker).into(),
+            supply: 0,
+            decimals: 9,
+            is_initialized: true,
+            freeze_authority: None.into(),
+        };
+        state.pack_base();
+        state.init_account_type().unwrap();
+    }
+
+    let vault = Pubkey::find_program_address(
+        &[&fundraiser.to_bytes(), &spl_token_2022::ID.to_bytes(), &mint.to_bytes()],
+        &associated_token_program,
+    ).0;
+
+    let mint_account = AccountSharedData::from(solana_sdk::account::Account {
+        lamports: mollusk.sysvars.rent.minimum_balance(mint_len),
+        data: mint_data,
+        owner: spl_token_2022::ID,
+        executable: false,
+        rent_epoch: 0,
+    });
+
+    let instruction_data = [
+        0u8.to_le_bytes().to_vec(),
+        100_000_000u64.to_le_bytes().to_vec(),
+        i64::MAX.to_le_bytes().to_vec(),
+        campaign_id.to_le_bytes().to_vec(),
+        bump.to_le_bytes().to_vec(),
+        0u8.to_le_bytes().to_vec(), // milestone_count
+        vec![0u8; 36], // milestones
+        0i64.to_le_bytes().to_vec(), // voting_period
+        0u16.to_le_bytes().to_vec(), // quorum_bps
+        0u16.to_le_bytes().to_vec(), // approval_bps
+        0u8.to_le_bytes().to_vec(), // funding_mode
+        vec![0u8; 25], // contribution_limits
+        vec![0], // max_amendments
+    ].concat();
+
+    let instruction = Instruction::new_with_bytes(
+        program_id,
+        &instruction_data,
+        vec![
+            AccountMeta::new(maker, true),
+            AccountMeta::new(fundraiser, false),
+            AccountMeta::new_readonly(mint, false),
+            AccountMeta::new(vault, false),
+            AccountMeta::new_readonly(system_program, false),
+            AccountMeta::new_readonly(spl_token_2022::ID, false),
+            AccountMeta::new_readonly(associated_token_program, false),
+            AccountMeta::new_readonly(config, false),
+        ],
+    );
+
+    let result = mollusk.process_instruction(
+        &instruction,
+        &[
+            (maker, AccountSharedData::new(1_000_000_000, 0, &system_program::ID)),
+            (fundraiser, AccountSharedData::default()),
+            (mint, mint_account),
+            (vault, AccountSharedData::default()),
+            (system_program, system_program_account),
+            (spl_token_2022::ID, AccountSharedData::default()),
+            (associated_token_program, associated_token_program_account),
+            (config, AccountSharedData::default()),
+        ],
+    );
+
+    assert!(
+        result.program_result.is_err(),
+        "Initialize should reject a mint with a transfer hook"
+    );
 }
\ No newline at end of file
@@ -1,11 +1,31 @@
 #[cfg(test)]
+mod amendment_test;
+#[cfg(test)]
+mod cancel_test;
+#[cfg(test)]
 mod checker_test;
 #[cfg(test)]
 mod contribute_test;
 #[cfg(test)]
 mod initialize_test;
 #[cfg(test)]
+mod multi_mint_test;
+#[cfg(test)]
+mod platform_fee_test;
+#[cfg(test)]
+mod receipts_test;
+#[cfg(test)]
 mod refund_test;
+#[cfg(test)]
+mod release_milestone_test;
+#[cfg(test)]
+mod reward_tiers_test;
+#[cfg(test)]
+mod sol_test;
+#[cfg(test)]
+mod validation_test;
+#[cfg(test)]
+mod vote_test;
 
 use mollusk_svm::Mollusk;
 use solana_sdk::account::{AccountSharedData, WritableAccount};
@@ -20,6 +40,7 @@ pub fn setup() -> (Pubkey, Mollusk) {
     ));
     let mut mollusk = Mollusk::new(&program_id, "target/deploy/fundraiser");
     mollusk_token::token::add_program(&mut mollusk);
+    mollusk_token::associated_token::add_program(&mut mollusk);
     (program_id, mollusk)
 }
 
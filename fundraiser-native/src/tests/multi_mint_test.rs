@@ -0,0 +1,230 @@
+use crate::{
+    state::{Contributor, Fundraiser, PriceFeed},
+    tests::setup,
+};
+use mollusk_svm::result::Check;
+use pinocchio_token::state::TokenAccount;
+use solana_sdk::{
+    account::{AccountSharedData, ReadableAccount},
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    system_program,
+};
+
+#[test]
+fn multi_mint_test() {
+    let (program_id, mollusk) = setup();
+    let (token_program, token_program_account) = mollusk_token::token::keyed_account();
+
+    let maker = Pubkey::new_from_array([0x1; 32]);
+    let signer = Pubkey::new_from_array([0x2; 32]);
+    let price_authority = Pubkey::new_from_array([0x3; 32]);
+    let campaign_id = 0u64;
+    let (fundraiser, bump) =
+        Pubkey::find_program_address(&[b"fundraiser".as_ref(), &maker.to_bytes().as_ref(), &campaign_id.to_le_bytes()], &program_id);
+    let contributor = Pubkey::find_program_address(
+        &[
+            b"contributor",
+            fundraiser.as_ref(),
+            signer.to_bytes().as_ref(),
+        ],
+        &program_id,
+    )
+    .0;
+
+    // Two accepted mints, contributions come in with the second one
+    let first_mint = Pubkey::new_from_array([0x4; 32]);
+    let mint = Pubkey::new_from_array([0x5; 32]);
+    let mint_account = crate::tests::pack_mint(&maker, 1_000_000_000);
+    let price_feed = Pubkey::find_program_address(
+        &[b"price_feed", price_authority.as_ref(), mint.as_ref()],
+        &program_id,
+    )
+    .0;
+
+    let vault = Pubkey::new_from_array([0x6; 32]);
+    let signer_ta = Pubkey::new_from_array([0x7; 32]);
+    let vault_account = crate::tests::pack_token_account(&fundraiser, &mint, 0);
+    let signer_ta_account = crate::tests::pack_token_account(&signer, &mint, 1_000_000_000);
+
+    let mut fundraiser_account = AccountSharedData::new(
+        mollusk.sysvars.rent.minimum_balance(Fundraiser::LEN),
+        Fundraiser::LEN,
+        &program_id,
+    );
+    fundraiser_account.set_data_from_slice(
+        &[
+            maker.to_bytes().to_vec(),
+            first_mint.to_bytes().to_vec(), // mint_to_raise
+            1_000_000u64.to_le_bytes().to_vec(), // amount_to_raise, in the common unit
+            0u64.to_le_bytes().to_vec(), // current_amount
+            i64::MAX.to_le_bytes().to_vec(), // time_ending
+            bump.to_le_bytes().to_vec(),   // bump
+            campaign_id.to_le_bytes().to_vec(), // campaign_id
+            0u8.to_le_bytes().to_vec(), // status
+            0u8.to_le_bytes().to_vec(), // milestone_count
+            vec![0u8; 36], // milestones
+            0u8.to_le_bytes().to_vec(), // milestones_released
+            0u64.to_le_bytes().to_vec(), // amount_released
+            0i64.to_le_bytes().to_vec(), // voting_period
+            0u16.to_le_bytes().to_vec(), // quorum_bps
+            0u16.to_le_bytes().to_vec(), // approval_bps
+            0u64.to_le_bytes().to_vec(), // votes_for
+            0u64.to_le_bytes().to_vec(), // votes_against
+            0u8.to_le_bytes().to_vec(), // funding_mode
+            price_authority.to_bytes().to_vec(), // price_authority
+            2u8.to_le_bytes().to_vec(), // accepted_mint_count
+            first_mint.to_bytes().to_vec(), // accepted_mints
+            mint.to_bytes().to_vec(),
+            vec![0u8; 32],
+            vec![0u8; 24], // amounts_raised
+            vec![0u8; 25], // contribution_limits
+            Pubkey::default().to_bytes().to_vec(), // receipt_mint
+            0u16.to_le_bytes().to_vec(), // fee_bps
+            0u8.to_le_bytes().to_vec(), // max_amendments
+            0u8.to_le_bytes().to_vec(), // amendment_count
+            0i64.to_le_bytes().to_vec(), // grace_ending
+        ]
+        .concat(),
+    );
+
+    // The price authority sets the second mint at 2 units per whole token
+    let set_price_instruction = Instruction::new_with_bytes(
+        program_id,
+        &[vec![12], 2_000_000u64.to_le_bytes().to_vec()].concat(),
+        vec![
+            AccountMeta::new(price_authority, true),
+            AccountMeta::new_readonly(mint, false),
+            AccountMeta::new(price_feed, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+    );
+
+    let result = mollusk.process_and_validate_instruction(
+        &set_price_instruction,
+        &vec![
+            (price_authority, AccountSharedData::new(100_000_000, 0, &system_program::id())),
+            (mint, mint_account.clone()),
+            (
+                price_feed,
+                crate::tests::create_account(
+                    mollusk.sysvars.rent.minimum_balance(PriceFeed::LEN),
+                    PriceFeed::LEN,
+                    &program_id,
+                ),
+            ),
+            (system_program::ID, AccountSharedData::default()),
+        ],
+        &[Check::success()],
+    );
+    let price_feed_account = result
+        .get_account(&price_feed)
+        .expect("Failed to find price feed account")
+        .clone();
+
+    // Contribute 0.3 tokens of the second mint, worth 600_000 in the common unit. It is the signer's
+    // first contribution, so it creates the contributor account
+    let contribute_instruction = Instruction::new_with_bytes(
+        program_id,
+        &[vec![14], 300_000_000u64.to_le_bytes().to_vec()].concat(),
+        vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(contributor, false),
+            AccountMeta::new(signer_ta, false),
+            AccountMeta::new(fundraiser, false),
+            AccountMeta::new(vault, false),
+            AccountMeta::new_readonly(token_program, false),
+            AccountMeta::new_readonly(mint, false),
+            AccountMeta::new_readonly(price_feed, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+    );
+
+    let result = mollusk.process_and_validate_instruction(
+        &contribute_instruction,
+        &vec![
+            (signer, AccountSharedData::new(100_000_000, 0, &system_program::id())),
+            (contributor, AccountSharedData::default()),
+            (signer_ta, signer_ta_account),
+            (fundraiser, fundraiser_account),
+            (vault, vault_account),
+            (token_program, token_program_account.clone()),
+            (mint, mint_account.clone()),
+            (price_feed, price_feed_account.clone()),
+            mollusk_svm::program::keyed_account_for_system_program(),
+        ],
+        &[Check::success()],
+    );
+    println!("Compute Units: {}", result.compute_units_consumed);
+
+    let fundraiser_result = result
+        .get_account(&fundraiser)
+        .expect("Failed to find fundraiser account")
+        .clone();
+    let data = fundraiser_result.data();
+    assert_eq!(
+        u64::from_le_bytes(data[72..80].try_into().unwrap()),
+        600_000,
+        "The contribution should be credited at its value"
+    );
+    assert_eq!(
+        u64::from_le_bytes(data[310..318].try_into().unwrap()),
+        300_000_000,
+        "The tokens should be tracked against their mint"
+    );
+    let contributor_result = result.get_account(&contributor).expect("Failed to find contributor account");
+    assert_eq!(contributor_result.owner(), &program_id, "The contributor account should be created");
+    assert_eq!(contributor_result.data().len(), Contributor::LEN);
+
+    // Cancel the fundraiser so the contributor can get their tokens back
+    let mut cancelled_fundraiser = fundraiser_result.clone();
+    let mut cancelled_data = data.to_vec();
+    cancelled_data[97] = 1;
+    cancelled_fundraiser.set_data_from_slice(&cancelled_data);
+
+    let refund_instruction = Instruction::new_with_bytes(
+        program_id,
+        &[vec![16]].concat(),
+        vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(maker, false),
+            AccountMeta::new(fundraiser, false),
+            AccountMeta::new(contributor, false),
+            AccountMeta::new(signer_ta, false),
+            AccountMeta::new(vault, false),
+            AccountMeta::new_readonly(token_program, false),
+            AccountMeta::new_readonly(mint, false),
+            AccountMeta::new_readonly(price_feed, false),
+        ],
+    );
+
+    let result = mollusk.process_and_validate_instruction(
+        &refund_instruction,
+        &vec![
+            (signer, AccountSharedData::new(100_000_000, 0, &system_program::id())),
+            (maker, AccountSharedData::new(100_000_000, 0, &system_program::id())),
+            (fundraiser, cancelled_fundraiser),
+            (contributor, result.get_account(&contributor).unwrap().clone()),
+            (signer_ta, result.get_account(&signer_ta).unwrap().clone()),
+            (vault, result.get_account(&vault).unwrap().clone()),
+            (token_program, token_program_account),
+            (mint, mint_account),
+            (price_feed, price_feed_account),
+        ],
+        &[Check::success()],
+    );
+
+    let signer_ta_result = result
+        .get_account(&signer_ta)
+        .expect("Failed to find signer_ta account");
+    let signer_ta_data = unsafe { TokenAccount::from_bytes(signer_ta_result.data()) };
+    assert_eq!(signer_ta_data.amount(), 1_000_000_000, "The original tokens should be refunded");
+
+    let data = result
+        .get_account(&fundraiser)
+        .expect("Failed to find fundraiser account")
+        .data()
+        .to_vec();
+    assert_eq!(u64::from_le_bytes(data[72..80].try_into().unwrap()), 0);
+    assert_eq!(u64::from_le_bytes(data[310..318].try_into().unwrap()), 0);
+}
\ No newline at end of file
@@ -0,0 +1,276 @@
+use crate::{
+    error::FundraiserError,
+    state::{Contributor, Fundraiser},
+    tests::setup,
+};
+use mollusk_svm::result::Check;
+use solana_sdk::{
+    account::{AccountSharedData, ReadableAccount},
+    instruction::{AccountMeta, Instruction},
+    program_error::ProgramError,
+    program_pack::Pack,
+    pubkey::Pubkey,
+    system_program,
+};
+
+#[test]
+fn receipts_test() {
+    let (program_id, mollusk) = setup();
+    let (token_program, token_program_account) = mollusk_token::token::keyed_account();
+    let (system_program, system_program_account) = mollusk_svm::program::keyed_account_for_system_program();
+    let (associated_token_program, associated_token_program_account) =
+        mollusk_token::associated_token::keyed_account();
+
+    let maker = Pubkey::new_from_array([0x1; 32]);
+    let signer = Pubkey::new_from_array([0x2; 32]);
+    let holder = Pubkey::new_from_array([0x3; 32]);
+    let campaign_id = 0u64;
+    let (fundraiser, bump) =
+        Pubkey::find_program_address(&[b"fundraiser".as_ref(), &maker.to_bytes().as_ref(), &campaign_id.to_le_bytes()], &program_id);
+
+    let mint = Pubkey::new_from_array([0x4; 32]);
+    let mint_account = crate::tests::pack_mint(&maker, 1_000_000_000);
+    let receipt_mint = Pubkey::new_from_array([0x5; 32]);
+
+    let vault = Pubkey::new_from_array([0x6; 32]);
+    let signer_ta = Pubkey::new_from_array([0x7; 32]);
+    // The contributor has no receipt account yet, the first contribution creates it
+    let signer_receipt_ta = Pubkey::find_program_address(
+        &[&signer.to_bytes(), &token_program.to_bytes(), &receipt_mint.to_bytes()],
+        &associated_token_program,
+    )
+    .0;
+    let holder_ta = Pubkey::new_from_array([0x9; 32]);
+    let holder_receipt_ta = Pubkey::new_from_array([0xa; 32]);
+
+    let mut fundraiser_account = AccountSharedData::new(
+        mollusk.sysvars.rent.minimum_balance(Fundraiser::LEN),
+        Fundraiser::LEN,
+        &program_id,
+    );
+    fundraiser_account.set_data_from_slice(
+        &[
+            maker.to_bytes().to_vec(),
+            mint.to_bytes().to_vec(), // mint_to_raise
+            1_000_000_000u64.to_le_bytes().to_vec(), // amount_to_raise
+            0u64.to_le_bytes().to_vec(), // current_amount
+            i64::MAX.to_le_bytes().to_vec(), // time_ending
+            bump.to_le_bytes().to_vec(),   // bump
+            campaign_id.to_le_bytes().to_vec(), // campaign_id
+            0u8.to_le_bytes().to_vec(), // status
+            0u8.to_le_bytes().to_vec(), // milestone_count
+            vec![0u8; 36], // milestones
+            0u8.to_le_bytes().to_vec(), // milestones_released
+            0u64.to_le_bytes().to_vec(), // amount_released
+            0i64.to_le_bytes().to_vec(), // voting_period
+            0u16.to_le_bytes().to_vec(), // quorum_bps
+            0u16.to_le_bytes().to_vec(), // approval_bps
+            0u64.to_le_bytes().to_vec(), // votes_for
+            0u64.to_le_bytes().to_vec(), // votes_against
+            0u8.to_le_bytes().to_vec(), // funding_mode
+            Pubkey::default().to_bytes().to_vec(), // price_authority
+            0u8.to_le_bytes().to_vec(), // accepted_mint_count
+            vec![0u8; 96], // accepted_mints
+            vec![0u8; 24], // amounts_raised
+            vec![0u8; 25], // contribution_limits
+            Pubkey::default().to_bytes().to_vec(), // receipt_mint
+            0u16.to_le_bytes().to_vec(), // fee_bps
+            0u8.to_le_bytes().to_vec(), // max_amendments
+            0u8.to_le_bytes().to_vec(), // amendment_count
+            0i64.to_le_bytes().to_vec(), // grace_ending
+        ]
+        .concat(),
+    );
+
+    let enable_receipts_instruction = |receipt_mint_account: AccountSharedData| {
+        (
+            Instruction::new_with_bytes(
+                program_id,
+                &[17],
+                vec![
+                    AccountMeta::new(maker, true),
+                    AccountMeta::new(fundraiser, false),
+                    AccountMeta::new_readonly(mint, false),
+                    AccountMeta::new_readonly(receipt_mint, false),
+                ],
+            ),
+            vec![
+                (maker, AccountSharedData::new(100_000_000, 0, &system_program::id())),
+                (fundraiser, fundraiser_account.clone()),
+                (mint, mint_account.clone()),
+                (receipt_mint, receipt_mint_account),
+            ],
+        )
+    };
+
+    // Anyone but the fundraiser being able to mint would let receipts be printed out of thin air
+    let (instruction, accounts) = enable_receipts_instruction(crate::tests::pack_mint(&maker, 0));
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &accounts,
+        &[Check::err(ProgramError::Custom(FundraiserError::InvalidReceiptMint as u32))],
+    );
+
+    let (instruction, accounts) = enable_receipts_instruction(crate::tests::pack_mint(&fundraiser, 0));
+    let result = mollusk.process_and_validate_instruction(&instruction, &accounts, &[Check::success()]);
+
+    let fundraiser_account = result
+        .get_account(&fundraiser)
+        .expect("Failed to find fundraiser account")
+        .clone();
+    assert_eq!(
+        &fundraiser_account.data()[351..383],
+        receipt_mint.as_ref(),
+        "The receipt mint should be stored on the fundraiser"
+    );
+    let receipt_mint_account = result
+        .get_account(&receipt_mint)
+        .expect("Failed to find receipt mint account")
+        .clone();
+
+    // The plain contribute instruction has no receipts to mint
+    let contributor = Pubkey::find_program_address(
+        &[b"contributor", fundraiser.as_ref(), signer.to_bytes().as_ref()],
+        &program_id,
+    )
+    .0;
+    mollusk.process_and_validate_instruction(
+        &Instruction::new_with_bytes(
+            program_id,
+            &[vec![2], 300_000_000u64.to_le_bytes().to_vec()].concat(),
+            vec![
+                AccountMeta::new(signer, true),
+                AccountMeta::new(contributor, false),
+                AccountMeta::new(signer_ta, false),
+                AccountMeta::new(fundraiser, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(token_program, false),
+                AccountMeta::new_readonly(mint, false),
+                AccountMeta::new_readonly(system_program, false),
+            ],
+        ),
+        &vec![
+            (signer, AccountSharedData::new(100_000_000, 0, &system_program::id())),
+            (
+                contributor,
+                crate::tests::create_account(
+                    mollusk.sysvars.rent.minimum_balance(Contributor::LEN),
+                    Contributor::LEN,
+                    &program_id,
+                ),
+            ),
+            (signer_ta, crate::tests::pack_token_account(&signer, &mint, 1_000_000_000)),
+            (fundraiser, fundraiser_account.clone()),
+            (vault, crate::tests::pack_token_account(&fundraiser, &mint, 0)),
+            (token_program, token_program_account.clone()),
+            (mint, mint_account.clone()),
+            (system_program, system_program_account.clone()),
+        ],
+        &[Check::err(ProgramError::Custom(FundraiserError::ReceiptsEnabled as u32))],
+    );
+
+    // Contribute 0.3 tokens and get as many receipts back
+    let contribute_instruction = Instruction::new_with_bytes(
+        program_id,
+        &[vec![18], 300_000_000u64.to_le_bytes().to_vec()].concat(),
+        vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(signer_ta, false),
+            AccountMeta::new(fundraiser, false),
+            AccountMeta::new(vault, false),
+            AccountMeta::new_readonly(token_program, false),
+            AccountMeta::new_readonly(mint, false),
+            AccountMeta::new(receipt_mint, false),
+            AccountMeta::new(signer_receipt_ta, false),
+            AccountMeta::new_readonly(system_program, false),
+            AccountMeta::new_readonly(associated_token_program, false),
+        ],
+    );
+
+    let result = mollusk.process_and_validate_instruction(
+        &contribute_instruction,
+        &vec![
+            (signer, AccountSharedData::new(100_000_000, 0, &system_program::id())),
+            (signer_ta, crate::tests::pack_token_account(&signer, &mint, 1_000_000_000)),
+            (fundraiser, fundraiser_account),
+            (vault, crate::tests::pack_token_account(&fundraiser, &mint, 0)),
+            (token_program, token_program_account.clone()),
+            (mint, mint_account.clone()),
+            (receipt_mint, receipt_mint_account),
+            (signer_receipt_ta, AccountSharedData::default()),
+            (system_program, system_program_account.clone()),
+            (associated_token_program, associated_token_program_account),
+        ],
+        &[Check::success()],
+    );
+    println!("Compute Units: {}", result.compute_units_consumed);
+
+    let fundraiser_result = result
+        .get_account(&fundraiser)
+        .expect("Failed to find fundraiser account")
+        .clone();
+    let data = fundraiser_result.data();
+    assert_eq!(
+        u64::from_le_bytes(data[72..80].try_into().unwrap()),
+        300_000_000,
+        "Current amount should be updated after contribution"
+    );
+    let signer_receipts = spl_token::state::Account::unpack(
+        result.get_account(&signer_receipt_ta).expect("Failed to find receipt account").data(),
+    )
+    .unwrap();
+    assert_eq!(signer_receipts.amount, 300_000_000, "Receipts should be minted 1:1");
+
+    // The receipts change hands, then the maker cancels
+    let mut cancelled_fundraiser = fundraiser_result.clone();
+    let mut cancelled_data = data.to_vec();
+    cancelled_data[97] = 1;
+    cancelled_fundraiser.set_data_from_slice(&cancelled_data);
+
+    let refund_instruction = Instruction::new_with_bytes(
+        program_id,
+        &[19],
+        vec![
+            AccountMeta::new(holder, true),
+            AccountMeta::new(holder_ta, false),
+            AccountMeta::new(fundraiser, false),
+            AccountMeta::new(vault, false),
+            AccountMeta::new_readonly(token_program, false),
+            AccountMeta::new_readonly(mint, false),
+            AccountMeta::new(receipt_mint, false),
+            AccountMeta::new(holder_receipt_ta, false),
+        ],
+    );
+
+    let result = mollusk.process_and_validate_instruction(
+        &refund_instruction,
+        &vec![
+            (holder, AccountSharedData::new(100_000_000, 0, &system_program::id())),
+            (holder_ta, crate::tests::pack_token_account(&holder, &mint, 0)),
+            (fundraiser, cancelled_fundraiser),
+            (vault, result.get_account(&vault).unwrap().clone()),
+            (token_program, token_program_account),
+            (mint, mint_account),
+            (receipt_mint, result.get_account(&receipt_mint).unwrap().clone()),
+            (holder_receipt_ta, crate::tests::pack_token_account(&holder, &receipt_mint, 300_000_000)),
+        ],
+        &[Check::success()],
+    );
+
+    let holder_tokens = spl_token::state::Account::unpack(
+        result.get_account(&holder_ta).expect("Failed to find holder token account").data(),
+    )
+    .unwrap();
+    assert_eq!(holder_tokens.amount, 300_000_000, "The refund should follow the receipts");
+    let receipt_supply = spl_token::state::Mint::unpack(
+        result.get_account(&receipt_mint).expect("Failed to find receipt mint").data(),
+    )
+    .unwrap()
+    .supply;
+    assert_eq!(receipt_supply, 0, "The receipts should be burned");
+    assert_eq!(
+        u64::from_le_bytes(result.get_account(&fundraiser).unwrap().data()[72..80].try_into().unwrap()),
+        0,
+        "Current amount should be reduced after the refund"
+    );
+}
\ No newline at end of file
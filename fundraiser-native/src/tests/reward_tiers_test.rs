@@ -0,0 +1,201 @@
+use mollusk_svm::result::Check;
+use solana_sdk::{
+    account::ReadableAccount,
+    instruction::{AccountMeta, Instruction},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    system_program,
+};
+use crate::{
+    error::FundraiserError,
+    state::{Contributor, Fundraiser, RewardTiers},
+    tests::setup,
+};
+
+#[test]
+fn reward_tiers_test() {
+    let (program_id, mollusk) = setup();
+    let (token_program, token_program_account) = mollusk_token::token::keyed_account();
+    let (system_program, system_program_account) = mollusk_svm::program::keyed_account_for_system_program();
+
+    let maker = Pubkey::new_from_array([0x1; 32]);
+    let campaign_id = 0u64;
+    let (fundraiser, bump) =
+        Pubkey::find_program_address(&[b"fundraiser", &maker.to_bytes(), &campaign_id.to_le_bytes()], &program_id);
+    let reward_tiers = Pubkey::find_program_address(&[b"reward_tiers", fundraiser.as_ref()], &program_id).0;
+    let mint = Pubkey::new_from_array([0x4; 32]);
+    let vault = Pubkey::new_from_array([0x5; 32]);
+
+    let mut fundraiser_account = crate::tests::create_account(
+        mollusk.sysvars.rent.minimum_balance(Fundraiser::LEN),
+        Fundraiser::LEN,
+        &program_id,
+    );
+    fundraiser_account.set_data_from_slice(
+        &[
+            maker.to_bytes().to_vec(),
+            mint.to_bytes().to_vec(),
+            100_000_000u64.to_le_bytes().to_vec(), // amount_to_raise
+            0u64.to_le_bytes().to_vec(), // current_amount
+            i64::MAX.to_le_bytes().to_vec(), // time_ending
+            bump.to_le_bytes().to_vec(), // bump
+            campaign_id.to_le_bytes().to_vec(),
+            0u8.to_le_bytes().to_vec(), // status
+            0u8.to_le_bytes().to_vec(), // milestone_count
+            vec![0u8; 36], // milestones
+            0u8.to_le_bytes().to_vec(), // milestones_released
+            0u64.to_le_bytes().to_vec(), // amount_released
+            0i64.to_le_bytes().to_vec(), // voting_period
+            0u16.to_le_bytes().to_vec(), // quorum_bps
+            0u16.to_le_bytes().to_vec(), // approval_bps
+            0u64.to_le_bytes().to_vec(), // votes_for
+            0u64.to_le_bytes().to_vec(), // votes_against
+            0u8.to_le_bytes().to_vec(), // funding_mode
+            Pubkey::default().to_bytes().to_vec(), // price_authority
+            0u8.to_le_bytes().to_vec(), // accepted_mint_count
+            vec![0u8; 96], // accepted_mints
+            vec![0u8; 24], // amounts_raised
+            vec![0u8; 25], // contribution_limits
+            Pubkey::default().to_bytes().to_vec(), // receipt_mint
+            0u16.to_le_bytes().to_vec(), // fee_bps
+            0u8.to_le_bytes().to_vec(), // max_amendments
+            0u8.to_le_bytes().to_vec(), // amendment_count
+            0i64.to_le_bytes().to_vec(), // grace_ending
+        ]
+        .concat(),
+    );
+
+    // A single reward for pledges of at least 1_000_000
+    let set_reward_tiers_instruction = Instruction::new_with_bytes(
+        program_id,
+        &[
+            vec![20, 1],
+            1_000_000u64.to_le_bytes().to_vec(), // min_pledge
+            1u64.to_le_bytes().to_vec(), // max_supply
+            vec![0u8; 48], // unused tiers
+        ]
+        .concat(),
+        vec![
+            AccountMeta::new(maker, true),
+            AccountMeta::new_readonly(fundraiser, false),
+            AccountMeta::new(reward_tiers, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    );
+    let reward_tiers_account = crate::tests::create_account(
+        mollusk.sysvars.rent.minimum_balance(RewardTiers::LEN),
+        RewardTiers::LEN,
+        &program_id,
+    );
+
+    let result = mollusk.process_and_validate_instruction(
+        &set_reward_tiers_instruction,
+        &vec![
+            (maker, crate::tests::create_account(0, 0, &system_program::id())),
+            (fundraiser, fundraiser_account.clone()),
+            (reward_tiers, reward_tiers_account),
+            (system_program::id(), crate::tests::create_account(0, 0, &system_program::id())),
+        ],
+        &[Check::success()],
+    );
+    let reward_tiers_account = result
+        .get_account(&reward_tiers)
+        .expect("Failed to find reward tiers account")
+        .clone();
+
+    let contribute = |signer: Pubkey, signer_ta: Pubkey, amount: u64, fundraiser_account, reward_tiers_account, check: Check| {
+        let contributor = Pubkey::find_program_address(
+            &[b"contributor", fundraiser.as_ref(), signer.as_ref()],
+            &program_id,
+        )
+        .0;
+
+        // The amount followed by Some(0), the first tier
+        let contribute_instruction = Instruction::new_with_bytes(
+            program_id,
+            &[vec![2], amount.to_le_bytes().to_vec(), vec![1, 0]].concat(),
+            vec![
+                AccountMeta::new(signer, true),
+                AccountMeta::new(contributor, true),
+                AccountMeta::new(signer_ta, false),
+                AccountMeta::new(fundraiser, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new(token_program, false),
+                AccountMeta::new_readonly(mint, false),
+                AccountMeta::new_readonly(system_program, false),
+                AccountMeta::new(reward_tiers, false),
+            ],
+        );
+
+        mollusk.process_and_validate_instruction(
+            &contribute_instruction,
+            &vec![
+                (signer, crate::tests::create_account(0, 0, &system_program::id())),
+                (signer_ta, crate::tests::pack_token_account(&signer, &mint, 2_000_000_000)),
+                (
+                    contributor,
+                    crate::tests::create_account(
+                        mollusk.sysvars.rent.minimum_balance(Contributor::LEN),
+                        Contributor::LEN,
+                        &program_id,
+                    ),
+                ),
+                (fundraiser, fundraiser_account),
+                (vault, crate::tests::pack_token_account(&fundraiser, &mint, 0)),
+                (token_program, token_program_account.clone()),
+                (mint, crate::tests::pack_mint(&maker, 2_000_000_000)),
+                (system_program, system_program_account.clone()),
+                (reward_tiers, reward_tiers_account),
+            ],
+            &[check],
+        )
+    };
+
+    // The pledge has to reach the minimum of the tier
+    contribute(
+        Pubkey::new_from_array([0x2; 32]),
+        Pubkey::new_from_array([0x3; 32]),
+        500_000,
+        fundraiser_account.clone(),
+        reward_tiers_account.clone(),
+        Check::err(ProgramError::Custom(FundraiserError::PledgeBelowTierMinimum as u32)),
+    );
+
+    let result = contribute(
+        Pubkey::new_from_array([0x2; 32]),
+        Pubkey::new_from_array([0x3; 32]),
+        1_000_000,
+        fundraiser_account.clone(),
+        reward_tiers_account.clone(),
+        Check::success(),
+    );
+    assert_eq!(
+        u64::from_le_bytes(result.get_account(&reward_tiers).unwrap().data()[97..105].try_into().unwrap()),
+        1,
+        "The tier should have one reward claimed"
+    );
+    let fundraiser_account = result.get_account(&fundraiser).unwrap().clone();
+    let reward_tiers_account = result.get_account(&reward_tiers).unwrap().clone();
+
+    // The only reward is gone
+    contribute(
+        Pubkey::new_from_array([0x6; 32]),
+        Pubkey::new_from_array([0x7; 32]),
+        1_000_000,
+        fundraiser_account.clone(),
+        reward_tiers_account.clone(),
+        Check::err(ProgramError::Custom(FundraiserError::RewardTierSoldOut as u32)),
+    );
+
+    // and the tiers are fixed now that contributions came in
+    mollusk.process_and_validate_instruction(
+        &set_reward_tiers_instruction,
+        &vec![
+            (maker, crate::tests::create_account(0, 0, &system_program::id())),
+            (fundraiser, fundraiser_account),
+            (reward_tiers, reward_tiers_account),
+            (system_program::id(), crate::tests::create_account(0, 0, &system_program::id())),
+        ],
+        &[Check::err(ProgramError::Custom(FundraiserError::RewardTiersLocked as u32))],
+    );
+}
\ No newline at end of file
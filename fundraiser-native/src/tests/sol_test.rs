@@ -0,0 +1,241 @@
+use crate::{
+    state::{Contributor, Fundraiser},
+    tests::setup,
+};
+use mollusk_svm::result::Check;
+use solana_sdk::{
+    account::{AccountSharedData, ReadableAccount},
+    instruction::{AccountMeta, Instruction},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    system_program,
+};
+
+#[test]
+fn sol_test() {
+    let (program_id, mut mollusk) = setup();
+    let (system_program_id, system_program_account) =
+        mollusk_svm::program::keyed_account_for_system_program();
+
+    let maker = Pubkey::new_from_array([0x1; 32]);
+    let signer = Pubkey::new_from_array([0x2; 32]);
+    let campaign_id = 0u64;
+    let (fundraiser, bump) =
+        Pubkey::find_program_address(&[b"fundraiser".as_ref(), &maker.to_bytes().as_ref(), &campaign_id.to_le_bytes()], &program_id);
+    let contributor = Pubkey::find_program_address(
+        &[
+            b"contributor",
+            fundraiser.as_ref(),
+            signer.to_bytes().as_ref(),
+        ],
+        &program_id,
+    )
+    .0;
+
+    let rent_exempt_lamports = mollusk.sysvars.rent.minimum_balance(Fundraiser::LEN);
+    let mut fundraiser_account = AccountSharedData::new(
+        rent_exempt_lamports,
+        Fundraiser::LEN,
+        &program_id,
+    );
+
+    // A SOL fundraiser has no mint
+    fundraiser_account.set_data_from_slice(
+        &[
+            maker.to_bytes().to_vec(),
+            Pubkey::default().to_bytes().to_vec(), // mint_to_raise
+            1_000_000_000u64.to_le_bytes().to_vec(), // amount_to_raise
+            0u64.to_le_bytes().to_vec(), // current_amount
+            100i64.to_le_bytes().to_vec(), // time_ending
+            bump.to_le_bytes().to_vec(),   // bump
+            campaign_id.to_le_bytes().to_vec(), // campaign_id
+            0u8.to_le_bytes().to_vec(), // status
+            0u8.to_le_bytes().to_vec(), // milestone_count
+            vec![0u8; 36], // milestones
+            0u8.to_le_bytes().to_vec(), // milestones_released
+            0u64.to_le_bytes().to_vec(), // amount_released
+            0i64.to_le_bytes().to_vec(), // voting_period
+            0u16.to_le_bytes().to_vec(), // quorum_bps
+            0u16.to_le_bytes().to_vec(), // approval_bps
+            0u64.to_le_bytes().to_vec(), // votes_for
+            0u64.to_le_bytes().to_vec(), // votes_against
+            0u8.to_le_bytes().to_vec(), // funding_mode
+            Pubkey::default().to_bytes().to_vec(), // price_authority
+            0u8.to_le_bytes().to_vec(), // accepted_mint_count
+            vec![0u8; 96], // accepted_mints
+            vec![0u8; 24], // amounts_raised
+            vec![0u8; 25], // contribution_limits
+            Pubkey::default().to_bytes().to_vec(), // receipt_mint
+            0u16.to_le_bytes().to_vec(), // fee_bps
+            0u8.to_le_bytes().to_vec(), // max_amendments
+            0u8.to_le_bytes().to_vec(), // amendment_count
+            0i64.to_le_bytes().to_vec(), // grace_ending
+        ]
+        .concat(),
+    );
+
+    let contributor_account = crate::tests::create_account(
+        mollusk.sysvars.rent.minimum_balance(Contributor::LEN),
+        Contributor::LEN,
+        &program_id,
+    );
+
+    let contribute_instruction = Instruction::new_with_bytes(
+        program_id,
+        &[vec![9], 100_000_000u64.to_le_bytes().to_vec()].concat(),
+        vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(contributor, false),
+            AccountMeta::new(fundraiser, false),
+            AccountMeta::new_readonly(system_program_id, false),
+        ],
+    );
+
+    let result = mollusk.process_and_validate_instruction(
+        &contribute_instruction,
+        &vec![
+            (signer, AccountSharedData::new(1_000_000_000, 0, &system_program::id())),
+            (contributor, contributor_account),
+            (fundraiser, fundraiser_account),
+            (system_program_id, system_program_account),
+        ],
+        &[Check::success()],
+    );
+    assert!(!result.program_result.is_err(), "contribute_sol failed.");
+    println!("Compute Units: {}", result.compute_units_consumed);
+
+    let fundraiser_result = result
+        .get_account(&fundraiser)
+        .expect("Failed to find fundraiser account")
+        .clone();
+    assert_eq!(
+        fundraiser_result.lamports(),
+        rent_exempt_lamports + 100_000_000,
+        "The contribution should sit on top of the rent-exempt balance"
+    );
+    assert_eq!(
+        u64::from_le_bytes(fundraiser_result.data()[72..80].try_into().unwrap()),
+        100_000_000,
+        "Current amount should be updated after contribution"
+    );
+
+    let contributor_result = result
+        .get_account(&contributor)
+        .expect("Failed to find contributor account")
+        .clone();
+    assert_eq!(
+        u64::from_le_bytes(contributor_result.data()[0..8].try_into().unwrap()),
+        100_000_000,
+        "Contributor amount should be updated after contribution"
+    );
+    let signer_result = result
+        .get_account(&signer)
+        .expect("Failed to find signer account")
+        .clone();
+
+    // The fundraiser ends without meeting its target, so the contributor can get their lamports back
+    mollusk.sysvars.clock.unix_timestamp = 100;
+    let refund_instruction = Instruction::new_with_bytes(
+        program_id,
+        &[vec![11]].concat(),
+        vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(fundraiser, false),
+            AccountMeta::new(contributor, false),
+        ],
+    );
+
+    let result = mollusk.process_and_validate_instruction(
+        &refund_instruction,
+        &vec![
+            (signer, signer_result),
+            (fundraiser, fundraiser_result),
+            (contributor, contributor_result),
+        ],
+        &[Check::success()],
+    );
+    assert!(!result.program_result.is_err(), "refund_sol failed.");
+    println!("Compute Units: {}", result.compute_units_consumed);
+
+    assert_eq!(
+        result.get_account(&fundraiser).unwrap().lamports(),
+        rent_exempt_lamports,
+        "The fundraiser account should stay rent-exempt"
+    );
+    assert_eq!(
+        result.get_account(&signer).unwrap().lamports(),
+        1_000_000_000,
+        "Contributor should have received their refund"
+    );
+}
+
+#[test]
+fn initialize_sol_test() {
+    let (program_id, mollusk) = setup();
+    let (system_program_id, system_program_account) =
+        mollusk_svm::program::keyed_account_for_system_program();
+
+    let maker = Pubkey::new_from_array([0x1; 32]);
+    let campaign_id = 0u64;
+    let (fundraiser, bump) =
+        Pubkey::find_program_address(&[b"fundraiser".as_ref(), &maker.to_bytes().as_ref(), &campaign_id.to_le_bytes()], &program_id);
+    let config = Pubkey::find_program_address(&[b"config"], &program_id).0;
+
+    let initialize_instruction = Instruction::new_with_bytes(
+        program_id,
+        &[
+            vec![8],
+            1_000_000_000u64.to_le_bytes().to_vec(), // amount_to_raise
+            i64::MAX.to_le_bytes().to_vec(), // time_ending
+            campaign_id.to_le_bytes().to_vec(), // campaign_id
+            vec![bump], // bump
+            vec![0], // funding_mode
+            vec![0u8; 25], // contribution_limits
+            vec![0], // max_amendments
+        ]
+        .concat(),
+        vec![
+            AccountMeta::new(maker, true),
+            AccountMeta::new(fundraiser, false),
+            AccountMeta::new_readonly(system_program_id, false),
+            AccountMeta::new_readonly(config, false),
+        ],
+    );
+
+    // The fundraiser does not exist yet, initialize_sol creates it
+    let result = mollusk.process_and_validate_instruction(
+        &initialize_instruction,
+        &vec![
+            (maker, AccountSharedData::new(1_000_000_000, 0, &system_program::id())),
+            (fundraiser, AccountSharedData::default()),
+            (system_program_id, system_program_account.clone()),
+            (config, AccountSharedData::default()),
+        ],
+        &[Check::success()],
+    );
+
+    let fundraiser_result = result
+        .get_account(&fundraiser)
+        .expect("Failed to find fundraiser account")
+        .clone();
+    assert_eq!(fundraiser_result.owner(), &program_id);
+    assert_eq!(fundraiser_result.data().len(), Fundraiser::LEN);
+    assert_eq!(
+        fundraiser_result.lamports(),
+        mollusk.sysvars.rent.minimum_balance(Fundraiser::LEN),
+        "The maker should pay for a rent-exempt fundraiser"
+    );
+
+    // Initializing it again would reset the terms of a fundraiser that already holds contributions
+    let maker_result = result.get_account(&maker).unwrap().clone();
+    mollusk.process_and_validate_instruction(
+        &initialize_instruction,
+        &vec![
+            (maker, maker_result),
+            (fundraiser, fundraiser_result),
+            (system_program_id, system_program_account),
+            (config, AccountSharedData::default()),
+        ],
+        &[Check::err(ProgramError::AccountAlreadyInitialized)],
+    );
+}
\ No newline at end of file
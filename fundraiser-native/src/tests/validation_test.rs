@@ -0,0 +1,400 @@
+use mollusk_svm::{result::Check, Mollusk};
+use solana_sdk::{
+    account::{AccountSharedData, WritableAccount},
+    instruction::{AccountMeta, Instruction},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    system_program,
+};
+
+use crate::{
+    error::FundraiserError,
+    state::{Contributor, Fundraiser},
+    tests::setup,
+};
+
+// A fundraiser with a goal of 100_000_000, one contributor put in 100_000 of it
+struct Campaign {
+    program_id: Pubkey,
+    mollusk: Mollusk,
+    maker: Pubkey,
+    contributor: Pubkey,
+    fundraiser: Pubkey,
+    bump: u8,
+    contributor_account: Pubkey,
+    contributor_bump: u8,
+    contributor_ta: Pubkey,
+    maker_ta: Pubkey,
+    vault: Pubkey,
+    mint: Pubkey,
+}
+
+impl Campaign {
+    fn new() -> Self {
+        let (program_id, mollusk) = setup();
+        let maker = Pubkey::new_from_array([0x1; 32]);
+        let contributor = Pubkey::new_from_array([0x2; 32]);
+        let (fundraiser, bump) =
+            Pubkey::find_program_address(&[b"fundraiser", maker.as_ref(), &0u64.to_le_bytes()], &program_id);
+        let (contributor_account, contributor_bump) =
+            Pubkey::find_program_address(&[b"contributor", fundraiser.as_ref(), contributor.as_ref()], &program_id);
+
+        Campaign {
+            program_id,
+            mollusk,
+            maker,
+            contributor,
+            fundraiser,
+            bump,
+            contributor_account,
+            contributor_bump,
+            contributor_ta: Pubkey::new_from_array([0x3; 32]),
+            maker_ta: Pubkey::new_from_array([0x4; 32]),
+            vault: Pubkey::new_from_array([0x5; 32]),
+            mint: Pubkey::new_from_array([0x6; 32]),
+        }
+    }
+
+    fn fundraiser_account(&self, current_amount: u64) -> AccountSharedData {
+        let mut account = crate::tests::create_account(
+            self.mollusk.sysvars.rent.minimum_balance(Fundraiser::LEN),
+            Fundraiser::LEN,
+            &self.program_id,
+        );
+        account.set_data_from_slice(
+            &[
+                self.maker.to_bytes().to_vec(),
+                self.mint.to_bytes().to_vec(),
+                100_000_000u64.to_le_bytes().to_vec(), // amount_to_raise
+                current_amount.to_le_bytes().to_vec(), // current_amount
+                i64::MAX.to_le_bytes().to_vec(), // time_ending
+                self.bump.to_le_bytes().to_vec(), // bump
+                0u64.to_le_bytes().to_vec(), // campaign_id
+                0u8.to_le_bytes().to_vec(), // status
+                0u8.to_le_bytes().to_vec(), // milestone_count
+                vec![0u8; 36], // milestones
+                0u8.to_le_bytes().to_vec(), // milestones_released
+                0u64.to_le_bytes().to_vec(), // amount_released
+                0i64.to_le_bytes().to_vec(), // voting_period
+                0u16.to_le_bytes().to_vec(), // quorum_bps
+                0u16.to_le_bytes().to_vec(), // approval_bps
+                0u64.to_le_bytes().to_vec(), // votes_for
+                0u64.to_le_bytes().to_vec(), // votes_against
+                0u8.to_le_bytes().to_vec(), // funding_mode
+                Pubkey::default().to_bytes().to_vec(), // price_authority
+                0u8.to_le_bytes().to_vec(), // accepted_mint_count
+                vec![0u8; 96], // accepted_mints
+                vec![0u8; 24], // amounts_raised
+                vec![0u8; 25], // contribution_limits
+                Pubkey::default().to_bytes().to_vec(), // receipt_mint
+                0u16.to_le_bytes().to_vec(), // fee_bps
+                0u8.to_le_bytes().to_vec(), // max_amendments
+                0u8.to_le_bytes().to_vec(), // amendment_count
+                0i64.to_le_bytes().to_vec(), // grace_ending
+            ]
+            .concat(),
+        );
+        account
+    }
+
+    fn contributor_account(&self) -> AccountSharedData {
+        let mut account = crate::tests::create_account(
+            self.mollusk.sysvars.rent.minimum_balance(Contributor::LEN),
+            Contributor::LEN,
+            &self.program_id,
+        );
+        account.set_data_from_slice(&[100_000u64.to_le_bytes().to_vec(), vec![0u8], vec![0u8; 24], vec![0u8], vec![self.contributor_bump]].concat());
+        account
+    }
+
+    fn contribute(&self) -> (Instruction, Vec<(Pubkey, AccountSharedData)>) {
+        let (token_program, token_program_account) = mollusk_token::token::keyed_account();
+        let (system_program, system_program_account) = mollusk_svm::program::keyed_account_for_system_program();
+
+        let instruction = Instruction::new_with_bytes(
+            self.program_id,
+            &[vec![2], 100_000u64.to_le_bytes().to_vec()].concat(),
+            vec![
+                AccountMeta::new(self.contributor, true),
+                AccountMeta::new(self.contributor_account, false),
+                AccountMeta::new(self.contributor_ta, false),
+                AccountMeta::new(self.fundraiser, false),
+                AccountMeta::new(self.vault, false),
+                AccountMeta::new_readonly(token_program, false),
+                AccountMeta::new_readonly(self.mint, false),
+                AccountMeta::new_readonly(system_program, false),
+            ],
+        );
+        let accounts = vec![
+            (self.contributor, AccountSharedData::new(1_000_000_000, 0, &system_program::ID)),
+            (self.contributor_account, self.contributor_account()),
+            (self.contributor_ta, crate::tests::pack_token_account(&self.contributor, &self.mint, 1_000_000)),
+            (self.fundraiser, self.fundraiser_account(100_000)),
+            (self.vault, crate::tests::pack_token_account(&self.fundraiser, &self.mint, 100_000)),
+            (token_program, token_program_account),
+            (self.mint, crate::tests::pack_mint(&self.maker, 1_000_000_000)),
+            (system_program, system_program_account),
+        ];
+
+        (instruction, accounts)
+    }
+
+    fn refund(&self) -> (Instruction, Vec<(Pubkey, AccountSharedData)>) {
+        let (token_program, token_program_account) = mollusk_token::token::keyed_account();
+
+        let instruction = Instruction::new_with_bytes(
+            self.program_id,
+            &[3],
+            vec![
+                AccountMeta::new(self.contributor, true),
+                AccountMeta::new_readonly(self.maker, false),
+                AccountMeta::new(self.fundraiser, false),
+                AccountMeta::new(self.contributor_account, false),
+                AccountMeta::new(self.contributor_ta, false),
+                AccountMeta::new(self.vault, false),
+                AccountMeta::new_readonly(token_program, false),
+                AccountMeta::new_readonly(self.mint, false),
+            ],
+        );
+        let accounts = vec![
+            (self.contributor, AccountSharedData::new(1_000_000_000, 0, &system_program::ID)),
+            (self.maker, AccountSharedData::new(1_000_000_000, 0, &system_program::ID)),
+            (self.fundraiser, self.fundraiser_account(100_000)),
+            (self.contributor_account, self.contributor_account()),
+            (self.contributor_ta, crate::tests::pack_token_account(&self.contributor, &self.mint, 0)),
+            (self.vault, crate::tests::pack_token_account(&self.fundraiser, &self.mint, 100_000)),
+            (token_program, token_program_account),
+            (self.mint, crate::tests::pack_mint(&self.maker, 1_000_000_000)),
+        ];
+
+        (instruction, accounts)
+    }
+
+    fn checker(&self) -> (Instruction, Vec<(Pubkey, AccountSharedData)>) {
+        let (token_program, token_program_account) = mollusk_token::token::keyed_account();
+
+        let instruction = Instruction::new_with_bytes(
+            self.program_id,
+            &[1],
+            vec![
+                AccountMeta::new(self.maker, true),
+                AccountMeta::new_readonly(self.mint, false),
+                AccountMeta::new(self.fundraiser, false),
+                AccountMeta::new(self.vault, false),
+                AccountMeta::new(self.maker_ta, false),
+                AccountMeta::new_readonly(token_program, false),
+            ],
+        );
+        let accounts = vec![
+            (self.maker, AccountSharedData::new(1_000_000_000, 0, &system_program::ID)),
+            (self.mint, crate::tests::pack_mint(&self.maker, 1_000_000_000)),
+            (self.fundraiser, self.fundraiser_account(100_000_000)),
+            (self.vault, crate::tests::pack_token_account(&self.fundraiser, &self.mint, 100_000_000)),
+            (self.maker_ta, crate::tests::pack_token_account(&self.maker, &self.mint, 0)),
+            (token_program, token_program_account),
+        ];
+
+        (instruction, accounts)
+    }
+
+    // Swaps the account at `index` for another one at another address
+    fn spoof(
+        instruction: &mut Instruction,
+        accounts: &mut [(Pubkey, AccountSharedData)],
+        index: usize,
+        key: Pubkey,
+        account: AccountSharedData,
+    ) {
+        instruction.accounts[index].pubkey = key;
+        accounts[index] = (key, account);
+    }
+
+    fn fails(&self, instruction: &Instruction, accounts: &[(Pubkey, AccountSharedData)], error: ProgramError) {
+        self.mollusk.process_and_validate_instruction(instruction, accounts, &[Check::err(error)]);
+    }
+}
+
+#[test]
+fn untouched_accounts_pass() {
+    let campaign = Campaign::new();
+
+    for (instruction, accounts) in [campaign.contribute(), campaign.refund(), campaign.checker()] {
+        campaign.mollusk.process_and_validate_instruction(&instruction, &accounts, &[Check::success()]);
+    }
+}
+
+#[test]
+fn contribute_without_signature() {
+    let campaign = Campaign::new();
+    let (mut instruction, accounts) = campaign.contribute();
+    instruction.accounts[0].is_signer = false;
+
+    campaign.fails(&instruction, &accounts, ProgramError::MissingRequiredSignature);
+}
+
+#[test]
+fn contribute_into_someone_elses_contributor_account() {
+    let campaign = Campaign::new();
+    let (mut instruction, mut accounts) = campaign.contribute();
+    let other = Pubkey::find_program_address(
+        &[b"contributor", campaign.fundraiser.as_ref(), Pubkey::new_from_array([0x7; 32]).as_ref()],
+        &campaign.program_id,
+    )
+    .0;
+    Campaign::spoof(&mut instruction, &mut accounts, 1, other, campaign.contributor_account());
+
+    campaign.fails(&instruction, &accounts, ProgramError::InvalidSeeds);
+}
+
+#[test]
+fn contribute_into_a_vault_the_fundraiser_does_not_own() {
+    let campaign = Campaign::new();
+    let (instruction, mut accounts) = campaign.contribute();
+    accounts[4].1 = crate::tests::pack_token_account(&campaign.contributor, &campaign.mint, 0);
+
+    campaign.fails(&instruction, &accounts, ProgramError::InvalidAccountData);
+}
+
+#[test]
+fn contribute_from_a_token_account_of_another_mint() {
+    let campaign = Campaign::new();
+    let (instruction, mut accounts) = campaign.contribute();
+    accounts[2].1 = crate::tests::pack_token_account(&campaign.contributor, &Pubkey::new_from_array([0x8; 32]), 1_000_000);
+
+    campaign.fails(&instruction, &accounts, ProgramError::InvalidAccountData);
+}
+
+#[test]
+fn contribute_through_a_fake_token_program() {
+    let campaign = Campaign::new();
+    let (mut instruction, mut accounts) = campaign.contribute();
+    Campaign::spoof(&mut instruction, &mut accounts, 5, Pubkey::new_from_array([0x9; 32]), AccountSharedData::default());
+
+    campaign.fails(&instruction, &accounts, ProgramError::IncorrectProgramId);
+}
+
+#[test]
+fn contribute_through_a_fake_system_program() {
+    let campaign = Campaign::new();
+    let (mut instruction, mut accounts) = campaign.contribute();
+    Campaign::spoof(&mut instruction, &mut accounts, 7, Pubkey::new_from_array([0x9; 32]), AccountSharedData::default());
+
+    campaign.fails(&instruction, &accounts, ProgramError::IncorrectProgramId);
+}
+
+#[test]
+fn fundraiser_owned_by_another_program() {
+    let campaign = Campaign::new();
+    let (instruction, mut accounts) = campaign.contribute();
+    let mut fundraiser_account = campaign.fundraiser_account(100_000);
+    fundraiser_account.set_owner(Pubkey::new_from_array([0x9; 32]));
+    accounts[3].1 = fundraiser_account;
+
+    campaign.fails(&instruction, &accounts, ProgramError::IncorrectProgramId);
+}
+
+#[test]
+fn fundraiser_copied_to_another_address() {
+    let campaign = Campaign::new();
+    let (mut instruction, mut accounts) = campaign.refund();
+    Campaign::spoof(
+        &mut instruction,
+        &mut accounts,
+        2,
+        Pubkey::new_from_array([0x9; 32]),
+        campaign.fundraiser_account(100_000),
+    );
+
+    campaign.fails(&instruction, &accounts, ProgramError::InvalidSeeds);
+}
+
+#[test]
+fn refund_without_signature() {
+    let campaign = Campaign::new();
+    let (mut instruction, accounts) = campaign.refund();
+    instruction.accounts[0].is_signer = false;
+
+    campaign.fails(&instruction, &accounts, ProgramError::MissingRequiredSignature);
+}
+
+#[test]
+fn refund_someone_elses_contribution() {
+    let campaign = Campaign::new();
+    let (mut instruction, mut accounts) = campaign.refund();
+    let thief = Pubkey::new_from_array([0x7; 32]);
+    Campaign::spoof(&mut instruction, &mut accounts, 0, thief, AccountSharedData::new(1_000_000_000, 0, &system_program::ID));
+    accounts[4].1 = crate::tests::pack_token_account(&thief, &campaign.mint, 0);
+
+    campaign.fails(&instruction, &accounts, ProgramError::InvalidSeeds);
+}
+
+#[test]
+fn refund_into_a_token_account_of_someone_else() {
+    let campaign = Campaign::new();
+    let (instruction, mut accounts) = campaign.refund();
+    accounts[4].1 = crate::tests::pack_token_account(&Pubkey::new_from_array([0x7; 32]), &campaign.mint, 0);
+
+    campaign.fails(&instruction, &accounts, ProgramError::InvalidAccountData);
+}
+
+#[test]
+fn refund_with_another_maker() {
+    let campaign = Campaign::new();
+    let (mut instruction, mut accounts) = campaign.refund();
+    Campaign::spoof(
+        &mut instruction,
+        &mut accounts,
+        1,
+        Pubkey::new_from_array([0x7; 32]),
+        AccountSharedData::new(1_000_000_000, 0, &system_program::ID),
+    );
+
+    campaign.fails(
+        &instruction,
+        &accounts,
+        ProgramError::Custom(FundraiserError::InvalidFundraiserAccount as u32),
+    );
+}
+
+#[test]
+fn refund_from_a_vault_the_fundraiser_does_not_own() {
+    let campaign = Campaign::new();
+    let (instruction, mut accounts) = campaign.refund();
+    accounts[5].1 = crate::tests::pack_token_account(&campaign.maker, &campaign.mint, 100_000);
+
+    campaign.fails(&instruction, &accounts, ProgramError::InvalidAccountData);
+}
+
+#[test]
+fn refund_with_another_mint() {
+    let campaign = Campaign::new();
+    let (mut instruction, mut accounts) = campaign.refund();
+    let other_mint = Pubkey::new_from_array([0x8; 32]);
+    Campaign::spoof(&mut instruction, &mut accounts, 7, other_mint, crate::tests::pack_mint(&campaign.maker, 0));
+
+    campaign.fails(&instruction, &accounts, ProgramError::InvalidAccountData);
+}
+
+#[test]
+fn checker_signed_by_someone_else() {
+    let campaign = Campaign::new();
+    let (mut instruction, mut accounts) = campaign.checker();
+    let other = Pubkey::new_from_array([0x7; 32]);
+    Campaign::spoof(&mut instruction, &mut accounts, 0, other, AccountSharedData::new(1_000_000_000, 0, &system_program::ID));
+    accounts[4].1 = crate::tests::pack_token_account(&other, &campaign.mint, 0);
+
+    campaign.fails(
+        &instruction,
+        &accounts,
+        ProgramError::Custom(FundraiserError::InvalidFundraiserAccount as u32),
+    );
+}
+
+#[test]
+fn checker_paying_out_to_someone_else() {
+    let campaign = Campaign::new();
+    let (instruction, mut accounts) = campaign.checker();
+    accounts[4].1 = crate::tests::pack_token_account(&Pubkey::new_from_array([0x7; 32]), &campaign.mint, 0);
+
+    campaign.fails(&instruction, &accounts, ProgramError::InvalidAccountData);
+}
\ No newline at end of file
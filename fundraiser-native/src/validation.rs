@@ -0,0 +1,142 @@
+use borsh::BorshDeserialize;
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    system_program,
+};
+use spl_token_2022::{extension::StateWithExtensions, state::Account as TokenAccount};
+
+use crate::{
+    state::{Config, Contributor, Fundraiser},
+    error::FundraiserError,
+};
+
+// Every account an instruction reads or signs for goes through these checks before it is trusted
+
+pub fn check_signer(account: &AccountInfo) -> ProgramResult {
+    if !account.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    Ok(())
+}
+
+pub fn check_owner(account: &AccountInfo, owner: &Pubkey) -> ProgramResult {
+    if account.owner != owner {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    Ok(())
+}
+
+// Checks the account is the PDA of the seeds and returns its bump
+pub fn check_pda(account: &AccountInfo, seeds: &[&[u8]]) -> Result<u8, ProgramError> {
+    let (pda, bump) = Pubkey::find_program_address(seeds, &crate::ID);
+    if pda != *account.key {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    Ok(bump)
+}
+
+// An account that already holds data was set up before, creating it again would overwrite its state
+pub fn check_uninitialized(account: &AccountInfo) -> ProgramResult {
+    if !account.data_is_empty() {
+        return Err(ProgramError::AccountAlreadyInitialized);
+    }
+
+    Ok(())
+}
+
+pub fn check_system_program(account: &AccountInfo) -> ProgramResult {
+    if *account.key != system_program::ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    Ok(())
+}
+
+// The fundraiser PDA signs for the vault, so the token program has to be a real one
+pub fn check_token_program(account: &AccountInfo) -> ProgramResult {
+    if *account.key != spl_token::ID && *account.key != spl_token_2022::ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    Ok(())
+}
+
+// Checks the mint is the expected one and belongs to the token program
+pub fn check_mint(account: &AccountInfo, mint: &Pubkey, token_program: &AccountInfo) -> ProgramResult {
+    if account.key != mint {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    check_owner(account, token_program.key)
+}
+
+// Loads a fundraiser owned by the program and checks it sits at the PDA of its own maker, campaign id and bump.
+// The stored maker is what the fundraiser signs with, so a maker account passed in is never needed for the seeds
+pub fn load_fundraiser(account: &AccountInfo) -> Result<Fundraiser, ProgramError> {
+    check_owner(account, &crate::ID)?;
+
+    let fundraiser = Fundraiser::try_from_slice(&account.data.borrow())?;
+    let pda = Pubkey::create_program_address(
+        &[b"fundraiser", fundraiser.maker.as_ref(), fundraiser.campaign_id.to_le_bytes().as_ref(), &[fundraiser.bump]],
+        &crate::ID,
+    )
+    .map_err(|_| ProgramError::InvalidSeeds)?;
+    if pda != *account.key {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    Ok(fundraiser)
+}
+
+pub fn check_maker(account: &AccountInfo, fundraiser: &Fundraiser) -> ProgramResult {
+    if *account.key != fundraiser.maker {
+        return Err(ProgramError::Custom(FundraiserError::InvalidFundraiserAccount as u32));
+    }
+
+    Ok(())
+}
+
+// Loads the contributor account the signer keeps for this fundraiser
+pub fn load_contributor(account: &AccountInfo, fundraiser: &Pubkey, contributor: &Pubkey) -> Result<Contributor, ProgramError> {
+    check_owner(account, &crate::ID)?;
+    check_pda(account, &[b"contributor", fundraiser.as_ref(), contributor.as_ref()])?;
+
+    Ok(Contributor::try_from_slice(&account.data.borrow())?)
+}
+
+// Unpacks a token account and checks it holds the mint for the owner
+pub fn load_token_account(account: &AccountInfo, mint: &Pubkey, owner: &Pubkey) -> Result<TokenAccount, ProgramError> {
+    if account.owner != &spl_token::ID && account.owner != &spl_token_2022::ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let token_account = StateWithExtensions::<TokenAccount>::unpack(&account.try_borrow_data()?)?.base;
+    if token_account.mint != *mint || token_account.owner != *owner {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    Ok(token_account)
+}
+
+// Loads the config a platform fee is paid under and returns who the fee goes to.
+// A fundraiser created with a fee can't pay out until the config and the fee recipient are passed
+pub fn load_fee_recipient(config: Option<&AccountInfo>) -> Result<Pubkey, ProgramError> {
+    let config = config.ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+    Config::load(config)?
+        .map(|config| config.fee_recipient)
+        .ok_or(ProgramError::Custom(FundraiserError::InvalidFeeRecipient as u32))
+}
+
+// The fee of a token fundraiser goes to a token account of the fee recipient for the raised mint
+pub fn check_fee_recipient_ta(account: &AccountInfo, mint: &Pubkey, fee_recipient: &Pubkey) -> ProgramResult {
+    load_token_account(account, mint, fee_recipient)
+        .map(|_| ())
+        .map_err(|_| ProgramError::Custom(FundraiserError::InvalidFeeRecipient as u32))
+}
\ No newline at end of file
@@ -0,0 +1,129 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{AccountMeta, Instruction, Signer},
+    program::invoke,
+    signer,
+    sysvars::{rent::Rent, Sysvar},
+    program_error::ProgramError,
+    pubkey::{find_program_address, Pubkey},
+    ProgramResult,
+};
+use pinocchio_system::instructions::{Allocate, Assign, CreateAccount, Transfer};
+
+use crate::state::Contributor;
+
+pub const ASSOCIATED_TOKEN_PROGRAM_ID: Pubkey =
+    five8_const::decode_32_const("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
+
+// Creates a rent-exempt account owned by the program at the PDA the signers sign for, like Anchor's `init`.
+// Anyone can send lamports to the address first, such an account is topped up, allocated and assigned instead.
+pub fn create_pda_account(payer: &AccountInfo, account: &AccountInfo, space: usize, signers: &[Signer]) -> ProgramResult {
+    let lamports = Rent::get()?.minimum_balance(space);
+
+    if account.lamports() == 0 {
+        return CreateAccount {
+            from: payer,
+            to: account,
+            lamports,
+            space: space as u64,
+            owner: &crate::ID,
+        }
+        .invoke_signed(signers);
+    }
+
+    let top_up = lamports.saturating_sub(account.lamports());
+    if top_up > 0 {
+        Transfer {
+            from: payer,
+            to: account,
+            lamports: top_up,
+        }
+        .invoke()?;
+    }
+    Allocate {
+        account,
+        space: space as u64,
+    }
+    .invoke_signed(signers)?;
+    Assign {
+        account,
+        owner: &crate::ID,
+    }
+    .invoke_signed(signers)
+}
+
+// Moves lamports out of an account the program owns, which the system program can't transfer from
+pub fn move_lamports(from: &AccountInfo, to: &AccountInfo, amount: u64) -> ProgramResult {
+    let from_lamports = from.lamports().checked_sub(amount).ok_or(ProgramError::InsufficientFunds)?;
+    let to_lamports = to.lamports().checked_add(amount).ok_or(ProgramError::ArithmeticOverflow)?;
+
+    *from.try_borrow_mut_lamports()? = from_lamports;
+    *to.try_borrow_mut_lamports()? = to_lamports;
+
+    Ok(())
+}
+
+// The first contribution creates the contributor account, the contributor pays for it. It goes at the canonical
+// bump and keeps it, so later instructions check the address with a single hash.
+pub fn create_contributor(signer: &AccountInfo, contributor: &AccountInfo, fundraiser: &AccountInfo) -> ProgramResult {
+    if contributor.owner() == &crate::ID {
+        return Ok(());
+    }
+
+    let contributor_seed = b"contributor".as_ref();
+    let fundraiser_seed = fundraiser.key().as_ref();
+    let signer_seed = signer.key().as_ref();
+    let (address, bump) = find_program_address(&[contributor_seed, fundraiser_seed, signer_seed], &crate::ID);
+    assert_eq!(&address, contributor.key(), "Invalid contributor account");
+
+    let bump_seed = &[bump];
+    create_pda_account(
+        signer,
+        contributor,
+        Contributor::LEN,
+        &[signer!(contributor_seed, fundraiser_seed, signer_seed, bump_seed)],
+    )?;
+
+    Contributor::init(contributor, bump);
+
+    Ok(())
+}
+
+// --Accounts--
+// payer: writable, signer
+// account: writable
+// owner: readonly
+// mint: readonly
+// system_program: readonly
+// token_program: readonly
+//
+// --Data--
+// discriminator: u8 (1, CreateIdempotent)
+//
+// The associated token program checks the address. Idempotent, so an account someone created ahead of
+// the maker does not block the fundraiser, and a contributor's existing account is reused
+pub fn create_associated_token_account(
+    payer: &AccountInfo,
+    account: &AccountInfo,
+    owner: &AccountInfo,
+    mint: &AccountInfo,
+    system_program: &AccountInfo,
+    token_program: &AccountInfo,
+) -> ProgramResult {
+    let account_metas: [AccountMeta; 6] = [
+        AccountMeta::writable_signer(payer.key()),
+        AccountMeta::writable(account.key()),
+        AccountMeta::readonly(owner.key()),
+        AccountMeta::readonly(mint.key()),
+        AccountMeta::readonly(system_program.key()),
+        AccountMeta::readonly(token_program.key()),
+    ];
+
+    let instruction = Instruction {
+        program_id: &ASSOCIATED_TOKEN_PROGRAM_ID,
+        accounts: &account_metas,
+        data: &[1],
+    };
+
+    invoke(&instruction, &[payer, account, owner, mint, system_program, token_program])
+}
\ No newline at end of file
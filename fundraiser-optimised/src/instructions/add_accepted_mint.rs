@@ -0,0 +1,53 @@
+use crate::{state::{Fundraiser, FundraiserStatus, PriceFeed, MAX_ACCEPTED_MINTS}, token::{has_transfer_hook, is_token_program}};
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+
+pub fn add_accepted_mint_instruction(accounts: &[AccountInfo]) -> ProgramResult {
+    let [maker, mint, price_feed, fundraiser] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if !maker.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let fundraiser_account = Fundraiser::from_account_info(fundraiser);
+    assert_eq!(&fundraiser_account.maker(), maker.key(), "Only the maker can add a mint");
+
+    assert!(is_token_program(mint.owner()), "Invalid mint");
+    assert!(!has_transfer_hook(mint), "Transfer hooks are not supported");
+
+    // Mints can only be added to a token fundraiser paying out in one go, before anyone has contributed
+    assert!(
+        fundraiser_account.status() == FundraiserStatus::Active as u8
+            && !fundraiser_account.is_sol()
+            && !fundraiser_account.issues_receipts()
+            && fundraiser_account.amount_raised() == 0
+            && fundraiser_account.milestone_count() == 0
+            && fundraiser_account.voting_period() == 0,
+        "Cannot add a mint to this fundraiser"
+    );
+
+    let price_feed_account = PriceFeed::from_account_info(price_feed);
+
+    // The first extra mint turns the fundraiser into a multi-mint one, the original mint stays accepted
+    if !fundraiser_account.is_multi_mint() {
+        fundraiser_account.set_price_authority(&price_feed_account.authority());
+        fundraiser_account.add_accepted_mint(&fundraiser_account.mint_to_raise());
+    }
+
+    // Every accepted mint is valued by the same price authority
+    assert!(
+        price_feed_account.authority() == fundraiser_account.price_authority() && &price_feed_account.mint() == mint.key(),
+        "Invalid price feed"
+    );
+
+    let index = fundraiser_account.accepted_mint_count() as usize;
+    assert!(
+        index < MAX_ACCEPTED_MINTS && fundraiser_account.accepted_mint_index(mint.key()).is_none(),
+        "Cannot add a mint to this fundraiser"
+    );
+
+    fundraiser_account.add_accepted_mint(mint.key());
+
+    Ok(())
+}
\ No newline at end of file
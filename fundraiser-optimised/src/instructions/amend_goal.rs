@@ -0,0 +1,39 @@
+use crate::{events::{emit, FundraiserEvent}, state::Fundraiser};
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult, sysvars::{clock::Clock, Sysvar}};
+
+pub fn amend_goal_instruction(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let [maker, fundraiser] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if data.len() != 8 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+    let amount_to_raise = u64::from_le_bytes(data.try_into().unwrap());
+
+    if !maker.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let fundraiser_account = Fundraiser::from_account_info(fundraiser);
+    assert_eq!(&fundraiser_account.maker(), maker.key(), "Only the maker can amend the fundraiser");
+
+    assert!(amount_to_raise > 0, "Invalid amount to raise");
+    assert!(amount_to_raise < fundraiser_account.amount_to_raise(), "The goal can only be lowered");
+
+    // Contributors can withdraw until the grace period is over
+    fundraiser_account.amend(Clock::get()?.unix_timestamp);
+    fundraiser_account.set_amount_to_raise(amount_to_raise);
+
+    // The contribution limits have to hold for the new goal too
+    assert!(fundraiser_account.contribution_limits_valid(), "Invalid contribution limits");
+
+    emit(FundraiserEvent::Amended {
+        fundraiser: *fundraiser.key(),
+        maker: *maker.key(),
+        amount_to_raise,
+        time_ending: fundraiser_account.time_ending(),
+    });
+
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,34 @@
+use crate::{events::{emit, FundraiserEvent}, state::{Fundraiser, FundraiserStatus}};
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+
+pub fn cancel_instruction(accounts: &[AccountInfo]) -> ProgramResult {
+    let [maker, fundraiser] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if !maker.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let fundraiser_account = Fundraiser::from_account_info(fundraiser);
+
+    assert_eq!(&fundraiser_account.maker(), maker.key(), "Only the maker can cancel the fundraiser");
+    assert!(
+        fundraiser_account.status() == FundraiserStatus::Active as u8,
+        "Only an active fundraiser can be cancelled"
+    );
+
+    assert!(
+        fundraiser_account.milestones_released() == 0,
+        "A fundraiser can not be cancelled once a milestone has been released"
+    );
+
+    fundraiser_account.set_status(FundraiserStatus::Cancelled);
+
+    emit(FundraiserEvent::Cancelled {
+        fundraiser: *fundraiser.key(),
+        maker: *maker.key(),
+    });
+
+    Ok(())
+}
@@ -1,32 +1,112 @@
-use crate::state::Fundraiser;
+use crate::{create::move_lamports, events::{emit, FundraiserEvent}, state::{Config, Fundraiser, FundraiserStatus, FundingMode}, token::{load_token_account, TransferChecked}};
+use fundraiser_interface::state::fee_amount;
 use pinocchio::{account_info::AccountInfo, program_error::ProgramError, signer, ProgramResult, sysvars::{clock::Clock, Sysvar}};
-use pinocchio_token::instructions::Transfer;
+use pinocchio_token::state::Mint;
 
 pub fn checker_instruction(accounts: &[AccountInfo]) -> ProgramResult {
-    let [maker, maker_ta, fundraiser, vault, _token_program] = accounts else {
+    let [maker, mint, fundraiser, vault, maker_ta, token_program, remaining @ ..] = accounts else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
+    if !maker.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
     let fundraiser_account = Fundraiser::from_account_info(fundraiser);
+    assert_eq!(&fundraiser_account.maker(), maker.key(), "Only the maker can withdraw the funds");
+
     let bump = fundraiser_account.bump();
     let fundraiser_seed = b"fundraiser".as_ref();
     let maker_seed = maker.key().as_ref();
+    let campaign_id = fundraiser_account.campaign_id().to_le_bytes();
+    let campaign_id_seed = campaign_id.as_ref();
     let bump_seed = &[bump];
 
+    assert!(
+        fundraiser_account.status() != FundraiserStatus::Cancelled as u8,
+        "A cancelled fundraiser can only be refunded"
+    );
+
+    assert!(
+        fundraiser_account.milestone_count() == 0,
+        "Funds are released through milestones"
+    );
+
     let current_time = Clock::get()?.unix_timestamp;
 
+    // Contributors get to withdraw after an amendment before the maker is paid
     assert!(
-        current_time > fundraiser_account.time_ending(),
-        "You can only withdraw funds if the fundraiser has ended"
+        !fundraiser_account.in_grace_period(current_time),
+        "Contributors can still withdraw after the last amendment"
     );
 
-    Transfer {
+    // A fixed fundraiser pays out as soon as its target is met, a flexible one pays out whatever was raised once it has ended
+    if fundraiser_account.funding_mode() == FundingMode::Fixed as u8 {
+        assert!(
+            fundraiser_account.amount_raised() >= fundraiser_account.amount_to_raise(),
+            "The amount to raise has not been met"
+        );
+    } else {
+        assert!(
+            current_time >= fundraiser_account.time_ending(),
+            "You can only withdraw funds if the fundraiser has ended"
+        );
+    }
+
+    assert!(!fundraiser_account.is_multi_mint(), "Use the multi-mint instructions");
+
+    assert_eq!(&fundraiser_account.mint_to_raise(), mint.key(), "Wrong mint");
+
+    // The funds go to the maker's own token account of the raised mint
+    load_token_account(maker_ta, mint.key(), maker.key());
+
+    let decimals = unsafe { Mint::from_bytes(mint.borrow_data_unchecked()).decimals() };
+
+    // A fundraiser created with a platform fee pays it to the fee recipient of the config
+    let fee = fee_amount(fundraiser_account.amount_raised(), fundraiser_account.fee_bps());
+    if fundraiser_account.fee_bps() > 0 {
+        let [config, fee_recipient_ta, ..] = remaining else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        let config = Config::load(config).expect("Invalid fee recipient");
+        load_token_account(fee_recipient_ta, mint.key(), &config.fee_recipient());
+
+        if fee > 0 {
+            TransferChecked {
+                from: vault,
+                mint,
+                to: fee_recipient_ta,
+                authority: fundraiser,
+                token_program,
+                amount: fee,
+                decimals,
+            }
+            .invoke_signed(&[signer!(fundraiser_seed, maker_seed, campaign_id_seed, bump_seed)])?;
+        }
+    }
+
+    TransferChecked {
         from: vault,
+        mint,
         to: maker_ta,
         authority: fundraiser,
-        amount: fundraiser_account.amount_raised(),
+        token_program,
+        amount: fundraiser_account.amount_raised() - fee,
+        decimals,
     }
-    .invoke_signed(&[signer!(fundraiser_seed, maker_seed, bump_seed)])?;
+    .invoke_signed(&[signer!(fundraiser_seed, maker_seed, campaign_id_seed, bump_seed)])?;
+
+    emit(FundraiserEvent::Withdrawn {
+        fundraiser: *fundraiser.key(),
+        maker: *maker.key(),
+        mint: *mint.key(),
+        amount: fundraiser_account.amount_raised() - fee,
+    });
+
+    // Close the fundraiser, its rent goes back to the maker
+    move_lamports(fundraiser, maker, fundraiser.lamports())?;
+    fundraiser.try_borrow_mut_data()?.fill(0);
 
     Ok(())
 }
\ No newline at end of file
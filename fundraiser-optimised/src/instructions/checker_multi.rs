@@ -0,0 +1,116 @@
+use crate::{events::{emit, FundraiserEvent}, state::{Config, Fundraiser, FundraiserStatus, FundingMode, PriceFeed}, token::{load_token_account, TransferChecked}};
+use fundraiser_interface::state::fee_amount;
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, signer, ProgramResult, sysvars::{clock::Clock, Sysvar}};
+use pinocchio_token::state::{Mint, TokenAccount};
+
+// Pays out the vault of one accepted mint, the maker calls this once per mint
+pub fn checker_multi_instruction(accounts: &[AccountInfo]) -> ProgramResult {
+    let [maker, mint, fundraiser, vault, maker_ta, token_program, price_feed, remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let fundraiser_account = Fundraiser::from_account_info(fundraiser);
+
+    // The config and fee recipient of a fundraiser with a platform fee come before the price feeds
+    let fee_accounts = if fundraiser_account.fee_bps() > 0 { 2 } else { 0 };
+    if remaining.len() < fee_accounts {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+    let (fee_accounts, price_feeds) = remaining.split_at(fee_accounts);
+
+    let bump = fundraiser_account.bump();
+    let fundraiser_seed = b"fundraiser".as_ref();
+    let maker_seed = maker.key().as_ref();
+    let campaign_id = fundraiser_account.campaign_id().to_le_bytes();
+    let campaign_id_seed = campaign_id.as_ref();
+    let bump_seed = &[bump];
+
+    assert!(
+        fundraiser_account.status() == FundraiserStatus::Active as u8,
+        "A cancelled fundraiser can only be refunded"
+    );
+
+    assert!(
+        fundraiser_account.accepted_mint_index(mint.key()).is_some(),
+        "Mint is not accepted by the fundraiser"
+    );
+
+    let price_feed_account = PriceFeed::from_account_info(price_feed);
+    assert!(
+        price_feed_account.authority() == fundraiser_account.price_authority() && &price_feed_account.mint() == mint.key(),
+        "Invalid price feed"
+    );
+
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // Contributors get to withdraw after an amendment before the maker is paid
+    assert!(
+        !fundraiser_account.in_grace_period(current_time),
+        "Contributors can still withdraw after the last amendment"
+    );
+
+    // A fixed fundraiser values every vault at the current prices, once the first payout went through the target counts as met
+    if fundraiser_account.funding_mode() == FundingMode::Fixed as u8 {
+        assert!(
+            fundraiser_account.amount_released() > 0
+                || fundraiser_account.value_raised(price_feeds) >= fundraiser_account.amount_to_raise(),
+            "The amount to raise has not been met"
+        );
+    } else {
+        assert!(
+            current_time >= fundraiser_account.time_ending(),
+            "You can only withdraw funds if the fundraiser has ended"
+        );
+    }
+
+    let vault_account = unsafe { TokenAccount::from_bytes(vault.borrow_data_unchecked()) };
+    assert!(
+        vault_account.owner() == fundraiser.key() && vault_account.mint() == mint.key(),
+        "Vault does not belong to the fundraiser mint"
+    );
+    let amount = vault_account.amount();
+    let decimals = unsafe { Mint::from_bytes(mint.borrow_data_unchecked()).decimals() };
+
+    // A fundraiser created with a platform fee pays it to the fee recipient of the config
+    let fee = fee_amount(amount, fundraiser_account.fee_bps());
+    if let [config, fee_recipient_ta] = fee_accounts {
+        let config = Config::load(config).expect("Invalid fee recipient");
+        load_token_account(fee_recipient_ta, mint.key(), &config.fee_recipient());
+
+        if fee > 0 {
+            TransferChecked {
+                from: vault,
+                mint,
+                to: fee_recipient_ta,
+                authority: fundraiser,
+                token_program,
+                amount: fee,
+                decimals,
+            }
+            .invoke_signed(&[signer!(fundraiser_seed, maker_seed, campaign_id_seed, bump_seed)])?;
+        }
+    }
+
+    TransferChecked {
+        from: vault,
+        mint,
+        to: maker_ta,
+        authority: fundraiser,
+        token_program,
+        amount: amount - fee,
+        decimals,
+    }
+    .invoke_signed(&[signer!(fundraiser_seed, maker_seed, campaign_id_seed, bump_seed)])?;
+
+    // Track the value paid out so far in the common unit, the fee included
+    fundraiser_account.add_amount_released(price_feed_account.value(amount));
+
+    emit(FundraiserEvent::Withdrawn {
+        fundraiser: *fundraiser.key(),
+        maker: *maker.key(),
+        mint: *mint.key(),
+        amount: amount - fee,
+    });
+
+    Ok(())
+}
\ No newline at end of file
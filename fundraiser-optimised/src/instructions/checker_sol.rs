@@ -0,0 +1,72 @@
+use crate::{create::move_lamports, events::{emit, FundraiserEvent}, state::{Config, Fundraiser, FundraiserStatus, FundingMode}};
+use fundraiser_interface::state::fee_amount;
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey, ProgramResult, sysvars::{clock::Clock, rent::Rent, Sysvar}};
+
+pub fn checker_sol_instruction(accounts: &[AccountInfo]) -> ProgramResult {
+    let [maker, fundraiser, remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if !maker.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let fundraiser_account = Fundraiser::from_account_info(fundraiser);
+    assert_eq!(&fundraiser_account.maker(), maker.key(), "Only the maker can withdraw the funds");
+    assert!(fundraiser_account.is_sol(), "Fundraiser does not raise SOL");
+
+    assert!(
+        fundraiser_account.status() != FundraiserStatus::Cancelled as u8,
+        "A cancelled fundraiser can only be refunded"
+    );
+
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // Contributors get to withdraw after an amendment before the maker is paid
+    assert!(
+        !fundraiser_account.in_grace_period(current_time),
+        "Contributors can still withdraw after the last amendment"
+    );
+
+    // A fixed fundraiser pays out as soon as its target is met, a flexible one pays out whatever was raised once it has ended
+    if fundraiser_account.funding_mode() == FundingMode::Fixed as u8 {
+        assert!(
+            fundraiser_account.amount_raised() >= fundraiser_account.amount_to_raise(),
+            "The amount to raise has not been met"
+        );
+    } else {
+        assert!(
+            current_time >= fundraiser_account.time_ending(),
+            "You can only withdraw funds if the fundraiser has ended"
+        );
+    }
+
+    // Everything above the rent-exempt balance was raised, the fundraiser account itself stays alive
+    let amount = fundraiser
+        .lamports()
+        .saturating_sub(Rent::get()?.minimum_balance(Fundraiser::LEN));
+
+    // A fundraiser created with a platform fee pays it to the fee recipient of the config
+    let fee = fee_amount(amount, fundraiser_account.fee_bps());
+    if fundraiser_account.fee_bps() > 0 {
+        let [config, fee_recipient, ..] = remaining else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        let config = Config::load(config).expect("Invalid fee recipient");
+        assert_eq!(fee_recipient.key(), &config.fee_recipient(), "Invalid fee recipient");
+
+        move_lamports(fundraiser, fee_recipient, fee)?;
+    }
+
+    move_lamports(fundraiser, maker, amount - fee)?;
+
+    emit(FundraiserEvent::Withdrawn {
+        fundraiser: *fundraiser.key(),
+        maker: *maker.key(),
+        mint: Pubkey::default(),
+        amount: amount - fee,
+    });
+
+    Ok(())
+}
\ No newline at end of file
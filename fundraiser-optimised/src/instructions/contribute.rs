@@ -1,36 +1,83 @@
-use crate::state::Fundraiser;
+use crate::{create::create_contributor, events::{emit, FundraiserEvent}, state::{Contributor, Fundraiser, FundraiserStatus, RewardTiers}, token::{load_token_account, TransferChecked}};
+use fundraiser_interface::instruction::{contribute, unpack_reward_tier};
 use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult,  sysvars::{clock::Clock, Sysvar}};
-use pinocchio_token::instructions::Transfer;
+use pinocchio_token::state::Mint;
 
 pub fn contribute_instruction(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
-    let amount: u64 = unsafe { *(data.as_ptr() as *const u64) };
+    let amount = u64::from_le_bytes(
+        data.get(contribute::AMOUNT..contribute::LEN)
+            .and_then(|amount| amount.try_into().ok())
+            .ok_or(ProgramError::InvalidInstructionData)?,
+    );
+    let reward_tier = unpack_reward_tier(data).ok_or(ProgramError::InvalidInstructionData)?;
 
-    let [signer, contributor, signer_ta, fundraiser, vault, _token_program] = accounts else {
+    let [signer, contributor, signer_ta, fundraiser, vault, token_program, mint, _system_program, remaining @ ..] = accounts else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
-    let fundraiser_account = Fundraiser::from_account_info_unchecked(fundraiser);
+    create_contributor(signer, contributor, fundraiser)?;
+
+    let fundraiser_account = Fundraiser::from_account_info(fundraiser);
+    let contributor_account = Contributor::from_account_info(contributor, fundraiser.key(), signer.key());
+
+    assert!(
+        fundraiser_account.status() == FundraiserStatus::Active as u8,
+        "Fundraiser has been cancelled"
+    );
 
     let current_time = Clock::get()?.unix_timestamp;
-    println!("current time: {:?}, fundraiser end time: {:?}", current_time, fundraiser_account.time_ending());
-    
     assert!(
         current_time <= fundraiser_account.time_ending(),
         "Fundraiser has ended"
     );
 
-    Transfer {
+    // Check the contribution against the limits the maker set
+    fundraiser_account.check_contribution(contributor_account.amount(), amount)?;
+
+    assert!(!fundraiser_account.is_multi_mint(), "Use the multi-mint instructions");
+    assert!(!fundraiser_account.issues_receipts(), "Use the receipt instructions");
+
+    assert_eq!(&fundraiser_account.mint_to_raise(), mint.key(), "Wrong mint");
+
+    // The contribution is credited by what the vault receives, so it has to be the fundraiser's vault.
+    // A transfer fee can make the vault receive less than the amount sent
+    let vault_amount_before = load_token_account(vault, mint.key(), fundraiser.key()).amount();
+
+    TransferChecked {
         from: signer_ta,
+        mint,
         to: vault,
         authority: signer,
+        token_program,
         amount,
+        decimals: unsafe { Mint::from_bytes(mint.borrow_data_unchecked()).decimals() },
     }
     .invoke()?;
 
-    unsafe {
-        *(fundraiser.borrow_mut_data_unchecked().as_mut_ptr().add(72) as *mut u64) += amount;
-        *(contributor.borrow_mut_data_unchecked().as_mut_ptr() as *mut u64) += amount;
+    // Only credit what actually landed in the vault
+    let amount_received = load_token_account(vault, mint.key(), fundraiser.key()).amount() - vault_amount_before;
+
+    fundraiser_account.add_amount_raised(amount_received);
+    contributor_account.add_amount(amount_received);
+
+    // The pledge has to reach the minimum of the tier the contributor picked, with a slot left
+    if let Some(index) = reward_tier {
+        let [reward_tiers, ..] = remaining else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+        let reward_tiers_account = RewardTiers::from_account_info(reward_tiers);
+        assert_eq!(&reward_tiers_account.fundraiser(), fundraiser.key(), "Invalid reward tier");
+
+        reward_tiers_account.claim(contributor_account.reward_tier(), index, contributor_account.amount());
+        contributor_account.set_reward_tier(index + 1);
     }
 
+    emit(FundraiserEvent::Contributed {
+        fundraiser: *fundraiser.key(),
+        contributor: *signer.key(),
+        mint: *mint.key(),
+        amount: amount_received,
+    });
+
     Ok(())
 }
\ No newline at end of file
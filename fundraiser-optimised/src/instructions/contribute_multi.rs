@@ -0,0 +1,77 @@
+use crate::{create::create_contributor, events::{emit, FundraiserEvent}, state::{Contributor, Fundraiser, FundraiserStatus, PriceFeed}, token::{load_token_account, TransferChecked}};
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult, sysvars::{clock::Clock, Sysvar}};
+use pinocchio_token::state::Mint;
+
+pub fn contribute_multi_instruction(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let amount = u64::from_le_bytes(data.try_into().map_err(|_| ProgramError::InvalidInstructionData)?);
+
+    let [signer, contributor, signer_ta, fundraiser, vault, token_program, mint, price_feed, _system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    create_contributor(signer, contributor, fundraiser)?;
+
+    let fundraiser_account = Fundraiser::from_account_info(fundraiser);
+    let contributor_account = Contributor::from_account_info(contributor, fundraiser.key(), signer.key());
+
+    assert!(
+        fundraiser_account.status() == FundraiserStatus::Active as u8,
+        "Fundraiser has been cancelled"
+    );
+
+    let current_time = Clock::get()?.unix_timestamp;
+    assert!(
+        current_time <= fundraiser_account.time_ending(),
+        "Fundraiser has ended"
+    );
+
+    let Some(index) = fundraiser_account.accepted_mint_index(mint.key()) else {
+        panic!("Mint is not accepted by the fundraiser");
+    };
+
+    let price_feed_account = PriceFeed::from_account_info(price_feed);
+    assert!(
+        price_feed_account.authority() == fundraiser_account.price_authority() && &price_feed_account.mint() == mint.key(),
+        "Invalid price feed"
+    );
+
+    // The contribution is valued in the common unit the goal is set in
+    assert!(price_feed_account.value(amount) > 0, "Contribution is worth nothing");
+
+    // Limits apply to the contribution's value in the common unit, not to the token amount
+    fundraiser_account.check_contribution(contributor_account.amount(), price_feed_account.value(amount))?;
+
+    // Every accepted mint has its own vault, the contribution is credited by what it receives
+    let vault_amount_before = load_token_account(vault, mint.key(), fundraiser.key()).amount();
+
+    TransferChecked {
+        from: signer_ta,
+        mint,
+        to: vault,
+        authority: signer,
+        token_program,
+        amount,
+        decimals: unsafe { Mint::from_bytes(mint.borrow_data_unchecked()).decimals() },
+    }
+    .invoke()?;
+
+    // Only credit what actually landed in the vault
+    let amount_received = load_token_account(vault, mint.key(), fundraiser.key()).amount() - vault_amount_before;
+    let value_received = price_feed_account.value(amount_received);
+
+    // Track the tokens per mint so refunds return them as they came in, and their value towards the goal
+    fundraiser_account.add_amount_raised(value_received);
+    fundraiser_account.add_mint_amount_raised(index, amount_received);
+
+    contributor_account.add_amount(value_received);
+    contributor_account.add_mint_amount(index, amount_received);
+
+    emit(FundraiserEvent::Contributed {
+        fundraiser: *fundraiser.key(),
+        contributor: *signer.key(),
+        mint: *mint.key(),
+        amount: amount_received,
+    });
+
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,85 @@
+use crate::{create::create_associated_token_account, events::{emit, FundraiserEvent}, state::{Fundraiser, FundraiserStatus}, token::{load_token_account, MintToChecked, TransferChecked}};
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, signer, ProgramResult, sysvars::{clock::Clock, Sysvar}};
+use pinocchio_token::state::Mint;
+
+pub fn contribute_receipts_instruction(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let amount = u64::from_le_bytes(data.try_into().map_err(|_| ProgramError::InvalidInstructionData)?);
+
+    let [signer, signer_ta, fundraiser, vault, token_program, mint, receipt_mint, signer_receipt_ta, system_program, _associated_token_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let fundraiser_account = Fundraiser::from_account_info(fundraiser);
+
+    assert!(
+        fundraiser_account.status() == FundraiserStatus::Active as u8,
+        "Fundraiser has been cancelled"
+    );
+
+    let current_time = Clock::get()?.unix_timestamp;
+
+    assert!(
+        current_time <= fundraiser_account.time_ending(),
+        "Fundraiser has ended"
+    );
+
+    assert!(fundraiser_account.issues_receipts(), "The fundraiser does not issue receipt tokens");
+    assert_eq!(&fundraiser_account.mint_to_raise(), mint.key(), "Wrong mint");
+    assert_eq!(&fundraiser_account.receipt_mint(), receipt_mint.key(), "Invalid receipt mint");
+
+    // The first contribution creates the contributor's receipt account, the contributor pays for it
+    create_associated_token_account(signer, signer_receipt_ta, signer, receipt_mint, system_program, token_program)?;
+
+    // The receipts held by the contributor take the place of the contributor account
+    let signer_receipt_account = load_token_account(signer_receipt_ta, receipt_mint.key(), signer.key());
+    fundraiser_account.check_contribution(signer_receipt_account.amount(), amount)?;
+
+    let decimals = unsafe { Mint::from_bytes(mint.borrow_data_unchecked()).decimals() };
+
+    // Receipts are minted for what the vault receives, so it has to be the fundraiser's vault.
+    // A transfer fee can make the vault receive less than the amount sent
+    let vault_amount_before = load_token_account(vault, mint.key(), fundraiser.key()).amount();
+
+    TransferChecked {
+        from: signer_ta,
+        mint,
+        to: vault,
+        authority: signer,
+        token_program,
+        amount,
+        decimals,
+    }
+    .invoke()?;
+
+    // Only what actually landed in the vault is backed by receipts
+    let amount_received = load_token_account(vault, mint.key(), fundraiser.key()).amount() - vault_amount_before;
+
+    let maker = fundraiser_account.maker();
+    let bump = fundraiser_account.bump();
+    let fundraiser_seed = b"fundraiser".as_ref();
+    let maker_seed = maker.as_ref();
+    let campaign_id = fundraiser_account.campaign_id().to_le_bytes();
+    let campaign_id_seed = campaign_id.as_ref();
+    let bump_seed = &[bump];
+
+    MintToChecked {
+        mint: receipt_mint,
+        to: signer_receipt_ta,
+        authority: fundraiser,
+        token_program,
+        amount: amount_received,
+        decimals,
+    }
+    .invoke_signed(&[signer!(fundraiser_seed, maker_seed, campaign_id_seed, bump_seed)])?;
+
+    fundraiser_account.add_amount_raised(amount_received);
+
+    emit(FundraiserEvent::Contributed {
+        fundraiser: *fundraiser.key(),
+        contributor: *signer.key(),
+        mint: *mint.key(),
+        amount: amount_received,
+    });
+
+    Ok(())
+}
\ No newline at end of file
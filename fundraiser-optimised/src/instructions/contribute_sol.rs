@@ -0,0 +1,51 @@
+use crate::{create::create_contributor, events::{emit, FundraiserEvent}, state::{Contributor, Fundraiser, FundraiserStatus}};
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey, ProgramResult, sysvars::{clock::Clock, Sysvar}};
+use pinocchio_system::instructions::Transfer;
+
+pub fn contribute_sol_instruction(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let amount = u64::from_le_bytes(data.try_into().map_err(|_| ProgramError::InvalidInstructionData)?);
+
+    let [signer, contributor, fundraiser, _system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    create_contributor(signer, contributor, fundraiser)?;
+
+    let fundraiser_account = Fundraiser::from_account_info(fundraiser);
+    let contributor_account = Contributor::from_account_info(contributor, fundraiser.key(), signer.key());
+
+    assert!(fundraiser_account.is_sol(), "Fundraiser does not raise SOL");
+    assert!(
+        fundraiser_account.status() == FundraiserStatus::Active as u8,
+        "Fundraiser has been cancelled"
+    );
+
+    let current_time = Clock::get()?.unix_timestamp;
+    assert!(
+        current_time <= fundraiser_account.time_ending(),
+        "Fundraiser has ended"
+    );
+
+    // Check the contribution against the limits the maker set
+    fundraiser_account.check_contribution(contributor_account.amount(), amount)?;
+
+    // The lamports sit on top of the fundraiser's rent-exempt balance
+    Transfer {
+        from: signer,
+        to: fundraiser,
+        lamports: amount,
+    }
+    .invoke()?;
+
+    fundraiser_account.add_amount_raised(amount);
+    contributor_account.add_amount(amount);
+
+    emit(FundraiserEvent::Contributed {
+        fundraiser: *fundraiser.key(),
+        contributor: *signer.key(),
+        mint: Pubkey::default(),
+        amount,
+    });
+
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,51 @@
+use crate::state::{Fundraiser, FundraiserStatus};
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use pinocchio_token::state::Mint;
+
+// A mint without any extensions, so nobody but the fundraiser can move or burn receipts on a holder's behalf
+const MINT_LEN: usize = 82;
+
+pub fn enable_receipts_instruction(accounts: &[AccountInfo]) -> ProgramResult {
+    let [maker, fundraiser, mint_to_raise, receipt_mint] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if !maker.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let fundraiser_account = Fundraiser::from_account_info(fundraiser);
+    assert_eq!(&fundraiser_account.maker(), maker.key(), "Only the maker can enable receipts");
+    assert!(!fundraiser_account.issues_receipts(), "The fundraiser issues receipt tokens");
+
+    // Receipts stand for a share of a single mint paid out in one go, and have to be enabled before anyone has contributed
+    assert!(
+        fundraiser_account.status() == FundraiserStatus::Active as u8
+            && !fundraiser_account.is_sol()
+            && !fundraiser_account.is_multi_mint()
+            && fundraiser_account.amount_raised() == 0
+            && fundraiser_account.milestone_count() == 0
+            && fundraiser_account.voting_period() == 0,
+        "Receipt tokens cannot be enabled for this fundraiser"
+    );
+
+    assert_eq!(&fundraiser_account.mint_to_raise(), mint_to_raise.key(), "Wrong mint");
+
+    // The fundraiser has to be the only one able to mint receipts, and they are counted in the units of the raised mint
+    let mint_account = unsafe { Mint::from_bytes(receipt_mint.borrow_data_unchecked()) };
+    let mint_to_raise_account = unsafe { Mint::from_bytes(mint_to_raise.borrow_data_unchecked()) };
+    assert!(
+        receipt_mint.owner() == mint_to_raise.owner()
+            && receipt_mint.data_len() == MINT_LEN
+            && mint_account.is_initialized()
+            && mint_account.mint_authority() == Some(fundraiser.key())
+            && mint_account.freeze_authority().is_none()
+            && mint_account.supply() == 0
+            && mint_account.decimals() == mint_to_raise_account.decimals(),
+        "Invalid receipt mint"
+    );
+
+    fundraiser_account.set_receipt_mint(receipt_mint.key());
+
+    Ok(())
+}
\ No newline at end of file
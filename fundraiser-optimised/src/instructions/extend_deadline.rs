@@ -0,0 +1,42 @@
+use crate::{events::{emit, FundraiserEvent}, state::Fundraiser};
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult, sysvars::{clock::Clock, Sysvar}};
+
+pub fn extend_deadline_instruction(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let [maker, fundraiser] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if data.len() != 8 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+    let time_ending = i64::from_le_bytes(data.try_into().unwrap());
+
+    if !maker.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let fundraiser_account = Fundraiser::from_account_info(fundraiser);
+    assert_eq!(&fundraiser_account.maker(), maker.key(), "Only the maker can amend the fundraiser");
+
+    assert!(time_ending > fundraiser_account.time_ending(), "The deadline can only be extended");
+
+    // The first milestone, and the vote on it, still have to come after the fundraiser ends
+    assert!(
+        fundraiser_account.milestone_count() == 0
+            || time_ending <= fundraiser_account.milestone_unlock_time(0) - fundraiser_account.voting_period(),
+        "The deadline can not move past the first milestone"
+    );
+
+    // Contributors can withdraw until the grace period is over
+    fundraiser_account.amend(Clock::get()?.unix_timestamp);
+    fundraiser_account.set_time_ending(time_ending);
+
+    emit(FundraiserEvent::Amended {
+        fundraiser: *fundraiser.key(),
+        maker: *maker.key(),
+        amount_to_raise: fundraiser_account.amount_to_raise(),
+        time_ending,
+    });
+
+    Ok(())
+}
\ No newline at end of file
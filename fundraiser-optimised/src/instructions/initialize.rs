@@ -1,58 +1,161 @@
 use pinocchio::{
-    account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey, ProgramResult,
+    account_info::AccountInfo, program_error::ProgramError, signer, ProgramResult,
 };
 
-use crate::state::fundraiser::Fundraiser;
+use fundraiser_interface::instruction::initialize;
+
+use crate::{
+    create::{create_associated_token_account, create_pda_account},
+    events::{emit, FundraiserEvent},
+    state::{config::Config, fundraiser::{ContributionLimits, Fundraiser, FundraiserStatus, FundingMode, Milestone, BPS_SCALER, MAX_MILESTONES, MILESTONE_LEN}},
+    token::{has_transfer_hook, is_token_program},
+};
 
 // --Data Scheme--
-// maker: Pubkey
-// mint_to_raise: Pubkey
 // amount_to_raise: u64
-// amount_raised: u64 (initialized to 0, user should not pass this)
-// time_started: i64
-// duration: u8
+// time_ending: i64
+// campaign_id: u64
+// bump: u8
+// milestone_count: u8
+// milestones: [(percentage: u8, unlock_time: i64); MAX_MILESTONES]
+// voting_period: i64
+// quorum_bps: u16
+// approval_bps: u16
+// funding_mode: u8
+// contribution_limits: (min_contribution: u64, max_contribution: u64, max_contribution_kind: u8, hard_cap: u64)
+// max_amendments: u8
+//
+// The maker and the mint come from the accounts, everything else the program starts at 0
 
 pub fn initialize_fundraiser(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
     // Destructure the accounts array
-    let [fundraiser] = accounts else {
+    let [maker, fundraiser, mint, vault, system_program, token_program, _associated_token_program, config] = accounts else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
+    // The fundraiser is derived from the maker, so only the maker can open one in their name
+    if !maker.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
     // Verify the data length is exactly as expected
-    if data.len() != Fundraiser::LEN - 8 { // We skip `amount_raised` since it will be initialized to 0
+    if data.len() != initialize::LEN {
         return Err(ProgramError::InvalidInstructionData);
     }
 
-    // Unsafe data manipulation:
-    let data_ptr = unsafe {fundraiser.borrow_mut_data_unchecked().as_mut_ptr()};
+    let amount_to_raise = u64::from_le_bytes(data[initialize::AMOUNT_TO_RAISE..initialize::TIME_ENDING].try_into().unwrap());
+    let time_ending = i64::from_le_bytes(data[initialize::TIME_ENDING..initialize::CAMPAIGN_ID].try_into().unwrap());
+
+    // Check the milestone schedule, an empty schedule releases everything at once
+    let milestone_count = data[initialize::MILESTONE_COUNT] as usize;
+    if milestone_count > MAX_MILESTONES {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    if milestone_count > 0 {
+        let mut total_percentage: u64 = 0;
+        let mut previous_unlock_time = time_ending;
+
+        for index in 0..milestone_count {
+            let percentage = data[initialize::MILESTONES + index * MILESTONE_LEN];
+            let unlock_time = Milestone::unpack_from(&data[initialize::MILESTONES + index * MILESTONE_LEN..]).unlock_time;
+
+            // Every tranche must release something and unlock in order, after the fundraiser ends
+            if percentage == 0 || unlock_time < previous_unlock_time {
+                return Err(ProgramError::InvalidInstructionData);
+            }
+
+            total_percentage += percentage as u64;
+            previous_unlock_time = unlock_time;
+        }
 
-    // Copy the maker key (32 bytes)
-    unsafe { *(data_ptr as *mut Pubkey) = *(data.as_ptr() as *const Pubkey); };
+        // The tranches must add up to the whole amount raised
+        if total_percentage != 100 {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+    }
 
-    // Copy mint_to_raise (32 bytes) from input data to account data
-    unsafe {
-        *(data_ptr.add(32) as *mut Pubkey) = *(data.as_ptr().add(32) as *const Pubkey);
+    // Check the voting configuration, a voting period of 0 disables voting
+    let voting_period = i64::from_le_bytes(data[initialize::VOTING_PERIOD..initialize::QUORUM_BPS].try_into().unwrap());
+    let quorum_bps = u16::from_le_bytes(data[initialize::QUORUM_BPS..initialize::APPROVAL_BPS].try_into().unwrap());
+    let approval_bps = u16::from_le_bytes(data[initialize::APPROVAL_BPS..initialize::FUNDING_MODE].try_into().unwrap());
+    if voting_period < 0 || quorum_bps as u64 > BPS_SCALER || approval_bps as u64 > BPS_SCALER {
+        return Err(ProgramError::InvalidInstructionData);
     }
 
-    // Copy amount_to_raise (8 bytes) from input data to account data
-    unsafe {
-        *(data_ptr.add(64) as *mut u64) = *(data.as_ptr().add(64) as *const u64);
+    // Backers vote on milestones, and only once the contributions are settled
+    if voting_period > 0 {
+        let first_unlock_time = Milestone::unpack_from(&data[initialize::MILESTONES..]).unlock_time;
+        if milestone_count == 0 || first_unlock_time - voting_period < time_ending {
+            return Err(ProgramError::InvalidInstructionData);
+        }
     }
 
-    // Initialize amount_raised (8 bytes) to 0
-    unsafe {
-        *(data_ptr.add(72) as *mut u64) = 0;
+    // Fixed keeps the all-or-nothing rules, flexible pays out whatever was raised
+    if data[initialize::FUNDING_MODE] > FundingMode::Flexible as u8 {
+        return Err(ProgramError::InvalidInstructionData);
     }
 
-    // Copy time_started (8 bytes) from input data to account data
-    unsafe {
-        *(data_ptr.add(80) as *mut i64) = *(data.as_ptr().add(72) as *const i64);
+    let contribution_limits = ContributionLimits::unpack_from(&data[initialize::CONTRIBUTION_LIMITS..initialize::MAX_AMENDMENTS])
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+    // The mint must belong to a token program, and transfer hooks are not supported
+    if !is_token_program(mint.owner()) || has_transfer_hook(mint) {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    if token_program.key() != mint.owner() {
+        return Err(ProgramError::IncorrectProgramId);
     }
 
-    // Copy duration (1 byte) from input data to account data
-    unsafe {
-        *(data_ptr.add(88) as *mut u8) = *(data.as_ptr().add(80) as *const u8);
+    // The fee in force now applies for the whole campaign, there is none until the platform is configured
+    let fee_bps = Config::load(config).map_or(0, |config| config.fee_bps());
+
+    // The maker pays for the fundraiser account and its vault
+    let fundraiser_seed = b"fundraiser".as_ref();
+    let maker_seed = maker.key().as_ref();
+    let campaign_id: [u8; 8] = data[initialize::CAMPAIGN_ID..initialize::BUMP].try_into().unwrap();
+    let campaign_id_seed = campaign_id.as_ref();
+    let bump_seed = &[data[initialize::BUMP]];
+    create_pda_account(
+        maker,
+        fundraiser,
+        Fundraiser::LEN,
+        &[signer!(fundraiser_seed, maker_seed, campaign_id_seed, bump_seed)],
+    )?;
+    create_associated_token_account(maker, vault, fundraiser, mint, system_program, token_program)?;
+
+    // The counters, the vote tallies and the accepted mints start at 0
+    let fundraiser_account = Fundraiser::init(fundraiser);
+    fundraiser_account.set_maker(maker.key());
+    fundraiser_account.set_mint_to_raise(mint.key());
+    fundraiser_account.set_amount_to_raise(amount_to_raise);
+    fundraiser_account.set_time_ending(time_ending);
+    fundraiser_account.set_bump(data[initialize::BUMP]);
+    fundraiser_account.set_campaign_id(u64::from_le_bytes(campaign_id));
+    fundraiser_account.set_status(FundraiserStatus::Active);
+    fundraiser_account.set_milestone_count(milestone_count as u8);
+    for index in 0..MAX_MILESTONES {
+        fundraiser_account.set_milestone(index, &Milestone::unpack_from(&data[initialize::MILESTONES + index * MILESTONE_LEN..]));
     }
+    fundraiser_account.set_voting(voting_period, quorum_bps, approval_bps);
+    fundraiser_account.set_funding_mode(data[initialize::FUNDING_MODE]);
+    fundraiser_account.set_contribution_limits(&contribution_limits);
+    fundraiser_account.set_max_amendments(data[initialize::MAX_AMENDMENTS]);
+    // Snapshot the platform fee
+    fundraiser_account.set_fee_bps(fee_bps);
+
+    // Every contributor must be able to put in the minimum, and a hard cap below the goal would make it unreachable
+    if !fundraiser_account.contribution_limits_valid() {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    emit(FundraiserEvent::FundraiserCreated {
+        fundraiser: *fundraiser.key(),
+        maker: *maker.key(),
+        mint_to_raise: *mint.key(),
+        amount_to_raise,
+        time_ending,
+    });
 
     Ok(())
-}
+}
\ No newline at end of file
@@ -0,0 +1,57 @@
+use crate::{create::create_pda_account, state::{Config, MAX_FEE_BPS}};
+use fundraiser_interface::{instruction::initialize_config, state::program_data};
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::{find_program_address, Pubkey}, signer, ProgramResult};
+
+// BPFLoaderUpgradeab1e11111111111111111111111
+const BPF_LOADER_UPGRADEABLE_ID: Pubkey =
+    five8_const::decode_32_const("BPFLoaderUpgradeab1e11111111111111111111111");
+
+pub fn initialize_config_instruction(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let [admin, config, program_data, _system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if data.len() != initialize_config::LEN {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+    let fee_bps = u16::from_le_bytes(data[initialize_config::FEE_BPS..initialize_config::FEE_RECIPIENT].try_into().unwrap());
+    let fee_recipient: Pubkey = data[initialize_config::FEE_RECIPIENT..initialize_config::LEN].try_into().unwrap();
+
+    if !admin.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Only the upgrade authority of this program can configure the platform
+    let (program_data_address, _) = find_program_address(&[crate::ID.as_ref()], &BPF_LOADER_UPGRADEABLE_ID);
+    assert!(
+        program_data.key() == &program_data_address && program_data.owner() == &BPF_LOADER_UPGRADEABLE_ID,
+        "Invalid program data"
+    );
+    let header = unsafe { program_data.borrow_data_unchecked() };
+    assert!(
+        header.len() >= program_data::LEN
+            && header[program_data::TAG..program_data::SLOT] == program_data::PROGRAM_DATA_TAG.to_le_bytes()
+            && header[program_data::HAS_UPGRADE_AUTHORITY] == 1
+            && header[program_data::UPGRADE_AUTHORITY..program_data::LEN] == *admin.key(),
+        "Only the upgrade authority can configure the platform"
+    );
+
+    // The config sits at its PDA and can only be created once
+    let (config_address, bump) = Config::pda();
+    assert_eq!(config.key(), &config_address, "Invalid config");
+    if config.data_len() > 0 {
+        return Err(ProgramError::AccountAlreadyInitialized);
+    }
+
+    assert!(fee_bps <= MAX_FEE_BPS, "The platform fee is above the maximum");
+    assert!(fee_recipient != Pubkey::default(), "Invalid fee recipient");
+
+    // The admin pays for the config
+    let config_seed = b"config".as_ref();
+    let bump_seed = &[bump];
+    create_pda_account(admin, config, Config::LEN, &[signer!(config_seed, bump_seed)])?;
+
+    Config::init(config).set(admin.key(), fee_bps, &fee_recipient, bump);
+
+    Ok(())
+}
\ No newline at end of file
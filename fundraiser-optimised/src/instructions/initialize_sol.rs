@@ -0,0 +1,98 @@
+use pinocchio::{
+    account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey, signer, ProgramResult,
+};
+
+use fundraiser_interface::instruction::initialize_sol;
+
+use crate::{
+    create::create_pda_account,
+    events::{emit, FundraiserEvent},
+    state::{config::Config, fundraiser::{ContributionLimits, Fundraiser, FundraiserStatus, FundingMode}},
+};
+
+// --Data Scheme--
+// amount_to_raise: u64
+// time_ending: i64
+// campaign_id: u64
+// bump: u8
+// funding_mode: u8
+// contribution_limits: (min_contribution: u64, max_contribution: u64, max_contribution_kind: u8, hard_cap: u64)
+// max_amendments: u8
+//
+// The maker comes from the accounts. A SOL fundraiser has no mint, milestones or voting, so those fields are zeroed
+
+pub fn initialize_sol_fundraiser(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    // Destructure the accounts array
+    let [maker, fundraiser, _system_program, config] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // The fundraiser is derived from the maker, so only the maker can open one in their name
+    if !maker.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Verify the data length is exactly as expected
+    if data.len() != initialize_sol::LEN {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    if data[initialize_sol::FUNDING_MODE] > FundingMode::Flexible as u8 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let amount_to_raise = u64::from_le_bytes(data[initialize_sol::AMOUNT_TO_RAISE..initialize_sol::TIME_ENDING].try_into().unwrap());
+    let time_ending = i64::from_le_bytes(data[initialize_sol::TIME_ENDING..initialize_sol::CAMPAIGN_ID].try_into().unwrap());
+    let contribution_limits = ContributionLimits::unpack_from(&data[initialize_sol::CONTRIBUTION_LIMITS..initialize_sol::MAX_AMENDMENTS])
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+    // The fee in force now applies for the whole campaign, there is none until the platform is configured
+    let fee_bps = Config::load(config).map_or(0, |config| config.fee_bps());
+
+    // An existing fundraiser keeps its terms and its lamports
+    if fundraiser.data_len() > 0 {
+        return Err(ProgramError::AccountAlreadyInitialized);
+    }
+
+    // The maker pays for the fundraiser account, signing with the seeds checks its address
+    let fundraiser_seed = b"fundraiser".as_ref();
+    let maker_seed = maker.key().as_ref();
+    let campaign_id: [u8; 8] = data[initialize_sol::CAMPAIGN_ID..initialize_sol::BUMP].try_into().unwrap();
+    let campaign_id_seed = campaign_id.as_ref();
+    let bump_seed = &[data[initialize_sol::BUMP]];
+    create_pda_account(
+        maker,
+        fundraiser,
+        Fundraiser::LEN,
+        &[signer!(fundraiser_seed, maker_seed, campaign_id_seed, bump_seed)],
+    )?;
+
+    // mint_to_raise stays the default pubkey
+    let fundraiser_account = Fundraiser::init(fundraiser);
+    fundraiser_account.set_maker(maker.key());
+    fundraiser_account.set_amount_to_raise(amount_to_raise);
+    fundraiser_account.set_time_ending(time_ending);
+    fundraiser_account.set_bump(data[initialize_sol::BUMP]);
+    fundraiser_account.set_campaign_id(u64::from_le_bytes(campaign_id));
+    fundraiser_account.set_status(FundraiserStatus::Active);
+    fundraiser_account.set_funding_mode(data[initialize_sol::FUNDING_MODE]);
+    fundraiser_account.set_contribution_limits(&contribution_limits);
+    fundraiser_account.set_max_amendments(data[initialize_sol::MAX_AMENDMENTS]);
+
+    // Snapshot the platform fee
+    fundraiser_account.set_fee_bps(fee_bps);
+
+    if !fundraiser_account.contribution_limits_valid() {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    emit(FundraiserEvent::FundraiserCreated {
+        fundraiser: *fundraiser.key(),
+        maker: *maker.key(),
+        mint_to_raise: Pubkey::default(),
+        amount_to_raise,
+        time_ending,
+    });
+
+    Ok(())
+}
\ No newline at end of file
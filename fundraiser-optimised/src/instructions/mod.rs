@@ -1,33 +1,52 @@
-use pinocchio::program_error::ProgramError;
-
 pub mod checker;
 pub mod contribute;
 pub mod refund;
 pub mod initialize;
+pub mod cancel;
+pub mod release_milestone;
+pub mod vote;
+pub mod reject_milestone;
+pub mod initialize_sol;
+pub mod contribute_sol;
+pub mod checker_sol;
+pub mod refund_sol;
+pub mod set_price;
+pub mod add_accepted_mint;
+pub mod contribute_multi;
+pub mod checker_multi;
+pub mod refund_multi;
+pub mod enable_receipts;
+pub mod contribute_receipts;
+pub mod refund_receipts;
+pub mod set_reward_tiers;
+pub mod initialize_config;
+pub mod extend_deadline;
+pub mod amend_goal;
 
 pub use checker::*;
 pub use contribute::*;
 pub use refund::*;
 pub use initialize::*;
+pub use cancel::*;
+pub use release_milestone::*;
+pub use vote::*;
+pub use reject_milestone::*;
+pub use initialize_sol::*;
+pub use contribute_sol::*;
+pub use checker_sol::*;
+pub use refund_sol::*;
+pub use set_price::*;
+pub use add_accepted_mint::*;
+pub use contribute_multi::*;
+pub use checker_multi::*;
+pub use refund_multi::*;
+pub use enable_receipts::*;
+pub use contribute_receipts::*;
+pub use refund_receipts::*;
+pub use set_reward_tiers::*;
+pub use initialize_config::*;
+pub use extend_deadline::*;
+pub use amend_goal::*;
 
-#[derive(Copy, Clone, PartialEq, Eq)]
-pub enum FundraiserInstruction {
-    Initialize = 0,
-    Checker = 1,
-    Contribute = 2,
-    Refund = 3,
-}
-
-impl TryFrom<&u8> for FundraiserInstruction {
-    type Error = ProgramError;
-
-    fn try_from(value: &u8) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(FundraiserInstruction::Initialize),
-            1 => Ok(FundraiserInstruction::Contribute),
-            2 => Ok(FundraiserInstruction::Checker),
-            3 => Ok(FundraiserInstruction::Refund),
-            _ => Err(ProgramError::InvalidInstructionData),
-        }
-    }
-}
\ No newline at end of file
+// The discriminators are shared with the other implementations, see `fundraiser-interface`
+pub use fundraiser_interface::instruction::FundraiserInstruction;
\ No newline at end of file
@@ -1,38 +1,108 @@
-use crate::state::{Contributor, Fundraiser};
+use crate::{events::{emit, FundraiserEvent}, state::{Contributor, Fundraiser, FundraiserStatus, FundingMode, RewardTiers}, token::{load_token_account, TransferChecked}};
 use pinocchio::account_info::AccountInfo;
 use pinocchio::program_error::ProgramError;
 use pinocchio::{signer, ProgramResult};
-use pinocchio_token::instructions::Transfer;
+use pinocchio::sysvars::{clock::Clock, Sysvar};
+use pinocchio_token::state::{Mint, TokenAccount};
 
 pub fn refund_instruction(accounts: &[AccountInfo]) -> ProgramResult {
-    let [fundraiser, contributor, contributor_ta, vault, _token_program] = accounts else {
+    let [contributor, _maker, fundraiser, contributor_account_info, contributor_ta, vault, token_program, mint, remaining @ ..] = accounts else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
+    if !contributor.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // The tokens go back to the signer, so the contributor account and the token account have to be theirs
     let fundraiser_account = Fundraiser::from_account_info(fundraiser);
-    let contributor_account = Contributor::from_account_info(contributor);
+    let contributor_account = Contributor::from_account_info(contributor_account_info, fundraiser.key(), contributor.key());
+    assert_eq!(
+        unsafe { TokenAccount::from_bytes(contributor_ta.borrow_data_unchecked()).owner() },
+        contributor.key(),
+        "Invalid contributor token account"
+    );
 
     //checking if the contributor has any contributions
     assert!(contributor_account.amount() > 0, "No amount to refund");
 
+    assert_eq!(&fundraiser_account.mint_to_raise(), mint.key(), "Wrong mint");
+    let vault_amount = load_token_account(vault, mint.key(), fundraiser.key()).amount();
+
+    // A cancelled or rejected fundraiser can be refunded right away, regardless of time or vault balance,
+    // and so can an active one for a while after the maker amended it
+    let current_time = Clock::get()?.unix_timestamp;
+    if fundraiser_account.status() == FundraiserStatus::Active as u8 && !fundraiser_account.in_grace_period(current_time) {
+        assert!(
+            fundraiser_account.funding_mode() == FundingMode::Fixed as u8,
+            "Refunds are disabled for flexible fundraisers"
+        );
+
+        assert!(
+            current_time >= fundraiser_account.time_ending(),
+            "Fundraiser has not ended yet"
+        );
+
+        // Nothing can be refunded once the maker has been paid
+        assert!(
+            fundraiser_account.amount_released() == 0
+                && fundraiser_account.amount_raised() < fundraiser_account.amount_to_raise(),
+            "The amount to raise has been met"
+        );
+    }
+
+    // Once backers reject a milestone, each of them gets their pro-rata share of what is still in the vault
+    let amount = if fundraiser_account.status() == FundraiserStatus::Rejected as u8 {
+        (contributor_account.amount() as u128 * vault_amount as u128)
+            .checked_div(fundraiser_account.amount_raised() as u128)
+            .ok_or(ProgramError::ArithmeticOverflow)? as u64
+    } else {
+        contributor_account.amount()
+    };
+
     let maker = fundraiser_account.maker();
     let bump = fundraiser_account.bump();
     let fundraiser_seed = b"fundraiser".as_ref();
     let maker_seed = maker.as_ref();
+    let campaign_id = fundraiser_account.campaign_id().to_le_bytes();
+    let campaign_id_seed = campaign_id.as_ref();
     let bump_seed = &[bump];
 
-    Transfer {
+    assert!(!fundraiser_account.is_multi_mint(), "Use the multi-mint instructions");
+    assert!(!fundraiser_account.issues_receipts(), "Use the receipt instructions");
+
+    TransferChecked {
         from: vault,
+        mint,
         to: contributor_ta,
         authority: fundraiser,
-        amount: contributor_account.amount(),
+        token_program,
+        amount,
+        decimals: unsafe { Mint::from_bytes(mint.borrow_data_unchecked()).decimals() },
     }
-    .invoke_signed(&[signer!(fundraiser_seed, maker_seed, bump_seed)])?;
+    .invoke_signed(&[signer!(fundraiser_seed, maker_seed, campaign_id_seed, bump_seed)])?;
 
-    unsafe {
-        *(fundraiser.borrow_mut_data_unchecked().as_mut_ptr().add(72) as *mut u64) -= contributor_account.amount();
-        *(contributor.borrow_mut_data_unchecked().as_mut_ptr() as *mut u64) = 0;
+    fundraiser_account.sub_amount_raised(contributor_account.amount());
+    contributor_account.set_amount(0);
+
+    // Give the reward tier slot back, the tiers are passed after the other accounts
+    if contributor_account.reward_tier() != 0 {
+        let [reward_tiers, ..] = remaining else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+        let reward_tiers_account = RewardTiers::from_account_info(reward_tiers);
+        assert_eq!(&reward_tiers_account.fundraiser(), fundraiser.key(), "Invalid reward tier");
+
+        reward_tiers_account.release(contributor_account.reward_tier());
+        contributor_account.set_reward_tier(0);
     }
 
+    emit(FundraiserEvent::Refunded {
+        fundraiser: *fundraiser.key(),
+        contributor: *contributor.key(),
+        mint: *mint.key(),
+        amount,
+    });
+
     Ok(())
 }
\ No newline at end of file
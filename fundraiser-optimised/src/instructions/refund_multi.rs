@@ -0,0 +1,90 @@
+use crate::{events::{emit, FundraiserEvent}, state::{Contributor, Fundraiser, FundraiserStatus, FundingMode, PriceFeed}, token::TransferChecked};
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, signer, ProgramResult, sysvars::{clock::Clock, Sysvar}};
+use pinocchio_token::state::Mint;
+
+// Returns what the contributor sent in one accepted mint, the contributor calls this once per mint
+pub fn refund_multi_instruction(accounts: &[AccountInfo]) -> ProgramResult {
+    let [contributor, _maker, fundraiser, contributor_account_info, contributor_ta, vault, token_program, mint, price_feed, price_feeds @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if !contributor.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let fundraiser_account = Fundraiser::from_account_info(fundraiser);
+    let contributor_account = Contributor::from_account_info(contributor_account_info, fundraiser.key(), contributor.key());
+
+    let Some(index) = fundraiser_account.accepted_mint_index(mint.key()) else {
+        panic!("Mint is not accepted by the fundraiser");
+    };
+
+    let price_feed_account = PriceFeed::from_account_info(price_feed);
+    assert!(
+        price_feed_account.authority() == fundraiser_account.price_authority() && &price_feed_account.mint() == mint.key(),
+        "Invalid price feed"
+    );
+
+    // Refunds return the original tokens, not their value
+    let amount = contributor_account.mint_amount(index);
+    assert!(amount > 0, "No amount to refund");
+
+    // A cancelled fundraiser can be refunded right away, regardless of time or prices,
+    // and so can an active one for a while after the maker amended it
+    let current_time = Clock::get()?.unix_timestamp;
+    if fundraiser_account.status() == FundraiserStatus::Active as u8 && !fundraiser_account.in_grace_period(current_time) {
+        assert!(
+            fundraiser_account.funding_mode() == FundingMode::Fixed as u8,
+            "Refunds are disabled for flexible fundraisers"
+        );
+
+        assert!(
+            current_time >= fundraiser_account.time_ending(),
+            "Fundraiser has not ended yet"
+        );
+
+        // Nothing can be refunded once the maker has been paid
+        assert!(
+            fundraiser_account.amount_released() == 0
+                && fundraiser_account.value_raised(price_feeds) < fundraiser_account.amount_to_raise(),
+            "The amount to raise has been met"
+        );
+    }
+
+    let maker = fundraiser_account.maker();
+    let bump = fundraiser_account.bump();
+    let fundraiser_seed = b"fundraiser".as_ref();
+    let maker_seed = maker.as_ref();
+    let campaign_id = fundraiser_account.campaign_id().to_le_bytes();
+    let campaign_id_seed = campaign_id.as_ref();
+    let bump_seed = &[bump];
+
+    TransferChecked {
+        from: vault,
+        mint,
+        to: contributor_ta,
+        authority: fundraiser,
+        token_program,
+        amount,
+        decimals: unsafe { Mint::from_bytes(mint.borrow_data_unchecked()).decimals() },
+    }
+    .invoke_signed(&[signer!(fundraiser_seed, maker_seed, campaign_id_seed, bump_seed)])?;
+
+    // The value is taken off at the current price, the tokens themselves are tracked exactly
+    let value = price_feed_account.value(amount);
+
+    fundraiser_account.set_amount_raised(fundraiser_account.amount_raised().saturating_sub(value));
+    fundraiser_account.sub_mint_amount_raised(index, amount);
+
+    contributor_account.set_amount(contributor_account.amount().saturating_sub(value));
+    contributor_account.set_mint_amount(index, 0);
+
+    emit(FundraiserEvent::Refunded {
+        fundraiser: *fundraiser.key(),
+        contributor: *contributor.key(),
+        mint: *mint.key(),
+        amount,
+    });
+
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,89 @@
+use crate::{events::{emit, FundraiserEvent}, state::{Fundraiser, FundraiserStatus, FundingMode}, token::{load_token_account, BurnChecked, TransferChecked}};
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, signer, ProgramResult, sysvars::{clock::Clock, Sysvar}};
+use pinocchio_token::state::{Mint, TokenAccount};
+
+pub fn refund_receipts_instruction(accounts: &[AccountInfo]) -> ProgramResult {
+    let [holder, holder_ta, fundraiser, vault, token_program, mint, receipt_mint, holder_receipt_ta] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if !holder.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let fundraiser_account = Fundraiser::from_account_info(fundraiser);
+
+    assert!(fundraiser_account.issues_receipts(), "The fundraiser does not issue receipt tokens");
+    assert_eq!(&fundraiser_account.mint_to_raise(), mint.key(), "Wrong mint");
+    assert_eq!(&fundraiser_account.receipt_mint(), receipt_mint.key(), "Invalid receipt mint");
+
+    load_token_account(vault, mint.key(), fundraiser.key());
+
+    // A cancelled fundraiser can be refunded right away, regardless of time or vault balance,
+    // and so can an active one for a while after the maker amended it
+    let current_time = Clock::get()?.unix_timestamp;
+    if fundraiser_account.status() == FundraiserStatus::Active as u8 && !fundraiser_account.in_grace_period(current_time) {
+        assert!(
+            fundraiser_account.funding_mode() == FundingMode::Fixed as u8,
+            "Refunds are disabled for flexible fundraisers"
+        );
+
+        assert!(
+            current_time >= fundraiser_account.time_ending(),
+            "Fundraiser has not ended yet"
+        );
+
+        // Nothing can be refunded once the maker has been paid
+        assert!(
+            fundraiser_account.amount_released() == 0
+                && fundraiser_account.amount_raised() < fundraiser_account.amount_to_raise(),
+            "The amount to raise has been met"
+        );
+    }
+
+    // Refunds follow the receipts, whoever holds them gets the tokens they stand for
+    let amount = unsafe { TokenAccount::from_bytes(holder_receipt_ta.borrow_data_unchecked()).amount() };
+    assert!(amount > 0, "No amount to refund");
+
+    let decimals = unsafe { Mint::from_bytes(mint.borrow_data_unchecked()).decimals() };
+
+    BurnChecked {
+        account: holder_receipt_ta,
+        mint: receipt_mint,
+        authority: holder,
+        token_program,
+        amount,
+        decimals,
+    }
+    .invoke()?;
+
+    let maker = fundraiser_account.maker();
+    let bump = fundraiser_account.bump();
+    let fundraiser_seed = b"fundraiser".as_ref();
+    let maker_seed = maker.as_ref();
+    let campaign_id = fundraiser_account.campaign_id().to_le_bytes();
+    let campaign_id_seed = campaign_id.as_ref();
+    let bump_seed = &[bump];
+
+    TransferChecked {
+        from: vault,
+        mint,
+        to: holder_ta,
+        authority: fundraiser,
+        token_program,
+        amount,
+        decimals,
+    }
+    .invoke_signed(&[signer!(fundraiser_seed, maker_seed, campaign_id_seed, bump_seed)])?;
+
+    fundraiser_account.sub_amount_raised(amount);
+
+    emit(FundraiserEvent::Refunded {
+        fundraiser: *fundraiser.key(),
+        contributor: *holder.key(),
+        mint: *mint.key(),
+        amount,
+    });
+
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,62 @@
+use crate::{create::move_lamports, events::{emit, FundraiserEvent}, state::{Contributor, Fundraiser, FundraiserStatus, FundingMode}};
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey, ProgramResult, sysvars::{clock::Clock, rent::Rent, Sysvar}};
+
+pub fn refund_sol_instruction(accounts: &[AccountInfo]) -> ProgramResult {
+    let [contributor, fundraiser, contributor_account_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if !contributor.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let fundraiser_account = Fundraiser::from_account_info(fundraiser);
+    // The lamports go straight to the signer, so the contributor account has to be theirs
+    let contributor_account = Contributor::from_account_info(contributor_account_info, fundraiser.key(), contributor.key());
+
+    assert!(fundraiser_account.is_sol(), "Fundraiser does not raise SOL");
+
+    //checking if the contributor has any contributions
+    let amount = contributor_account.amount();
+    assert!(amount > 0, "No amount to refund");
+
+    // A cancelled fundraiser can be refunded right away, regardless of time or vault balance,
+    // and so can an active one for a while after the maker amended it
+    let current_time = Clock::get()?.unix_timestamp;
+    if fundraiser_account.status() == FundraiserStatus::Active as u8 && !fundraiser_account.in_grace_period(current_time) {
+        assert!(
+            fundraiser_account.funding_mode() == FundingMode::Fixed as u8,
+            "Refunds are disabled for flexible fundraisers"
+        );
+
+        assert!(
+            current_time >= fundraiser_account.time_ending(),
+            "Fundraiser has not ended yet"
+        );
+
+        assert!(
+            fundraiser_account.amount_raised() < fundraiser_account.amount_to_raise(),
+            "The amount to raise has been met"
+        );
+    }
+
+    // Only the contributed lamports leave, the fundraiser account has to stay rent-exempt
+    assert!(
+        fundraiser.lamports().saturating_sub(amount) >= Rent::get()?.minimum_balance(Fundraiser::LEN),
+        "The fundraiser account would no longer be rent-exempt"
+    );
+
+    move_lamports(fundraiser, contributor, amount)?;
+
+    fundraiser_account.sub_amount_raised(amount);
+    contributor_account.set_amount(0);
+
+    emit(FundraiserEvent::Refunded {
+        fundraiser: *fundraiser.key(),
+        contributor: *contributor.key(),
+        mint: Pubkey::default(),
+        amount,
+    });
+
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,38 @@
+use crate::state::{Fundraiser, FundraiserStatus};
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult, sysvars::{clock::Clock, Sysvar}};
+
+pub fn reject_milestone_instruction(accounts: &[AccountInfo]) -> ProgramResult {
+    let [fundraiser] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let fundraiser_account = Fundraiser::from_account_info(fundraiser);
+
+    assert!(
+        fundraiser_account.status() == FundraiserStatus::Active as u8,
+        "A cancelled or rejected fundraiser can only be refunded"
+    );
+    assert!(fundraiser_account.voting_period() > 0, "Voting is disabled for this fundraiser");
+
+    let index = fundraiser_account.milestones_released() as usize;
+    assert!(index < fundraiser_account.milestone_count() as usize, "No milestone left to reject");
+
+    // The outcome is only final once the voting window has closed
+    let current_time = Clock::get()?.unix_timestamp;
+    assert!(
+        current_time >= fundraiser_account.milestone_unlock_time(index),
+        "The voting window is still open"
+    );
+
+    // The quorum is measured against what is raised, which can still shrink during the grace period
+    assert!(
+        !fundraiser_account.in_grace_period(current_time),
+        "Contributors can still withdraw after the last amendment"
+    );
+    assert!(!fundraiser_account.milestone_approved(), "The milestone has been approved");
+
+    // Anyone can settle a failed vote, contributors can then refund what is left in the vault
+    fundraiser_account.set_status(FundraiserStatus::Rejected);
+
+    Ok(())
+}
\ No newline at end of file
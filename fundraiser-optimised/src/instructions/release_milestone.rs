@@ -0,0 +1,114 @@
+use crate::{events::{emit, FundraiserEvent}, state::{Config, Fundraiser, FundraiserStatus}, token::{load_token_account, TransferChecked}};
+use fundraiser_interface::state::fee_amount;
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, signer, ProgramResult, sysvars::{clock::Clock, Sysvar}};
+use pinocchio_token::state::{Mint, TokenAccount};
+
+pub fn release_milestone_instruction(accounts: &[AccountInfo]) -> ProgramResult {
+    let [maker, mint, fundraiser, vault, maker_ta, token_program, remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if !maker.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let fundraiser_account = Fundraiser::from_account_info(fundraiser);
+    assert_eq!(&fundraiser_account.maker(), maker.key(), "Only the maker can release milestones");
+
+    assert!(
+        fundraiser_account.status() == FundraiserStatus::Active as u8,
+        "A cancelled or rejected fundraiser can only be refunded"
+    );
+
+    // Milestones are released in order, one at a time
+    let milestone_count = fundraiser_account.milestone_count() as usize;
+    let index = fundraiser_account.milestones_released() as usize;
+    assert!(index < milestone_count, "No milestone left to release");
+
+    // The raised funds are secured by meeting the target, or by a flexible fundraiser ending
+    let current_time = Clock::get()?.unix_timestamp;
+    assert!(
+        fundraiser_account.funds_secured(current_time),
+        "The amount to raise has not been met"
+    );
+    assert!(
+        !fundraiser_account.in_grace_period(current_time),
+        "Contributors can still withdraw after the last amendment"
+    );
+
+    assert!(
+        current_time >= fundraiser_account.milestone_unlock_time(index),
+        "The milestone is still locked"
+    );
+
+    // When backers vote, the milestone needs their approval before it leaves the vault
+    assert!(
+        fundraiser_account.voting_period() == 0 || fundraiser_account.milestone_approved(),
+        "The milestone has not been approved"
+    );
+
+    // The last milestone sweeps the vault so no rounding dust is left behind
+    let amount = if index + 1 == milestone_count {
+        unsafe { TokenAccount::from_bytes(vault.borrow_data_unchecked()).amount() }
+    } else {
+        fundraiser_account.amount_raised() * fundraiser_account.milestone_percentage(index) as u64 / 100
+    };
+
+    let bump = fundraiser_account.bump();
+    let fundraiser_seed = b"fundraiser".as_ref();
+    let maker_seed = maker.key().as_ref();
+    let campaign_id = fundraiser_account.campaign_id().to_le_bytes();
+    let campaign_id_seed = campaign_id.as_ref();
+    let bump_seed = &[bump];
+
+    assert_eq!(&fundraiser_account.mint_to_raise(), mint.key(), "Wrong mint");
+
+    let decimals = unsafe { Mint::from_bytes(mint.borrow_data_unchecked()).decimals() };
+
+    // A fundraiser created with a platform fee pays it on every release to the fee recipient of the config
+    let fee = fee_amount(amount, fundraiser_account.fee_bps());
+    if fundraiser_account.fee_bps() > 0 {
+        let [config, fee_recipient_ta, ..] = remaining else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        let config = Config::load(config).expect("Invalid fee recipient");
+        load_token_account(fee_recipient_ta, mint.key(), &config.fee_recipient());
+
+        if fee > 0 {
+            TransferChecked {
+                from: vault,
+                mint,
+                to: fee_recipient_ta,
+                authority: fundraiser,
+                token_program,
+                amount: fee,
+                decimals,
+            }
+            .invoke_signed(&[signer!(fundraiser_seed, maker_seed, campaign_id_seed, bump_seed)])?;
+        }
+    }
+
+    TransferChecked {
+        from: vault,
+        mint,
+        to: maker_ta,
+        authority: fundraiser,
+        token_program,
+        amount: amount - fee,
+        decimals,
+    }
+    .invoke_signed(&[signer!(fundraiser_seed, maker_seed, campaign_id_seed, bump_seed)])?;
+
+    // The fee counts as released. This also resets the tally for the next milestone
+    fundraiser_account.release_milestone(amount);
+
+    emit(FundraiserEvent::Withdrawn {
+        fundraiser: *fundraiser.key(),
+        maker: *maker.key(),
+        mint: *mint.key(),
+        amount: amount - fee,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use crate::{state::PriceFeed, token::is_token_program};
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::find_program_address, ProgramResult};
+use pinocchio_token::state::Mint;
+
+pub fn set_price_instruction(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let [authority, mint, price_feed, _system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let price = u64::from_le_bytes(data.try_into().map_err(|_| ProgramError::InvalidInstructionData)?);
+
+    if !authority.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let price_feed_account = PriceFeed::load_or_init(price_feed);
+    assert!(is_token_program(mint.owner()), "Invalid mint");
+
+    // A mint without a price could never count towards a goal
+    assert!(price > 0, "Invalid price");
+
+    // Every authority keeps one price feed per mint
+    let (price_feed_pda, bump) = find_program_address(
+        &[b"price_feed", authority.key().as_ref(), mint.key().as_ref()],
+        &crate::ID,
+    );
+    assert_eq!(&price_feed_pda, price_feed.key(), "Invalid price feed");
+
+    let decimals = unsafe { Mint::from_bytes(mint.borrow_data_unchecked()).decimals() };
+    price_feed_account.set(authority.key(), mint.key(), decimals, price, bump);
+
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,50 @@
+use crate::state::{Fundraiser, FundraiserStatus, RewardTiers, MAX_REWARD_TIERS, REWARD_TIER_LEN};
+use fundraiser_interface::instruction::set_reward_tiers;
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::find_program_address, ProgramResult};
+
+pub fn set_reward_tiers_instruction(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let [maker, fundraiser, reward_tiers, _system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if data.len() != set_reward_tiers::LEN {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    if !maker.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let fundraiser_account = Fundraiser::from_account_info(fundraiser);
+    assert_eq!(&fundraiser_account.maker(), maker.key(), "Only the maker can set reward tiers");
+
+    let reward_tiers_account = RewardTiers::load_or_init(reward_tiers);
+
+    // Every fundraiser keeps one set of reward tiers
+    let (reward_tiers_pda, bump) = find_program_address(
+        &[b"reward_tiers", fundraiser.key().as_ref()],
+        &crate::ID,
+    );
+    assert_eq!(&reward_tiers_pda, reward_tiers.key(), "Invalid reward tiers");
+
+    // Contributors pick a tier as they pledge, so the tiers are fixed once the first contribution came in
+    assert!(
+        fundraiser_account.status() == FundraiserStatus::Active as u8 && fundraiser_account.amount_raised() == 0,
+        "Reward tiers cannot change once contributions came in"
+    );
+
+    // Every tier in use has to offer at least one reward
+    let tier_count = data[set_reward_tiers::TIER_COUNT];
+    assert!(
+        tier_count as usize <= MAX_REWARD_TIERS
+            && (0..tier_count as usize).all(|index| {
+                let max_supply = set_reward_tiers::TIERS + index * REWARD_TIER_LEN + 8;
+                data[max_supply..max_supply + 8] != [0u8; 8]
+            }),
+        "Invalid reward tiers"
+    );
+
+    reward_tiers_account.set(fundraiser.key(), tier_count, &data[set_reward_tiers::TIERS..], bump);
+
+    Ok(())
+}
\ No newline at end of file
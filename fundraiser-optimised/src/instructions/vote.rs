@@ -0,0 +1,61 @@
+use crate::state::{Contributor, Fundraiser, FundraiserStatus};
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult, sysvars::{clock::Clock, Sysvar}};
+
+pub fn vote_instruction(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let [signer, fundraiser, contributor] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let approve = *data.first().ok_or(ProgramError::InvalidInstructionData)? != 0;
+
+    if !signer.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let fundraiser_account = Fundraiser::from_account_info(fundraiser);
+    // The contributor account has to belong to the signer
+    let contributor_account = Contributor::from_account_info(contributor, fundraiser.key(), signer.key());
+
+    assert!(
+        fundraiser_account.status() == FundraiserStatus::Active as u8,
+        "A cancelled or rejected fundraiser can only be refunded"
+    );
+    assert!(fundraiser_account.voting_period() > 0, "Voting is disabled for this fundraiser");
+
+    // Votes always apply to the next milestone waiting to be released
+    let index = fundraiser_account.milestones_released() as usize;
+    assert!(index < fundraiser_account.milestone_count() as usize, "No milestone left to vote on");
+
+    // The raised funds are secured by meeting the target, or by a flexible fundraiser ending
+    let current_time = Clock::get()?.unix_timestamp;
+    assert!(
+        fundraiser_account.funds_secured(current_time),
+        "The amount to raise has not been met"
+    );
+
+    // Contributors who vote now could still withdraw their stake, so wait for the grace period to end
+    assert!(
+        !fundraiser_account.in_grace_period(current_time),
+        "Contributors can still withdraw after the last amendment"
+    );
+
+    // The voting window closes when the milestone unlocks
+    let unlock_time = fundraiser_account.milestone_unlock_time(index);
+    assert!(
+        current_time >= unlock_time - fundraiser_account.voting_period() && current_time < unlock_time,
+        "The voting window is closed"
+    );
+
+    // Votes are weighted by the amount contributed, one vote per milestone
+    let weight = contributor_account.amount();
+    assert!(weight > 0, "No voting weight");
+    assert!(
+        contributor_account.voted_milestone() != index as u8 + 1,
+        "Already voted on this milestone"
+    );
+
+    fundraiser_account.add_vote(approve, weight);
+    contributor_account.set_voted_milestone(index as u8 + 1);
+
+    Ok(())
+}
\ No newline at end of file
@@ -1,5 +1,9 @@
+pub mod create;
+pub mod events;
 pub mod instructions;
+pub mod pda;
 pub mod state;
+pub mod token;
 
 use instructions::*;
 
@@ -22,11 +26,31 @@ fn process_instruction(
         .split_first()
         .ok_or(ProgramError::InvalidInstructionData)?;
 
-    match FundraiserInstruction::try_from(discriminator)? {
+    match FundraiserInstruction::try_from(*discriminator).map_err(|_| ProgramError::InvalidInstructionData)? {
         FundraiserInstruction::Initialize => initialize_fundraiser(accounts, data),
         FundraiserInstruction::Contribute => contribute_instruction(accounts, data),
         FundraiserInstruction::Checker => checker_instruction(accounts),
         FundraiserInstruction::Refund => refund_instruction(accounts),
+        FundraiserInstruction::Cancel => cancel_instruction(accounts),
+        FundraiserInstruction::ReleaseMilestone => release_milestone_instruction(accounts),
+        FundraiserInstruction::Vote => vote_instruction(accounts, data),
+        FundraiserInstruction::RejectMilestone => reject_milestone_instruction(accounts),
+        FundraiserInstruction::InitializeSol => initialize_sol_fundraiser(accounts, data),
+        FundraiserInstruction::ContributeSol => contribute_sol_instruction(accounts, data),
+        FundraiserInstruction::CheckerSol => checker_sol_instruction(accounts),
+        FundraiserInstruction::RefundSol => refund_sol_instruction(accounts),
+        FundraiserInstruction::SetPrice => set_price_instruction(accounts, data),
+        FundraiserInstruction::AddAcceptedMint => add_accepted_mint_instruction(accounts),
+        FundraiserInstruction::ContributeMulti => contribute_multi_instruction(accounts, data),
+        FundraiserInstruction::CheckerMulti => checker_multi_instruction(accounts),
+        FundraiserInstruction::RefundMulti => refund_multi_instruction(accounts),
+        FundraiserInstruction::EnableReceipts => enable_receipts_instruction(accounts),
+        FundraiserInstruction::ContributeReceipts => contribute_receipts_instruction(accounts, data),
+        FundraiserInstruction::RefundReceipts => refund_receipts_instruction(accounts),
+        FundraiserInstruction::SetRewardTiers => set_reward_tiers_instruction(accounts, data),
+        FundraiserInstruction::InitializeConfig => initialize_config_instruction(accounts, data),
+        FundraiserInstruction::ExtendDeadline => extend_deadline_instruction(accounts, data),
+        FundraiserInstruction::AmendGoal => amend_goal_instruction(accounts, data),
     }
 }
 
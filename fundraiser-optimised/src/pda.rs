@@ -0,0 +1,21 @@
+use pinocchio::pubkey::Pubkey;
+use solana_nostd_sha256::hashv;
+
+const MAX_SEEDS: usize = 16;
+const PDA_MARKER: &[u8] = b"ProgramDerivedAddress";
+
+// The address `create_program_address` derives from the seeds and the bump, hashed in the program instead of
+// through the syscall. It skips the curve check: an account only exists at such an address if the program signed
+// for it, so matching the address is all a check on an existing account needs.
+pub fn derive_address(seeds: &[&[u8]], bump: u8) -> Pubkey {
+    assert!(seeds.len() < MAX_SEEDS, "Too many seeds");
+
+    let bump = [bump];
+    let mut data: [&[u8]; MAX_SEEDS + 2] = [&[]; MAX_SEEDS + 2];
+    data[..seeds.len()].copy_from_slice(seeds);
+    data[seeds.len()] = &bump;
+    data[seeds.len() + 1] = &crate::ID;
+    data[seeds.len() + 2] = PDA_MARKER;
+
+    hashv(&data[..seeds.len() + 3])
+}
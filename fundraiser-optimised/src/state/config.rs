@@ -0,0 +1,58 @@
+use pinocchio::{account_info::AccountInfo, pubkey::{find_program_address, Pubkey}};
+
+use fundraiser_interface::state::config::*;
+
+use crate::state::{AccountData, AccountDiscriminator, HEADER_LEN};
+
+// --Data--
+// header: (discriminator: u8, version: u8)
+// admin: Pubkey
+// fee_bps: u16
+// fee_recipient: Pubkey
+// bump: u8
+
+pub use fundraiser_interface::state::MAX_FEE_BPS;
+
+pub struct Config<'a>(AccountData<'a>);
+
+impl<'a> Config<'a> {
+    pub const LEN: usize = HEADER_LEN + 32 + 2 + 32 + 1;
+
+    // The program keeps a single config at [b"config"]
+    pub fn pda() -> (Pubkey, u8) {
+        find_program_address(&[b"config"], &crate::ID)
+    }
+
+    // None until the upgrade authority has created the config
+    pub fn load(account_info: &'a AccountInfo) -> Option<Self> {
+        assert_eq!(account_info.key(), &Self::pda().0, "Invalid config");
+
+        AccountData::try_load(account_info, AccountDiscriminator::Config, Self::LEN).map(Self)
+    }
+
+    pub fn init(account_info: &'a AccountInfo) -> Self {
+        Self(AccountData::init(account_info, AccountDiscriminator::Config, Self::LEN))
+    }
+
+    pub fn admin(&self) -> Pubkey {
+        self.0.pubkey(ADMIN)
+    }
+    pub fn fee_bps(&self) -> u16 {
+        self.0.u16(FEE_BPS)
+    }
+    pub fn fee_recipient(&self) -> Pubkey {
+        self.0.pubkey(FEE_RECIPIENT)
+    }
+    pub fn bump(&self) -> u8 {
+        self.0.u8(BUMP)
+    }
+
+    pub fn set(&self, admin: &Pubkey, fee_bps: u16, fee_recipient: &Pubkey, bump: u8) {
+        self.0.set_pubkey(ADMIN, admin);
+        self.0.set_u16(FEE_BPS, fee_bps);
+        self.0.set_pubkey(FEE_RECIPIENT, fee_recipient);
+        self.0.set_u8(BUMP, bump);
+    }
+}
+
+const _: () = assert!(Config::LEN == HEADER_LEN + fundraiser_interface::state::config::LEN);
\ No newline at end of file
@@ -1,24 +1,89 @@
-use pinocchio::account_info::AccountInfo;
+use pinocchio::{account_info::AccountInfo, pubkey::Pubkey};
+
+use fundraiser_interface::state::contributor::*;
+
+use crate::{
+    pda::derive_address,
+    state::{AccountData, AccountDiscriminator, HEADER_LEN, MAX_ACCEPTED_MINTS},
+};
 
 // --data--
+// header: (discriminator: u8, version: u8)
 // amount: u64
+// voted_milestone: u8
+// amounts: [u64; MAX_ACCEPTED_MINTS]
+// reward_tier: u8 (0 without a reward, otherwise the tier index plus one)
+// bump: u8
+
+pub struct Contributor<'a>(AccountData<'a>);
+
+impl<'a> Contributor<'a> {
+    pub const LEN: usize = HEADER_LEN + 8 + 1 + 8 * MAX_ACCEPTED_MINTS + 1 + 1;
 
-pub struct Contributor(*const u8);
+    // `owner` is the wallet the contributor account belongs to
+    pub fn from_account_info(account_info: &'a AccountInfo, fundraiser: &Pubkey, owner: &Pubkey) -> Self {
+        let contributor = Self(AccountData::load(account_info, AccountDiscriminator::Contributor, Self::LEN));
 
-impl Contributor {
-    pub const LEN: usize = 8;
+        // The account was created at the canonical bump, which it keeps
+        assert_eq!(
+            &derive_address(&[b"contributor".as_ref(), fundraiser.as_ref(), owner.as_ref()], contributor.bump()),
+            account_info.key(),
+            "Invalid contributor account"
+        );
 
-    pub fn from_account_info_unchecked(account_info: &AccountInfo) -> Self {
-        unsafe { Self(account_info.borrow_data_unchecked().as_ptr()) }
+        contributor
     }
 
-    pub fn from_account_info(account_info: &AccountInfo) -> Self {
-        assert_eq!(account_info.data_len(), Self::LEN);
-        assert_eq!(account_info.owner(), &crate::ID);
-        Self::from_account_info_unchecked(account_info)
+    // Sets up the account `create_contributor` just created
+    pub fn init(account_info: &'a AccountInfo, bump: u8) -> Self {
+        let contributor = Self(AccountData::init(account_info, AccountDiscriminator::Contributor, Self::LEN));
+        contributor.0.set_u8(BUMP, bump);
+        contributor
     }
 
     pub fn amount(&self) -> u64 {
-        unsafe { *(self.0 as *const u64) }
+        self.0.u64(AMOUNT)
     }
-}
\ No newline at end of file
+
+    pub fn voted_milestone(&self) -> u8 {
+        self.0.u8(VOTED_MILESTONE)
+    }
+
+    pub fn mint_amount(&self, index: usize) -> u64 {
+        self.0.u64(AMOUNTS + index * 8)
+    }
+
+    pub fn reward_tier(&self) -> u8 {
+        self.0.u8(REWARD_TIER)
+    }
+
+    pub fn bump(&self) -> u8 {
+        self.0.u8(BUMP)
+    }
+
+    pub fn add_amount(&self, amount: u64) {
+        self.0.add_u64(AMOUNT, amount);
+    }
+
+    pub fn set_amount(&self, amount: u64) {
+        self.0.set_u64(AMOUNT, amount);
+    }
+
+    pub fn set_voted_milestone(&self, voted_milestone: u8) {
+        self.0.set_u8(VOTED_MILESTONE, voted_milestone);
+    }
+
+    pub fn add_mint_amount(&self, index: usize, amount: u64) {
+        self.0.add_u64(AMOUNTS + index * 8, amount);
+    }
+
+    pub fn set_mint_amount(&self, index: usize, amount: u64) {
+        self.0.set_u64(AMOUNTS + index * 8, amount);
+    }
+
+    pub fn set_reward_tier(&self, reward_tier: u8) {
+        self.0.set_u8(REWARD_TIER, reward_tier);
+    }
+}
+
+const _: () = assert!(Contributor::LEN == HEADER_LEN + fundraiser_interface::state::contributor::LEN);
\ No newline at end of file
@@ -0,0 +1,124 @@
+use pinocchio::{account_info::AccountInfo, pubkey::Pubkey};
+
+// --Header--
+// discriminator: u8
+// version: u8
+//
+// Every account the program owns starts with the header, the layout shared through `fundraiser-interface` follows it.
+// Fields are read and written unaligned and little-endian. Each access takes a tracked borrow of the account data
+// that ends with it, so a view never holds a borrow across a CPI and fails on data someone else borrowed mutably.
+
+pub use fundraiser_interface::state::{
+    AccountDiscriminator, OPTIMISED_HEADER_LEN as HEADER_LEN, OPTIMISED_LAYOUT_VERSION as LAYOUT_VERSION,
+};
+
+#[derive(Clone, Copy)]
+pub struct AccountData<'a>(&'a AccountInfo);
+
+impl<'a> AccountData<'a> {
+    // Checks the account is a program account of the type and of the current layout, `len` includes the header
+    pub fn load(account_info: &'a AccountInfo, discriminator: AccountDiscriminator, len: usize) -> Self {
+        let account_data = Self::owned(account_info, len);
+        assert_eq!(account_data.header(), [discriminator as u8, LAYOUT_VERSION], "Invalid account type or version");
+        account_data
+    }
+
+    // Zeroes an account the program owns but has not written yet and writes its header
+    pub fn init(account_info: &'a AccountInfo, discriminator: AccountDiscriminator, len: usize) -> Self {
+        let account_data = Self::owned(account_info, len);
+        assert!(account_data.is_uninitialized(), "The account is already initialized");
+
+        let mut data = account_info.try_borrow_mut_data().expect("The account data is already borrowed");
+        data.fill(0);
+        data[..HEADER_LEN].copy_from_slice(&[discriminator as u8, LAYOUT_VERSION]);
+
+        account_data
+    }
+
+    // For accounts created outside the program and set up on first use
+    pub fn load_or_init(account_info: &'a AccountInfo, discriminator: AccountDiscriminator, len: usize) -> Self {
+        if Self::owned(account_info, len).is_uninitialized() {
+            Self::init(account_info, discriminator, len)
+        } else {
+            Self::load(account_info, discriminator, len)
+        }
+    }
+
+    // None for an account the program has not set up yet
+    pub fn try_load(account_info: &'a AccountInfo, discriminator: AccountDiscriminator, len: usize) -> Option<Self> {
+        if account_info.owner() != &crate::ID || account_info.data_len() != len || Self(account_info).is_uninitialized() {
+            return None;
+        }
+
+        Some(Self::load(account_info, discriminator, len))
+    }
+
+    fn owned(account_info: &'a AccountInfo, len: usize) -> Self {
+        assert_eq!(account_info.owner(), &crate::ID, "Invalid account owner");
+        assert_eq!(account_info.data_len(), len, "Invalid account length");
+        Self(account_info)
+    }
+
+    fn header(&self) -> [u8; HEADER_LEN] {
+        let data = self.0.try_borrow_data().expect("The account data is already borrowed");
+        data[..HEADER_LEN].try_into().unwrap()
+    }
+
+    pub fn is_uninitialized(&self) -> bool {
+        self.header() == [0u8; HEADER_LEN]
+    }
+
+    // `offset` is the offset of the field in the shared layout, the header is skipped here
+    fn read<const N: usize>(&self, offset: usize) -> [u8; N] {
+        let data = self.0.try_borrow_data().expect("The account data is already borrowed");
+        data[HEADER_LEN + offset..HEADER_LEN + offset + N].try_into().unwrap()
+    }
+
+    fn write(&self, offset: usize, bytes: &[u8]) {
+        let mut data = self.0.try_borrow_mut_data().expect("The account data is already borrowed");
+        data[HEADER_LEN + offset..HEADER_LEN + offset + bytes.len()].copy_from_slice(bytes);
+    }
+
+    pub fn u8(&self, offset: usize) -> u8 {
+        self.read::<1>(offset)[0]
+    }
+    pub fn u16(&self, offset: usize) -> u16 {
+        u16::from_le_bytes(self.read(offset))
+    }
+    pub fn u64(&self, offset: usize) -> u64 {
+        u64::from_le_bytes(self.read(offset))
+    }
+    pub fn i64(&self, offset: usize) -> i64 {
+        i64::from_le_bytes(self.read(offset))
+    }
+    pub fn pubkey(&self, offset: usize) -> Pubkey {
+        self.read(offset)
+    }
+
+    pub fn set_u8(&self, offset: usize, value: u8) {
+        self.write(offset, &[value]);
+    }
+    pub fn set_u16(&self, offset: usize, value: u16) {
+        self.write(offset, &value.to_le_bytes());
+    }
+    pub fn set_u64(&self, offset: usize, value: u64) {
+        self.write(offset, &value.to_le_bytes());
+    }
+    pub fn set_i64(&self, offset: usize, value: i64) {
+        self.write(offset, &value.to_le_bytes());
+    }
+    pub fn set_pubkey(&self, offset: usize, value: &Pubkey) {
+        self.write(offset, value);
+    }
+
+    // Counters never wrap, an overflow or underflow aborts the instruction
+    pub fn add_u8(&self, offset: usize, amount: u8) {
+        self.set_u8(offset, self.u8(offset).checked_add(amount).expect("Arithmetic overflow"));
+    }
+    pub fn add_u64(&self, offset: usize, amount: u64) {
+        self.set_u64(offset, self.u64(offset).checked_add(amount).expect("Arithmetic overflow"));
+    }
+    pub fn sub_u64(&self, offset: usize, amount: u64) {
+        self.set_u64(offset, self.u64(offset).checked_sub(amount).expect("Arithmetic underflow"));
+    }
+}
\ No newline at end of file
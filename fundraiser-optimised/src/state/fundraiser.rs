@@ -1,48 +1,386 @@
 use pinocchio::{
-    account_info::AccountInfo,  pubkey::Pubkey, 
+    account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey, 
+};
+
+use fundraiser_interface::{error::FundraiserError, state::{contribution_limits, fundraiser::*, milestone}};
+
+use crate::{
+    pda::derive_address,
+    state::{AccountData, AccountDiscriminator, PriceFeed, HEADER_LEN},
 };
 
 // --Data--
+// header: (discriminator: u8, version: u8)
 // maker: Pubkey
 // mint_to_raise: pubkey
 // amount_to_raise: u64
 // amount_raised: u64
 // time_ending: i64
 // bump: u8
+// campaign_id: u64
+// status: u8
+// milestone_count: u8
+// milestones: [(percentage: u8, unlock_time: i64); MAX_MILESTONES]
+// milestones_released: u8
+// amount_released: u64
+// voting_period: i64
+// quorum_bps: u16
+// approval_bps: u16
+// votes_for: u64
+// votes_against: u64
+// funding_mode: u8
+// price_authority: Pubkey
+// accepted_mint_count: u8
+// accepted_mints: [Pubkey; MAX_ACCEPTED_MINTS]
+// amounts_raised: [u64; MAX_ACCEPTED_MINTS]
+// contribution_limits: (min_contribution: u64, max_contribution: u64, max_contribution_kind: u8, hard_cap: u64)
+// receipt_mint: Pubkey
+// fee_bps: u16
+// max_amendments: u8
+// amendment_count: u8
+// grace_ending: i64
 
-pub struct Fundraiser(*mut u8);
+// The layout and the constants are shared with the other implementations, see `fundraiser-interface`
+pub use fundraiser_interface::state::{
+    ContributionLimits, FundingMode, FundraiserStatus, MaxContributionKind, Milestone, AMENDMENT_GRACE_PERIOD, BPS_SCALER, MAX_ACCEPTED_MINTS,
+    MAX_MILESTONES, PERCENTAGE_SCALER,
+};
+pub const MILESTONE_LEN: usize = fundraiser_interface::state::milestone::LEN;
+pub const CONTRIBUTION_LIMITS_LEN: usize = fundraiser_interface::state::contribution_limits::LEN;
+
+pub struct Fundraiser<'a>(AccountData<'a>);
 
-impl Fundraiser {
-    pub const LEN: usize = 32 + 32 + 8 + 8 + 8 + 1;
+impl<'a> Fundraiser<'a> {
+    pub const LEN: usize = HEADER_LEN + 32 + 32 + 8 + 8 + 8 + 1 + 8 + 1 + 1 + MILESTONE_LEN * MAX_MILESTONES + 1 + 8 + 8 + 2 + 2 + 8 + 8 + 1 + 32 + 1 + 32 * MAX_ACCEPTED_MINTS + 8 * MAX_ACCEPTED_MINTS + CONTRIBUTION_LIMITS_LEN + 32 + 2 + 1 + 1 + 8;
 
-    #[inline(always)]
-    pub fn from_account_info_unchecked(account_info: &AccountInfo) -> Self {
-        unsafe { Self(account_info.borrow_mut_data_unchecked().as_mut_ptr()) }
+    // The fundraiser has to sit at the PDA of its own maker, campaign and bump
+    pub fn from_account_info(account_info: &'a AccountInfo) -> Self {
+        let fundraiser = Self(AccountData::load(account_info, AccountDiscriminator::Fundraiser, Self::LEN));
+        let maker = fundraiser.maker();
+        let campaign_id = fundraiser.campaign_id().to_le_bytes();
+        assert_eq!(
+            &derive_address(&[b"fundraiser".as_ref(), maker.as_ref(), campaign_id.as_ref()], fundraiser.bump()),
+            account_info.key(),
+            "Invalid fundraiser account"
+        );
+        fundraiser
     }
 
-    pub fn from_account_info(account_info: &AccountInfo) -> Self {
-        assert_eq!(account_info.data_len(), Self::LEN);
-        assert_eq!(account_info.owner(), &crate::ID);
-        Self::from_account_info_unchecked(account_info)
+    // A new fundraiser starts zeroed, the counters, the vote tallies and the accepted mints at 0
+    pub fn init(account_info: &'a AccountInfo) -> Self {
+        Self(AccountData::init(account_info, AccountDiscriminator::Fundraiser, Self::LEN))
     }
 
     pub fn maker(&self) -> Pubkey {
-        unsafe { *(self.0 as *const Pubkey) }
+        self.0.pubkey(MAKER)
     }
     pub fn mint_to_raise(&self) -> Pubkey {
-        unsafe { *(self.0.add(32) as *const Pubkey) } 
+        self.0.pubkey(MINT_TO_RAISE)
     }
     pub fn amount_to_raise(&self) -> u64 {
-        unsafe { *(self.0.add(64) as *const u64) } 
+        self.0.u64(AMOUNT_TO_RAISE)
     }
     pub fn amount_raised(&self) -> u64 {
-        unsafe { *(self.0.add(72) as *const u64) } 
+        self.0.u64(CURRENT_AMOUNT)
     }
     pub fn time_ending(&self) -> i64 {
-        unsafe { *(self.0.add(80) as *const i64) } 
+        self.0.i64(TIME_ENDING)
     }
     pub fn bump(&self) -> u8 {
-        unsafe { *(self.0.add(88) as *const u8) } 
+        self.0.u8(BUMP)
+    }
+    pub fn campaign_id(&self) -> u64 {
+        self.0.u64(CAMPAIGN_ID)
+    }
+    pub fn status(&self) -> u8 {
+        self.0.u8(STATUS)
+    }
+    pub fn milestone_count(&self) -> u8 {
+        self.0.u8(MILESTONE_COUNT)
+    }
+    pub fn milestone_percentage(&self, index: usize) -> u8 {
+        self.0.u8(MILESTONES + index * MILESTONE_LEN + milestone::PERCENTAGE)
+    }
+    pub fn milestone_unlock_time(&self, index: usize) -> i64 {
+        self.0.i64(MILESTONES + index * MILESTONE_LEN + milestone::UNLOCK_TIME)
+    }
+    pub fn milestones_released(&self) -> u8 {
+        self.0.u8(MILESTONES_RELEASED)
+    }
+    pub fn amount_released(&self) -> u64 {
+        self.0.u64(AMOUNT_RELEASED)
+    }
+    pub fn voting_period(&self) -> i64 {
+        self.0.i64(VOTING_PERIOD)
+    }
+    pub fn quorum_bps(&self) -> u16 {
+        self.0.u16(QUORUM_BPS)
+    }
+    pub fn approval_bps(&self) -> u16 {
+        self.0.u16(APPROVAL_BPS)
+    }
+    pub fn votes_for(&self) -> u64 {
+        self.0.u64(VOTES_FOR)
+    }
+    pub fn votes_against(&self) -> u64 {
+        self.0.u64(VOTES_AGAINST)
+    }
+    pub fn funding_mode(&self) -> u8 {
+        self.0.u8(FUNDING_MODE)
+    }
+    pub fn price_authority(&self) -> Pubkey {
+        self.0.pubkey(PRICE_AUTHORITY)
+    }
+    pub fn accepted_mint_count(&self) -> u8 {
+        self.0.u8(ACCEPTED_MINT_COUNT)
+    }
+    pub fn accepted_mint(&self, index: usize) -> Pubkey {
+        self.0.pubkey(ACCEPTED_MINTS + index * 32)
+    }
+    pub fn mint_amount_raised(&self, index: usize) -> u64 {
+        self.0.u64(AMOUNTS_RAISED + index * 8)
+    }
+    pub fn min_contribution(&self) -> u64 {
+        self.0.u64(CONTRIBUTION_LIMITS + contribution_limits::MIN_CONTRIBUTION)
+    }
+    pub fn max_contribution(&self) -> u64 {
+        self.0.u64(CONTRIBUTION_LIMITS + contribution_limits::MAX_CONTRIBUTION)
+    }
+    pub fn max_contribution_kind(&self) -> u8 {
+        self.0.u8(CONTRIBUTION_LIMITS + contribution_limits::MAX_CONTRIBUTION_KIND)
+    }
+    pub fn hard_cap(&self) -> u64 {
+        self.0.u64(CONTRIBUTION_LIMITS + contribution_limits::HARD_CAP)
+    }
+    pub fn receipt_mint(&self) -> Pubkey {
+        self.0.pubkey(RECEIPT_MINT)
+    }
+    pub fn fee_bps(&self) -> u16 {
+        self.0.u16(FEE_BPS)
+    }
+    pub fn max_amendments(&self) -> u8 {
+        self.0.u8(MAX_AMENDMENTS)
+    }
+    pub fn amendment_count(&self) -> u8 {
+        self.0.u8(AMENDMENT_COUNT)
+    }
+    pub fn grace_ending(&self) -> i64 {
+        self.0.i64(GRACE_ENDING)
+    }
+
+    pub fn set_maker(&self, maker: &Pubkey) {
+        self.0.set_pubkey(MAKER, maker);
+    }
+    pub fn set_mint_to_raise(&self, mint: &Pubkey) {
+        self.0.set_pubkey(MINT_TO_RAISE, mint);
+    }
+    pub fn set_amount_to_raise(&self, amount: u64) {
+        self.0.set_u64(AMOUNT_TO_RAISE, amount);
+    }
+    pub fn set_time_ending(&self, time_ending: i64) {
+        self.0.set_i64(TIME_ENDING, time_ending);
+    }
+    pub fn set_bump(&self, bump: u8) {
+        self.0.set_u8(BUMP, bump);
+    }
+    pub fn set_campaign_id(&self, campaign_id: u64) {
+        self.0.set_u64(CAMPAIGN_ID, campaign_id);
+    }
+    pub fn set_status(&self, status: FundraiserStatus) {
+        self.0.set_u8(STATUS, status as u8);
+    }
+    pub fn set_milestone_count(&self, count: u8) {
+        self.0.set_u8(MILESTONE_COUNT, count);
+    }
+    pub fn set_milestone(&self, index: usize, tranche: &Milestone) {
+        self.0.set_u8(MILESTONES + index * MILESTONE_LEN + milestone::PERCENTAGE, tranche.percentage);
+        self.0.set_i64(MILESTONES + index * MILESTONE_LEN + milestone::UNLOCK_TIME, tranche.unlock_time);
+    }
+    pub fn set_voting(&self, voting_period: i64, quorum_bps: u16, approval_bps: u16) {
+        self.0.set_i64(VOTING_PERIOD, voting_period);
+        self.0.set_u16(QUORUM_BPS, quorum_bps);
+        self.0.set_u16(APPROVAL_BPS, approval_bps);
+    }
+    pub fn set_funding_mode(&self, funding_mode: u8) {
+        self.0.set_u8(FUNDING_MODE, funding_mode);
+    }
+    pub fn set_price_authority(&self, price_authority: &Pubkey) {
+        self.0.set_pubkey(PRICE_AUTHORITY, price_authority);
+    }
+    pub fn set_contribution_limits(&self, limits: &ContributionLimits) {
+        self.0.set_u64(CONTRIBUTION_LIMITS + contribution_limits::MIN_CONTRIBUTION, limits.min_contribution);
+        self.0.set_u64(CONTRIBUTION_LIMITS + contribution_limits::MAX_CONTRIBUTION, limits.max_contribution);
+        self.0.set_u8(CONTRIBUTION_LIMITS + contribution_limits::MAX_CONTRIBUTION_KIND, limits.max_contribution_kind as u8);
+        self.0.set_u64(CONTRIBUTION_LIMITS + contribution_limits::HARD_CAP, limits.hard_cap);
+    }
+    pub fn set_receipt_mint(&self, receipt_mint: &Pubkey) {
+        self.0.set_pubkey(RECEIPT_MINT, receipt_mint);
     }
+    pub fn set_fee_bps(&self, fee_bps: u16) {
+        self.0.set_u16(FEE_BPS, fee_bps);
+    }
+    pub fn set_max_amendments(&self, max_amendments: u8) {
+        self.0.set_u8(MAX_AMENDMENTS, max_amendments);
+    }
+
+    pub fn add_amount_raised(&self, amount: u64) {
+        self.0.add_u64(CURRENT_AMOUNT, amount);
+    }
+    pub fn sub_amount_raised(&self, amount: u64) {
+        self.0.sub_u64(CURRENT_AMOUNT, amount);
+    }
+    // A multi-mint refund takes back the value at today's prices, which can be more than was counted in
+    pub fn set_amount_raised(&self, amount: u64) {
+        self.0.set_u64(CURRENT_AMOUNT, amount);
+    }
+    pub fn add_mint_amount_raised(&self, index: usize, amount: u64) {
+        self.0.add_u64(AMOUNTS_RAISED + index * 8, amount);
+    }
+    pub fn sub_mint_amount_raised(&self, index: usize, amount: u64) {
+        self.0.sub_u64(AMOUNTS_RAISED + index * 8, amount);
+    }
+    pub fn add_accepted_mint(&self, mint: &Pubkey) {
+        let index = self.accepted_mint_count() as usize;
+        assert!(index < MAX_ACCEPTED_MINTS, "Too many accepted mints");
+        self.0.set_pubkey(ACCEPTED_MINTS + index * 32, mint);
+        self.0.add_u8(ACCEPTED_MINT_COUNT, 1);
+    }
+
+    // Releasing a milestone pays out its tranche and opens the vote on the next one
+    pub fn release_milestone(&self, amount: u64) {
+        self.0.add_u8(MILESTONES_RELEASED, 1);
+        self.0.add_u64(AMOUNT_RELEASED, amount);
+        self.0.set_u64(VOTES_FOR, 0);
+        self.0.set_u64(VOTES_AGAINST, 0);
+    }
+    pub fn add_amount_released(&self, amount: u64) {
+        self.0.add_u64(AMOUNT_RELEASED, amount);
+    }
+    pub fn add_vote(&self, approve: bool, weight: u64) {
+        self.0.add_u64(if approve { VOTES_FOR } else { VOTES_AGAINST }, weight);
+    }
+
+    // Counts an extended deadline or a lowered goal against the maker's allowance and lets contributors reconsider
+    pub fn amend(&self, current_time: i64) {
+        assert!(
+            self.status() == FundraiserStatus::Active as u8,
+            "Only an active fundraiser can be amended"
+        );
+        assert!(current_time <= self.time_ending(), "The fundraiser has ended");
+        assert!(
+            self.amendment_count() < self.max_amendments(),
+            "The fundraiser has no amendments left"
+        );
+
+        self.0.add_u8(AMENDMENT_COUNT, 1);
+        self.0.set_i64(GRACE_ENDING, current_time + AMENDMENT_GRACE_PERIOD);
+    }
+
+    // Contributors can withdraw from an active fundraiser for a while after it was amended,
+    // and the maker cannot be paid until they had the chance to
+    pub fn in_grace_period(&self, current_time: i64) -> bool {
+        current_time < self.grace_ending()
+    }
+
+    // Votes always apply to the next milestone waiting to be released
+    pub fn milestone_approved(&self) -> bool {
+        let total_votes = self.votes_for() as u128 + self.votes_against() as u128;
+        let quorum_met = total_votes * BPS_SCALER as u128
+            >= self.amount_raised() as u128 * self.quorum_bps() as u128;
+        let approved = self.votes_for() as u128 * BPS_SCALER as u128
+            >= total_votes * self.approval_bps() as u128;
+
+        quorum_met && approved
+    }
+
+    // A SOL fundraiser has no mint, the fundraiser account itself holds the raised lamports
+    pub fn is_sol(&self) -> bool {
+        self.mint_to_raise() == Pubkey::default()
+    }
+
+    // A multi-mint fundraiser sets its goal in a common unit and values each accepted mint through a price feed
+    pub fn is_multi_mint(&self) -> bool {
+        self.accepted_mint_count() > 0
+    }
+
+    // Contributions mint receipt tokens to the contributor, and refunds burn them
+    pub fn issues_receipts(&self) -> bool {
+        self.receipt_mint() != Pubkey::default()
+    }
+
+    pub fn accepted_mint_index(&self, mint: &Pubkey) -> Option<usize> {
+        (0..self.accepted_mint_count() as usize).find(|index| &self.accepted_mint(*index) == mint)
+    }
+
+    // Values everything raised at the current prices, the price feeds have to be passed in the order of the accepted mints
+    pub fn value_raised(&self, price_feeds: &[AccountInfo]) -> u64 {
+        assert_eq!(price_feeds.len(), self.accepted_mint_count() as usize, "Missing price feeds");
+
+        price_feeds.iter().enumerate().fold(0, |value, (index, price_feed)| {
+            let price_feed = PriceFeed::from_account_info(price_feed);
+            assert!(
+                price_feed.authority() == self.price_authority() && price_feed.mint() == self.accepted_mint(index),
+                "Invalid price feed"
+            );
+            value.checked_add(price_feed.value(self.mint_amount_raised(index))).expect("Arithmetic overflow")
+        })
+    }
+
+    // A percentage maximum is read against the goal, a hard cap of 0 leaves the fundraiser uncapped
+    pub fn max_per_contributor(&self) -> Option<u64> {
+        match self.max_contribution_kind() {
+            kind if kind == MaxContributionKind::Absolute as u8 => Some(self.max_contribution()),
+            kind if kind == MaxContributionKind::Percentage as u8 => Some(
+                (self.amount_to_raise() as u128 * self.max_contribution() as u128 / PERCENTAGE_SCALER as u128) as u64
+            ),
+            _ => None,
+        }
+    }
+
+    pub fn contribution_limits_valid(&self) -> bool {
+        let kind = self.max_contribution_kind();
+        if kind > MaxContributionKind::Percentage as u8
+            || (kind == MaxContributionKind::Percentage as u8 && self.max_contribution() > PERCENTAGE_SCALER)
+        {
+            return false;
+        }
+
+        if let Some(max_contribution) = self.max_per_contributor() {
+            if max_contribution == 0 || max_contribution < self.min_contribution() {
+                return false;
+            }
+        }
+
+        self.hard_cap() == 0 || self.hard_cap() >= self.amount_to_raise()
+    }
+
+    // Checks a contribution against the limits the maker set, `contributed` is what the contributor has put in so far
+    pub fn check_contribution(&self, contributed: u64, amount: u64) -> Result<(), ProgramError> {
+        if amount == 0 || amount < self.min_contribution() {
+            return Err(ProgramError::Custom(FundraiserError::ContributionTooSmall as u32));
+        }
+
+        if let Some(max_contribution) = self.max_per_contributor() {
+            if contributed.checked_add(amount).ok_or(ProgramError::ArithmeticOverflow)? > max_contribution {
+                return Err(ProgramError::Custom(FundraiserError::MaximumContributionsReached as u32));
+            }
+        }
+
+        if self.hard_cap() != 0
+            && self.amount_raised().checked_add(amount).ok_or(ProgramError::ArithmeticOverflow)? > self.hard_cap()
+        {
+            return Err(ProgramError::Custom(FundraiserError::HardCapReached as u32));
+        }
+
+        Ok(())
+    }
+
+    // A fixed fundraiser has to meet its target, a flexible one keeps whatever it raised once the deadline passes
+    pub fn funds_secured(&self, current_time: i64) -> bool {
+        self.amount_raised() >= self.amount_to_raise()
+            || (self.funding_mode() == FundingMode::Flexible as u8 && current_time >= self.time_ending())
+    }
+
+}
 
-}
\ No newline at end of file
+const _: () = assert!(Fundraiser::LEN == HEADER_LEN + fundraiser_interface::state::fundraiser::LEN);
\ No newline at end of file
@@ -0,0 +1,59 @@
+use pinocchio::{account_info::AccountInfo, pubkey::Pubkey};
+
+use fundraiser_interface::state::price_feed::*;
+
+use crate::state::{AccountData, AccountDiscriminator, HEADER_LEN};
+
+// --Data--
+// header: (discriminator: u8, version: u8)
+// authority: Pubkey
+// mint: Pubkey
+// decimals: u8
+// price: u64
+// bump: u8
+
+pub struct PriceFeed<'a>(AccountData<'a>);
+
+impl<'a> PriceFeed<'a> {
+    pub const LEN: usize = HEADER_LEN + 32 + 32 + 1 + 8 + 1;
+
+    pub fn from_account_info(account_info: &'a AccountInfo) -> Self {
+        Self(AccountData::load(account_info, AccountDiscriminator::PriceFeed, Self::LEN))
+    }
+
+    // The authority creates the account, the first price set up fills it in
+    pub fn load_or_init(account_info: &'a AccountInfo) -> Self {
+        Self(AccountData::load_or_init(account_info, AccountDiscriminator::PriceFeed, Self::LEN))
+    }
+
+    pub fn authority(&self) -> Pubkey {
+        self.0.pubkey(AUTHORITY)
+    }
+    pub fn mint(&self) -> Pubkey {
+        self.0.pubkey(MINT)
+    }
+    pub fn decimals(&self) -> u8 {
+        self.0.u8(DECIMALS)
+    }
+    pub fn price(&self) -> u64 {
+        self.0.u64(PRICE)
+    }
+    pub fn bump(&self) -> u8 {
+        self.0.u8(BUMP)
+    }
+
+    pub fn set(&self, authority: &Pubkey, mint: &Pubkey, decimals: u8, price: u64, bump: u8) {
+        self.0.set_pubkey(AUTHORITY, authority);
+        self.0.set_pubkey(MINT, mint);
+        self.0.set_u8(DECIMALS, decimals);
+        self.0.set_u64(PRICE, price);
+        self.0.set_u8(BUMP, bump);
+    }
+
+    // The price is what one whole token is worth in base units of the common unit
+    pub fn value(&self, amount: u64) -> u64 {
+        (amount as u128 * self.price() as u128 / 10u128.pow(self.decimals() as u32)) as u64
+    }
+}
+
+const _: () = assert!(PriceFeed::LEN == HEADER_LEN + fundraiser_interface::state::price_feed::LEN);
\ No newline at end of file
@@ -0,0 +1,96 @@
+use pinocchio::{account_info::AccountInfo, pubkey::Pubkey};
+
+use fundraiser_interface::state::{reward_tier, reward_tiers::*};
+
+use crate::state::{AccountData, AccountDiscriminator, HEADER_LEN};
+
+// --Data--
+// header: (discriminator: u8, version: u8)
+// fundraiser: Pubkey
+// tier_count: u8
+// tiers: [(min_pledge: u64, max_supply: u64); MAX_REWARD_TIERS]
+// claimed: [u64; MAX_REWARD_TIERS]
+// bump: u8
+
+pub use fundraiser_interface::state::MAX_REWARD_TIERS;
+pub const REWARD_TIER_LEN: usize = fundraiser_interface::state::reward_tier::LEN;
+
+pub struct RewardTiers<'a>(AccountData<'a>);
+
+impl<'a> RewardTiers<'a> {
+    pub const LEN: usize = HEADER_LEN + 32 + 1 + REWARD_TIER_LEN * MAX_REWARD_TIERS + 8 * MAX_REWARD_TIERS + 1;
+
+    pub fn from_account_info(account_info: &'a AccountInfo) -> Self {
+        Self(AccountData::load(account_info, AccountDiscriminator::RewardTiers, Self::LEN))
+    }
+
+    // The maker creates the account, setting the tiers fills it in
+    pub fn load_or_init(account_info: &'a AccountInfo) -> Self {
+        Self(AccountData::load_or_init(account_info, AccountDiscriminator::RewardTiers, Self::LEN))
+    }
+
+    pub fn fundraiser(&self) -> Pubkey {
+        self.0.pubkey(FUNDRAISER)
+    }
+    pub fn tier_count(&self) -> u8 {
+        self.0.u8(TIER_COUNT)
+    }
+    pub fn min_pledge(&self, index: usize) -> u64 {
+        self.0.u64(TIERS + index * REWARD_TIER_LEN + reward_tier::MIN_PLEDGE)
+    }
+    pub fn max_supply(&self, index: usize) -> u64 {
+        self.0.u64(TIERS + index * REWARD_TIER_LEN + reward_tier::MAX_SUPPLY)
+    }
+    pub fn claimed(&self, index: usize) -> u64 {
+        self.0.u64(CLAIMED + index * 8)
+    }
+    pub fn bump(&self) -> u8 {
+        self.0.u8(BUMP)
+    }
+
+    // Replaces the tiers, nothing has been claimed from the new ones yet
+    pub fn set(&self, fundraiser: &Pubkey, tier_count: u8, tiers: &[u8], bump: u8) {
+        self.0.set_pubkey(FUNDRAISER, fundraiser);
+        self.0.set_u8(TIER_COUNT, tier_count);
+        for index in 0..MAX_REWARD_TIERS {
+            let tier = &tiers[index * REWARD_TIER_LEN..];
+            let min_pledge = u64::from_le_bytes(tier[reward_tier::MIN_PLEDGE..reward_tier::MAX_SUPPLY].try_into().unwrap());
+            let max_supply = u64::from_le_bytes(tier[reward_tier::MAX_SUPPLY..reward_tier::LEN].try_into().unwrap());
+            self.0.set_u64(TIERS + index * REWARD_TIER_LEN + reward_tier::MIN_PLEDGE, min_pledge);
+            self.0.set_u64(TIERS + index * REWARD_TIER_LEN + reward_tier::MAX_SUPPLY, max_supply);
+            self.0.set_u64(CLAIMED + index * 8, 0);
+        }
+        self.0.set_u8(BUMP, bump);
+    }
+
+    // Moves a contributor from their current tier (0 for none, otherwise the index plus one) to `index`
+    // once their pledge reaches its minimum, staying in the same tier does not take another slot
+    pub fn claim(&self, current: u8, index: u8, pledge: u64) {
+        assert!(index < self.tier_count(), "Invalid reward tier");
+        assert!(
+            pledge >= self.min_pledge(index as usize),
+            "The pledge is below the minimum of the reward tier"
+        );
+
+        if current == index + 1 {
+            return;
+        }
+
+        assert!(
+            self.claimed(index as usize) < self.max_supply(index as usize),
+            "The reward tier is sold out"
+        );
+
+        self.release(current);
+        self.0.add_u64(CLAIMED + index as usize * 8, 1);
+    }
+
+    // Gives the slot of a contributor's tier back, 0 holds no slot
+    pub fn release(&self, current: u8) {
+        if current > 0 {
+            self.0.sub_u64(CLAIMED + (current as usize - 1) * 8, 1);
+        }
+    }
+}
+
+const _: () = assert!(RewardTiers::LEN == HEADER_LEN + fundraiser_interface::state::reward_tiers::LEN);
\ No newline at end of file
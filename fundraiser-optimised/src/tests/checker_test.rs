@@ -1,13 +1,12 @@
 use crate::{
-    state::{Contributor, Fundraiser},
-    tests::setup,
+    state::{AccountDiscriminator, Fundraiser, HEADER_LEN},
+    tests::{header, setup},
 };
 use mollusk_svm::result::Check;
 use pinocchio_token::state::TokenAccount;
 use solana_sdk::{
     account::{AccountSharedData, ReadableAccount},
     instruction::{AccountMeta, Instruction},
-    program_pack::Pack,
     pubkey::Pubkey,
 };
 
@@ -15,20 +14,15 @@ use solana_sdk::{
 fn check_test() {
     let (program_id, mollusk) = setup();
     let (token_program, token_program_account) = mollusk_token::token::keyed_account();
+    let (system_program, system_program_account) = mollusk_svm::program::keyed_account_for_system_program();
 
     let maker = Pubkey::new_from_array([0x1; 32]);
+    let campaign_id = 0u64;
     let signer = maker;
-    let signer_account = crate::tests::create_account(
-        mollusk
-            .sysvars
-            .rent
-            .minimum_balance(spl_token::state::Account::LEN),
-        spl_token::state::Account::LEN,
-        &program_id,
-    );
+    let signer_account = crate::tests::create_account(1_000_000_000, 0, &solana_sdk::system_program::ID);
     let signer_ta = Pubkey::new_from_array([0x3; 32]);
     let (fundraiser, bump) =
-        Pubkey::find_program_address(&[b"fundraiser".as_ref(), &maker.to_bytes().as_ref()], &program_id);
+        Pubkey::find_program_address(&[b"fundraiser".as_ref(), &maker.to_bytes().as_ref(), &campaign_id.to_le_bytes()], &program_id);
     let contributor = Pubkey::find_program_address(
         &[
             b"contributor",
@@ -54,11 +48,8 @@ fn check_test() {
         Fundraiser::LEN,
         &program_id,
     );
-    let contributor_account = crate::tests::create_account(
-        mollusk.sysvars.rent.minimum_balance(Contributor::LEN),
-        Contributor::LEN,
-        &program_id,
-    );
+    // The contribution creates the contributor account
+    let contributor_account = AccountSharedData::default();
 
     // Get current time from mollusk's clock
     let current_time = mollusk.sysvars.clock.unix_timestamp;
@@ -70,13 +61,17 @@ fn check_test() {
     println!("Setting initial end time to: {}", one_year_from_now);
 
     // Properly set up the fundraiser data with future end time
-    let mut buffer = vec![0u8; Fundraiser::LEN];
-    buffer[0..32].copy_from_slice(&maker.to_bytes());
-    buffer[32..64].copy_from_slice(&mint.to_bytes());
-    buffer[64..72].copy_from_slice(&200_000_000u64.to_le_bytes());
-    buffer[72..80].copy_from_slice(&0u64.to_le_bytes());
-    buffer[80..88].copy_from_slice(&one_year_from_now.to_le_bytes()); // Future timestamp for contribution
-    buffer[88..89].copy_from_slice(&[bump]); // bump
+    let mut buffer = header(AccountDiscriminator::Fundraiser);
+    buffer.resize(Fundraiser::LEN, 0);
+    let data = &mut buffer[HEADER_LEN..];
+    data[0..32].copy_from_slice(&maker.to_bytes());
+    data[32..64].copy_from_slice(&mint.to_bytes());
+    data[64..72].copy_from_slice(&200_000_000u64.to_le_bytes());
+    data[72..80].copy_from_slice(&0u64.to_le_bytes());
+    data[80..88].copy_from_slice(&one_year_from_now.to_le_bytes()); // Future timestamp for contribution
+    data[88..89].copy_from_slice(&[bump]); // bump
+    data[89..97].copy_from_slice(&campaign_id.to_le_bytes());
+    data[172] = 1; // Flexible, the maker withdraws what was raised once the fundraiser ends
 
     fundraiser_account.set_data_from_slice(&buffer);
 
@@ -87,7 +82,7 @@ fn check_test() {
     assert_eq!(fundraiser_account.data().len(), Fundraiser::LEN);
 
     let amount_to_contribute: u64 = 100_000; 
-    let contribute_data = [vec![1], amount_to_contribute.to_le_bytes().to_vec()].concat();
+    let contribute_data = [vec![2], amount_to_contribute.to_le_bytes().to_vec()].concat();
 
     let contribute_instruction = Instruction::new_with_bytes(
         program_id,
@@ -99,6 +94,8 @@ fn check_test() {
             AccountMeta::new(fundraiser, false),
             AccountMeta::new(vault, false),
             AccountMeta::new(token_program, false),
+            AccountMeta::new_readonly(mint, false),
+            AccountMeta::new_readonly(system_program, false),
         ],
     );
 
@@ -111,6 +108,8 @@ fn check_test() {
             (fundraiser, fundraiser_account.clone()),
             (vault, vault_account.clone()),
             (token_program, token_program_account.clone()),
+            (mint, mint_account.clone()),
+            (system_program, system_program_account.clone()),
         ],
         &[Check::success()],
     );
@@ -125,7 +124,7 @@ fn check_test() {
         .expect("Failed to find fundraiser account")
         .clone();
     
-    let fundraiser_data = updated_fundraiser_account.data();
+    let fundraiser_data = &updated_fundraiser_account.data()[HEADER_LEN..];
     println!(
         "Amount to raise: {:?}",
         u64::from_le_bytes(fundraiser_data[64..72].try_into().unwrap())
@@ -147,7 +146,7 @@ fn check_test() {
     
     // Create a new fundraiser account with the past end time but preserving the amount raised
     let mut updated_data = updated_fundraiser_account.data().to_vec();
-    updated_data[80..88].copy_from_slice(&one_day_ago.to_le_bytes());
+    updated_data[HEADER_LEN + 80..HEADER_LEN + 88].copy_from_slice(&one_day_ago.to_le_bytes());
     
     // Create a new account with the modified data
     let mut final_fundraiser_account = AccountSharedData::new(
@@ -159,12 +158,13 @@ fn check_test() {
   
     let check_instruction = Instruction::new_with_bytes(
         program_id,
-        &[vec![2]].concat(),
+        &[vec![1]].concat(),
         vec![
             AccountMeta::new(signer, true),
-            AccountMeta::new(signer_ta, false),
+            AccountMeta::new_readonly(mint, false),
             AccountMeta::new(fundraiser, true),
             AccountMeta::new(vault, true),
+            AccountMeta::new(signer_ta, false),
             AccountMeta::new_readonly(token_program, false),
         ],
     );
@@ -187,6 +187,22 @@ fn check_test() {
         signer_ta_before.amount()
     );
 
+    // Only the maker can withdraw, and has to sign for it
+    let mut unsigned_instruction = check_instruction.clone();
+    unsigned_instruction.accounts[0].is_signer = false;
+    let result = mollusk.process_instruction(
+        &unsigned_instruction,
+        &vec![
+            (signer, signer_account.clone()),
+            (signer_ta, updated_signer_ta_account.clone()),
+            (fundraiser, final_fundraiser_account.clone()),
+            (vault, updated_vault_account.clone()),
+            (token_program, token_program_account.clone()),
+            (mint, mint_account.clone()),
+        ],
+    );
+    assert!(result.program_result.is_err(), "checker should fail without the maker's signature");
+
     // Execute the check instruction using the updated accounts
     let result = mollusk.process_and_validate_instruction(
         &check_instruction,
@@ -196,6 +212,7 @@ fn check_test() {
             (fundraiser, final_fundraiser_account),
             (vault, updated_vault_account),
             (token_program, token_program_account),
+            (mint, mint_account),
         ],
         &[Check::success()],
     );
@@ -1,31 +1,28 @@
+use fundraiser_interface::error::FundraiserError;
+use mollusk_svm::result::Check;
 use solana_sdk::{
-    account::ReadableAccount,
+    account::{AccountSharedData, ReadableAccount},
     instruction::{AccountMeta, Instruction},
-    program_pack::Pack,
+    program_error::ProgramError,
     pubkey::Pubkey,
 };
 
-use crate::state::Contributor;
-use crate::{state::Fundraiser, tests::setup};
+use crate::state::{AccountDiscriminator, HEADER_LEN};
+use crate::{state::Fundraiser, tests::{header, setup}};
 
 #[test]
 fn contribute_test() {
     let (program_id, mollusk) = setup();
     let (token_program, token_program_account) = mollusk_token::token::keyed_account();
+    let (system_program, system_program_account) = mollusk_svm::program::keyed_account_for_system_program();
 
     let maker = Pubkey::new_from_array([0x1; 32]);
+    let campaign_id = 0u64;
     let signer = Pubkey::new_from_array([0x2; 32]);
-    let signer_account = crate::tests::create_account(
-        mollusk
-            .sysvars
-            .rent
-            .minimum_balance(spl_token::state::Account::LEN),
-        spl_token::state::Account::LEN,
-        &program_id,
-    );
+    let signer_account = crate::tests::create_account(1_000_000_000, 0, &solana_sdk::system_program::ID);
     let signer_ta = Pubkey::new_from_array([0x3; 32]);
-    let fundraiser =
-        Pubkey::find_program_address(&[b"fundraiser", &maker.to_bytes()], &program_id).0;
+    let (fundraiser, bump) =
+        Pubkey::find_program_address(&[b"fundraiser", &maker.to_bytes(), &campaign_id.to_le_bytes()], &program_id);
     let contributor = Pubkey::find_program_address(
         &[
             b"contributor",
@@ -46,27 +43,47 @@ fn contribute_test() {
         Fundraiser::LEN,
         &program_id,
     );
-    let contributor_account = crate::tests::create_account(
-        mollusk.sysvars.rent.minimum_balance(Contributor::LEN),
-        Contributor::LEN,
-        &program_id,
-    );
+    // The contribution creates the contributor account
+    let contributor_account = AccountSharedData::default();
 
     
     fundraiser_account.set_data_from_slice(
         &[
+            header(AccountDiscriminator::Fundraiser),
             maker.to_bytes().to_vec(),
             mint.to_bytes().to_vec(),
             100_000_000u64.to_le_bytes().to_vec(),
             0u64.to_le_bytes().to_vec(),
             i64::MAX.to_le_bytes().to_vec(), 
-            0u8.to_le_bytes().to_vec(),
+            bump.to_le_bytes().to_vec(),
+            campaign_id.to_le_bytes().to_vec(),
+            0u8.to_le_bytes().to_vec(), // status
+            0u8.to_le_bytes().to_vec(), // milestone_count
+            vec![0u8; 36], // milestones
+            0u8.to_le_bytes().to_vec(), // milestones_released
+            0u64.to_le_bytes().to_vec(), // amount_released
+            0i64.to_le_bytes().to_vec(), // voting_period
+            0u16.to_le_bytes().to_vec(), // quorum_bps
+            0u16.to_le_bytes().to_vec(), // approval_bps
+            0u64.to_le_bytes().to_vec(), // votes_for
+            0u64.to_le_bytes().to_vec(), // votes_against
+            0u8.to_le_bytes().to_vec(), // funding_mode
+            Pubkey::default().to_bytes().to_vec(), // price_authority
+            0u8.to_le_bytes().to_vec(), // accepted_mint_count
+            vec![0u8; 96], // accepted_mints
+            vec![0u8; 24], // amounts_raised
+            vec![0u8; 25], // contribution_limits
+            Pubkey::default().to_bytes().to_vec(), // receipt_mint
+            0u16.to_le_bytes().to_vec(), // fee_bps
+            0u8.to_le_bytes().to_vec(), // max_amendments
+            0u8.to_le_bytes().to_vec(), // amendment_count
+            0i64.to_le_bytes().to_vec(), // grace_ending
         ]
         .concat(),
     );
 
     let amount = 300_000u64;
-    let data = [vec![1], amount.to_le_bytes().to_vec()].concat();
+    let data = [vec![2], amount.to_le_bytes().to_vec()].concat();
 
     let contribute_instruction = Instruction::new_with_bytes(
         program_id,
@@ -78,8 +95,29 @@ fn contribute_test() {
             AccountMeta::new(fundraiser, false),
             AccountMeta::new(vault, false),
             AccountMeta::new(token_program, false),
+            AccountMeta::new_readonly(mint, false),
+            AccountMeta::new_readonly(system_program, false),
+        ],
+    );
+
+    // The contribution is credited by what the vault receives, a vault the fundraiser does not own is refused
+    let result = mollusk.process_instruction(
+        &contribute_instruction,
+        &vec![
+            (signer, signer_account.clone()),
+            (contributor, contributor_account.clone()),
+            (signer_ta, signer_ta_account.clone()),
+            (fundraiser, fundraiser_account.clone()),
+            (vault, crate::tests::pack_token_account(&signer, &mint, 0)),
+            (token_program, token_program_account.clone()),
+            (mint, crate::tests::pack_mint(&maker, 2_000_000_000)),
+            (system_program, system_program_account.clone()),
         ],
     );
+    assert!(
+        result.program_result.is_err(),
+        "Contributing into a vault the fundraiser does not own should fail."
+    );
 
     let result = mollusk.process_instruction_chain(
         &[contribute_instruction],
@@ -90,6 +128,8 @@ fn contribute_test() {
             (fundraiser, fundraiser_account.clone()),
             (vault, vault_account.clone()),
             (token_program, token_program_account),
+            (mint, crate::tests::pack_mint(&maker, 2_000_000_000)),
+            (system_program, system_program_account.clone()),
         ],
     );
     assert!(
@@ -101,7 +141,7 @@ fn contribute_test() {
     let fundraiser_result_account = result
         .get_account(&fundraiser)
         .expect("Failed to find fundraiser account");
-    let data = fundraiser_result_account.data();
+    let data = &fundraiser_result_account.data()[HEADER_LEN..];
     assert_eq!(
         u64::from_le_bytes(data[72..80].try_into().unwrap()),
         amount,
@@ -111,10 +151,117 @@ fn contribute_test() {
     let contributor_result_account = result
         .get_account(&contributor)
         .expect("Failed to find contributor account");
-    let data = contributor_result_account.data();
+    let data = &contributor_result_account.data()[HEADER_LEN..];
     assert_eq!(
         u64::from_le_bytes(data[0..8].try_into().unwrap()),
         amount,
         "Contributor amount should be updated after contribution"
     );
+}
+
+#[test]
+fn contribute_limits_test() {
+    let (program_id, mollusk) = setup();
+    let (token_program, token_program_account) = mollusk_token::token::keyed_account();
+    let (system_program, system_program_account) = mollusk_svm::program::keyed_account_for_system_program();
+
+    let maker = Pubkey::new_from_array([0x1; 32]);
+    let campaign_id = 0u64;
+    let signer = Pubkey::new_from_array([0x2; 32]);
+    let signer_ta = Pubkey::new_from_array([0x3; 32]);
+    let (fundraiser, bump) =
+        Pubkey::find_program_address(&[b"fundraiser", &maker.to_bytes(), &campaign_id.to_le_bytes()], &program_id);
+    let contributor = Pubkey::find_program_address(
+        &[
+            b"contributor",
+            fundraiser.as_ref(),
+            signer.to_bytes().as_ref(),
+        ],
+        &program_id,
+    )
+    .0;
+    let mint = Pubkey::new_from_array([0x4; 32]);
+    let vault = Pubkey::new_from_array([0x5; 32]);
+
+    let mut fundraiser_account = crate::tests::create_account(
+        mollusk.sysvars.rent.minimum_balance(Fundraiser::LEN),
+        Fundraiser::LEN,
+        &program_id,
+    );
+
+    // 80_000_000 of the 100_000_000 goal is already raised, and the goal is also the hard cap
+    fundraiser_account.set_data_from_slice(
+        &[
+            header(AccountDiscriminator::Fundraiser),
+            maker.to_bytes().to_vec(),
+            mint.to_bytes().to_vec(),
+            100_000_000u64.to_le_bytes().to_vec(), // amount_to_raise
+            80_000_000u64.to_le_bytes().to_vec(), // amount_raised
+            i64::MAX.to_le_bytes().to_vec(), // time_ending
+            bump.to_le_bytes().to_vec(), // bump
+            campaign_id.to_le_bytes().to_vec(),
+            0u8.to_le_bytes().to_vec(), // status
+            0u8.to_le_bytes().to_vec(), // milestone_count
+            vec![0u8; 36], // milestones
+            0u8.to_le_bytes().to_vec(), // milestones_released
+            0u64.to_le_bytes().to_vec(), // amount_released
+            0i64.to_le_bytes().to_vec(), // voting_period
+            0u16.to_le_bytes().to_vec(), // quorum_bps
+            0u16.to_le_bytes().to_vec(), // approval_bps
+            0u64.to_le_bytes().to_vec(), // votes_for
+            0u64.to_le_bytes().to_vec(), // votes_against
+            0u8.to_le_bytes().to_vec(), // funding_mode
+            Pubkey::default().to_bytes().to_vec(), // price_authority
+            0u8.to_le_bytes().to_vec(), // accepted_mint_count
+            vec![0u8; 96], // accepted_mints
+            vec![0u8; 24], // amounts_raised
+            10_000_000u64.to_le_bytes().to_vec(), // min_contribution
+            50_000_000u64.to_le_bytes().to_vec(), // max_contribution
+            1u8.to_le_bytes().to_vec(), // max_contribution_kind (Absolute)
+            100_000_000u64.to_le_bytes().to_vec(), // hard_cap
+            Pubkey::default().to_bytes().to_vec(), // receipt_mint
+            0u16.to_le_bytes().to_vec(), // fee_bps
+            0u8.to_le_bytes().to_vec(), // max_amendments
+            0u8.to_le_bytes().to_vec(), // amendment_count
+            0i64.to_le_bytes().to_vec(), // grace_ending
+        ]
+        .concat(),
+    );
+
+    // Each limit fails with its own error code, the same one the other implementations return
+    for (amount, error) in [
+        (1_000_000u64, FundraiserError::ContributionTooSmall),
+        (60_000_000u64, FundraiserError::MaximumContributionsReached),
+        (30_000_000u64, FundraiserError::HardCapReached),
+    ] {
+        let contribute_instruction = Instruction::new_with_bytes(
+            program_id,
+            &[vec![2], amount.to_le_bytes().to_vec()].concat(),
+            vec![
+                AccountMeta::new(signer, true),
+                AccountMeta::new(contributor, true),
+                AccountMeta::new(signer_ta, false),
+                AccountMeta::new(fundraiser, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new(token_program, false),
+                AccountMeta::new_readonly(mint, false),
+                AccountMeta::new_readonly(system_program, false),
+            ],
+        );
+
+        mollusk.process_and_validate_instruction(
+            &contribute_instruction,
+            &vec![
+                (signer, crate::tests::create_account(1_000_000_000, 0, &solana_sdk::system_program::ID)),
+                (contributor, AccountSharedData::default()),
+                (signer_ta, crate::tests::pack_token_account(&signer, &mint, 2_000_000_000)),
+                (fundraiser, fundraiser_account.clone()),
+                (vault, crate::tests::pack_token_account(&fundraiser, &mint, 80_000_000)),
+                (token_program, token_program_account.clone()),
+                (mint, crate::tests::pack_mint(&maker, 2_000_000_000)),
+                (system_program, system_program_account.clone()),
+            ],
+            &[Check::err(ProgramError::Custom(error as u32))],
+        );
+    }
 }
\ No newline at end of file
@@ -1,28 +1,46 @@
-use crate::state::Fundraiser;
-use crate::tests::setup;
+use crate::state::{AccountDiscriminator, Fundraiser, HEADER_LEN};
+use crate::tests::{header, setup};
 use solana_sdk::account::ReadableAccount;
 use solana_sdk::{
     account::AccountSharedData,
     instruction::{AccountMeta, Instruction},
     pubkey::Pubkey,
+    system_program,
 };
 
 #[test]
 pub fn initialize_test() {
     let (program_id, mollusk) = setup();
+    let (token_program, token_program_account) = mollusk_token::token::keyed_account();
+    let (associated_token_program, associated_token_program_account) =
+        mollusk_token::associated_token::keyed_account();
+    let (system_program, system_program_account) = mollusk_svm::program::keyed_account_for_system_program();
 
     let maker = Pubkey::new_from_array([0x01; 32]);
-    let (fundraiser, _) =
-        Pubkey::find_program_address(&[b"fundraiser", &maker.to_bytes()], &program_id);
+    let campaign_id = 0u64;
+    let (fundraiser, bump) =
+        Pubkey::find_program_address(&[b"fundraiser", &maker.to_bytes(), &campaign_id.to_le_bytes()], &program_id);
+    let config = Pubkey::find_program_address(&[b"config"], &program_id).0;
     let mint = Pubkey::new_from_array([0x02; 32]);
+    let vault = Pubkey::find_program_address(
+        &[&fundraiser.to_bytes(), &token_program.to_bytes(), &mint.to_bytes()],
+        &associated_token_program,
+    ).0;
    
     let data = [
         vec![0],
-        maker.to_bytes().to_vec(),   // maker pubkey           
-        mint.to_bytes().to_vec(),    //mint to raise          
         100_000_000u64.to_le_bytes().to_vec(), //amount to raise
         i64::MAX.to_le_bytes().to_vec(),     // time ending 
-        1u8.to_le_bytes().to_vec(),           //bump
+        campaign_id.to_le_bytes().to_vec(),   // campaign id
+        bump.to_le_bytes().to_vec(),          //bump
+        0u8.to_le_bytes().to_vec(),           // milestone count
+        vec![0u8; 36],                        // milestones
+        0i64.to_le_bytes().to_vec(),          // voting period
+        0u16.to_le_bytes().to_vec(),          // quorum bps
+        0u16.to_le_bytes().to_vec(),          // approval bps
+        0u8.to_le_bytes().to_vec(),           // funding mode
+        vec![0u8; 25],                        // contribution limits
+        0u8.to_le_bytes().to_vec(),           // max amendments
         
     ]
     .concat();
@@ -31,19 +49,28 @@ pub fn initialize_test() {
         program_id,
         &data,
         vec![
+            AccountMeta::new(maker, true),
             AccountMeta::new(fundraiser, false),
+            AccountMeta::new_readonly(mint, false),
+            AccountMeta::new(vault, false),
+            AccountMeta::new_readonly(system_program, false),
+            AccountMeta::new_readonly(token_program, false),
+            AccountMeta::new_readonly(associated_token_program, false),
+            AccountMeta::new_readonly(config, false),
         ],
     );
 
-    let lamports = mollusk.sysvars.rent.minimum_balance(Fundraiser::LEN);
-
     let result: mollusk_svm::result::InstructionResult = mollusk.process_instruction(
         &instruction,
         &[
-            (
-                fundraiser,
-                AccountSharedData::new(lamports, Fundraiser::LEN, &program_id),
-            ),
+            (maker, AccountSharedData::new(1_000_000_000, 0, &system_program::ID)),
+            (fundraiser, AccountSharedData::default()),
+            (mint, crate::tests::pack_mint(&maker, 0)),
+            (vault, AccountSharedData::default()),
+            (system_program, system_program_account),
+            (token_program, token_program_account),
+            (associated_token_program, associated_token_program_account),
+            (config, AccountSharedData::default()),
         ],
     );
     assert!(
@@ -54,7 +81,10 @@ pub fn initialize_test() {
     let fundraiser_result_account = result
         .get_account(&fundraiser)
         .expect("Failed to find fundraiser account");
+    assert_eq!(fundraiser_result_account.owner(), &program_id);
     let data = fundraiser_result_account.data();
+    assert_eq!(data.len(), Fundraiser::LEN);
+    assert_eq!(data[..HEADER_LEN], header(AccountDiscriminator::Fundraiser)[..], "The fundraiser should carry its header");
     println!("{:?}", data);
     println!(
         "Amount to raise {}, Mint to raise {}",
@@ -1,12 +1,31 @@
 #[cfg(test)]
+mod amendment_test;
+#[cfg(test)]
+mod cancel_test;
+#[cfg(test)]
 mod checker_test;
 #[cfg(test)]
 mod contribute_test;
 #[cfg(test)]
 mod initialize_test;
 #[cfg(test)]
+mod multi_mint_test;
+#[cfg(test)]
+mod platform_fee_test;
+#[cfg(test)]
+mod receipts_test;
+#[cfg(test)]
 mod refund_test;
+#[cfg(test)]
+mod release_milestone_test;
+#[cfg(test)]
+mod reward_tiers_test;
+#[cfg(test)]
+mod sol_test;
+#[cfg(test)]
+mod vote_test;
 
+use crate::state::{AccountDiscriminator, LAYOUT_VERSION};
 use mollusk_svm::Mollusk;
 use solana_sdk::account::{AccountSharedData, WritableAccount};
 use solana_sdk::program_option::COption;
@@ -20,9 +39,15 @@ pub fn setup() -> (Pubkey, Mollusk) {
     ));
     let mut mollusk = Mollusk::new(&program_id, "target/deploy/fundraiser_optimised");
     mollusk_token::token::add_program(&mut mollusk);
+    mollusk_token::associated_token::add_program(&mut mollusk);
     (program_id, mollusk)
 }
 
+// The header the program writes in front of the data of every account it owns
+pub fn header(discriminator: AccountDiscriminator) -> Vec<u8> {
+    vec![discriminator as u8, LAYOUT_VERSION]
+}
+
 pub fn create_account(lamports: u64, data_len: usize, owner: &Pubkey) -> AccountSharedData {
     AccountSharedData::new(lamports, data_len, owner)
 }
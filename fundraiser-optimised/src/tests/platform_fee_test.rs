@@ -0,0 +1,347 @@
+use mollusk_svm::{result::Check, Mollusk};
+use solana_sdk::{
+    account::{AccountSharedData, ReadableAccount},
+    bpf_loader_upgradeable,
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    system_program,
+};
+use crate::{
+    state::{AccountDiscriminator, Config, Fundraiser, HEADER_LEN},
+    tests::{header, setup},
+};
+
+// The account of the upgradeable loader naming `upgrade_authority`
+fn program_data_account(upgrade_authority: &Pubkey) -> AccountSharedData {
+    let mut account = AccountSharedData::new(1_000_000_000, 45, &bpf_loader_upgradeable::id());
+    account.set_data_from_slice(
+        &[
+            3u32.to_le_bytes().to_vec(), // ProgramData
+            0u64.to_le_bytes().to_vec(), // slot
+            vec![1], // Some(upgrade_authority)
+            upgrade_authority.to_bytes().to_vec(),
+        ]
+        .concat(),
+    );
+    account
+}
+
+// The config of a platform charging `fee_bps` for `fee_recipient`
+fn config_account(mollusk: &Mollusk, program_id: &Pubkey, fee_bps: u16, fee_recipient: &Pubkey) -> (Pubkey, AccountSharedData) {
+    let (config, bump) = Pubkey::find_program_address(&[b"config"], program_id);
+
+    let mut account = crate::tests::create_account(
+        mollusk.sysvars.rent.minimum_balance(Config::LEN),
+        Config::LEN,
+        program_id,
+    );
+    account.set_data_from_slice(
+        &[
+            header(AccountDiscriminator::Config),
+            Pubkey::new_from_array([0x9; 32]).to_bytes().to_vec(), // admin
+            fee_bps.to_le_bytes().to_vec(),
+            fee_recipient.to_bytes().to_vec(),
+            vec![bump],
+        ]
+        .concat(),
+    );
+
+    (config, account)
+}
+
+// A fundraiser that met its goal of `amount` while the platform charged 2.5%
+fn fundraiser_data(maker: &Pubkey, mint: &Pubkey, bump: u8, amount: u64, time_ending: i64, milestones: &[u8]) -> Vec<u8> {
+    [
+        header(AccountDiscriminator::Fundraiser),
+        maker.to_bytes().to_vec(),
+        mint.to_bytes().to_vec(),
+        amount.to_le_bytes().to_vec(), // amount_to_raise
+        amount.to_le_bytes().to_vec(), // amount_raised
+        time_ending.to_le_bytes().to_vec(),
+        bump.to_le_bytes().to_vec(), // bump
+        0u64.to_le_bytes().to_vec(), // campaign_id
+        0u8.to_le_bytes().to_vec(), // status
+        ((milestones.len() / 9) as u8).to_le_bytes().to_vec(), // milestone_count
+        [milestones.to_vec(), vec![0u8; 36 - milestones.len()]].concat(), // milestones
+        0u8.to_le_bytes().to_vec(), // milestones_released
+        0u64.to_le_bytes().to_vec(), // amount_released
+        0i64.to_le_bytes().to_vec(), // voting_period
+        0u16.to_le_bytes().to_vec(), // quorum_bps
+        0u16.to_le_bytes().to_vec(), // approval_bps
+        0u64.to_le_bytes().to_vec(), // votes_for
+        0u64.to_le_bytes().to_vec(), // votes_against
+        0u8.to_le_bytes().to_vec(), // funding_mode
+        Pubkey::default().to_bytes().to_vec(), // price_authority
+        0u8.to_le_bytes().to_vec(), // accepted_mint_count
+        vec![0u8; 96], // accepted_mints
+        vec![0u8; 24], // amounts_raised
+        vec![0u8; 25], // contribution_limits
+        Pubkey::default().to_bytes().to_vec(), // receipt_mint
+        250u16.to_le_bytes().to_vec(), // fee_bps
+        0u8.to_le_bytes().to_vec(), // max_amendments
+        0u8.to_le_bytes().to_vec(), // amendment_count
+        0i64.to_le_bytes().to_vec(), // grace_ending
+    ]
+    .concat()
+}
+
+#[test]
+fn initialize_config_test() {
+    let (program_id, mollusk) = setup();
+    let (_, system_program_account) = mollusk_svm::program::keyed_account_for_system_program();
+
+    let admin = Pubkey::new_from_array([0x1; 32]);
+    let fee_recipient = Pubkey::new_from_array([0x2; 32]);
+    let config = Pubkey::find_program_address(&[b"config"], &program_id).0;
+    let program_data = Pubkey::find_program_address(&[program_id.as_ref()], &bpf_loader_upgradeable::id()).0;
+
+    let initialize_config = |signer: Pubkey, fee_bps: u16, config_account: AccountSharedData| {
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            &[vec![21], fee_bps.to_le_bytes().to_vec(), fee_recipient.to_bytes().to_vec()].concat(),
+            vec![
+                AccountMeta::new(signer, true),
+                AccountMeta::new(config, false),
+                AccountMeta::new_readonly(program_data, false),
+                AccountMeta::new_readonly(system_program::id(), false),
+            ],
+        );
+
+        mollusk.process_instruction(
+            &instruction,
+            &vec![
+                (signer, crate::tests::create_account(1_000_000_000, 0, &system_program::id())),
+                (config, config_account),
+                (program_data, program_data_account(&admin)),
+                (system_program::id(), system_program_account.clone()),
+            ],
+        )
+    };
+
+    // Only the upgrade authority can configure the platform
+    let result = initialize_config(Pubkey::new_from_array([0x3; 32]), 250, AccountSharedData::default());
+    assert!(result.program_result.is_err(), "Only the upgrade authority should configure the platform.");
+
+    // and only up to the maximum fee
+    let result = initialize_config(admin, 1_001, AccountSharedData::default());
+    assert!(result.program_result.is_err(), "The fee should be capped.");
+
+    // The config does not exist until the upgrade authority creates it
+    let result = initialize_config(admin, 250, AccountSharedData::default());
+    assert!(!result.program_result.is_err(), "initialize_config failed.");
+
+    let config_account = result.get_account(&config).unwrap().clone();
+    assert_eq!(config_account.owner(), &program_id, "The config should be created");
+    assert_eq!(config_account.data().len(), Config::LEN);
+    let data = &config_account.data()[HEADER_LEN..];
+    assert_eq!(data[32..34], 250u16.to_le_bytes(), "The fee should be stored");
+
+    // The config is created once
+    let result = initialize_config(admin, 100, config_account);
+    assert!(result.program_result.is_err(), "The config should only be created once.");
+}
+
+#[test]
+fn checker_fee_test() {
+    let (program_id, mollusk) = setup();
+    let (token_program, token_program_account) = mollusk_token::token::keyed_account();
+
+    let maker = Pubkey::new_from_array([0x1; 32]);
+    let maker_ta = Pubkey::new_from_array([0x3; 32]);
+    let (fundraiser, bump) =
+        Pubkey::find_program_address(&[b"fundraiser", &maker.to_bytes(), &0u64.to_le_bytes()], &program_id);
+    let mint = Pubkey::new_from_array([0x4; 32]);
+    let vault = Pubkey::new_from_array([0x5; 32]);
+    let fee_recipient = Pubkey::new_from_array([0x6; 32]);
+    let fee_recipient_ta = Pubkey::new_from_array([0x7; 32]);
+
+    let mut fundraiser_account = crate::tests::create_account(
+        mollusk.sysvars.rent.minimum_balance(Fundraiser::LEN),
+        Fundraiser::LEN,
+        &program_id,
+    );
+    fundraiser_account.set_data_from_slice(&fundraiser_data(
+        &maker,
+        &mint,
+        bump,
+        100_000,
+        mollusk.sysvars.clock.unix_timestamp - 1,
+        &[],
+    ));
+
+    // The fee has been raised to 5% since the fundraiser was created
+    let (config, config_account) = config_account(&mollusk, &program_id, 500, &fee_recipient);
+
+    let accounts = vec![
+        (maker, crate::tests::create_account(0, 0, &system_program::id())),
+        (mint, crate::tests::pack_mint(&maker, 100_000)),
+        (fundraiser, fundraiser_account),
+        (vault, crate::tests::pack_token_account(&fundraiser, &mint, 100_000)),
+        (maker_ta, crate::tests::pack_token_account(&maker, &mint, 0)),
+        (token_program, token_program_account),
+        (config, config_account),
+        (fee_recipient_ta, crate::tests::pack_token_account(&fee_recipient, &mint, 0)),
+    ];
+    let mut account_metas = vec![
+        AccountMeta::new(maker, true),
+        AccountMeta::new_readonly(mint, false),
+        AccountMeta::new(fundraiser, false),
+        AccountMeta::new(vault, false),
+        AccountMeta::new(maker_ta, false),
+        AccountMeta::new_readonly(token_program, false),
+    ];
+
+    // The fee recipient has to be paid
+    let result = mollusk.process_instruction(
+        &Instruction::new_with_bytes(program_id, &[1], account_metas.clone()),
+        &accounts[..6],
+    );
+    assert!(result.program_result.is_err(), "The checker should not skip the fee.");
+
+    account_metas.push(AccountMeta::new_readonly(config, false));
+    account_metas.push(AccountMeta::new(fee_recipient_ta, false));
+    let result = mollusk.process_and_validate_instruction(
+        &Instruction::new_with_bytes(program_id, &[1], account_metas),
+        &accounts,
+        &[Check::success()],
+    );
+
+    // The fee snapshotted at creation applies, not the one in the config now
+    let balance = |key: &Pubkey| {
+        u64::from_le_bytes(result.get_account(key).unwrap().data()[64..72].try_into().unwrap())
+    };
+    assert_eq!(balance(&fee_recipient_ta), 2_500, "The fee recipient should get 2.5%");
+    assert_eq!(balance(&maker_ta), 97_500, "The maker should get the rest");
+    assert_eq!(balance(&vault), 0);
+}
+
+#[test]
+fn release_milestone_fee_test() {
+    let (program_id, mollusk) = setup();
+    let (token_program, token_program_account) = mollusk_token::token::keyed_account();
+
+    let maker = Pubkey::new_from_array([0x1; 32]);
+    let maker_ta = Pubkey::new_from_array([0x3; 32]);
+    let (fundraiser, bump) =
+        Pubkey::find_program_address(&[b"fundraiser", &maker.to_bytes(), &0u64.to_le_bytes()], &program_id);
+    let mint = Pubkey::new_from_array([0x4; 32]);
+    let vault = Pubkey::new_from_array([0x5; 32]);
+    let fee_recipient = Pubkey::new_from_array([0x6; 32]);
+    let fee_recipient_ta = Pubkey::new_from_array([0x7; 32]);
+
+    let current_time = mollusk.sysvars.clock.unix_timestamp;
+
+    // Two tranches, 60% unlocked now and 40% in a year
+    let milestones = [
+        vec![60u8],
+        current_time.to_le_bytes().to_vec(),
+        vec![40u8],
+        (current_time + 365 * 24 * 60 * 60).to_le_bytes().to_vec(),
+    ]
+    .concat();
+
+    let mut fundraiser_account = crate::tests::create_account(
+        mollusk.sysvars.rent.minimum_balance(Fundraiser::LEN),
+        Fundraiser::LEN,
+        &program_id,
+    );
+    fundraiser_account.set_data_from_slice(&fundraiser_data(&maker, &mint, bump, 100_000, current_time - 1, &milestones));
+
+    let (config, config_account) = config_account(&mollusk, &program_id, 250, &fee_recipient);
+
+    let accounts = vec![
+        (maker, crate::tests::create_account(0, 0, &system_program::id())),
+        (mint, crate::tests::pack_mint(&maker, 100_000)),
+        (fundraiser, fundraiser_account),
+        (vault, crate::tests::pack_token_account(&fundraiser, &mint, 100_000)),
+        (maker_ta, crate::tests::pack_token_account(&maker, &mint, 0)),
+        (token_program, token_program_account),
+        (config, config_account),
+        (fee_recipient_ta, crate::tests::pack_token_account(&fee_recipient, &mint, 0)),
+    ];
+    let mut account_metas = vec![
+        AccountMeta::new(maker, true),
+        AccountMeta::new_readonly(mint, false),
+        AccountMeta::new(fundraiser, false),
+        AccountMeta::new(vault, false),
+        AccountMeta::new(maker_ta, false),
+        AccountMeta::new_readonly(token_program, false),
+    ];
+
+    // Every release pays the fee, so the fee recipient has to be passed
+    let result = mollusk.process_instruction(
+        &Instruction::new_with_bytes(program_id, &[5], account_metas.clone()),
+        &accounts[..6],
+    );
+    assert!(result.program_result.is_err(), "A release should not skip the fee.");
+
+    account_metas.push(AccountMeta::new_readonly(config, false));
+    account_metas.push(AccountMeta::new(fee_recipient_ta, false));
+    let result = mollusk.process_and_validate_instruction(
+        &Instruction::new_with_bytes(program_id, &[5], account_metas),
+        &accounts,
+        &[Check::success()],
+    );
+
+    let balance = |key: &Pubkey| {
+        u64::from_le_bytes(result.get_account(key).unwrap().data()[64..72].try_into().unwrap())
+    };
+    assert_eq!(balance(&fee_recipient_ta), 1_500, "The fee recipient should get 2.5% of the first tranche");
+    assert_eq!(balance(&maker_ta), 58_500, "The maker should get the rest of the first tranche");
+    assert_eq!(balance(&vault), 40_000, "The second tranche should stay in the vault");
+}
+
+#[test]
+fn checker_sol_fee_test() {
+    let (program_id, mollusk) = setup();
+
+    let maker = Pubkey::new_from_array([0x1; 32]);
+    let (fundraiser, bump) =
+        Pubkey::find_program_address(&[b"fundraiser", &maker.to_bytes(), &0u64.to_le_bytes()], &program_id);
+    let fee_recipient = Pubkey::new_from_array([0x6; 32]);
+
+    // A SOL fundraiser holds the 1 SOL it raised on top of its rent
+    let rent = mollusk.sysvars.rent.minimum_balance(Fundraiser::LEN);
+    let mut fundraiser_account = crate::tests::create_account(rent + 1_000_000_000, Fundraiser::LEN, &program_id);
+    fundraiser_account.set_data_from_slice(&fundraiser_data(
+        &maker,
+        &Pubkey::default(),
+        bump,
+        1_000_000_000,
+        mollusk.sysvars.clock.unix_timestamp - 1,
+        &[],
+    ));
+
+    let (config, config_account) = config_account(&mollusk, &program_id, 250, &fee_recipient);
+
+    let result = mollusk.process_and_validate_instruction(
+        &Instruction::new_with_bytes(
+            program_id,
+            &[10],
+            vec![
+                AccountMeta::new(maker, true),
+                AccountMeta::new(fundraiser, false),
+                AccountMeta::new_readonly(config, false),
+                AccountMeta::new(fee_recipient, false),
+            ],
+        ),
+        &vec![
+            (maker, crate::tests::create_account(0, 0, &system_program::id())),
+            (fundraiser, fundraiser_account),
+            (config, config_account),
+            (fee_recipient, crate::tests::create_account(1_000_000_000, 0, &system_program::id())),
+        ],
+        &[Check::success()],
+    );
+
+    assert_eq!(
+        result.get_account(&fee_recipient).unwrap().lamports(),
+        1_025_000_000,
+        "The fee recipient should get 2.5% of the raised lamports"
+    );
+    assert_eq!(
+        result.get_account(&maker).unwrap().lamports(),
+        975_000_000,
+        "The maker should get the rest"
+    );
+}
\ No newline at end of file
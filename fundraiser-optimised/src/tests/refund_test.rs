@@ -1,12 +1,13 @@
 use crate::{
-    state::{Contributor, Fundraiser},
-    tests::setup,
+    state::{AccountDiscriminator, Contributor, Fundraiser},
+    tests::{header, setup},
 };
 use mollusk_svm::result::Check;
 use pinocchio_token::state::TokenAccount;
 use solana_sdk::{
     account::{AccountSharedData, ReadableAccount},
     instruction::{AccountMeta, Instruction},
+    program_error::ProgramError,
     pubkey::Pubkey,
 };
 
@@ -16,14 +17,18 @@ fn refund_test() {
     let (token_program, token_program_account) = mollusk_token::token::keyed_account();
 
     let maker = Pubkey::new_from_array([0x1; 32]);
+    let campaign_id = 0u64;
     let contributor = Pubkey::new_from_array([0x6; 32]);
     let (fundraiser, bump) =
-        Pubkey::find_program_address(&[b"fundraiser".as_ref(), &maker.to_bytes().as_ref()], &program_id);
+        Pubkey::find_program_address(&[b"fundraiser".as_ref(), &maker.to_bytes().as_ref(), &campaign_id.to_le_bytes()], &program_id);
+    let (contributor_pda, contributor_bump) =
+        Pubkey::find_program_address(&[b"contributor".as_ref(), fundraiser.as_ref(), contributor.as_ref()], &program_id);
     let contributor_ta = Pubkey::new_from_array([0x7; 32]);
     let vault = Pubkey::new_from_array([0x8; 32]);
     let mint = Pubkey::new_from_array([0x9; 32]);
 
-    let vault_account = crate::tests::pack_token_account(&fundraiser, &mint, 100_000_000);
+    // The fixed fundraiser ended below its target, so its contributors get their tokens back
+    let vault_account = crate::tests::pack_token_account(&fundraiser, &mint, 100_000);
     let contributor_ta_account = crate::tests::pack_token_account(&contributor, &mint, 0);
     let mut contributor_account = crate::tests::create_account(
         mollusk.sysvars.rent.minimum_balance(Contributor::LEN),
@@ -40,17 +45,40 @@ fn refund_test() {
 
     fundraiser_account.set_data_from_slice(
         &[
+            header(AccountDiscriminator::Fundraiser),
             maker.to_bytes().to_vec(),
             mint.to_bytes().to_vec(),
             100_000_000u64.to_le_bytes().to_vec(), // amount_to_raise
             100_000u64.to_le_bytes().to_vec(), // current_amount
-            i64::MAX.to_le_bytes().to_vec(), // time_ending
+            0i64.to_le_bytes().to_vec(), // time_ending
             bump.to_le_bytes().to_vec(),   // bump
+            campaign_id.to_le_bytes().to_vec(), // campaign_id
+            0u8.to_le_bytes().to_vec(), // status
+            0u8.to_le_bytes().to_vec(), // milestone_count
+            vec![0u8; 36], // milestones
+            0u8.to_le_bytes().to_vec(), // milestones_released
+            0u64.to_le_bytes().to_vec(), // amount_released
+            0i64.to_le_bytes().to_vec(), // voting_period
+            0u16.to_le_bytes().to_vec(), // quorum_bps
+            0u16.to_le_bytes().to_vec(), // approval_bps
+            0u64.to_le_bytes().to_vec(), // votes_for
+            0u64.to_le_bytes().to_vec(), // votes_against
+            0u8.to_le_bytes().to_vec(), // funding_mode
+            Pubkey::default().to_bytes().to_vec(), // price_authority
+            0u8.to_le_bytes().to_vec(), // accepted_mint_count
+            vec![0u8; 96], // accepted_mints
+            vec![0u8; 24], // amounts_raised
+            vec![0u8; 25], // contribution_limits
+            Pubkey::default().to_bytes().to_vec(), // receipt_mint
+            0u16.to_le_bytes().to_vec(), // fee_bps
+            0u8.to_le_bytes().to_vec(), // max_amendments
+            0u8.to_le_bytes().to_vec(), // amendment_count
+            0i64.to_le_bytes().to_vec(), // grace_ending
         ]
         .concat(),
     );
 
-    contributor_account.set_data_from_slice(&100_000u64.to_le_bytes());
+    contributor_account.set_data_from_slice(&[header(AccountDiscriminator::Contributor), 100_000u64.to_le_bytes().to_vec(), vec![0u8], vec![0u8; 24], vec![0u8], vec![contributor_bump]].concat());
 
     let refund_data = vec![vec![3]].concat();
 
@@ -58,23 +86,40 @@ fn refund_test() {
         program_id,
         &refund_data,
         vec![
-            AccountMeta::new(fundraiser, true),
-            AccountMeta::new(contributor, false),
+            AccountMeta::new(contributor, true),
+            AccountMeta::new_readonly(maker, false),
+            AccountMeta::new(fundraiser, false),
+            AccountMeta::new(contributor_pda, false),
             AccountMeta::new(contributor_ta, false),
             AccountMeta::new(vault, true),
             AccountMeta::new_readonly(token_program, false),
+            AccountMeta::new_readonly(mint, false),
         ],
     );
 
+    let refund_accounts = vec![
+        (contributor, AccountSharedData::new(100_000_000, 0, &solana_sdk::system_program::id())),
+        (maker, AccountSharedData::default()),
+        (fundraiser, fundraiser_account),
+        (contributor_pda, contributor_account),
+        (contributor_ta, contributor_ta_account),
+        (vault, vault_account),
+        (token_program, token_program_account),
+        (mint, crate::tests::pack_mint(&maker, 100_000_000)),
+    ];
+
+    // Only the contributor can ask for their refund
+    let mut unsigned_refund_instruction = refund_instruction.clone();
+    unsigned_refund_instruction.accounts[0].is_signer = false;
+    mollusk.process_and_validate_instruction(
+        &unsigned_refund_instruction,
+        &refund_accounts,
+        &[Check::err(ProgramError::MissingRequiredSignature)],
+    );
+
     let result = mollusk.process_and_validate_instruction(
         &refund_instruction,
-        &vec![
-            (fundraiser, fundraiser_account),
-            (contributor, contributor_account),
-            (contributor_ta, contributor_ta_account),
-            (vault, vault_account),
-            (token_program, token_program_account),
-        ],
+        &refund_accounts,
         &[Check::success()],
     );
     assert!(
@@ -100,4 +145,101 @@ fn refund_test() {
         100_000,
         "Contributor should have received their refund"
     );
+}
+
+#[test]
+fn refund_flexible_test() {
+    let (program_id, mollusk) = setup();
+    let (token_program, token_program_account) = mollusk_token::token::keyed_account();
+
+    let maker = Pubkey::new_from_array([0x1; 32]);
+    let campaign_id = 0u64;
+    let contributor = Pubkey::new_from_array([0x6; 32]);
+    let (fundraiser, bump) =
+        Pubkey::find_program_address(&[b"fundraiser".as_ref(), &maker.to_bytes().as_ref(), &campaign_id.to_le_bytes()], &program_id);
+    let (contributor_pda, contributor_bump) =
+        Pubkey::find_program_address(&[b"contributor".as_ref(), fundraiser.as_ref(), contributor.as_ref()], &program_id);
+    let contributor_ta = Pubkey::new_from_array([0x7; 32]);
+    let vault = Pubkey::new_from_array([0x8; 32]);
+    let mint = Pubkey::new_from_array([0x9; 32]);
+
+    let mut contributor_account = crate::tests::create_account(
+        mollusk.sysvars.rent.minimum_balance(Contributor::LEN),
+        Contributor::LEN,
+        &program_id,
+    );
+    contributor_account.set_data_from_slice(&[header(AccountDiscriminator::Contributor), 100_000u64.to_le_bytes().to_vec(), vec![0u8], vec![0u8; 24], vec![0u8], vec![contributor_bump]].concat());
+
+    let mut fundraiser_account = AccountSharedData::new(
+        mollusk.sysvars.rent.minimum_balance(Fundraiser::LEN),
+        Fundraiser::LEN,
+        &program_id,
+    );
+    fundraiser_account.set_data_from_slice(
+        &[
+            header(AccountDiscriminator::Fundraiser),
+            maker.to_bytes().to_vec(),
+            mint.to_bytes().to_vec(),
+            100_000_000u64.to_le_bytes().to_vec(), // amount_to_raise
+            100_000u64.to_le_bytes().to_vec(), // current_amount
+            i64::MAX.to_le_bytes().to_vec(), // time_ending
+            bump.to_le_bytes().to_vec(),   // bump
+            campaign_id.to_le_bytes().to_vec(), // campaign_id
+            0u8.to_le_bytes().to_vec(), // status
+            0u8.to_le_bytes().to_vec(), // milestone_count
+            vec![0u8; 36], // milestones
+            0u8.to_le_bytes().to_vec(), // milestones_released
+            0u64.to_le_bytes().to_vec(), // amount_released
+            0i64.to_le_bytes().to_vec(), // voting_period
+            0u16.to_le_bytes().to_vec(), // quorum_bps
+            0u16.to_le_bytes().to_vec(), // approval_bps
+            0u64.to_le_bytes().to_vec(), // votes_for
+            0u64.to_le_bytes().to_vec(), // votes_against
+            1u8.to_le_bytes().to_vec(), // funding_mode (Flexible)
+            Pubkey::default().to_bytes().to_vec(), // price_authority
+            0u8.to_le_bytes().to_vec(), // accepted_mint_count
+            vec![0u8; 96], // accepted_mints
+            vec![0u8; 24], // amounts_raised
+            vec![0u8; 25], // contribution_limits
+            Pubkey::default().to_bytes().to_vec(), // receipt_mint
+            0u16.to_le_bytes().to_vec(), // fee_bps
+            0u8.to_le_bytes().to_vec(), // max_amendments
+            0u8.to_le_bytes().to_vec(), // amendment_count
+            0i64.to_le_bytes().to_vec(), // grace_ending
+        ]
+        .concat(),
+    );
+
+    let refund_instruction = Instruction::new_with_bytes(
+        program_id,
+        &[vec![3]].concat(),
+        vec![
+            AccountMeta::new(contributor, true),
+            AccountMeta::new_readonly(maker, false),
+            AccountMeta::new(fundraiser, false),
+            AccountMeta::new(contributor_pda, false),
+            AccountMeta::new(contributor_ta, false),
+            AccountMeta::new(vault, true),
+            AccountMeta::new_readonly(token_program, false),
+            AccountMeta::new_readonly(mint, false),
+        ],
+    );
+
+    let result = mollusk.process_instruction(
+        &refund_instruction,
+        &vec![
+            (contributor, AccountSharedData::new(100_000_000, 0, &solana_sdk::system_program::id())),
+            (maker, AccountSharedData::default()),
+            (fundraiser, fundraiser_account),
+            (contributor_pda, contributor_account),
+            (contributor_ta, crate::tests::pack_token_account(&contributor, &mint, 0)),
+            (vault, crate::tests::pack_token_account(&fundraiser, &mint, 100_000)),
+            (token_program, token_program_account),
+            (mint, crate::tests::pack_mint(&maker, 100_000_000)),
+        ],
+    );
+    assert!(
+        result.program_result.is_err(),
+        "A flexible fundraiser should not be refunded"
+    );
 }
\ No newline at end of file
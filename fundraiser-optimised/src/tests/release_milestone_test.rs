@@ -0,0 +1,198 @@
+use crate::{
+    state::{AccountDiscriminator, Contributor, Fundraiser, HEADER_LEN},
+    tests::{header, setup},
+};
+use mollusk_svm::result::Check;
+use pinocchio_token::state::TokenAccount;
+use solana_sdk::{
+    account::{AccountSharedData, ReadableAccount},
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    system_program,
+};
+
+#[test]
+fn release_milestone_test() {
+    let (program_id, mollusk) = setup();
+    let (token_program, token_program_account) = mollusk_token::token::keyed_account();
+
+    let maker = Pubkey::new_from_array([0x1; 32]);
+    let campaign_id = 0u64;
+    let (fundraiser, bump) =
+        Pubkey::find_program_address(&[b"fundraiser".as_ref(), &maker.to_bytes().as_ref(), &campaign_id.to_le_bytes()], &program_id);
+    let mint = Pubkey::new_from_array([0x4; 32]);
+    let vault = Pubkey::new_from_array([0x5; 32]);
+    let maker_ta = Pubkey::new_from_array([0x3; 32]);
+
+    let vault_account = crate::tests::pack_token_account(&fundraiser, &mint, 1_000_000);
+    let maker_ta_account = crate::tests::pack_token_account(&maker, &mint, 0);
+    let mint_account = crate::tests::pack_mint(&maker, 1_000_000);
+
+    let current_time = mollusk.sysvars.clock.unix_timestamp;
+
+    // Two tranches: 60% unlocked now, 40% unlocked in a year
+    let milestones = [
+        vec![60u8],
+        current_time.to_le_bytes().to_vec(),
+        vec![40u8],
+        (current_time + 365 * 24 * 60 * 60).to_le_bytes().to_vec(),
+        vec![0u8; 18],
+    ]
+    .concat();
+
+    let mut fundraiser_account = AccountSharedData::new(
+        mollusk.sysvars.rent.minimum_balance(Fundraiser::LEN),
+        Fundraiser::LEN,
+        &program_id,
+    );
+
+    fundraiser_account.set_data_from_slice(
+        &[
+            header(AccountDiscriminator::Fundraiser),
+            maker.to_bytes().to_vec(),
+            mint.to_bytes().to_vec(),
+            1_000_000u64.to_le_bytes().to_vec(), // amount_to_raise
+            1_000_000u64.to_le_bytes().to_vec(), // current_amount
+            (current_time - 1).to_le_bytes().to_vec(), // time_ending
+            bump.to_le_bytes().to_vec(),   // bump
+            campaign_id.to_le_bytes().to_vec(), // campaign_id
+            0u8.to_le_bytes().to_vec(), // status
+            2u8.to_le_bytes().to_vec(), // milestone_count
+            milestones, // milestones
+            0u8.to_le_bytes().to_vec(), // milestones_released
+            0u64.to_le_bytes().to_vec(), // amount_released
+            0i64.to_le_bytes().to_vec(), // voting_period
+            0u16.to_le_bytes().to_vec(), // quorum_bps
+            0u16.to_le_bytes().to_vec(), // approval_bps
+            0u64.to_le_bytes().to_vec(), // votes_for
+            0u64.to_le_bytes().to_vec(), // votes_against
+            0u8.to_le_bytes().to_vec(), // funding_mode
+            Pubkey::default().to_bytes().to_vec(), // price_authority
+            0u8.to_le_bytes().to_vec(), // accepted_mint_count
+            vec![0u8; 96], // accepted_mints
+            vec![0u8; 24], // amounts_raised
+            vec![0u8; 25], // contribution_limits
+            Pubkey::default().to_bytes().to_vec(), // receipt_mint
+            0u16.to_le_bytes().to_vec(), // fee_bps
+            0u8.to_le_bytes().to_vec(), // max_amendments
+            0u8.to_le_bytes().to_vec(), // amendment_count
+            0i64.to_le_bytes().to_vec(), // grace_ending
+        ]
+        .concat(),
+    );
+
+    let release_instruction = Instruction::new_with_bytes(
+        program_id,
+        &[vec![5]].concat(),
+        vec![
+            AccountMeta::new(maker, true),
+            AccountMeta::new_readonly(mint, false),
+            AccountMeta::new(fundraiser, false),
+            AccountMeta::new(vault, false),
+            AccountMeta::new(maker_ta, false),
+            AccountMeta::new_readonly(token_program, false),
+        ],
+    );
+
+    let result = mollusk.process_and_validate_instruction(
+        &release_instruction,
+        &vec![
+            (maker, AccountSharedData::new(100_000_000, 0, &system_program::id())),
+            (maker_ta, maker_ta_account),
+            (fundraiser, fundraiser_account),
+            (vault, vault_account),
+            (token_program, token_program_account.clone()),
+            (mint, mint_account.clone()),
+        ],
+        &[Check::success()],
+    );
+    assert!(
+        !result.program_result.is_err(),
+        "process_release_milestone_instruction failed."
+    );
+    println!("Compute Units: {}", result.compute_units_consumed);
+
+    let vault_result = result
+        .get_account(&vault)
+        .expect("Failed to find vault account");
+    let vault_ta = unsafe { TokenAccount::from_bytes(vault_result.data()) };
+    assert_eq!(vault_ta.amount(), 400_000, "Only the first tranche should leave the vault");
+
+    let maker_ta_result = result
+        .get_account(&maker_ta)
+        .expect("Failed to find maker_ta account");
+    let maker_ta_data = unsafe { TokenAccount::from_bytes(maker_ta_result.data()) };
+    assert_eq!(maker_ta_data.amount(), 600_000, "Maker should have received the first tranche");
+
+    let fundraiser_result = result
+        .get_account(&fundraiser)
+        .expect("Failed to find fundraiser account")
+        .clone();
+    let data = &fundraiser_result.data()[HEADER_LEN..];
+    assert_eq!(data[135], 1, "One milestone should be released");
+    assert_eq!(
+        u64::from_le_bytes(data[136..144].try_into().unwrap()),
+        600_000,
+        "Released amount should be tracked"
+    );
+
+    // The second tranche is still locked
+    let result = mollusk.process_instruction(
+        &release_instruction,
+        &vec![
+            (maker, AccountSharedData::new(100_000_000, 0, &system_program::id())),
+            (maker_ta, maker_ta_result.clone()),
+            (fundraiser, fundraiser_result.clone()),
+            (vault, vault_result.clone()),
+            (token_program, token_program_account.clone()),
+            (mint, mint_account.clone()),
+        ],
+    );
+    assert!(
+        result.program_result.is_err(),
+        "A locked milestone should not be released"
+    );
+
+    // The vault is now below the target and the deadline has passed, but what is left belongs to the second tranche
+    let contributor = Pubkey::new_from_array([0x6; 32]);
+    let (contributor_pda, contributor_bump) =
+        Pubkey::find_program_address(&[b"contributor".as_ref(), fundraiser.as_ref(), contributor.as_ref()], &program_id);
+    let contributor_ta = Pubkey::new_from_array([0x7; 32]);
+    let mut contributor_account = crate::tests::create_account(
+        mollusk.sysvars.rent.minimum_balance(Contributor::LEN),
+        Contributor::LEN,
+        &program_id,
+    );
+    contributor_account.set_data_from_slice(&[header(AccountDiscriminator::Contributor), 400_000u64.to_le_bytes().to_vec(), vec![0u8], vec![0u8; 24], vec![0u8], vec![contributor_bump]].concat());
+
+    let result = mollusk.process_instruction(
+        &Instruction::new_with_bytes(
+            program_id,
+            &[3],
+            vec![
+                AccountMeta::new(contributor, true),
+                AccountMeta::new_readonly(maker, false),
+                AccountMeta::new(fundraiser, false),
+                AccountMeta::new(contributor_pda, false),
+                AccountMeta::new(contributor_ta, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(token_program, false),
+                AccountMeta::new_readonly(mint, false),
+            ],
+        ),
+        &vec![
+            (contributor, AccountSharedData::new(100_000_000, 0, &system_program::id())),
+            (maker, AccountSharedData::default()),
+            (fundraiser, fundraiser_result),
+            (contributor_pda, contributor_account),
+            (contributor_ta, crate::tests::pack_token_account(&contributor, &mint, 0)),
+            (vault, vault_result),
+            (token_program, token_program_account),
+            (mint, mint_account),
+        ],
+    );
+    assert!(
+        result.program_result.is_err(),
+        "Contributors should not be refunded once a milestone has been released"
+    );
+}
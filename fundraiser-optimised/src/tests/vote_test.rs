@@ -0,0 +1,159 @@
+use crate::{
+    state::{AccountDiscriminator, Contributor, Fundraiser, HEADER_LEN},
+    tests::{header, setup},
+};
+use mollusk_svm::result::Check;
+use solana_sdk::{
+    account::{AccountSharedData, ReadableAccount, WritableAccount},
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    system_program,
+};
+
+#[test]
+fn vote_test() {
+    let (program_id, mollusk) = setup();
+
+    let maker = Pubkey::new_from_array([0x1; 32]);
+    let signer = Pubkey::new_from_array([0x2; 32]);
+    let campaign_id = 0u64;
+    let (fundraiser, bump) =
+        Pubkey::find_program_address(&[b"fundraiser".as_ref(), &maker.to_bytes().as_ref(), &campaign_id.to_le_bytes()], &program_id);
+    let (contributor, contributor_bump) = Pubkey::find_program_address(
+        &[
+            b"contributor",
+            fundraiser.as_ref(),
+            signer.to_bytes().as_ref(),
+        ],
+        &program_id,
+    );
+    let mint = Pubkey::new_from_array([0x4; 32]);
+
+    let current_time = mollusk.sysvars.clock.unix_timestamp;
+
+    // A single tranche that unlocks in an hour, backers vote during the day before
+    let milestones = [
+        vec![100u8],
+        (current_time + 60 * 60).to_le_bytes().to_vec(),
+        vec![0u8; 27],
+    ]
+    .concat();
+
+    let mut fundraiser_account = AccountSharedData::new(
+        mollusk.sysvars.rent.minimum_balance(Fundraiser::LEN),
+        Fundraiser::LEN,
+        &program_id,
+    );
+
+    fundraiser_account.set_data_from_slice(
+        &[
+            header(AccountDiscriminator::Fundraiser),
+            maker.to_bytes().to_vec(),
+            mint.to_bytes().to_vec(),
+            1_000_000u64.to_le_bytes().to_vec(), // amount_to_raise
+            1_000_000u64.to_le_bytes().to_vec(), // current_amount
+            (current_time - 1).to_le_bytes().to_vec(), // time_ending
+            bump.to_le_bytes().to_vec(),   // bump
+            campaign_id.to_le_bytes().to_vec(), // campaign_id
+            0u8.to_le_bytes().to_vec(), // status
+            1u8.to_le_bytes().to_vec(), // milestone_count
+            milestones, // milestones
+            0u8.to_le_bytes().to_vec(), // milestones_released
+            0u64.to_le_bytes().to_vec(), // amount_released
+            (24 * 60 * 60i64).to_le_bytes().to_vec(), // voting_period
+            5_000u16.to_le_bytes().to_vec(), // quorum_bps
+            5_000u16.to_le_bytes().to_vec(), // approval_bps
+            0u64.to_le_bytes().to_vec(), // votes_for
+            0u64.to_le_bytes().to_vec(), // votes_against
+            0u8.to_le_bytes().to_vec(), // funding_mode
+            Pubkey::default().to_bytes().to_vec(), // price_authority
+            0u8.to_le_bytes().to_vec(), // accepted_mint_count
+            vec![0u8; 96], // accepted_mints
+            vec![0u8; 24], // amounts_raised
+            vec![0u8; 25], // contribution_limits
+            Pubkey::default().to_bytes().to_vec(), // receipt_mint
+            0u16.to_le_bytes().to_vec(), // fee_bps
+            0u8.to_le_bytes().to_vec(), // max_amendments
+            0u8.to_le_bytes().to_vec(), // amendment_count
+            0i64.to_le_bytes().to_vec(), // grace_ending
+        ]
+        .concat(),
+    );
+
+    let mut contributor_account = crate::tests::create_account(
+        mollusk.sysvars.rent.minimum_balance(Contributor::LEN),
+        Contributor::LEN,
+        &program_id,
+    );
+    contributor_account.set_data_from_slice(&[header(AccountDiscriminator::Contributor), 600_000u64.to_le_bytes().to_vec(), vec![0u8], vec![0u8; 24], vec![0u8], vec![contributor_bump]].concat());
+
+    let vote_instruction = Instruction::new_with_bytes(
+        program_id,
+        &[vec![6], vec![1]].concat(),
+        vec![
+            AccountMeta::new_readonly(signer, true),
+            AccountMeta::new(fundraiser, false),
+            AccountMeta::new(contributor, false),
+        ],
+    );
+
+    // After an amendment backers can still withdraw what they would vote with, so voting waits for the grace period
+    let mut amended_fundraiser_account = fundraiser_account.clone();
+    amended_fundraiser_account.data_as_mut_slice()[Fundraiser::LEN - 8..]
+        .copy_from_slice(&(current_time + 60).to_le_bytes());
+    let result = mollusk.process_instruction(
+        &vote_instruction,
+        &vec![
+            (signer, AccountSharedData::new(100_000_000, 0, &system_program::id())),
+            (fundraiser, amended_fundraiser_account),
+            (contributor, contributor_account.clone()),
+        ],
+    );
+    assert!(
+        result.program_result.is_err(),
+        "Votes should wait for the grace period to end"
+    );
+
+    let result = mollusk.process_and_validate_instruction(
+        &vote_instruction,
+        &vec![
+            (signer, AccountSharedData::new(100_000_000, 0, &system_program::id())),
+            (fundraiser, fundraiser_account),
+            (contributor, contributor_account),
+        ],
+        &[Check::success()],
+    );
+    assert!(!result.program_result.is_err(), "process_vote failed.");
+    println!("Compute Units: {}", result.compute_units_consumed);
+
+    let fundraiser_result = result
+        .get_account(&fundraiser)
+        .expect("Failed to find fundraiser account")
+        .clone();
+    let data = &fundraiser_result.data()[HEADER_LEN..];
+    assert_eq!(
+        u64::from_le_bytes(data[156..164].try_into().unwrap()),
+        600_000,
+        "The vote should be weighted by the contribution"
+    );
+
+    let contributor_result = result
+        .get_account(&contributor)
+        .expect("Failed to find contributor account")
+        .clone();
+    assert_eq!(contributor_result.data()[HEADER_LEN + 8], 1, "The vote should be recorded on the contributor");
+
+    // A contributor only gets one vote per milestone
+    let result = mollusk.process_instruction(
+        &vote_instruction,
+        &vec![
+            (signer, AccountSharedData::new(100_000_000, 0, &system_program::id())),
+            (fundraiser, fundraiser_result),
+            (contributor, contributor_result),
+        ],
+    );
+    assert!(
+        result.program_result.is_err(),
+        "A second vote on the same milestone should be rejected"
+    );
+}
\ No newline at end of file
@@ -0,0 +1,217 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{AccountMeta, Instruction, Signer},
+    program::invoke_signed,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    ProgramResult,
+};
+use pinocchio_token::state::TokenAccount;
+
+// pinocchio-token always targets the legacy token program, so transfers that
+// also have to work with Token-2022 mints are built here
+
+pub const TOKEN_2022_PROGRAM_ID: Pubkey =
+    five8_const::decode_32_const("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
+
+// Token-2022 mints store their extensions after the account type byte at this offset
+const MINT_EXTENSIONS_OFFSET: usize = 165 + 1;
+const TRANSFER_HOOK_EXTENSION: u16 = 14;
+
+// --Accounts--
+// from: writable
+// mint: readonly
+// to: writable
+// authority: signer
+//
+// --Data--
+// discriminator: u8 (12)
+// amount: u64
+// decimals: u8
+pub struct TransferChecked<'a> {
+    pub from: &'a AccountInfo,
+    pub mint: &'a AccountInfo,
+    pub to: &'a AccountInfo,
+    pub authority: &'a AccountInfo,
+    pub token_program: &'a AccountInfo,
+    pub amount: u64,
+    pub decimals: u8,
+}
+
+impl TransferChecked<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        if !is_token_program(self.token_program.key()) {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+
+        let account_metas: [AccountMeta; 4] = [
+            AccountMeta::writable(self.from.key()),
+            AccountMeta::readonly(self.mint.key()),
+            AccountMeta::writable(self.to.key()),
+            AccountMeta::readonly_signer(self.authority.key()),
+        ];
+
+        let mut instruction_data = [0u8; 10];
+        instruction_data[0] = 12;
+        instruction_data[1..9].copy_from_slice(&self.amount.to_le_bytes());
+        instruction_data[9] = self.decimals;
+
+        let instruction = Instruction {
+            program_id: self.token_program.key(),
+            accounts: &account_metas,
+            data: &instruction_data,
+        };
+
+        invoke_signed(&instruction, &[self.from, self.mint, self.to, self.authority], signers)
+    }
+}
+
+// --Accounts--
+// mint: writable
+// to: writable
+// authority: signer
+//
+// --Data--
+// discriminator: u8 (14)
+// amount: u64
+// decimals: u8
+pub struct MintToChecked<'a> {
+    pub mint: &'a AccountInfo,
+    pub to: &'a AccountInfo,
+    pub authority: &'a AccountInfo,
+    pub token_program: &'a AccountInfo,
+    pub amount: u64,
+    pub decimals: u8,
+}
+
+impl MintToChecked<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        if !is_token_program(self.token_program.key()) {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+
+        let account_metas: [AccountMeta; 3] = [
+            AccountMeta::writable(self.mint.key()),
+            AccountMeta::writable(self.to.key()),
+            AccountMeta::readonly_signer(self.authority.key()),
+        ];
+
+        let mut instruction_data = [0u8; 10];
+        instruction_data[0] = 14;
+        instruction_data[1..9].copy_from_slice(&self.amount.to_le_bytes());
+        instruction_data[9] = self.decimals;
+
+        let instruction = Instruction {
+            program_id: self.token_program.key(),
+            accounts: &account_metas,
+            data: &instruction_data,
+        };
+
+        invoke_signed(&instruction, &[self.mint, self.to, self.authority], signers)
+    }
+}
+
+// --Accounts--
+// account: writable
+// mint: writable
+// authority: signer
+//
+// --Data--
+// discriminator: u8 (15)
+// amount: u64
+// decimals: u8
+pub struct BurnChecked<'a> {
+    pub account: &'a AccountInfo,
+    pub mint: &'a AccountInfo,
+    pub authority: &'a AccountInfo,
+    pub token_program: &'a AccountInfo,
+    pub amount: u64,
+    pub decimals: u8,
+}
+
+impl BurnChecked<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        if !is_token_program(self.token_program.key()) {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+
+        let account_metas: [AccountMeta; 3] = [
+            AccountMeta::writable(self.account.key()),
+            AccountMeta::writable(self.mint.key()),
+            AccountMeta::readonly_signer(self.authority.key()),
+        ];
+
+        let mut instruction_data = [0u8; 10];
+        instruction_data[0] = 15;
+        instruction_data[1..9].copy_from_slice(&self.amount.to_le_bytes());
+        instruction_data[9] = self.decimals;
+
+        let instruction = Instruction {
+            program_id: self.token_program.key(),
+            accounts: &account_metas,
+            data: &instruction_data,
+        };
+
+        invoke_signed(&instruction, &[self.account, self.mint, self.authority], signers)
+    }
+}
+
+pub fn is_token_program(program_id: &Pubkey) -> bool {
+    program_id == &pinocchio_token::ID || program_id == &TOKEN_2022_PROGRAM_ID
+}
+
+// Reads a token account of either token program, it has to hold `mint` for `owner`
+pub fn load_token_account<'a>(account: &'a AccountInfo, mint: &Pubkey, owner: &Pubkey) -> &'a TokenAccount {
+    assert!(
+        is_token_program(account.owner()) && account.data_len() >= TokenAccount::LEN,
+        "Invalid token account"
+    );
+
+    let token_account = unsafe { TokenAccount::from_bytes(account.borrow_data_unchecked()) };
+    assert!(
+        token_account.mint() == mint && token_account.owner() == owner,
+        "Invalid token account"
+    );
+
+    token_account
+}
+
+// Walks the Token-2022 extension TLV entries of a mint looking for a transfer hook with a program set
+pub fn has_transfer_hook(mint: &AccountInfo) -> bool {
+    if mint.owner() != &TOKEN_2022_PROGRAM_ID {
+        return false;
+    }
+
+    let data = unsafe { mint.borrow_data_unchecked() };
+    let mut offset = MINT_EXTENSIONS_OFFSET;
+
+    while offset + 4 <= data.len() {
+        let extension_type = u16::from_le_bytes([data[offset], data[offset + 1]]);
+        let length = u16::from_le_bytes([data[offset + 2], data[offset + 3]]) as usize;
+        let value = offset + 4;
+
+        if extension_type == TRANSFER_HOOK_EXTENSION && value + 64 <= data.len() {
+            // authority: Pubkey, program_id: Pubkey (all zeroes when unset)
+            return data[value + 32..value + 64].iter().any(|byte| *byte != 0);
+        }
+
+        offset = value + length;
+    }
+
+    false
+}
\ No newline at end of file